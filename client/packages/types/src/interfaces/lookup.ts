@@ -2048,6 +2048,8 @@ export default {
       "RevertTimedOut",
       "RevertKill",
       "RevertMisbehaviour",
+      "RevertGatewayInactive",
+      "Scheduled",
     ],
   },
   /** Lookup259: t3rn_primitives::volatile::LocalState */
@@ -2939,6 +2939,8 @@ declare module "@polkadot/types/lookup" {
     readonly isRevertTimedOut: boolean;
     readonly isRevertKill: boolean;
     readonly isRevertMisbehaviour: boolean;
+    readonly isRevertGatewayInactive: boolean;
+    readonly isScheduled: boolean;
     readonly type:
       | "Requested"
       | "PendingBidding"
@@ -2951,7 +2953,9 @@ declare module "@polkadot/types/lookup" {
       | "Reverted"
       | "RevertTimedOut"
       | "RevertKill"
-      | "RevertMisbehaviour";
+      | "RevertMisbehaviour"
+      | "RevertGatewayInactive"
+      | "Scheduled";
   }
 
   /** @name T3rnPrimitivesVolatileLocalState (259) */
@@ -0,0 +1,1245 @@
+//! Ethereum Beacon Finality Verifier Pallet
+//!
+//! This pallet is an on-chain light client of the Ethereum beacon chain, following the Altair
+//! light client sync protocol.
+//!
+//! Starting from a trusted checkpoint and its sync committee given on registration, it imports
+//! finality updates signed by the current (or next) sync committee. An update is accepted once the
+//! aggregated BLS signature of at least two thirds of the committee over the attested header
+//! verifies, and the finalized header is proven against the attested state root. Updates may
+//! carry the next sync committee, which becomes the current one when the finalized header enters
+//! the following sync committee period.
+//!
+//! Each finalized header brings its execution payload header, proven against the beacon block
+//! body. Side effects are confirmed by proving the inclusion of a transaction receipt against the
+//! finalized execution payloads receipts root, using the snowfork MPT proof verification. As for
+//! the PoW light client, only logs of the contracts named in the gateway's XDNS security
+//! coordinates are accepted.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use crate::{
+    ssz::{compute_domain, compute_signing_root, is_valid_merkle_branch, HashTreeRoot},
+    types::*,
+    weights::WeightInfo,
+};
+use codec::{Decode, Encode};
+use frame_support::{ensure, pallet_prelude::*};
+use frame_system::{ensure_signed, RawOrigin};
+use milagro_bls::{AggregateSignature, PublicKey};
+use pallet_ethereum_finality_verifier::side_effects::{self, DecodeLogError};
+use snowbridge_ethereum::{mpt, HeaderId, H256};
+use sp_runtime::traits::BadOrigin;
+use sp_std::vec::Vec;
+use t3rn_primitives::portal::LightClient;
+
+#[cfg(test)]
+mod mock;
+
+pub mod ssz;
+pub mod types;
+/// Pallet containing weights for this pallet.
+pub mod weights;
+
+// Re-export in crate namespace for `construct_runtime!`
+pub use pallet::*;
+
+const SLOTS_PER_EPOCH: u64 = 32;
+const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: u64 = 256;
+const DOMAIN_SYNC_COMMITTEE: [u8; 4] = [7, 0, 0, 0];
+
+/// Generalized indices of the proven fields in the `BeaconState` and `BeaconBlockBody` containers
+const FINALIZED_ROOT_INDEX: u64 = 105;
+const CURRENT_SYNC_COMMITTEE_INDEX: u64 = 54;
+const NEXT_SYNC_COMMITTEE_INDEX: u64 = 55;
+const EXECUTION_PAYLOAD_INDEX: u64 = 25;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Number of validators in a sync committee of the bridged network.
+        #[pallet::constant]
+        type SyncCommitteeSize: Get<u32>;
+
+        /// Maximal number of finalized execution headers to keep in the storage.
+        ///
+        /// Side effects can only be confirmed against execution headers that weren't pruned yet.
+        #[pallet::constant]
+        type ExecutionHeadersToStore: Get<u32>;
+
+        /// Weights gathered through benchmarking.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn integrity_test() {
+            // Execution headers are kept in a ring buffer of this size
+            assert!(T::ExecutionHeadersToStore::get() > 0);
+        }
+    }
+
+    /// Genesis validators root and fork schedule of the bridged network.
+    #[pallet::storage]
+    #[pallet::getter(fn get_beacon_config)]
+    pub type BeaconConfigMap<T: Config> = StorageMap<_, Blake2_256, ChainId, BeaconConfig>;
+
+    /// Sync committee of the current sync committee period.
+    #[pallet::storage]
+    #[pallet::getter(fn get_current_sync_committee)]
+    pub type CurrentSyncCommitteeMap<T: Config> = StorageMap<_, Blake2_256, ChainId, SyncCommittee>;
+
+    /// Sync committee of the following period, once an update proved it.
+    #[pallet::storage]
+    #[pallet::getter(fn get_next_sync_committee)]
+    pub type NextSyncCommitteeMap<T: Config> = StorageMap<_, Blake2_256, ChainId, SyncCommittee>;
+
+    /// Sync committee period the current sync committee signs for.
+    #[pallet::storage]
+    #[pallet::getter(fn get_sync_committee_period)]
+    pub type SyncCommitteePeriodMap<T: Config> =
+        StorageMap<_, Blake2_256, ChainId, u64, ValueQuery>;
+
+    /// The latest finalized beacon header.
+    #[pallet::storage]
+    #[pallet::getter(fn get_finalized_beacon_header)]
+    pub type FinalizedBeaconHeaderMap<T: Config> =
+        StorageMap<_, Blake2_256, ChainId, FinalizedBeaconHeader>;
+
+    /// Finalized execution headers by block hash.
+    #[pallet::storage]
+    #[pallet::getter(fn get_execution_header)]
+    pub type ExecutionHeaders<T: Config> =
+        StorageDoubleMap<_, Blake2_256, ChainId, Identity, H256, ExecutionHeaderInfo>;
+
+    /// The latest finalized execution header.
+    #[pallet::storage]
+    #[pallet::getter(fn get_latest_execution_header)]
+    pub type LatestExecutionHeaderMap<T: Config> = StorageMap<_, Blake2_256, ChainId, HeaderId>;
+
+    /// A ring buffer of the imported execution header hashes, ordered by insertion time.
+    #[pallet::storage]
+    pub(super) type ExecutionHeaderRing<T: Config> =
+        StorageDoubleMap<_, Blake2_256, ChainId, Identity, u32, H256>;
+
+    /// Current ring buffer position.
+    #[pallet::storage]
+    pub(super) type ExecutionHeaderRingPointer<T: Config> =
+        StorageMap<_, Blake2_256, ChainId, u32, ValueQuery>;
+
+    /// Optional pallet owner.
+    ///
+    /// Gateway owner has a right to halt all operations of the gateway and then resume it.
+    #[pallet::storage]
+    pub(super) type PalletOwnerMap<T: Config> = StorageMap<_, Blake2_256, ChainId, T::AccountId>;
+
+    /// If true, all gateway transactions are failed immediately.
+    #[pallet::storage]
+    pub(super) type IsHaltedMap<T: Config> = StorageMap<_, Blake2_256, ChainId, bool>;
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The gateway has been initialized already
+        AlreadyInitialized,
+        /// The gateway hasn't been initialized
+        NotInitialized,
+        /// The given bytes couldn't be decoded as registration data
+        RegistrationDataDecodingError,
+        /// The given bytes couldn't be decoded as header data
+        HeaderDataDecodingError,
+        /// The inclusion data couldn't be decoded
+        InclusionDataDecodeError,
+        /// The submitted range is empty
+        EmptyRangeSubmitted,
+        /// The fork schedule doesn't cover the slot
+        UnknownFork,
+        /// The sync committee doesn't have the expected size
+        InvalidSyncCommitteeSize,
+        /// The sync committee isn't part of the beacon state
+        InvalidSyncCommitteeProof,
+        /// The sync committee conflicts with the one already known for the period
+        ConflictingSyncCommittee,
+        /// The next sync committee can only be proven from the current period
+        InvalidSyncCommitteeUpdatePeriod,
+        /// The sync committee signing the update isn't known yet
+        SyncCommitteeNotKnown,
+        /// The update is signed by a sync committee more than one period ahead
+        SkippedSyncCommitteePeriod,
+        /// The slots of the signature, attested and finalized headers aren't ordered
+        InvalidUpdateSlots,
+        /// The finalized header isn't newer than the stored one
+        StaleUpdate,
+        /// The finalized header isn't part of the attested beacon state
+        InvalidFinalityProof,
+        /// The execution payload header isn't part of the beacon block body
+        InvalidExecutionPayloadProof,
+        /// The sync committee participation bits don't match the committee size
+        InvalidSyncCommitteeBits,
+        /// Less than two thirds of the sync committee signed the update
+        InsufficientSyncCommitteeParticipants,
+        /// A sync committee public key couldn't be decoded
+        InvalidBLSPublicKey,
+        /// The aggregated signature couldn't be decoded
+        InvalidBLSSignature,
+        /// The aggregated signature doesn't match the attested header
+        SignatureVerificationFailed,
+        /// The execution header couldn't be found in storage
+        UnknownExecutionHeader,
+        /// The transaction was executed before the side effect was created
+        ExecutedBeforeCreation,
+        /// The receipt proof doesn't match the execution headers receipts root
+        InvalidReceiptProof,
+        /// The proven transaction failed
+        TransactionFailed,
+        /// The log is not part of the proven receipt
+        LogNotIncluded,
+        /// The logs paramaters couldn't be decoded
+        EventDecodingFailed,
+        /// The decoded amount doesn't fit the gateways value type
+        AmountOverflow,
+        /// The side effect is not known for this vendor
+        UnkownSideEffect,
+        /// The log wasn't emitted by the contract the gateway's XDNS record expects
+        UnexpectedEmitter,
+        /// The gateway is currently halted
+        Halted,
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn initialize(
+        origin: T::Origin,
+        gateway_id: ChainId,
+        encoded_registration_data: Vec<u8>,
+    ) -> Result<(), DispatchError> {
+        ensure_owner_or_root::<T>(origin, gateway_id)?;
+        ensure!(
+            !<BeaconConfigMap<T>>::contains_key(gateway_id),
+            Error::<T>::AlreadyInitialized
+        );
+        let registration_data: BeaconRegistrationData<T::AccountId> =
+            Decode::decode(&mut &*encoded_registration_data)
+                .map_err(|_| Error::<T>::RegistrationDataDecodingError)?;
+
+        let header = registration_data.header;
+        ensure!(
+            registration_data
+                .config
+                .fork_version(compute_epoch(header.slot))
+                .is_some(),
+            Error::<T>::UnknownFork
+        );
+        ensure_sync_committee_size::<T>(&registration_data.current_sync_committee)?;
+        ensure!(
+            is_valid_merkle_branch(
+                registration_data.current_sync_committee.hash_tree_root(),
+                &registration_data.current_sync_committee_branch,
+                CURRENT_SYNC_COMMITTEE_INDEX,
+                header.state_root,
+            ),
+            Error::<T>::InvalidSyncCommitteeProof
+        );
+        verify_execution_payload::<T>(
+            &registration_data.execution_header,
+            &registration_data.execution_branch,
+            &header,
+        )?;
+
+        <BeaconConfigMap<T>>::insert(gateway_id, registration_data.config);
+        <CurrentSyncCommitteeMap<T>>::insert(gateway_id, registration_data.current_sync_committee);
+        <SyncCommitteePeriodMap<T>>::insert(gateway_id, compute_period(header.slot));
+        <FinalizedBeaconHeaderMap<T>>::insert(
+            gateway_id,
+            FinalizedBeaconHeader {
+                root: header.hash_tree_root(),
+                slot: header.slot,
+            },
+        );
+        import_execution_header::<T>(gateway_id, &registration_data.execution_header);
+        <PalletOwnerMap<T>>::insert(gateway_id, registration_data.owner);
+        <IsHaltedMap<T>>::insert(gateway_id, false);
+
+        Ok(())
+    }
+
+    /// Owner named by the encoded registration data, if it decodes.
+    pub fn registration_owner(encoded_registration_data: &[u8]) -> Option<T::AccountId> {
+        BeaconRegistrationData::<T::AccountId>::decode(&mut &*encoded_registration_data)
+            .ok()
+            .map(|registration_data| registration_data.owner)
+    }
+
+    /// Change `PalletOwner`.
+    ///
+    /// May only be called either by root, or by `PalletOwner`.
+    pub fn set_owner(
+        origin: T::Origin,
+        gateway_id: ChainId,
+        encoded_new_owner: Vec<u8>,
+    ) -> Result<(), DispatchError> {
+        ensure_owner_or_root::<T>(origin, gateway_id)?;
+        let new_owner: Option<T::AccountId> = Decode::decode(&mut &*encoded_new_owner)
+            .map_err(|_| DispatchError::Other("New Owner decoding error"))?;
+
+        match new_owner {
+            Some(new_owner) => {
+                <PalletOwnerMap<T>>::insert(gateway_id, &new_owner);
+                log::info!("Setting pallet Owner to: {:?}", new_owner);
+            },
+            None => {
+                <PalletOwnerMap<T>>::remove(gateway_id);
+                log::info!("Removed Owner of pallet.");
+            },
+        }
+
+        Ok(())
+    }
+
+    /// Halt or resume all operations of the gateway.
+    ///
+    /// May only be called either by root, or by `PalletOwner`.
+    pub fn set_operational(
+        origin: T::Origin,
+        operational: bool,
+        gateway_id: ChainId,
+    ) -> Result<(), DispatchError> {
+        ensure_owner_or_root::<T>(origin, gateway_id)?;
+        <IsHaltedMap<T>>::insert(gateway_id, !operational);
+
+        Ok(())
+    }
+
+    /// Imports a range of finality updates, ordered by slot.
+    /// Returns the encoded height of the latest finalized execution header.
+    pub fn submit_headers(
+        origin: T::Origin,
+        gateway_id: ChainId,
+        encoded_header_data: Vec<u8>,
+    ) -> Result<Vec<u8>, DispatchError> {
+        ensure_operational::<T>(gateway_id)?;
+        if ensure_owner_or_root::<T>(origin.clone(), gateway_id).is_err() {
+            ensure_signed(origin)?;
+        }
+
+        let data: BeaconHeaderData = Decode::decode(&mut &*encoded_header_data)
+            .map_err(|_| Error::<T>::HeaderDataDecodingError)?;
+        ensure!(!data.updates.is_empty(), Error::<T>::EmptyRangeSubmitted);
+
+        for update in data.updates {
+            import_finality_update::<T>(gateway_id, update)?;
+        }
+
+        <LatestExecutionHeaderMap<T>>::get(gateway_id)
+            .map(|header_id| header_id.number.encode())
+            .ok_or_else(|| Error::<T>::NotInitialized.into())
+    }
+
+    pub fn confirm_and_decode_payload_params(
+        gateway_id: ChainId,
+        encoded_inclusion_data: Vec<u8>,
+        submission_target_height: Vec<u8>,
+        value_abi_unsigned_type: &[u8],
+        security_coordinates: &[u8],
+        side_effect_id: [u8; 4],
+    ) -> Result<(Vec<Vec<u8>>, Vec<u8>), DispatchError> {
+        let inclusion_data: EthereumInclusionData =
+            Decode::decode(&mut &*encoded_inclusion_data)
+                .map_err(|_| Error::<T>::InclusionDataDecodeError)?;
+
+        // only execution headers of finalized beacon blocks are stored
+        let execution_header = <ExecutionHeaders<T>>::get(gateway_id, inclusion_data.block_hash)
+            .ok_or(Error::<T>::UnknownExecutionHeader)?;
+
+        // ensures old equal side_effects can't be replayed
+        let submission_target: u64 = Decode::decode(&mut &*submission_target_height)
+            .map_err(|_| Error::<T>::ExecutedBeforeCreation)?;
+        ensure!(
+            submission_target < execution_header.block_number,
+            Error::<T>::ExecutedBeforeCreation
+        );
+
+        let receipt = match mpt::check_receipt_proof(
+            execution_header.receipts_root,
+            &inclusion_data.receipt_proof,
+        ) {
+            Some(Ok(receipt)) => receipt,
+            _ => return Err(Error::<T>::InvalidReceiptProof.into()),
+        };
+        ensure!(
+            receipt.post_state_or_status == [1u8],
+            Error::<T>::TransactionFailed
+        );
+        ensure!(
+            receipt.contains_log(&inclusion_data.log),
+            Error::<T>::LogNotIncluded
+        );
+
+        side_effects::decode_log(
+            &side_effect_id,
+            inclusion_data.log,
+            value_abi_unsigned_type,
+            security_coordinates,
+        )
+        .map_err(|err| Error::<T>::from(err).into())
+    }
+
+    pub fn get_latest_finalized_header(gateway_id: ChainId) -> Option<Vec<u8>> {
+        <LatestExecutionHeaderMap<T>>::get(gateway_id).map(|header_id| header_id.hash.encode())
+    }
+
+    pub fn get_latest_finalized_height(gateway_id: ChainId) -> Option<Vec<u8>> {
+        <LatestExecutionHeaderMap<T>>::get(gateway_id).map(|header_id| header_id.number.encode())
+    }
+
+    /// Height of the oldest execution header left in the ring buffer
+    pub fn get_oldest_provable_height(gateway_id: ChainId) -> Option<Vec<u8>> {
+        let pointer = <ExecutionHeaderRingPointer<T>>::get(gateway_id);
+        // until the buffer wrapped around, the slot at the pointer is empty
+        let hash = <ExecutionHeaderRing<T>>::get(gateway_id, pointer)
+            .or_else(|| <ExecutionHeaderRing<T>>::get(gateway_id, 0))?;
+        <ExecutionHeaders<T>>::get(gateway_id, hash).map(|header| header.block_number.encode())
+    }
+}
+
+impl<T: Config> LightClient<T> for Pallet<T> {
+    fn initialize(
+        origin: T::Origin,
+        gateway_id: ChainId,
+        encoded_registration_data: Vec<u8>,
+    ) -> Result<(), DispatchError> {
+        Self::initialize(origin, gateway_id, encoded_registration_data)
+    }
+
+    fn registration_owner(encoded_registration_data: &[u8]) -> Option<T::AccountId> {
+        Self::registration_owner(encoded_registration_data)
+    }
+
+    fn set_owner(
+        origin: T::Origin,
+        gateway_id: ChainId,
+        encoded_new_owner: Vec<u8>,
+    ) -> Result<(), DispatchError> {
+        Self::set_owner(origin, gateway_id, encoded_new_owner)
+    }
+
+    fn set_operational(
+        origin: T::Origin,
+        operational: bool,
+        gateway_id: ChainId,
+    ) -> Result<(), DispatchError> {
+        Self::set_operational(origin, operational, gateway_id)
+    }
+
+    fn submit_headers(
+        origin: T::Origin,
+        gateway_id: ChainId,
+        encoded_header_data: Vec<u8>,
+    ) -> Result<Vec<u8>, DispatchError> {
+        Self::submit_headers(origin, gateway_id, encoded_header_data)
+    }
+
+    fn get_latest_finalized_header(gateway_id: ChainId) -> Option<Vec<u8>> {
+        Self::get_latest_finalized_header(gateway_id)
+    }
+
+    fn get_latest_finalized_height(gateway_id: ChainId) -> Option<Vec<u8>> {
+        Self::get_latest_finalized_height(gateway_id)
+    }
+
+    fn get_oldest_provable_height(gateway_id: ChainId) -> Option<Vec<u8>> {
+        Self::get_oldest_provable_height(gateway_id)
+    }
+
+    fn confirm_and_decode_payload_params(
+        gateway_id: ChainId,
+        encoded_inclusion_data: Vec<u8>,
+        submission_target_height: Vec<u8>,
+        value_abi_unsigned_type: &[u8],
+        security_coordinates: &[u8],
+        side_effect_id: [u8; 4],
+    ) -> Result<(Vec<Vec<u8>>, Vec<u8>), DispatchError> {
+        Self::confirm_and_decode_payload_params(
+            gateway_id,
+            encoded_inclusion_data,
+            submission_target_height,
+            value_abi_unsigned_type,
+            security_coordinates,
+            side_effect_id,
+        )
+    }
+}
+
+pub(crate) fn import_finality_update<T: Config>(
+    gateway_id: ChainId,
+    update: FinalityUpdate,
+) -> Result<(), DispatchError> {
+    let config = <BeaconConfigMap<T>>::get(gateway_id).ok_or(Error::<T>::NotInitialized)?;
+    let finalized =
+        <FinalizedBeaconHeaderMap<T>>::get(gateway_id).ok_or(Error::<T>::NotInitialized)?;
+    ensure!(
+        update.signature_slot > update.attested_header.slot
+            && update.attested_header.slot >= update.finalized_header.slot,
+        Error::<T>::InvalidUpdateSlots
+    );
+    ensure!(
+        update.finalized_header.slot > finalized.slot,
+        Error::<T>::StaleUpdate
+    );
+
+    let store_period = <SyncCommitteePeriodMap<T>>::get(gateway_id);
+    let signature_period = compute_period(update.signature_slot);
+    let sync_committee = if signature_period == store_period {
+        <CurrentSyncCommitteeMap<T>>::get(gateway_id).ok_or(Error::<T>::NotInitialized)?
+    } else if signature_period == store_period + 1 {
+        <NextSyncCommitteeMap<T>>::get(gateway_id).ok_or(Error::<T>::SyncCommitteeNotKnown)?
+    } else {
+        return Err(Error::<T>::SkippedSyncCommitteePeriod.into())
+    };
+
+    ensure!(
+        is_valid_merkle_branch(
+            update.finalized_header.hash_tree_root(),
+            &update.finality_branch,
+            FINALIZED_ROOT_INDEX,
+            update.attested_header.state_root,
+        ),
+        Error::<T>::InvalidFinalityProof
+    );
+
+    if let Some(committee_update) = &update.next_sync_committee_update {
+        ensure!(
+            compute_period(update.attested_header.slot) == store_period,
+            Error::<T>::InvalidSyncCommitteeUpdatePeriod
+        );
+        ensure_sync_committee_size::<T>(&committee_update.next_sync_committee)?;
+        ensure!(
+            is_valid_merkle_branch(
+                committee_update.next_sync_committee.hash_tree_root(),
+                &committee_update.next_sync_committee_branch,
+                NEXT_SYNC_COMMITTEE_INDEX,
+                update.attested_header.state_root,
+            ),
+            Error::<T>::InvalidSyncCommitteeProof
+        );
+        ensure!(
+            <NextSyncCommitteeMap<T>>::get(gateway_id)
+                .map_or(true, |known| known == committee_update.next_sync_committee),
+            Error::<T>::ConflictingSyncCommittee
+        );
+    }
+
+    verify_sync_committee_signature::<T>(
+        &config,
+        &sync_committee,
+        &update.sync_aggregate,
+        update.attested_header.hash_tree_root(),
+        update.signature_slot,
+    )?;
+    verify_execution_payload::<T>(
+        &update.execution_header,
+        &update.execution_branch,
+        &update.finalized_header,
+    )?;
+
+    if let Some(committee_update) = update.next_sync_committee_update {
+        <NextSyncCommitteeMap<T>>::insert(gateway_id, committee_update.next_sync_committee);
+    }
+    // signature and finalized header are at most one period ahead, so this rotates at most once
+    if compute_period(update.finalized_header.slot) == store_period + 1 {
+        let next =
+            <NextSyncCommitteeMap<T>>::take(gateway_id).ok_or(Error::<T>::SyncCommitteeNotKnown)?;
+        <CurrentSyncCommitteeMap<T>>::insert(gateway_id, next);
+        <SyncCommitteePeriodMap<T>>::insert(gateway_id, store_period + 1);
+    }
+    <FinalizedBeaconHeaderMap<T>>::insert(
+        gateway_id,
+        FinalizedBeaconHeader {
+            root: update.finalized_header.hash_tree_root(),
+            slot: update.finalized_header.slot,
+        },
+    );
+    import_execution_header::<T>(gateway_id, &update.execution_header);
+
+    Ok(())
+}
+
+fn verify_sync_committee_signature<T: Config>(
+    config: &BeaconConfig,
+    sync_committee: &SyncCommittee,
+    sync_aggregate: &SyncAggregate,
+    attested_header_root: H256,
+    signature_slot: u64,
+) -> Result<(), DispatchError> {
+    let committee_size = T::SyncCommitteeSize::get() as usize;
+    ensure!(
+        sync_aggregate.sync_committee_bits.len() * 8 == committee_size,
+        Error::<T>::InvalidSyncCommitteeBits
+    );
+
+    let participants = sync_committee
+        .pubkeys
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| (sync_aggregate.sync_committee_bits[i / 8] >> (i % 8)) & 1 == 1)
+        .map(|(_, pubkey)| PublicKey::from_bytes(pubkey))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| Error::<T>::InvalidBLSPublicKey)?;
+    ensure!(
+        participants.len() * 3 >= committee_size * 2,
+        Error::<T>::InsufficientSyncCommitteeParticipants
+    );
+
+    // the committee signs the header of the previous slot
+    let fork_version = config
+        .fork_version(compute_epoch(signature_slot.saturating_sub(1)))
+        .ok_or(Error::<T>::UnknownFork)?;
+    let domain = compute_domain(
+        DOMAIN_SYNC_COMMITTEE,
+        fork_version,
+        config.genesis_validators_root,
+    );
+    let signing_root = compute_signing_root(attested_header_root, domain);
+
+    let signature = AggregateSignature::from_bytes(&sync_aggregate.sync_committee_signature)
+        .map_err(|_| Error::<T>::InvalidBLSSignature)?;
+    let participants: Vec<&PublicKey> = participants.iter().collect();
+    ensure!(
+        signature.fast_aggregate_verify(signing_root.as_bytes(), &participants),
+        Error::<T>::SignatureVerificationFailed
+    );
+
+    Ok(())
+}
+
+fn verify_execution_payload<T: Config>(
+    execution_header: &ExecutionPayloadHeader,
+    execution_branch: &[H256],
+    beacon_header: &BeaconBlockHeader,
+) -> Result<(), DispatchError> {
+    ensure!(
+        is_valid_merkle_branch(
+            execution_header.hash_tree_root(),
+            execution_branch,
+            EXECUTION_PAYLOAD_INDEX,
+            beacon_header.body_root,
+        ),
+        Error::<T>::InvalidExecutionPayloadProof
+    );
+
+    Ok(())
+}
+
+/// Stores the execution header and prunes the one it replaces in the ring buffer.
+fn import_execution_header<T: Config>(
+    gateway_id: ChainId,
+    execution_header: &ExecutionPayloadHeader,
+) {
+    let header_id = HeaderId {
+        number: execution_header.block_number,
+        hash: execution_header.block_hash,
+    };
+    let pointer = <ExecutionHeaderRingPointer<T>>::get(gateway_id);
+    if let Some(pruned) = <ExecutionHeaderRing<T>>::get(gateway_id, pointer) {
+        <ExecutionHeaders<T>>::remove(gateway_id, pruned);
+    }
+    <ExecutionHeaderRing<T>>::insert(gateway_id, pointer, header_id.hash);
+    <ExecutionHeaderRingPointer<T>>::insert(
+        gateway_id,
+        (pointer + 1) % T::ExecutionHeadersToStore::get(),
+    );
+
+    <ExecutionHeaders<T>>::insert(
+        gateway_id,
+        header_id.hash,
+        ExecutionHeaderInfo {
+            block_number: header_id.number,
+            receipts_root: execution_header.receipts_root,
+        },
+    );
+    <LatestExecutionHeaderMap<T>>::insert(gateway_id, header_id);
+}
+
+fn ensure_sync_committee_size<T: Config>(sync_committee: &SyncCommittee) -> Result<(), Error<T>> {
+    ensure!(
+        sync_committee.pubkeys.len() == T::SyncCommitteeSize::get() as usize,
+        Error::<T>::InvalidSyncCommitteeSize
+    );
+
+    Ok(())
+}
+
+fn compute_epoch(slot: u64) -> u64 {
+    slot / SLOTS_PER_EPOCH
+}
+
+fn compute_period(slot: u64) -> u64 {
+    compute_epoch(slot) / EPOCHS_PER_SYNC_COMMITTEE_PERIOD
+}
+
+impl<T> From<DecodeLogError> for Error<T> {
+    fn from(err: DecodeLogError) -> Self {
+        match err {
+            DecodeLogError::EventDecodingFailed => Error::<T>::EventDecodingFailed,
+            DecodeLogError::AmountOverflow => Error::<T>::AmountOverflow,
+            DecodeLogError::UnkownSideEffect => Error::<T>::UnkownSideEffect,
+            DecodeLogError::UnexpectedEmitter => Error::<T>::UnexpectedEmitter,
+        }
+    }
+}
+
+/// Ensure that the gateway is in operational mode (not halted).
+fn ensure_operational<T: Config>(gateway_id: ChainId) -> Result<(), Error<T>> {
+    match <IsHaltedMap<T>>::get(gateway_id) {
+        Some(false) => Ok(()),
+        Some(true) => Err(<Error<T>>::Halted),
+        None => Err(<Error<T>>::NotInitialized),
+    }
+}
+
+/// Ensure that the origin is either root, or `PalletOwner`.
+fn ensure_owner_or_root<T: Config>(
+    origin: T::Origin,
+    gateway_id: ChainId,
+) -> Result<(), DispatchError> {
+    match origin.into() {
+        Ok(RawOrigin::Root) => Ok(()),
+        Ok(RawOrigin::Signed(ref signer))
+            if Some(signer) == <PalletOwnerMap<T>>::get(gateway_id).as_ref() =>
+            Ok(()),
+        _ => Err(BadOrigin.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mock::{run_test, Origin, TestRuntime},
+        ssz::compute_root,
+    };
+    use frame_support::{assert_noop, assert_ok};
+    use hex_literal::hex;
+    use milagro_bls::{SecretKey, Signature};
+    use snowbridge_ethereum::{Log, H160, U256};
+    use sp_std::collections::btree_map::BTreeMap;
+
+    const GATEWAY_ID: ChainId = *b"eth2";
+    const OWNER: u64 = 1;
+    const SLOTS_PER_PERIOD: u64 = SLOTS_PER_EPOCH * EPOCHS_PER_SYNC_COMMITTEE_PERIOD;
+    const CHECKPOINT_SLOT: u64 = 10 * SLOTS_PER_PERIOD;
+    const ALL_SIGNED: u8 = 0b1111_1111;
+
+    fn secret_keys(seed: u8) -> Vec<SecretKey> {
+        (0..8u8)
+            .map(|i| {
+                let mut bytes = [0u8; 32];
+                bytes[30] = seed;
+                bytes[31] = i + 1;
+                SecretKey::from_bytes(&bytes).unwrap()
+            })
+            .collect()
+    }
+
+    fn sync_committee(keys: &[SecretKey]) -> SyncCommittee {
+        let pubkeys: Vec<BLSPubkey> = keys
+            .iter()
+            .map(|key| {
+                let mut pubkey = [0u8; 48];
+                pubkey.copy_from_slice(&PublicKey::from_secret_key(key).as_bytes());
+                pubkey
+            })
+            .collect();
+
+        SyncCommittee {
+            aggregate_pubkey: pubkeys[0],
+            pubkeys,
+        }
+    }
+
+    /// Builds a tree holding the leaves at their generalized indices and zeros everywhere else.
+    /// Returns its root and the branch of every leaf.
+    fn merkle_tree(leaves: &[(u64, H256)]) -> (H256, Vec<Vec<H256>>) {
+        fn node(index: u64, depth: u32, leaves: &BTreeMap<u64, H256>) -> H256 {
+            match leaves.get(&index) {
+                Some(leaf) => *leaf,
+                None if 63 - index.leading_zeros() == depth => H256::zero(),
+                None => compute_root(
+                    node(2 * index, depth, leaves),
+                    &[node(2 * index + 1, depth, leaves)],
+                    2,
+                ),
+            }
+        }
+
+        let depth = leaves
+            .iter()
+            .map(|(index, _)| 63 - index.leading_zeros())
+            .max()
+            .unwrap_or_default();
+        let nodes: BTreeMap<u64, H256> = leaves.iter().cloned().collect();
+        let branches = leaves
+            .iter()
+            .map(|(index, _)| {
+                let mut index = *index;
+                let mut branch = vec![];
+                while index > 1 {
+                    branch.push(node(index ^ 1, depth, &nodes));
+                    index /= 2;
+                }
+                branch
+            })
+            .collect();
+
+        (node(1, depth, &nodes), branches)
+    }
+
+    fn beacon_config() -> BeaconConfig {
+        BeaconConfig {
+            genesis_validators_root: H256::repeat_byte(9),
+            forks: vec![
+                Fork {
+                    version: [0, 0, 0, 0],
+                    epoch: 0,
+                },
+                Fork {
+                    version: [1, 0, 0, 0],
+                    epoch: 100,
+                },
+                Fork {
+                    version: [3, 0, 0, 0],
+                    epoch: 2_000,
+                },
+            ],
+        }
+    }
+
+    fn execution_header(block_number: u64) -> ExecutionPayloadHeader {
+        ExecutionPayloadHeader {
+            parent_hash: H256::zero(),
+            fee_recipient: H160::zero(),
+            state_root: H256::zero(),
+            receipts_root: hex!("d128e3a57142d2bf15bc0cbcac7ad54f40750d571b5c3097e425882c10c9ba66")
+                .into(),
+            logs_bloom: vec![0u8; 256],
+            prev_randao: H256::zero(),
+            block_number,
+            gas_limit: 30_000_000,
+            gas_used: 0,
+            timestamp: 0,
+            extra_data: vec![],
+            base_fee_per_gas: U256::from(7u64),
+            block_hash: H256::from_low_u64_be(block_number),
+            transactions_root: H256::zero(),
+            withdrawals_root: Some(H256::zero()),
+        }
+    }
+
+    /// Beacon header whose body carries the execution header, along with the execution branch
+    fn beacon_header(
+        slot: u64,
+        state_root: H256,
+        execution_header: &ExecutionPayloadHeader,
+    ) -> (BeaconBlockHeader, Vec<H256>) {
+        let (body_root, branches) =
+            merkle_tree(&[(EXECUTION_PAYLOAD_INDEX, execution_header.hash_tree_root())]);
+
+        (
+            BeaconBlockHeader {
+                slot,
+                proposer_index: 0,
+                parent_root: H256::zero(),
+                state_root,
+                body_root,
+            },
+            branches[0].clone(),
+        )
+    }
+
+    fn registration_data(committee: &SyncCommittee) -> BeaconRegistrationData<u64> {
+        let execution_header = execution_header(100);
+        let (state_root, branches) =
+            merkle_tree(&[(CURRENT_SYNC_COMMITTEE_INDEX, committee.hash_tree_root())]);
+        let (header, execution_branch) =
+            beacon_header(CHECKPOINT_SLOT, state_root, &execution_header);
+
+        BeaconRegistrationData {
+            config: beacon_config(),
+            header,
+            current_sync_committee: committee.clone(),
+            current_sync_committee_branch: branches[0].clone(),
+            execution_header,
+            execution_branch,
+            owner: OWNER,
+        }
+    }
+
+    fn initialize(committee: &SyncCommittee) {
+        assert_ok!(Pallet::<TestRuntime>::initialize(
+            Origin::root(),
+            GATEWAY_ID,
+            registration_data(committee).encode()
+        ));
+    }
+
+    fn sync_aggregate(
+        attested_header: &BeaconBlockHeader,
+        signature_slot: u64,
+        committee_keys: &[SecretKey],
+        participation: u8,
+    ) -> SyncAggregate {
+        let config = beacon_config();
+        let fork_version = config
+            .fork_version(compute_epoch(signature_slot - 1))
+            .unwrap();
+        let domain = compute_domain(
+            DOMAIN_SYNC_COMMITTEE,
+            fork_version,
+            config.genesis_validators_root,
+        );
+        let signing_root = compute_signing_root(attested_header.hash_tree_root(), domain);
+
+        let mut signature = AggregateSignature::new();
+        for (_, key) in committee_keys
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| (participation >> i) & 1 == 1)
+        {
+            signature.add(&Signature::new(signing_root.as_bytes(), key));
+        }
+        let mut sync_committee_signature = [0u8; 96];
+        sync_committee_signature.copy_from_slice(&signature.as_bytes());
+
+        SyncAggregate {
+            sync_committee_bits: vec![participation],
+            sync_committee_signature,
+        }
+    }
+
+    fn finality_update(
+        finalized_slot: u64,
+        block_number: u64,
+        committee_keys: &[SecretKey],
+        participation: u8,
+        next_sync_committee: Option<&SyncCommittee>,
+    ) -> FinalityUpdate {
+        let execution_header = execution_header(block_number);
+        let (finalized_header, execution_branch) =
+            beacon_header(finalized_slot, H256::repeat_byte(2), &execution_header);
+
+        let mut leaves = vec![(FINALIZED_ROOT_INDEX, finalized_header.hash_tree_root())];
+        if let Some(committee) = next_sync_committee {
+            leaves.push((NEXT_SYNC_COMMITTEE_INDEX, committee.hash_tree_root()));
+        }
+        let (state_root, branches) = merkle_tree(&leaves);
+        let attested_header = BeaconBlockHeader {
+            slot: finalized_slot + 64,
+            proposer_index: 0,
+            parent_root: H256::zero(),
+            state_root,
+            body_root: H256::zero(),
+        };
+        let signature_slot = attested_header.slot + 1;
+
+        FinalityUpdate {
+            sync_aggregate: sync_aggregate(
+                &attested_header,
+                signature_slot,
+                committee_keys,
+                participation,
+            ),
+            attested_header,
+            finalized_header,
+            finality_branch: branches[0].clone(),
+            signature_slot,
+            next_sync_committee_update: next_sync_committee.map(|committee| SyncCommitteeUpdate {
+                next_sync_committee: committee.clone(),
+                next_sync_committee_branch: branches[1].clone(),
+            }),
+            execution_header,
+            execution_branch,
+        }
+    }
+
+    fn submit(updates: Vec<FinalityUpdate>) -> Result<Vec<u8>, DispatchError> {
+        Pallet::<TestRuntime>::submit_headers(
+            Origin::signed(2),
+            GATEWAY_ID,
+            BeaconHeaderData { updates }.encode(),
+        )
+    }
+
+    /// XDNS security coordinates naming USDT the token contract confirming transfers
+    fn security_coordinates() -> Vec<u8> {
+        [[0u8; 20], hex!("dac17f958d2ee523a2206206994597c13d831ec7")].concat()
+    }
+
+    // USDT transfer, taken from the snowfork receipt proof fixtures
+    fn usdt_transfer_inclusion_data(block_hash: H256) -> EthereumInclusionData {
+        EthereumInclusionData {
+            log: Log {
+                address: hex!("dac17f958d2ee523a2206206994597c13d831ec7").into(),
+                topics: vec![
+                    hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef").into(),
+                    H256::from(H160::from(hex!("2e514404ff6823f1b46a8318a709251db414e5e1"))),
+                    H256::from(H160::from(hex!("55021c55847c00d764357a352e5803237d328954"))),
+                ],
+                data: hex!("000000000000000000000000000000000000000000000000000000000201c370")
+                    .to_vec(),
+            },
+            receipt_proof: vec![
+                hex!("f90131a00d3cb8d3f57ac1c0e12918a2ebe0cafed8c273577b9dd73e7ed1079b403ef494a0678b9835b834f8a287c0dd33a8fca9146e456ca688555ed4ec1361a2180b778da0fe42da181a46677a043b3d9d4b8bb05a6a17b7b5c010c17e7c1d31cfb7c4f911a0c89f0e2c53241cdb578e1f2b4caf6ba36e00500bdc57fecd66b84a6a58394c19a086c3c1fae5a0575940b5d38e111c469d07883106c26856f3ef608469a2081f13a06c5992ff00aab6226a70a032fd2f571ba22f797321f45e2daa73020d638d21b0a050861e9503ef68728f6c90a44f7fe1bceb2a9bdab6957bbe7136166bd849561ea006aa6eaca8a07e57176e9aa41e6a09edfb7678d1a112404e0ec779d7e567e82ea0bb0b430d303ba21b0af11c487b8a218bd75db54c98940b3f11bad8ff47cad3ef8080808080808080").to_vec(),
+                hex!("f871a0246de222036ee6a03329b0105da0a6b3f916fc95a9ed5a403a581a0c4d74242ca0ac108a49a88b57a05ac34a108b39f1e45f6f167f2b9fbc8d52fb58e2e5a6af1ea0fcfe07ac2ccd3c28b6eab68d1bce112f6f6dbd9023e4ec3c05b96615aa803d798080808080808080808080808080").to_vec(),
+                hex!("e4820001a04fff54398cad4d05ea6abfd8b0f3b4fe14c04d7ff5f5211c5b927d9cf72ac1d8").to_vec(),
+                hex!("f851a096d010643ca2d47412ca66898286b5f2412963b9ec051b33e570d575914c9c5ca028cd24c652989542fe89479ec6388eac4592432242af5ba97563b3ac7c71c019808080808080808080808080808080").to_vec(),
+                hex!("f90211a0bb35a84c5b1dcb78ec9d32614912c696e62df77bebf9ab326ee55b5d3acdde46a01084b30dac8df0accfcd0fd6330b7f6fc72a4651246d0694be9162151686a620a03eed50afdce7909d784c6157c445a444c806b5f23d31f3b63786f600c84a95b2a0af5232f1df6c6d41879804d081abe867002abe26ba3e5f8e0254a83a54769831a0607915fb13dd5da594256389a45007a67a7f7a86e95d38d8462792b6c98a722ea00e1260fda1730f2738c650ce2bfba83857bc10f8fb119ebc4fb39acba24e6fbaa0d11de17e417327457812675ca3b84ae8e1b64827abfe01420953697c8313d5b1a05fcaf2f7a88f76336a0c32ffc78acb87ae2005454bd25d658035331be3173b46a03f94f4952ab9e650f83cfd0e7f367b1bcc493aacf39a06f16c4a2e1b5605da48a0bdb4ec79785ca8ae22d60f1bbd42d707b4d7ec4aff231a3ebab755e315b35053a043a67c3f2bcef37c8f47a673adcb7061007a553696d1092408601c11b2e6846aa0c519d5af48cae87c7f4538845417c9735813bee892a6fe2dda79f5c414e8576aa0f7058256e09589501d7c231d739e61c84a850e139690989d24fda6058b432e98a081a52faab520978cb19ce14400dba0cd5bcdc4e5a3c0740678aa8f97ee0e5c56a0bcecc61cadeae52518e3b68a48af4b11603dfd9d99d99d7985efa6d2de44f904a02cba4accfc6f39bc5adb6d4440eb6358b4a5103ef93298e4e694f1f940f8b48280").to_vec(),
+                hex!("f901ae20b901aaf901a70183bb444eb9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010000000000000001000000000000000000000000000100000000000008000000000000000000000000000000000000000000000000000000000000000000000000000000000200000000000010000000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000080000000000000000000000000000000000000000000000002000000000000000000081000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000f89df89b94dac17f958d2ee523a2206206994597c13d831ec7f863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa00000000000000000000000002e514404ff6823f1b46a8318a709251db414e5e1a000000000000000000000000055021c55847c00d764357a352e5803237d328954a0000000000000000000000000000000000000000000000000000000000201c370").to_vec(),
+            ],
+            block_hash,
+        }
+    }
+
+    #[test]
+    fn rejects_checkpoint_with_invalid_sync_committee_proof() {
+        run_test(|| {
+            let mut data = registration_data(&sync_committee(&secret_keys(0)));
+            data.current_sync_committee = sync_committee(&secret_keys(1));
+
+            assert_noop!(
+                Pallet::<TestRuntime>::initialize(Origin::root(), GATEWAY_ID, data.encode()),
+                Error::<TestRuntime>::InvalidSyncCommitteeProof
+            );
+        })
+    }
+
+    #[test]
+    fn imports_finality_update_signed_by_sync_committee() {
+        run_test(|| {
+            let keys = secret_keys(0);
+            initialize(&sync_committee(&keys));
+            let update = finality_update(CHECKPOINT_SLOT + 96, 110, &keys, ALL_SIGNED, None);
+            let finalized_root = update.finalized_header.hash_tree_root();
+
+            assert_eq!(submit(vec![update]), Ok(110u64.encode()));
+            assert_eq!(
+                Pallet::<TestRuntime>::get_finalized_beacon_header(GATEWAY_ID),
+                Some(FinalizedBeaconHeader {
+                    root: finalized_root,
+                    slot: CHECKPOINT_SLOT + 96,
+                })
+            );
+            assert_eq!(
+                Pallet::<TestRuntime>::get_latest_finalized_height(GATEWAY_ID),
+                Some(110u64.encode())
+            );
+        })
+    }
+
+    #[test]
+    fn accepts_two_thirds_participation_only() {
+        run_test(|| {
+            let keys = secret_keys(0);
+            initialize(&sync_committee(&keys));
+
+            assert_noop!(
+                submit(vec![finality_update(
+                    CHECKPOINT_SLOT + 96,
+                    110,
+                    &keys,
+                    0b0001_1111,
+                    None
+                )]),
+                Error::<TestRuntime>::InsufficientSyncCommitteeParticipants
+            );
+            assert_ok!(submit(vec![finality_update(
+                CHECKPOINT_SLOT + 96,
+                110,
+                &keys,
+                0b0011_1111,
+                None
+            )]));
+        })
+    }
+
+    #[test]
+    fn rejects_update_signed_by_foreign_committee() {
+        run_test(|| {
+            initialize(&sync_committee(&secret_keys(0)));
+
+            assert_noop!(
+                submit(vec![finality_update(
+                    CHECKPOINT_SLOT + 96,
+                    110,
+                    &secret_keys(1),
+                    ALL_SIGNED,
+                    None
+                )]),
+                Error::<TestRuntime>::SignatureVerificationFailed
+            );
+        })
+    }
+
+    #[test]
+    fn rejects_update_with_invalid_finality_branch() {
+        run_test(|| {
+            let keys = secret_keys(0);
+            initialize(&sync_committee(&keys));
+            let mut update = finality_update(CHECKPOINT_SLOT + 96, 110, &keys, ALL_SIGNED, None);
+            update.finality_branch[0] = H256::repeat_byte(1);
+
+            assert_noop!(
+                submit(vec![update]),
+                Error::<TestRuntime>::InvalidFinalityProof
+            );
+        })
+    }
+
+    #[test]
+    fn rotates_sync_committee_when_finalizing_the_next_period() {
+        run_test(|| {
+            let (current_keys, next_keys) = (secret_keys(0), secret_keys(1));
+            let next_committee = sync_committee(&next_keys);
+            initialize(&sync_committee(&current_keys));
+            let next_period_update = finality_update(
+                CHECKPOINT_SLOT + SLOTS_PER_PERIOD + 32,
+                120,
+                &next_keys,
+                ALL_SIGNED,
+                None,
+            );
+
+            assert_noop!(
+                submit(vec![next_period_update.clone()]),
+                Error::<TestRuntime>::SyncCommitteeNotKnown
+            );
+
+            assert_ok!(submit(vec![
+                finality_update(
+                    CHECKPOINT_SLOT + 96,
+                    110,
+                    &current_keys,
+                    ALL_SIGNED,
+                    Some(&next_committee)
+                ),
+                next_period_update
+            ]));
+            assert_eq!(
+                Pallet::<TestRuntime>::get_sync_committee_period(GATEWAY_ID),
+                11
+            );
+            assert_eq!(
+                Pallet::<TestRuntime>::get_current_sync_committee(GATEWAY_ID),
+                Some(next_committee)
+            );
+            assert_eq!(
+                Pallet::<TestRuntime>::get_next_sync_committee(GATEWAY_ID),
+                None
+            );
+        })
+    }
+
+    #[test]
+    fn prunes_execution_headers_beyond_the_ring_buffer() {
+        run_test(|| {
+            let keys = secret_keys(0);
+            initialize(&sync_committee(&keys));
+
+            assert_ok!(submit(
+                (1..=3)
+                    .map(|i| finality_update(
+                        CHECKPOINT_SLOT + 96 * i,
+                        100 + i,
+                        &keys,
+                        ALL_SIGNED,
+                        None
+                    ))
+                    .collect()
+            ));
+            assert_eq!(
+                Pallet::<TestRuntime>::get_execution_header(GATEWAY_ID, H256::from_low_u64_be(100)),
+                None
+            );
+            assert!(Pallet::<TestRuntime>::get_execution_header(
+                GATEWAY_ID,
+                H256::from_low_u64_be(101)
+            )
+            .is_some());
+            assert_eq!(
+                Pallet::<TestRuntime>::get_oldest_provable_height(GATEWAY_ID),
+                Some(101u64.encode())
+            );
+        })
+    }
+
+    #[test]
+    fn confirms_transfer_included_in_finalized_execution_payload() {
+        run_test(|| {
+            initialize(&sync_committee(&secret_keys(0)));
+            let block_hash = H256::from_low_u64_be(100);
+
+            let (params, _) = Pallet::<TestRuntime>::confirm_and_decode_payload_params(
+                GATEWAY_ID,
+                usdt_transfer_inclusion_data(block_hash).encode(),
+                50u64.encode(),
+                b"uint128",
+                &security_coordinates(),
+                *b"tran",
+            )
+            .unwrap();
+
+            assert_eq!(
+                params,
+                vec![
+                    hex!("2e514404ff6823f1b46a8318a709251db414e5e1").to_vec(),
+                    hex!("55021c55847c00d764357a352e5803237d328954").to_vec(),
+                    33_670_000u128.encode(),
+                ]
+            );
+        })
+    }
+
+    #[test]
+    fn rejects_transfer_in_unknown_execution_payload() {
+        run_test(|| {
+            initialize(&sync_committee(&secret_keys(0)));
+
+            assert_noop!(
+                Pallet::<TestRuntime>::confirm_and_decode_payload_params(
+                    GATEWAY_ID,
+                    usdt_transfer_inclusion_data(H256::from_low_u64_be(101)).encode(),
+                    50u64.encode(),
+                    b"uint128",
+                    &security_coordinates(),
+                    *b"tran",
+                ),
+                Error::<TestRuntime>::UnknownExecutionHeader
+            );
+        })
+    }
+
+    #[test]
+    fn rejects_transfer_of_a_token_other_than_the_gateways() {
+        run_test(|| {
+            initialize(&sync_committee(&secret_keys(0)));
+            let other_token_coordinates = [[0u8; 20], [9u8; 20]].concat();
+
+            assert_noop!(
+                Pallet::<TestRuntime>::confirm_and_decode_payload_params(
+                    GATEWAY_ID,
+                    usdt_transfer_inclusion_data(H256::from_low_u64_be(100)).encode(),
+                    50u64.encode(),
+                    b"uint128",
+                    &other_token_coordinates,
+                    *b"tran",
+                ),
+                Error::<TestRuntime>::UnexpectedEmitter
+            );
+        })
+    }
+}
@@ -0,0 +1,71 @@
+// From construct_runtime macro
+#![allow(clippy::from_over_into)]
+
+use frame_support::{construct_runtime, parameter_types, traits::Everything};
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+};
+
+pub type AccountId = u64;
+
+type Block = frame_system::mocking::MockBlock<TestRuntime>;
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<TestRuntime>;
+
+construct_runtime! {
+    pub enum TestRuntime where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+        EthereumBeaconFinalityVerifier: crate::{Pallet, Storage},
+    }
+}
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for TestRuntime {
+    type AccountData = ();
+    type AccountId = AccountId;
+    type BaseCallFilter = Everything;
+    type BlockHashCount = BlockHashCount;
+    type BlockLength = ();
+    type BlockNumber = u64;
+    type BlockWeights = ();
+    type Call = Call;
+    type DbWeight = ();
+    type Event = Event;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type Header = Header;
+    type Index = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+    type OnKilledAccount = ();
+    type OnNewAccount = ();
+    type OnSetCode = ();
+    type Origin = Origin;
+    type PalletInfo = PalletInfo;
+    type SS58Prefix = ();
+    type SystemWeightInfo = ();
+    type Version = ();
+}
+
+parameter_types! {
+    pub const SyncCommitteeSize: u32 = 8;
+    pub const ExecutionHeadersToStore: u32 = 3;
+}
+
+impl crate::Config for TestRuntime {
+    type ExecutionHeadersToStore = ExecutionHeadersToStore;
+    type SyncCommitteeSize = SyncCommitteeSize;
+    type WeightInfo = ();
+}
+
+pub fn run_test<T>(test: impl FnOnce() -> T) -> T {
+    sp_io::TestExternalities::new(Default::default()).execute_with(test)
+}
@@ -0,0 +1,206 @@
+//! SSZ merkleization of the beacon chain containers the light client verifies proofs against.
+
+use crate::types::*;
+use snowbridge_ethereum::H256;
+use sp_io::hashing::sha2_256;
+use sp_std::{vec, vec::Vec};
+
+const BYTES_PER_CHUNK: usize = 32;
+
+pub trait HashTreeRoot {
+    fn hash_tree_root(&self) -> H256;
+}
+
+impl HashTreeRoot for BeaconBlockHeader {
+    fn hash_tree_root(&self) -> H256 {
+        merkleize(vec![
+            uint64_chunk(self.slot),
+            uint64_chunk(self.proposer_index),
+            self.parent_root.0,
+            self.state_root.0,
+            self.body_root.0,
+        ])
+    }
+}
+
+impl HashTreeRoot for SyncCommittee {
+    fn hash_tree_root(&self) -> H256 {
+        let pubkeys = merkleize(
+            self.pubkeys
+                .iter()
+                .map(|pubkey| merkleize(pack_bytes(pubkey)).0)
+                .collect(),
+        );
+
+        merkleize(vec![
+            pubkeys.0,
+            merkleize(pack_bytes(&self.aggregate_pubkey)).0,
+        ])
+    }
+}
+
+impl HashTreeRoot for ExecutionPayloadHeader {
+    fn hash_tree_root(&self) -> H256 {
+        let mut base_fee_per_gas = [0u8; 32];
+        self.base_fee_per_gas
+            .to_little_endian(&mut base_fee_per_gas);
+        let extra_data = mix_in_length(
+            merkleize_with_limit(pack_bytes(&self.extra_data), 1),
+            self.extra_data.len() as u64,
+        );
+
+        let mut fields = vec![
+            self.parent_hash.0,
+            bytes_chunk(self.fee_recipient.as_bytes()),
+            self.state_root.0,
+            self.receipts_root.0,
+            merkleize(pack_bytes(&self.logs_bloom)).0,
+            self.prev_randao.0,
+            uint64_chunk(self.block_number),
+            uint64_chunk(self.gas_limit),
+            uint64_chunk(self.gas_used),
+            uint64_chunk(self.timestamp),
+            extra_data.0,
+            base_fee_per_gas,
+            self.block_hash.0,
+            self.transactions_root.0,
+        ];
+        if let Some(withdrawals_root) = self.withdrawals_root {
+            fields.push(withdrawals_root.0);
+        }
+
+        merkleize(fields)
+    }
+}
+
+/// Domain the sync committee signs over for the given fork
+pub fn compute_domain(
+    domain_type: [u8; 4],
+    fork_version: [u8; 4],
+    genesis_validators_root: H256,
+) -> H256 {
+    let fork_data_root = hash_pair(&bytes_chunk(&fork_version), &genesis_validators_root.0);
+
+    let mut domain = [0u8; 32];
+    domain[..4].copy_from_slice(&domain_type);
+    domain[4..].copy_from_slice(&fork_data_root[..28]);
+    domain.into()
+}
+
+pub fn compute_signing_root(object_root: H256, domain: H256) -> H256 {
+    hash_pair(&object_root.0, &domain.0).into()
+}
+
+/// Root reached by hashing `leaf` up with `branch`, ordered bottom up, at the generalized index
+pub fn compute_root(leaf: H256, branch: &[H256], generalized_index: u64) -> H256 {
+    branch
+        .iter()
+        .enumerate()
+        .fold(leaf, |node, (depth, sibling)| {
+            if (generalized_index >> depth) & 1 == 1 {
+                hash_pair(&sibling.0, &node.0).into()
+            } else {
+                hash_pair(&node.0, &sibling.0).into()
+            }
+        })
+}
+
+pub fn is_valid_merkle_branch(
+    leaf: H256,
+    branch: &[H256],
+    generalized_index: u64,
+    root: H256,
+) -> bool {
+    let depth = 63 - generalized_index.leading_zeros() as usize;
+    branch.len() == depth && compute_root(leaf, branch, generalized_index) == root
+}
+
+fn merkleize(chunks: Vec<[u8; 32]>) -> H256 {
+    merkleize_with_limit(chunks, 1)
+}
+
+/// Pads the chunks to the next power of two of at least `limit` and hashes them up to the root
+fn merkleize_with_limit(mut chunks: Vec<[u8; 32]>, limit: usize) -> H256 {
+    let width = chunks.len().max(limit).next_power_of_two();
+    chunks.resize(width, [0u8; 32]);
+
+    while chunks.len() > 1 {
+        chunks = chunks
+            .chunks(2)
+            .map(|pair| hash_pair(&pair[0], &pair[1]))
+            .collect();
+    }
+
+    chunks[0].into()
+}
+
+fn mix_in_length(root: H256, length: u64) -> H256 {
+    hash_pair(&root.0, &uint64_chunk(length)).into()
+}
+
+fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut data = [0u8; 64];
+    data[..32].copy_from_slice(left);
+    data[32..].copy_from_slice(right);
+    sha2_256(&data)
+}
+
+fn uint64_chunk(value: u64) -> [u8; 32] {
+    bytes_chunk(&value.to_le_bytes())
+}
+
+/// Right pads up to 32 bytes into a single chunk
+fn bytes_chunk(bytes: &[u8]) -> [u8; 32] {
+    let mut chunk = [0u8; 32];
+    chunk[..bytes.len()].copy_from_slice(bytes);
+    chunk
+}
+
+fn pack_bytes(bytes: &[u8]) -> Vec<[u8; 32]> {
+    bytes.chunks(BYTES_PER_CHUNK).map(bytes_chunk).collect()
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn merkleize_pads_to_the_next_power_of_two() {
+        let zero_pair = hash_pair(&[0u8; 32], &[0u8; 32]);
+
+        assert_eq!(
+            merkleize(vec![[1u8; 32], [2u8; 32], [3u8; 32]]),
+            H256::from(hash_pair(
+                &hash_pair(&[1u8; 32], &[2u8; 32]),
+                &hash_pair(&[3u8; 32], &[0u8; 32])
+            ))
+        );
+        assert_eq!(
+            merkleize_with_limit(vec![], 4),
+            H256::from(hash_pair(&zero_pair, &zero_pair))
+        );
+    }
+
+    #[test]
+    fn verifies_branch_at_generalized_index() {
+        let leaf = H256::repeat_byte(1);
+        let branch = vec![H256::repeat_byte(2), H256::repeat_byte(3)];
+        // generalized index 6 is the left child of the right subtree
+        let root = H256::from(hash_pair(&[3u8; 32], &hash_pair(&leaf.0, &branch[0].0)));
+
+        assert_eq!(compute_root(leaf, &branch, 6), root);
+        assert!(is_valid_merkle_branch(leaf, &branch, 6, root));
+        assert!(!is_valid_merkle_branch(leaf, &branch, 7, root));
+        assert!(!is_valid_merkle_branch(leaf, &branch[..1], 3, root));
+    }
+
+    #[test]
+    fn domain_keeps_type_and_truncated_fork_data_root() {
+        let genesis_validators_root = H256::repeat_byte(7);
+        let domain = compute_domain([7, 0, 0, 0], [1, 0, 0, 0], genesis_validators_root);
+        let fork_data_root = hash_pair(&bytes_chunk(&[1, 0, 0, 0]), &[7u8; 32]);
+
+        assert_eq!(domain[..4], [7, 0, 0, 0]);
+        assert_eq!(domain[4..], fork_data_root[..28]);
+    }
+}
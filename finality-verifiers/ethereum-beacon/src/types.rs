@@ -0,0 +1,135 @@
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use snowbridge_ethereum::{H160, H256, U256};
+use sp_std::vec::Vec;
+
+pub use pallet_ethereum_finality_verifier::types::EthereumInclusionData;
+
+pub type ChainId = [u8; 4];
+pub type BLSPubkey = [u8; 48];
+pub type BLSSignature = [u8; 96];
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct BeaconBlockHeader {
+    pub slot: u64,
+    pub proposer_index: u64,
+    pub parent_root: H256,
+    pub state_root: H256,
+    pub body_root: H256,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct SyncCommittee {
+    pub pubkeys: Vec<BLSPubkey>,
+    pub aggregate_pubkey: BLSPubkey,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct SyncAggregate {
+    /// Bitvector of the committee members that took part in signing, little endian bit order
+    pub sync_committee_bits: Vec<u8>,
+    pub sync_committee_signature: BLSSignature,
+}
+
+/// Execution payload header as committed to in the beacon block body
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct ExecutionPayloadHeader {
+    pub parent_hash: H256,
+    pub fee_recipient: H160,
+    pub state_root: H256,
+    pub receipts_root: H256,
+    pub logs_bloom: Vec<u8>,
+    pub prev_randao: H256,
+    pub block_number: u64,
+    pub gas_limit: u64,
+    pub gas_used: u64,
+    pub timestamp: u64,
+    pub extra_data: Vec<u8>,
+    pub base_fee_per_gas: U256,
+    pub block_hash: H256,
+    pub transactions_root: H256,
+    /// Added with Capella, `None` for Bellatrix payloads
+    pub withdrawals_root: Option<H256>,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct Fork {
+    pub version: [u8; 4],
+    pub epoch: u64,
+}
+
+/// Parameters of the bridged beacon chain, set once on registration.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct BeaconConfig {
+    pub genesis_validators_root: H256,
+    /// Fork schedule, ordered by activation epoch
+    pub forks: Vec<Fork>,
+}
+
+impl BeaconConfig {
+    /// Version of the latest fork active at `epoch`
+    pub fn fork_version(&self, epoch: u64) -> Option<[u8; 4]> {
+        self.forks
+            .iter()
+            .rev()
+            .find(|fork| fork.epoch <= epoch)
+            .map(|fork| fork.version)
+    }
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct BeaconRegistrationData<AccountId> {
+    pub config: BeaconConfig,
+    /// Trusted finalized checkpoint the light client starts from
+    pub header: BeaconBlockHeader,
+    pub current_sync_committee: SyncCommittee,
+    /// Proof of `current_sync_committee` against the checkpoints state root
+    pub current_sync_committee_branch: Vec<H256>,
+    pub execution_header: ExecutionPayloadHeader,
+    /// Proof of `execution_header` against the checkpoints body root
+    pub execution_branch: Vec<H256>,
+    pub owner: AccountId,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct SyncCommitteeUpdate {
+    pub next_sync_committee: SyncCommittee,
+    /// Proof of `next_sync_committee` against the attested headers state root
+    pub next_sync_committee_branch: Vec<H256>,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct FinalityUpdate {
+    /// Header the sync committee signed
+    pub attested_header: BeaconBlockHeader,
+    pub finalized_header: BeaconBlockHeader,
+    /// Proof of `finalized_header` against the attested headers state root
+    pub finality_branch: Vec<H256>,
+    pub sync_aggregate: SyncAggregate,
+    /// Slot of the block carrying `sync_aggregate`
+    pub signature_slot: u64,
+    pub next_sync_committee_update: Option<SyncCommitteeUpdate>,
+    /// Execution payload of the finalized header
+    pub execution_header: ExecutionPayloadHeader,
+    /// Proof of `execution_header` against the finalized headers body root
+    pub execution_branch: Vec<H256>,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct BeaconHeaderData {
+    /// Finality updates to apply, ordered by slot
+    pub updates: Vec<FinalityUpdate>,
+}
+
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct FinalizedBeaconHeader {
+    pub root: H256,
+    pub slot: u64,
+}
+
+/// The parts of a finalized execution payload needed to confirm side effects
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct ExecutionHeaderInfo {
+    pub block_number: u64,
+    pub receipts_root: H256,
+}
@@ -0,0 +1,44 @@
+//! Weights for pallet_ethereum_beacon_finality_verifier, not yet benchmarked.
+
+#![allow(clippy::all)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_ethereum_beacon_finality_verifier.
+pub trait WeightInfo {
+    fn import_finality_update() -> Weight;
+    fn confirm_and_decode_payload_params() -> Weight;
+}
+
+/// Weights for pallet_ethereum_beacon_finality_verifier using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    fn import_finality_update() -> Weight {
+        (450_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(7 as Weight))
+            .saturating_add(T::DbWeight::get().writes(7 as Weight))
+    }
+
+    fn confirm_and_decode_payload_params() -> Weight {
+        (40_000_000 as Weight).saturating_add(T::DbWeight::get().reads(1 as Weight))
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    fn import_finality_update() -> Weight {
+        (450_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(7 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(7 as Weight))
+    }
+
+    fn confirm_and_decode_payload_params() -> Weight {
+        (40_000_000 as Weight).saturating_add(RocksDbWeight::get().reads(1 as Weight))
+    }
+}
@@ -0,0 +1,876 @@
+//! Ethereum Finality Verifier Pallet
+//!
+//! This pallet is an on-chain Ethereum light client, built on the snowfork Ethereum primitives.
+//!
+//! Starting from a trusted header given on registration, it imports descendant headers and
+//! tracks the heaviest chain. PoW headers need a correct difficulty and an ethash seal proven with
+//! DAG merkle proofs. Past the networks merge block, headers carry no seal anymore, so they're
+//! only accepted from the gateway owner. A header is considered final once the best header has
+//! `descendants_until_final` descendants on top of it.
+//!
+//! Side effects are confirmed by proving the inclusion of a transaction receipt in a finalized
+//! header with `Header::check_receipt_proof` and decoding the receipts log with `ethabi-decode`.
+//! Only logs of the contracts named in the gateway's XDNS security coordinates are accepted.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use crate::{types::*, weights::WeightInfo};
+use codec::{Decode, Encode};
+use frame_support::{ensure, pallet_prelude::*};
+use frame_system::{ensure_signed, RawOrigin};
+use snowbridge_ethereum::{
+    difficulty::calc_difficulty,
+    ethashproof::{DoubleNodeWithMerkleProof, EthashProver},
+    Header, HeaderId, H256, U256,
+};
+use sp_runtime::traits::BadOrigin;
+use sp_std::{vec, vec::Vec};
+use t3rn_primitives::portal::LightClient;
+
+#[cfg(test)]
+mod mock;
+
+pub mod side_effects;
+pub mod types;
+/// Pallet containing weights for this pallet.
+pub mod weights;
+
+// Re-export in crate namespace for `construct_runtime!`
+pub use pallet::*;
+
+/// Upper bound of block heights pruned while importing a single header
+const MAX_PRUNED_HEIGHTS_PER_IMPORT: u64 = 4;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Maximal number of block heights below the finalized header to keep in the storage.
+        ///
+        /// The setting is there to prevent growing the on-chain state indefinitely. Side effects
+        /// can only be confirmed against headers that weren't pruned yet.
+        #[pallet::constant]
+        type HeadersToStore: Get<u64>;
+
+        /// Weights gathered through benchmarking.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+    /// Consensus parameters of the bridged network.
+    #[pallet::storage]
+    #[pallet::getter(fn get_network_config)]
+    pub type NetworkConfigMap<T: Config> = StorageMap<_, Blake2_256, ChainId, NetworkConfig>;
+
+    /// Headers which have been imported into the pallet.
+    #[pallet::storage]
+    #[pallet::getter(fn get_imported_header)]
+    pub type ImportedHeaders<T: Config> =
+        StorageDoubleMap<_, Blake2_256, ChainId, Identity, H256, StoredHeader>;
+
+    /// Hashes of the imported headers by block height, including the ones of stale forks.
+    #[pallet::storage]
+    pub(super) type HeadersByNumber<T: Config> =
+        StorageDoubleMap<_, Blake2_256, ChainId, Identity, u64, Vec<H256>, ValueQuery>;
+
+    /// Lowest block height still kept in the storage.
+    #[pallet::storage]
+    pub(super) type OldestHeaderNumber<T: Config> =
+        StorageMap<_, Blake2_256, ChainId, u64, ValueQuery>;
+
+    /// Head of the chain with the most total difficulty, with its total difficulty.
+    #[pallet::storage]
+    #[pallet::getter(fn get_best_header)]
+    pub type BestHeaderMap<T: Config> = StorageMap<_, Blake2_256, ChainId, (HeaderId, U256)>;
+
+    /// The latest finalized header.
+    #[pallet::storage]
+    #[pallet::getter(fn get_finalized_header)]
+    pub type FinalizedHeaderMap<T: Config> = StorageMap<_, Blake2_256, ChainId, HeaderId>;
+
+    /// Optional pallet owner.
+    ///
+    /// Gateway owner has a right to halt all operations of the gateway and then resume it,
+    /// and is the only one to submit PoS headers.
+    #[pallet::storage]
+    pub(super) type PalletOwnerMap<T: Config> = StorageMap<_, Blake2_256, ChainId, T::AccountId>;
+
+    /// If true, all gateway transactions are failed immediately.
+    #[pallet::storage]
+    pub(super) type IsHaltedMap<T: Config> = StorageMap<_, Blake2_256, ChainId, bool>;
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The gateway has been initialized already
+        AlreadyInitialized,
+        /// The gateway hasn't been initialized
+        NotInitialized,
+        /// The given bytes couldn't be decoded as registration data
+        RegistrationDataDecodingError,
+        /// The given bytes couldn't be decoded as header data
+        HeaderDataDecodingError,
+        /// The inclusion data couldn't be decoded
+        InclusionDataDecodeError,
+        /// The submitted range is empty
+        EmptyRangeSubmitted,
+        /// The header has been imported already
+        DuplicateHeader,
+        /// The parent of the header is unknown
+        UnknownParent,
+        /// The header doesn't extend its parent by a single block
+        InvalidRangeLinkage,
+        /// The header doesn't extend the finalized chain
+        ConflictsWithFinalizedHeader,
+        /// The headers difficulty doesn't match the one calculated from its parent
+        InvalidDifficulty,
+        /// The headers seal doesn't contain a mix hash and nonce
+        InvalidSeal,
+        /// The ethash proof doesn't match the headers seal
+        InvalidEthashProof,
+        /// The ethash result doesn't meet the headers difficulty
+        InsufficientProofOfWork,
+        /// PoS headers can only be submitted by the gateway owner
+        UntrustedProofOfStakeHeader,
+        /// No finalized header was found in storage
+        NoFinalizedHeader,
+        /// The header couldn't be found in storage
+        UnknownHeader,
+        /// The header isn't finalized yet
+        HeaderNotFinalized,
+        /// The transaction was executed before the side effect was created
+        ExecutedBeforeCreation,
+        /// The receipt proof doesn't match the headers receipts root
+        InvalidReceiptProof,
+        /// The proven transaction failed
+        TransactionFailed,
+        /// The log is not part of the proven receipt
+        LogNotIncluded,
+        /// The logs paramaters couldn't be decoded
+        EventDecodingFailed,
+        /// The decoded amount doesn't fit the gateways value type
+        AmountOverflow,
+        /// The side effect is not known for this vendor
+        UnkownSideEffect,
+        /// The log wasn't emitted by the contract the gateway's XDNS record expects
+        UnexpectedEmitter,
+        /// The gateway is currently halted
+        Halted,
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn initialize(
+        origin: T::Origin,
+        gateway_id: ChainId,
+        encoded_registration_data: Vec<u8>,
+    ) -> Result<(), DispatchError> {
+        ensure_owner_or_root::<T>(origin, gateway_id)?;
+        ensure!(
+            !<NetworkConfigMap<T>>::contains_key(gateway_id),
+            Error::<T>::AlreadyInitialized
+        );
+        let registration_data: EthereumRegistrationData<T::AccountId> =
+            Decode::decode(&mut &*encoded_registration_data)
+                .map_err(|_| Error::<T>::RegistrationDataDecodingError)?;
+
+        let header = registration_data.first_header;
+        let header_id = HeaderId {
+            number: header.number,
+            hash: header.compute_hash(),
+        };
+
+        <ImportedHeaders<T>>::insert(
+            gateway_id,
+            header_id.hash,
+            StoredHeader {
+                header,
+                total_difficulty: registration_data.total_difficulty,
+                finalized: true,
+            },
+        );
+        <HeadersByNumber<T>>::insert(gateway_id, header_id.number, vec![header_id.hash]);
+        <OldestHeaderNumber<T>>::insert(gateway_id, header_id.number);
+        <BestHeaderMap<T>>::insert(gateway_id, (header_id, registration_data.total_difficulty));
+        <FinalizedHeaderMap<T>>::insert(gateway_id, header_id);
+        <NetworkConfigMap<T>>::insert(gateway_id, registration_data.network_config);
+        <PalletOwnerMap<T>>::insert(gateway_id, registration_data.owner);
+        <IsHaltedMap<T>>::insert(gateway_id, false);
+
+        Ok(())
+    }
+
+    /// Owner named by the encoded registration data, if it decodes.
+    pub fn registration_owner(encoded_registration_data: &[u8]) -> Option<T::AccountId> {
+        EthereumRegistrationData::<T::AccountId>::decode(&mut &*encoded_registration_data)
+            .ok()
+            .map(|registration_data| registration_data.owner)
+    }
+
+    /// Change `PalletOwner`.
+    ///
+    /// May only be called either by root, or by `PalletOwner`.
+    pub fn set_owner(
+        origin: T::Origin,
+        gateway_id: ChainId,
+        encoded_new_owner: Vec<u8>,
+    ) -> Result<(), DispatchError> {
+        ensure_owner_or_root::<T>(origin, gateway_id)?;
+        let new_owner: Option<T::AccountId> = Decode::decode(&mut &*encoded_new_owner)
+            .map_err(|_| DispatchError::Other("New Owner decoding error"))?;
+
+        match new_owner {
+            Some(new_owner) => {
+                <PalletOwnerMap<T>>::insert(gateway_id, &new_owner);
+                log::info!("Setting pallet Owner to: {:?}", new_owner);
+            },
+            None => {
+                <PalletOwnerMap<T>>::remove(gateway_id);
+                log::info!("Removed Owner of pallet.");
+            },
+        }
+
+        Ok(())
+    }
+
+    /// Halt or resume all operations of the gateway.
+    ///
+    /// May only be called either by root, or by `PalletOwner`.
+    pub fn set_operational(
+        origin: T::Origin,
+        operational: bool,
+        gateway_id: ChainId,
+    ) -> Result<(), DispatchError> {
+        ensure_owner_or_root::<T>(origin, gateway_id)?;
+        <IsHaltedMap<T>>::insert(gateway_id, !operational);
+
+        Ok(())
+    }
+
+    /// Imports a range of headers, ordered parent first. Returns the encoded height of the last one.
+    pub fn submit_headers(
+        origin: T::Origin,
+        gateway_id: ChainId,
+        encoded_header_data: Vec<u8>,
+    ) -> Result<Vec<u8>, DispatchError> {
+        ensure_operational::<T>(gateway_id)?;
+        let submitted_by_owner = ensure_owner_or_root::<T>(origin.clone(), gateway_id).is_ok();
+        if !submitted_by_owner {
+            ensure_signed(origin)?;
+        }
+
+        let data: EthereumHeaderData = Decode::decode(&mut &*encoded_header_data)
+            .map_err(|_| Error::<T>::HeaderDataDecodingError)?;
+        let last_number = data
+            .headers
+            .last()
+            .map(|header_with_proof| header_with_proof.header.number)
+            .ok_or(Error::<T>::EmptyRangeSubmitted)?;
+
+        for HeaderWithProof { header, proof } in data.headers {
+            import_header::<T>(gateway_id, header, &proof, submitted_by_owner)?;
+        }
+
+        Ok(last_number.encode())
+    }
+
+    pub fn confirm_and_decode_payload_params(
+        gateway_id: ChainId,
+        encoded_inclusion_data: Vec<u8>,
+        submission_target_height: Vec<u8>,
+        value_abi_unsigned_type: &[u8],
+        security_coordinates: &[u8],
+        side_effect_id: [u8; 4],
+    ) -> Result<(Vec<Vec<u8>>, Vec<u8>), DispatchError> {
+        let inclusion_data: EthereumInclusionData =
+            Decode::decode(&mut &*encoded_inclusion_data)
+                .map_err(|_| Error::<T>::InclusionDataDecodeError)?;
+
+        let stored = <ImportedHeaders<T>>::get(gateway_id, inclusion_data.block_hash)
+            .ok_or(Error::<T>::UnknownHeader)?;
+        ensure!(stored.finalized, Error::<T>::HeaderNotFinalized);
+
+        // ensures old equal side_effects can't be replayed
+        let submission_target: u64 = Decode::decode(&mut &*submission_target_height)
+            .map_err(|_| Error::<T>::ExecutedBeforeCreation)?;
+        ensure!(
+            submission_target < stored.header.number,
+            Error::<T>::ExecutedBeforeCreation
+        );
+
+        let receipt = match stored
+            .header
+            .check_receipt_proof(&inclusion_data.receipt_proof)
+        {
+            Some(Ok(receipt)) => receipt,
+            _ => return Err(Error::<T>::InvalidReceiptProof.into()),
+        };
+        // Pre-Byzantium receipts carry the post transaction state root instead of a status
+        ensure!(
+            receipt.post_state_or_status == [1u8] || receipt.post_state_or_status.len() == 32,
+            Error::<T>::TransactionFailed
+        );
+        ensure!(
+            receipt.contains_log(&inclusion_data.log),
+            Error::<T>::LogNotIncluded
+        );
+
+        side_effects::decode_log(
+            &side_effect_id,
+            inclusion_data.log,
+            value_abi_unsigned_type,
+            security_coordinates,
+        )
+        .map_err(|err| Error::<T>::from(err).into())
+    }
+
+    pub fn get_latest_finalized_header(gateway_id: ChainId) -> Option<Vec<u8>> {
+        <FinalizedHeaderMap<T>>::get(gateway_id).map(|header_id| header_id.hash.encode())
+    }
+
+    pub fn get_latest_finalized_height(gateway_id: ChainId) -> Option<Vec<u8>> {
+        <FinalizedHeaderMap<T>>::get(gateway_id).map(|header_id| header_id.number.encode())
+    }
+
+    /// Height of the oldest header that wasn't pruned yet
+    pub fn get_oldest_provable_height(gateway_id: ChainId) -> Option<Vec<u8>> {
+        <FinalizedHeaderMap<T>>::get(gateway_id)
+            .map(|_| <OldestHeaderNumber<T>>::get(gateway_id).encode())
+    }
+}
+
+impl<T: Config> LightClient<T> for Pallet<T> {
+    fn initialize(
+        origin: T::Origin,
+        gateway_id: ChainId,
+        encoded_registration_data: Vec<u8>,
+    ) -> Result<(), DispatchError> {
+        Self::initialize(origin, gateway_id, encoded_registration_data)
+    }
+
+    fn registration_owner(encoded_registration_data: &[u8]) -> Option<T::AccountId> {
+        Self::registration_owner(encoded_registration_data)
+    }
+
+    fn set_owner(
+        origin: T::Origin,
+        gateway_id: ChainId,
+        encoded_new_owner: Vec<u8>,
+    ) -> Result<(), DispatchError> {
+        Self::set_owner(origin, gateway_id, encoded_new_owner)
+    }
+
+    fn set_operational(
+        origin: T::Origin,
+        operational: bool,
+        gateway_id: ChainId,
+    ) -> Result<(), DispatchError> {
+        Self::set_operational(origin, operational, gateway_id)
+    }
+
+    fn submit_headers(
+        origin: T::Origin,
+        gateway_id: ChainId,
+        encoded_header_data: Vec<u8>,
+    ) -> Result<Vec<u8>, DispatchError> {
+        Self::submit_headers(origin, gateway_id, encoded_header_data)
+    }
+
+    fn get_latest_finalized_header(gateway_id: ChainId) -> Option<Vec<u8>> {
+        Self::get_latest_finalized_header(gateway_id)
+    }
+
+    fn get_latest_finalized_height(gateway_id: ChainId) -> Option<Vec<u8>> {
+        Self::get_latest_finalized_height(gateway_id)
+    }
+
+    fn get_oldest_provable_height(gateway_id: ChainId) -> Option<Vec<u8>> {
+        Self::get_oldest_provable_height(gateway_id)
+    }
+
+    fn confirm_and_decode_payload_params(
+        gateway_id: ChainId,
+        encoded_inclusion_data: Vec<u8>,
+        submission_target_height: Vec<u8>,
+        value_abi_unsigned_type: &[u8],
+        security_coordinates: &[u8],
+        side_effect_id: [u8; 4],
+    ) -> Result<(Vec<Vec<u8>>, Vec<u8>), DispatchError> {
+        Self::confirm_and_decode_payload_params(
+            gateway_id,
+            encoded_inclusion_data,
+            submission_target_height,
+            value_abi_unsigned_type,
+            security_coordinates,
+            side_effect_id,
+        )
+    }
+}
+
+pub(crate) fn import_header<T: Config>(
+    gateway_id: ChainId,
+    header: Header,
+    proof: &[DoubleNodeWithMerkleProof],
+    submitted_by_owner: bool,
+) -> Result<(), DispatchError> {
+    let config = <NetworkConfigMap<T>>::get(gateway_id).ok_or(Error::<T>::NotInitialized)?;
+    let hash = header.compute_hash();
+    ensure!(
+        !<ImportedHeaders<T>>::contains_key(gateway_id, hash),
+        Error::<T>::DuplicateHeader
+    );
+    let parent = <ImportedHeaders<T>>::get(gateway_id, header.parent_hash)
+        .ok_or(Error::<T>::UnknownParent)?;
+    ensure!(
+        header.number == parent.header.number + 1,
+        Error::<T>::InvalidRangeLinkage
+    );
+    let finalized =
+        <FinalizedHeaderMap<T>>::get(gateway_id).ok_or(Error::<T>::NoFinalizedHeader)?;
+    ensure!(
+        header.number > finalized.number,
+        Error::<T>::ConflictsWithFinalizedHeader
+    );
+
+    match config.merge_block {
+        Some(merge_block) if header.number >= merge_block => {
+            ensure!(header.difficulty.is_zero(), Error::<T>::InvalidDifficulty);
+            ensure!(submitted_by_owner, Error::<T>::UntrustedProofOfStakeHeader);
+        },
+        _ => verify_pow_header::<T>(&config, &header, &parent.header, proof)?,
+    }
+
+    let header_id = HeaderId {
+        number: header.number,
+        hash,
+    };
+    let total_difficulty = parent.total_difficulty.saturating_add(header.difficulty);
+    <ImportedHeaders<T>>::insert(
+        gateway_id,
+        hash,
+        StoredHeader {
+            header,
+            total_difficulty,
+            finalized: false,
+        },
+    );
+    <HeadersByNumber<T>>::append(gateway_id, header_id.number, hash);
+
+    // PoS headers don't add to the total difficulty, so the longer chain wins ties
+    let (best, best_total_difficulty) =
+        <BestHeaderMap<T>>::get(gateway_id).ok_or(Error::<T>::NoFinalizedHeader)?;
+    if (total_difficulty, header_id.number) > (best_total_difficulty, best.number) {
+        <BestHeaderMap<T>>::insert(gateway_id, (header_id, total_difficulty));
+        try_finalize::<T>(gateway_id, &config, header_id, finalized)?;
+    }
+
+    Ok(())
+}
+
+fn verify_pow_header<T: Config>(
+    config: &NetworkConfig,
+    header: &Header,
+    parent: &Header,
+    proof: &[DoubleNodeWithMerkleProof],
+) -> Result<(), DispatchError> {
+    let expected_difficulty = calc_difficulty(&config.difficulty_config, header.timestamp, parent)
+        .map_err(|_| Error::<T>::InvalidDifficulty)?;
+    ensure!(
+        header.difficulty == expected_difficulty,
+        Error::<T>::InvalidDifficulty
+    );
+
+    let seal_mix_hash = header.mix_hash().ok_or(Error::<T>::InvalidSeal)?;
+    let seal_nonce = header.nonce().ok_or(Error::<T>::InvalidSeal)?;
+    let (mix_hash, result) = EthashProver::new()
+        .hashimoto_merkle(
+            header.compute_partial_hash(),
+            seal_nonce,
+            header.number,
+            proof,
+        )
+        .map_err(|_| Error::<T>::InvalidEthashProof)?;
+    ensure!(mix_hash == seal_mix_hash, Error::<T>::InvalidEthashProof);
+    ensure!(
+        U256::from(result.0) < ethash::cross_boundary(header.difficulty),
+        Error::<T>::InsufficientProofOfWork
+    );
+
+    Ok(())
+}
+
+/// Finalizes the ancestor of the new best header that has `descendants_until_final` descendants.
+fn try_finalize<T: Config>(
+    gateway_id: ChainId,
+    config: &NetworkConfig,
+    best: HeaderId,
+    finalized: HeaderId,
+) -> Result<(), DispatchError> {
+    let target_number = match best
+        .number
+        .checked_sub(config.descendants_until_final as u64)
+    {
+        Some(number) if number > finalized.number => number,
+        _ => return Ok(()),
+    };
+
+    let mut hash = best.hash;
+    let mut newly_finalized: Vec<H256> = vec![];
+    let mut new_finalized = best;
+    loop {
+        let stored =
+            <ImportedHeaders<T>>::get(gateway_id, hash).ok_or(Error::<T>::UnknownHeader)?;
+        if stored.header.number <= finalized.number {
+            break
+        }
+        if stored.header.number == target_number {
+            new_finalized = HeaderId {
+                number: target_number,
+                hash,
+            };
+        }
+        if stored.header.number <= target_number {
+            newly_finalized.push(hash);
+        }
+        hash = stored.header.parent_hash;
+    }
+    ensure!(
+        hash == finalized.hash,
+        Error::<T>::ConflictsWithFinalizedHeader
+    );
+
+    for hash in newly_finalized {
+        <ImportedHeaders<T>>::mutate(gateway_id, hash, |maybe_stored| {
+            if let Some(stored) = maybe_stored {
+                stored.finalized = true;
+            }
+        });
+    }
+    <FinalizedHeaderMap<T>>::insert(gateway_id, new_finalized);
+    prune_headers::<T>(gateway_id, new_finalized.number);
+
+    Ok(())
+}
+
+/// Removes all headers more than `HeadersToStore` heights below the finalized one.
+fn prune_headers<T: Config>(gateway_id: ChainId, finalized_number: u64) {
+    let keep_from = finalized_number.saturating_sub(T::HeadersToStore::get());
+    let mut oldest = <OldestHeaderNumber<T>>::get(gateway_id);
+    let prune_until = keep_from.min(oldest.saturating_add(MAX_PRUNED_HEIGHTS_PER_IMPORT));
+
+    while oldest < prune_until {
+        for hash in <HeadersByNumber<T>>::take(gateway_id, oldest) {
+            <ImportedHeaders<T>>::remove(gateway_id, hash);
+        }
+        oldest += 1;
+    }
+    <OldestHeaderNumber<T>>::insert(gateway_id, oldest);
+}
+
+impl<T> From<side_effects::DecodeLogError> for Error<T> {
+    fn from(err: side_effects::DecodeLogError) -> Self {
+        match err {
+            side_effects::DecodeLogError::EventDecodingFailed => Error::<T>::EventDecodingFailed,
+            side_effects::DecodeLogError::AmountOverflow => Error::<T>::AmountOverflow,
+            side_effects::DecodeLogError::UnkownSideEffect => Error::<T>::UnkownSideEffect,
+            side_effects::DecodeLogError::UnexpectedEmitter => Error::<T>::UnexpectedEmitter,
+        }
+    }
+}
+
+/// Ensure that the gateway is in operational mode (not halted).
+fn ensure_operational<T: Config>(gateway_id: ChainId) -> Result<(), Error<T>> {
+    match <IsHaltedMap<T>>::get(gateway_id) {
+        Some(false) => Ok(()),
+        Some(true) => Err(<Error<T>>::Halted),
+        None => Err(<Error<T>>::NotInitialized),
+    }
+}
+
+/// Ensure that the origin is either root, or `PalletOwner`.
+fn ensure_owner_or_root<T: Config>(
+    origin: T::Origin,
+    gateway_id: ChainId,
+) -> Result<(), DispatchError> {
+    match origin.into() {
+        Ok(RawOrigin::Root) => Ok(()),
+        Ok(RawOrigin::Signed(ref signer))
+            if Some(signer) == <PalletOwnerMap<T>>::get(gateway_id).as_ref() =>
+            Ok(()),
+        _ => Err(BadOrigin.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, Origin, TestRuntime};
+    use frame_support::{assert_noop, assert_ok};
+    use hex_literal::hex;
+    use snowbridge_ethereum::{difficulty::DifficultyConfig, Log, H160};
+
+    const GATEWAY_ID: ChainId = *b"eth2";
+    const OWNER: u64 = 1;
+
+    fn first_header() -> Header {
+        Header {
+            number: 100,
+            receipts_root: hex!("d128e3a57142d2bf15bc0cbcac7ad54f40750d571b5c3097e425882c10c9ba66")
+                .into(),
+            ..Default::default()
+        }
+    }
+
+    fn child_of(parent: &Header) -> Header {
+        Header {
+            parent_hash: parent.compute_hash(),
+            number: parent.number + 1,
+            timestamp: parent.timestamp + 12,
+            ..Default::default()
+        }
+    }
+
+    fn initialize(merge_block: Option<u64>) {
+        let registration_data = EthereumRegistrationData::<u64> {
+            first_header: first_header(),
+            total_difficulty: U256::from(1_000_000u64),
+            network_config: NetworkConfig {
+                difficulty_config: DifficultyConfig::mainnet(),
+                descendants_until_final: 2,
+                merge_block,
+            },
+            owner: OWNER,
+        };
+
+        assert_ok!(Pallet::<TestRuntime>::initialize(
+            Origin::root(),
+            GATEWAY_ID,
+            registration_data.encode()
+        ));
+    }
+
+    fn submit(origin: Origin, headers: Vec<Header>) -> Result<Vec<u8>, DispatchError> {
+        let data = EthereumHeaderData {
+            headers: headers
+                .into_iter()
+                .map(|header| HeaderWithProof {
+                    header,
+                    proof: vec![],
+                })
+                .collect(),
+        };
+
+        Pallet::<TestRuntime>::submit_headers(origin, GATEWAY_ID, data.encode())
+    }
+
+    /// XDNS security coordinates naming USDT the token contract confirming transfers
+    fn security_coordinates() -> Vec<u8> {
+        [[0u8; 20], hex!("dac17f958d2ee523a2206206994597c13d831ec7")].concat()
+    }
+
+    // USDT transfer, taken from the snowfork receipt proof fixtures
+    fn usdt_transfer_inclusion_data(block_hash: H256) -> EthereumInclusionData {
+        EthereumInclusionData {
+            log: Log {
+                address: hex!("dac17f958d2ee523a2206206994597c13d831ec7").into(),
+                topics: vec![
+                    hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef").into(),
+                    H256::from(H160::from(hex!("2e514404ff6823f1b46a8318a709251db414e5e1"))),
+                    H256::from(H160::from(hex!("55021c55847c00d764357a352e5803237d328954"))),
+                ],
+                data: hex!("000000000000000000000000000000000000000000000000000000000201c370")
+                    .to_vec(),
+            },
+            receipt_proof: vec![
+                hex!("f90131a00d3cb8d3f57ac1c0e12918a2ebe0cafed8c273577b9dd73e7ed1079b403ef494a0678b9835b834f8a287c0dd33a8fca9146e456ca688555ed4ec1361a2180b778da0fe42da181a46677a043b3d9d4b8bb05a6a17b7b5c010c17e7c1d31cfb7c4f911a0c89f0e2c53241cdb578e1f2b4caf6ba36e00500bdc57fecd66b84a6a58394c19a086c3c1fae5a0575940b5d38e111c469d07883106c26856f3ef608469a2081f13a06c5992ff00aab6226a70a032fd2f571ba22f797321f45e2daa73020d638d21b0a050861e9503ef68728f6c90a44f7fe1bceb2a9bdab6957bbe7136166bd849561ea006aa6eaca8a07e57176e9aa41e6a09edfb7678d1a112404e0ec779d7e567e82ea0bb0b430d303ba21b0af11c487b8a218bd75db54c98940b3f11bad8ff47cad3ef8080808080808080").to_vec(),
+                hex!("f871a0246de222036ee6a03329b0105da0a6b3f916fc95a9ed5a403a581a0c4d74242ca0ac108a49a88b57a05ac34a108b39f1e45f6f167f2b9fbc8d52fb58e2e5a6af1ea0fcfe07ac2ccd3c28b6eab68d1bce112f6f6dbd9023e4ec3c05b96615aa803d798080808080808080808080808080").to_vec(),
+                hex!("e4820001a04fff54398cad4d05ea6abfd8b0f3b4fe14c04d7ff5f5211c5b927d9cf72ac1d8").to_vec(),
+                hex!("f851a096d010643ca2d47412ca66898286b5f2412963b9ec051b33e570d575914c9c5ca028cd24c652989542fe89479ec6388eac4592432242af5ba97563b3ac7c71c019808080808080808080808080808080").to_vec(),
+                hex!("f90211a0bb35a84c5b1dcb78ec9d32614912c696e62df77bebf9ab326ee55b5d3acdde46a01084b30dac8df0accfcd0fd6330b7f6fc72a4651246d0694be9162151686a620a03eed50afdce7909d784c6157c445a444c806b5f23d31f3b63786f600c84a95b2a0af5232f1df6c6d41879804d081abe867002abe26ba3e5f8e0254a83a54769831a0607915fb13dd5da594256389a45007a67a7f7a86e95d38d8462792b6c98a722ea00e1260fda1730f2738c650ce2bfba83857bc10f8fb119ebc4fb39acba24e6fbaa0d11de17e417327457812675ca3b84ae8e1b64827abfe01420953697c8313d5b1a05fcaf2f7a88f76336a0c32ffc78acb87ae2005454bd25d658035331be3173b46a03f94f4952ab9e650f83cfd0e7f367b1bcc493aacf39a06f16c4a2e1b5605da48a0bdb4ec79785ca8ae22d60f1bbd42d707b4d7ec4aff231a3ebab755e315b35053a043a67c3f2bcef37c8f47a673adcb7061007a553696d1092408601c11b2e6846aa0c519d5af48cae87c7f4538845417c9735813bee892a6fe2dda79f5c414e8576aa0f7058256e09589501d7c231d739e61c84a850e139690989d24fda6058b432e98a081a52faab520978cb19ce14400dba0cd5bcdc4e5a3c0740678aa8f97ee0e5c56a0bcecc61cadeae52518e3b68a48af4b11603dfd9d99d99d7985efa6d2de44f904a02cba4accfc6f39bc5adb6d4440eb6358b4a5103ef93298e4e694f1f940f8b48280").to_vec(),
+                hex!("f901ae20b901aaf901a70183bb444eb9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010000000000000001000000000000000000000000000100000000000008000000000000000000000000000000000000000000000000000000000000000000000000000000000200000000000010000000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000080000000000000000000000000000000000000000000000002000000000000000000081000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000f89df89b94dac17f958d2ee523a2206206994597c13d831ec7f863a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa00000000000000000000000002e514404ff6823f1b46a8318a709251db414e5e1a000000000000000000000000055021c55847c00d764357a352e5803237d328954a0000000000000000000000000000000000000000000000000000000000201c370").to_vec(),
+            ],
+            block_hash,
+        }
+    }
+
+    #[test]
+    fn confirms_transfer_included_in_finalized_header() {
+        run_test(|| {
+            initialize(Some(0));
+            let block_hash = first_header().compute_hash();
+
+            let (params, emitter) = Pallet::<TestRuntime>::confirm_and_decode_payload_params(
+                GATEWAY_ID,
+                usdt_transfer_inclusion_data(block_hash).encode(),
+                50u64.encode(),
+                b"uint128",
+                &security_coordinates(),
+                *b"tran",
+            )
+            .unwrap();
+
+            assert_eq!(
+                params,
+                vec![
+                    hex!("2e514404ff6823f1b46a8318a709251db414e5e1").to_vec(),
+                    hex!("55021c55847c00d764357a352e5803237d328954").to_vec(),
+                    33_670_000u128.encode(),
+                ]
+            );
+            assert_eq!(
+                emitter,
+                hex!("dac17f958d2ee523a2206206994597c13d831ec7").to_vec()
+            );
+        })
+    }
+
+    #[test]
+    fn rejects_transfer_of_a_token_other_than_the_gateways() {
+        run_test(|| {
+            initialize(Some(0));
+            let other_token_coordinates = [[0u8; 20], [9u8; 20]].concat();
+
+            assert_noop!(
+                Pallet::<TestRuntime>::confirm_and_decode_payload_params(
+                    GATEWAY_ID,
+                    usdt_transfer_inclusion_data(first_header().compute_hash()).encode(),
+                    50u64.encode(),
+                    b"uint128",
+                    &other_token_coordinates,
+                    *b"tran",
+                ),
+                Error::<TestRuntime>::UnexpectedEmitter
+            );
+        })
+    }
+
+    #[test]
+    fn rejects_log_missing_from_proven_receipt() {
+        run_test(|| {
+            initialize(Some(0));
+            let mut inclusion_data = usdt_transfer_inclusion_data(first_header().compute_hash());
+            inclusion_data.log.data = U256::from(1u64).encode();
+
+            assert_noop!(
+                Pallet::<TestRuntime>::confirm_and_decode_payload_params(
+                    GATEWAY_ID,
+                    inclusion_data.encode(),
+                    50u64.encode(),
+                    b"uint128",
+                    &security_coordinates(),
+                    *b"tran",
+                ),
+                Error::<TestRuntime>::LogNotIncluded
+            );
+        })
+    }
+
+    #[test]
+    fn rejects_transfer_executed_before_side_effect_creation() {
+        run_test(|| {
+            initialize(Some(0));
+
+            assert_noop!(
+                Pallet::<TestRuntime>::confirm_and_decode_payload_params(
+                    GATEWAY_ID,
+                    usdt_transfer_inclusion_data(first_header().compute_hash()).encode(),
+                    100u64.encode(),
+                    b"uint128",
+                    &security_coordinates(),
+                    *b"tran",
+                ),
+                Error::<TestRuntime>::ExecutedBeforeCreation
+            );
+        })
+    }
+
+    #[test]
+    fn finalizes_pos_headers_after_enough_descendants() {
+        run_test(|| {
+            assert_eq!(
+                Pallet::<TestRuntime>::get_oldest_provable_height(GATEWAY_ID),
+                None
+            );
+            initialize(Some(0));
+            assert_eq!(
+                Pallet::<TestRuntime>::get_oldest_provable_height(GATEWAY_ID),
+                Some(100u64.encode())
+            );
+            let h101 = child_of(&first_header());
+            let h102 = child_of(&h101);
+            let h103 = child_of(&h102);
+
+            assert_ok!(submit(
+                Origin::signed(OWNER),
+                vec![h101.clone(), h102.clone()]
+            ));
+            assert_eq!(
+                Pallet::<TestRuntime>::get_latest_finalized_height(GATEWAY_ID),
+                Some(100u64.encode())
+            );
+            assert!(
+                !Pallet::<TestRuntime>::get_imported_header(GATEWAY_ID, h101.compute_hash())
+                    .unwrap()
+                    .finalized
+            );
+
+            assert_ok!(submit(Origin::signed(OWNER), vec![h103]));
+            assert_eq!(
+                Pallet::<TestRuntime>::get_finalized_header(GATEWAY_ID),
+                Some(HeaderId {
+                    number: 101,
+                    hash: h101.compute_hash()
+                })
+            );
+            assert!(
+                Pallet::<TestRuntime>::get_imported_header(GATEWAY_ID, h101.compute_hash())
+                    .unwrap()
+                    .finalized
+            );
+        })
+    }
+
+    #[test]
+    fn rejects_pos_headers_not_submitted_by_owner() {
+        run_test(|| {
+            initialize(Some(0));
+
+            assert_noop!(
+                submit(Origin::signed(2), vec![child_of(&first_header())]),
+                Error::<TestRuntime>::UntrustedProofOfStakeHeader
+            );
+        })
+    }
+
+    #[test]
+    fn rejects_pow_headers_with_invalid_difficulty() {
+        run_test(|| {
+            initialize(None);
+
+            assert_noop!(
+                submit(Origin::signed(2), vec![child_of(&first_header())]),
+                Error::<TestRuntime>::InvalidDifficulty
+            );
+        })
+    }
+
+    #[test]
+    fn rejects_headers_with_unknown_parent() {
+        run_test(|| {
+            initialize(Some(0));
+            let orphan = child_of(&child_of(&first_header()));
+
+            assert_noop!(
+                submit(Origin::signed(OWNER), vec![orphan]),
+                Error::<TestRuntime>::UnknownParent
+            );
+        })
+    }
+}
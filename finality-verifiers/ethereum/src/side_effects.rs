@@ -0,0 +1,283 @@
+use codec::Encode;
+use ethabi::{Event, Param, ParamKind, Token};
+use snowbridge_ethereum::{Log, U256};
+use sp_std::{convert::TryFrom, vec, vec::Vec};
+use t3rn_primitives::xdns::{ethereum_t3rn_contract, ethereum_transfer_token};
+
+/// Reasons a proven log can't be decoded into side effect arguments
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum DecodeLogError {
+    /// The logs paramaters couldn't be decoded
+    EventDecodingFailed,
+    /// The decoded amount doesn't fit the gateways value type
+    AmountOverflow,
+    /// The side effect is not known for this vendor
+    UnkownSideEffect,
+    /// The log wasn't emitted by the contract the gateway's XDNS record expects
+    UnexpectedEmitter,
+}
+
+/// ERC-20 `Transfer(address indexed from, address indexed to, uint256 value)`
+fn transfer_event_params() -> Vec<Param> {
+    vec![
+        Param {
+            kind: ParamKind::Address,
+            indexed: true,
+        },
+        Param {
+            kind: ParamKind::Address,
+            indexed: true,
+        },
+        Param {
+            kind: ParamKind::Uint(256),
+            indexed: false,
+        },
+    ]
+}
+
+/// Emitted by the t3rn gateway contract once an executor relayed an EVM call:
+///     `ExecutedCall(address indexed executor, address indexed target, uint256 value, bytes input)`
+fn executed_call_event_params() -> Vec<Param> {
+    vec![
+        Param {
+            kind: ParamKind::Address,
+            indexed: true,
+        },
+        Param {
+            kind: ParamKind::Address,
+            indexed: true,
+        },
+        Param {
+            kind: ParamKind::Uint(256),
+            indexed: false,
+        },
+        Param {
+            kind: ParamKind::Bytes,
+            indexed: false,
+        },
+    ]
+}
+
+/// Decodes the log emitted by the side effect into its arguments,
+///     provided it's emitted by the contract named in the gateway's security coordinates:
+///     the ERC-20 token contract for transfers, or the t3rn gateway contract for EVM calls.
+/// Returns the arguments along with the address of the emitter.
+/// Shared by the light clients of all Ethereum flavoured gateways.
+pub fn decode_log(
+    id: &[u8; 4],
+    log: Log,
+    value_abi_unsigned_type: &[u8],
+    security_coordinates: &[u8],
+) -> Result<(Vec<Vec<u8>>, Vec<u8>), DecodeLogError> {
+    let expected_emitter = match id {
+        b"tran" => ethereum_transfer_token(security_coordinates),
+        b"cevm" => ethereum_t3rn_contract(security_coordinates),
+        _ => return Err(DecodeLogError::UnkownSideEffect),
+    };
+    let emitter = log.address.as_bytes().to_vec();
+    if expected_emitter != Some(&emitter[..]) {
+        return Err(DecodeLogError::UnexpectedEmitter)
+    }
+
+    match id {
+        b"tran" => {
+            let inputs = transfer_event_params();
+            let event = Event {
+                signature: "Transfer(address,address,uint256)",
+                inputs: &inputs,
+                anonymous: false,
+            };
+            let mut tokens = event
+                .decode(log.topics, log.data)
+                .map_err(|_| DecodeLogError::EventDecodingFailed)?
+                .into_iter();
+
+            let from = next_address(&mut tokens)?;
+            let to = next_address(&mut tokens)?;
+            let amount = next_amount(&mut tokens, value_abi_unsigned_type)?;
+
+            Ok((vec![from, to, amount], emitter))
+        },
+        b"cevm" => {
+            let inputs = executed_call_event_params();
+            let event = Event {
+                signature: "ExecutedCall(address,address,uint256,bytes)",
+                inputs: &inputs,
+                anonymous: false,
+            };
+            let mut tokens = event
+                .decode(log.topics, log.data)
+                .map_err(|_| DecodeLogError::EventDecodingFailed)?
+                .into_iter();
+
+            let executor = next_address(&mut tokens)?;
+            let target = next_address(&mut tokens)?;
+            let value = next_amount(&mut tokens, value_abi_unsigned_type)?;
+            let input = tokens
+                .next()
+                .and_then(Token::to_bytes)
+                .ok_or(DecodeLogError::EventDecodingFailed)?;
+
+            Ok((vec![executor, target, value, input], emitter))
+        },
+        _ => Err(DecodeLogError::UnkownSideEffect),
+    }
+}
+
+fn next_address(tokens: &mut impl Iterator<Item = Token>) -> Result<Vec<u8>, DecodeLogError> {
+    tokens
+        .next()
+        .and_then(Token::to_address)
+        .map(|address| address.as_bytes().to_vec())
+        .ok_or(DecodeLogError::EventDecodingFailed)
+}
+
+/// Decodes the next uint256 and SCALE encodes it as the gateways value type,
+/// so it compares to the side effects arguments like the amounts decoded by the other verifiers.
+fn next_amount(
+    tokens: &mut impl Iterator<Item = Token>,
+    value_abi_unsigned_type: &[u8],
+) -> Result<Vec<u8>, DecodeLogError> {
+    let value: U256 = tokens
+        .next()
+        .and_then(Token::to_uint)
+        .ok_or(DecodeLogError::EventDecodingFailed)?;
+
+    match value_abi_unsigned_type {
+        b"uint32" => u32::try_from(value).map(|v| v.encode()),
+        b"uint64" => u64::try_from(value).map(|v| v.encode()),
+        b"uint128" => u128::try_from(value).map(|v| v.encode()),
+        _ => return Err(DecodeLogError::EventDecodingFailed),
+    }
+    .map_err(|_| DecodeLogError::AmountOverflow)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use hex_literal::hex;
+    use snowbridge_ethereum::{H160, H256};
+
+    const T3RN_CONTRACT: [u8; 20] = [3u8; 20];
+    const USDT_CONTRACT: [u8; 20] = hex!("dac17f958d2ee523a2206206994597c13d831ec7");
+
+    fn security_coordinates() -> Vec<u8> {
+        [T3RN_CONTRACT, USDT_CONTRACT].concat()
+    }
+
+    fn transfer_log(value: U256) -> Log {
+        Log {
+            address: USDT_CONTRACT.into(),
+            topics: vec![
+                hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef").into(),
+                H256::from(H160::from(hex!("2e514404ff6823f1b46a8318a709251db414e5e1"))),
+                H256::from(H160::from(hex!("55021c55847c00d764357a352e5803237d328954"))),
+            ],
+            data: ethabi::encode(&[Token::Uint(value)]),
+        }
+    }
+
+    #[test]
+    fn decodes_erc20_transfer_log() {
+        let res = decode_log(
+            b"tran",
+            transfer_log(33_670_000u64.into()),
+            b"uint128",
+            &security_coordinates(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            res.0,
+            vec![
+                hex!("2e514404ff6823f1b46a8318a709251db414e5e1").to_vec(),
+                hex!("55021c55847c00d764357a352e5803237d328954").to_vec(),
+                33_670_000u128.encode(),
+            ]
+        );
+        assert_eq!(res.1, USDT_CONTRACT.to_vec());
+    }
+
+    #[test]
+    fn fails_to_decode_transfer_amount_overflowing_value_type() {
+        assert_eq!(
+            decode_log(
+                b"tran",
+                transfer_log(U256::from(u64::MAX) + 1),
+                b"uint64",
+                &security_coordinates()
+            ),
+            Err(DecodeLogError::AmountOverflow)
+        );
+    }
+
+    #[test]
+    fn fails_to_decode_log_with_foreign_signature() {
+        let mut log = transfer_log(1u64.into());
+        log.topics[0] = H256::repeat_byte(1);
+
+        assert_eq!(
+            decode_log(b"tran", log, b"uint128", &security_coordinates()),
+            Err(DecodeLogError::EventDecodingFailed)
+        );
+    }
+
+    #[test]
+    fn fails_to_decode_transfer_log_of_foreign_token_contract() {
+        let mut log = transfer_log(1u64.into());
+        log.address = H160::repeat_byte(9);
+
+        assert_eq!(
+            decode_log(b"tran", log, b"uint128", &security_coordinates()),
+            Err(DecodeLogError::UnexpectedEmitter)
+        );
+    }
+
+    #[test]
+    fn fails_to_decode_transfer_log_without_token_contract_in_security_coordinates() {
+        assert_eq!(
+            decode_log(
+                b"tran",
+                transfer_log(1u64.into()),
+                b"uint128",
+                &T3RN_CONTRACT
+            ),
+            Err(DecodeLogError::UnexpectedEmitter)
+        );
+    }
+
+    #[test]
+    fn decodes_executed_call_log() {
+        let executor = H160::repeat_byte(1);
+        let target = H160::repeat_byte(2);
+        let log = Log {
+            address: T3RN_CONTRACT.into(),
+            topics: vec![
+                H256::from(sp_io::hashing::keccak_256(
+                    b"ExecutedCall(address,address,uint256,bytes)",
+                )),
+                H256::from(executor),
+                H256::from(target),
+            ],
+            data: ethabi::encode(&[Token::Uint(100u64.into()), Token::Bytes(vec![1, 2, 3])]),
+        };
+
+        let res = decode_log(b"cevm", log.clone(), b"uint64", &security_coordinates()).unwrap();
+
+        assert_eq!(
+            res.0,
+            vec![
+                executor.as_bytes().to_vec(),
+                target.as_bytes().to_vec(),
+                100u64.encode(),
+                vec![1, 2, 3],
+            ]
+        );
+
+        // The same log emitted by any other contract, like the token one, is no proof
+        assert_eq!(
+            decode_log(b"cevm", log, b"uint64", &USDT_CONTRACT),
+            Err(DecodeLogError::UnexpectedEmitter)
+        );
+    }
+}
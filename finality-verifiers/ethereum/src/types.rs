@@ -0,0 +1,60 @@
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use snowbridge_ethereum::{
+    difficulty::DifficultyConfig, ethashproof::DoubleNodeWithMerkleProof, Header, Log, H256, U256,
+};
+use sp_std::vec::Vec;
+
+pub type ChainId = [u8; 4];
+
+/// Consensus parameters of the bridged Ethereum network, set once on registration.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct NetworkConfig {
+    /// Hard fork heights used to recompute the difficulty of PoW headers
+    pub difficulty_config: DifficultyConfig,
+    /// Number of descendants a header needs before it's considered final
+    pub descendants_until_final: u8,
+    /// First block produced under PoS, if the network went through the merge.
+    /// Headers from this height on carry no PoW seal and are only accepted from the gateway owner.
+    pub merge_block: Option<u64>,
+}
+
+#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo)]
+pub struct EthereumRegistrationData<AccountId> {
+    /// Trusted header the light client starts from. It's treated as final.
+    pub first_header: Header,
+    /// Total difficulty of the chain up to and including `first_header`
+    pub total_difficulty: U256,
+    pub network_config: NetworkConfig,
+    pub owner: AccountId,
+}
+
+#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo)]
+pub struct HeaderWithProof {
+    pub header: Header,
+    /// Ethash DAG nodes proving the PoW seal. Empty for PoS headers.
+    pub proof: Vec<DoubleNodeWithMerkleProof>,
+}
+
+#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo)]
+pub struct EthereumHeaderData {
+    /// Headers to import, ordered parent first
+    pub headers: Vec<HeaderWithProof>,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct EthereumInclusionData {
+    /// the log we're proving to be included in the receipt of a transaction in `block_hash`
+    pub log: Log,
+    /// MPT proof of the receipt against the headers receipts root
+    pub receipt_proof: Vec<Vec<u8>>,
+    pub block_hash: H256,
+}
+
+#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo)]
+pub struct StoredHeader {
+    pub header: Header,
+    pub total_difficulty: U256,
+    /// Set once the header is an ancestor of (or is) the finalized header
+    pub finalized: bool,
+}
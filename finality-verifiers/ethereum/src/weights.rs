@@ -0,0 +1,44 @@
+//! Weights for pallet_ethereum_finality_verifier, not yet benchmarked.
+
+#![allow(clippy::all)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_ethereum_finality_verifier.
+pub trait WeightInfo {
+    fn import_header() -> Weight;
+    fn confirm_and_decode_payload_params() -> Weight;
+}
+
+/// Weights for pallet_ethereum_finality_verifier using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    fn import_header() -> Weight {
+        (120_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(6 as Weight))
+            .saturating_add(T::DbWeight::get().writes(5 as Weight))
+    }
+
+    fn confirm_and_decode_payload_params() -> Weight {
+        (40_000_000 as Weight).saturating_add(T::DbWeight::get().reads(2 as Weight))
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    fn import_header() -> Weight {
+        (120_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(6 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(5 as Weight))
+    }
+
+    fn confirm_and_decode_payload_params() -> Weight {
+        (40_000_000 as Weight).saturating_add(RocksDbWeight::get().reads(2 as Weight))
+    }
+}
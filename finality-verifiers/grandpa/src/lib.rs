@@ -45,14 +45,14 @@ use sp_std::convert::TryInto;
 
 use finality_grandpa::voter_set::VoterSet;
 use frame_support::{ensure, pallet_prelude::*, StorageHasher};
-use frame_system::{ensure_signed, RawOrigin};
+use frame_system::{ensure_root, ensure_signed, RawOrigin};
 use num_traits::cast::AsPrimitive;
 use sp_core::crypto::ByteArray;
 use sp_finality_grandpa::{ConsensusLog, GRANDPA_ENGINE_ID};
 use sp_runtime::traits::{BadOrigin, Header as HeaderT, Zero};
 use sp_std::{vec, vec::Vec};
 
-mod types;
+pub mod types;
 
 use sp_trie::{read_trie_value, LayoutV1, StorageProof};
 use types::GrandpaRegistrationData;
@@ -86,7 +86,10 @@ pub type BridgedHeader<T, I> = HeaderOf<<T as Config<I>>::BridgedChain>;
 
 use crate::{
     side_effects::decode_event,
-    types::{InclusionData, Parachain, ParachainHeaderData, RelaychainHeaderData},
+    types::{
+        InclusionData, Parachain, ParachainHeadRange, ParachainHeaderData, ParachainHeadsData,
+        RelaychainHeaderData,
+    },
 };
 use frame_system::pallet_prelude::*;
 
@@ -105,6 +108,7 @@ pub mod pallet {
         /// The setting is there to prevent growing the on-chain state indefinitely. Note
         /// the setting does not relate to block numbers - we will simply keep as much items
         /// in the storage, so it doesn't guarantee any fixed timeframe for finality headers.
+        /// Governance may override it per gateway with `set_headers_to_store`.
         #[pallet::constant]
         type HeadersToStore: Get<u32>;
 
@@ -143,6 +147,12 @@ pub mod pallet {
     pub(super) type MultiImportedHashesPointer<T: Config<I>, I: 'static = ()> =
         StorageMap<_, Blake2_256, ChainId, u32>;
 
+    /// Number of headers kept for a gateway, overriding `HeadersToStore`.
+    #[pallet::storage]
+    #[pallet::getter(fn get_headers_to_store)]
+    pub(super) type HeadersToStoreMap<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_256, ChainId, u32>;
+
     /// Headers which have been imported into the pallet.
     #[pallet::storage]
     #[pallet::getter(fn get_multi_imported_headers)]
@@ -223,6 +233,8 @@ pub mod pallet {
         InvalidJustificationLinkage,
         /// The parachain entry was not found in storage
         ParachainEntryNotFound,
+        /// The parachain isn't registered on the relaychain the heads were proven for
+        ParachainRelaychainMismatch,
         /// The relaychains storge root was not found. This implies the header is not available
         StorageRootNotFound,
         /// The inclusion data couldn't be decoded
@@ -249,6 +261,14 @@ pub mod pallet {
         Halted,
         /// The block height couldn't be converted
         BlockHeightConversionError,
+        /// The referenced header was pruned already and can't be used for proofs anymore
+        HeaderPruned,
+        /// At least one header has to be kept per gateway and the current number must be bounded
+        InvalidHeadersToStore,
+        /// The gateway isn't known to this verifier
+        UnknownGateway,
+        /// The batch holds more parachain heads than were declared
+        TooManyParachainHeads,
     }
 
     /// Add a header range for the relaychain
@@ -271,7 +291,7 @@ pub mod pallet {
         ensure!(!range.is_empty(), Error::<T, I>::EmptyRangeSubmitted);
 
         // °°°°° Implicit Check: °°°°°
-        // range.len() < T::HeadersToStore::get() - ensures that we don't mess up our ring buffer
+        // range.len() < headers_to_store(gateway_id) - ensures that we don't mess up our ring buffer
         // Since polkadot updates its authority set every 24h, this is implicitly ensured => Justification check would fail after 1/7th of max len
 
         // we get the latest header from storage
@@ -373,18 +393,6 @@ pub mod pallet {
         proof: StorageProof,
     ) -> Result<Vec<u8>, DispatchError> {
         ensure!(!range.is_empty(), Error::<T, I>::EmptyRangeSubmitted);
-        ensure!(
-            range.len() < T::HeadersToStore::get().try_into().unwrap(),
-            Error::<T, I>::RangeToLarge
-        ); // this should be safe to do, as u32
-
-        let mut best_finalized = <MultiImportedHeaders<T, I>>::get(
-            gateway_id,
-            // Every time `BestFinalized` is updated `ImportedHeaders` is also updated. Therefore
-            // `ImportedHeaders` must contain an entry for `BestFinalized`.
-            <BestFinalizedMap<T, I>>::get(gateway_id).ok_or(Error::<T, I>::NoFinalizedHeader)?,
-        )
-        .ok_or(Error::<T, I>::NoFinalizedHeader)?;
 
         // °°°°° Explaination °°°°°
         // To be able to submit ranges of headers, we need to ensure a number of things.
@@ -400,6 +408,80 @@ pub mod pallet {
             verify_header_storage_proof::<T, I>(relay_block_hash, proof, parachain)?;
         // °°°°° Check Success: #1 °°°°°
 
+        import_parachain_headers::<T, I>(gateway_id, range, signed_header)
+    }
+
+    /// Verify the heads of several parachains with a single storage proof of their relaychain
+    ///
+    /// Each parachain of the batch is then imported like in `submit_parachain_header`. Parachains
+    /// whose proven head was imported already are skipped, so relayers can always submit all of
+    /// them.
+    pub(crate) fn submit_parachain_heads<T: Config<I>, I>(
+        relay_chain_id: ChainId,
+        relay_block_hash: BridgedBlockHash<T, I>,
+        parachains: Vec<ParachainHeadRange<BridgedHeader<T, I>>>,
+        proof: StorageProof,
+    ) -> Result<Vec<(ChainId, Vec<u8>)>, DispatchError> {
+        ensure!(!parachains.is_empty(), Error::<T, I>::EmptyRangeSubmitted);
+
+        let mut keys = Vec::with_capacity(parachains.len());
+        for ParachainHeadRange { gateway_id, .. } in parachains.iter() {
+            ensure_operational_single::<T, I>(*gateway_id)?;
+            let parachain = <ParachainIdMap<T, I>>::try_get(gateway_id)
+                .map_err(|_| Error::<T, I>::ParachainEntryNotFound)?;
+            ensure!(
+                parachain.relay_chain_id == relay_chain_id,
+                Error::<T, I>::ParachainRelaychainMismatch
+            );
+            keys.push(parachain_head_storage_key(&parachain));
+        }
+
+        let encoded_heads = verify_storage_proof_for_keys::<T, I>(
+            relay_chain_id,
+            relay_block_hash,
+            keys,
+            proof,
+            ProofTriePointer::State,
+        )?;
+
+        let mut imported = vec![];
+        for (ParachainHeadRange { gateway_id, range }, encoded_head) in
+            parachains.into_iter().zip(encoded_heads)
+        {
+            let head = decode_parachain_head::<T, I>(encoded_head)?;
+            if range.is_empty()
+                && <MultiImportedHeaders<T, I>>::contains_key(gateway_id, head.hash())
+            {
+                continue
+            }
+
+            let height = import_parachain_headers::<T, I>(gateway_id, range, head)?;
+            imported.push((gateway_id, height));
+        }
+
+        Ok(imported)
+    }
+
+    /// Imports the range and the verified head of a parachain on top of its best finalized header.
+    /// Returns the height of the head.
+    pub(crate) fn import_parachain_headers<T: Config<I>, I>(
+        gateway_id: ChainId,
+        range: Vec<BridgedHeader<T, I>>,
+        signed_header: BridgedHeader<T, I>,
+    ) -> Result<Vec<u8>, DispatchError> {
+        ensure!(
+            range.len() < headers_to_store::<T, I>(gateway_id).try_into().unwrap(),
+            Error::<T, I>::RangeToLarge
+        ); // this should be safe to do, as u32
+
+        let mut best_finalized = <MultiImportedHeaders<T, I>>::get(
+            gateway_id,
+            // Every time `BestFinalized` is updated `ImportedHeaders` is also updated. Therefore
+            // `ImportedHeaders` must contain an entry for `BestFinalized`.
+            <BestFinalizedMap<T, I>>::get(gateway_id).ok_or(Error::<T, I>::NoFinalizedHeader)?,
+        )
+        .ok_or(Error::<T, I>::NoFinalizedHeader)?;
+
         // We get the latest buffer_index, which maps to the next header we can overwrite, and the index where we insert the verified header
         let mut buffer_index =
             <MultiImportedHashesPointer<T, I>>::get(gateway_id).unwrap_or_default();
@@ -579,7 +661,14 @@ pub mod pallet {
         let initial_hash = header.hash();
         <InitialHashMap<T, I>>::insert(gateway_id, initial_hash);
         <BestFinalizedMap<T, I>>::insert(gateway_id, initial_hash);
+        <MultiImportedRoots<T, I>>::insert(
+            gateway_id,
+            initial_hash,
+            (header.extrinsics_root(), header.state_root()),
+        );
         <MultiImportedHeaders<T, I>>::insert(gateway_id, initial_hash, header);
+        // the initial header takes the first slot, so it is pruned like any other header
+        <MultiImportedHashes<T, I>>::insert(gateway_id, 0u32, initial_hash);
         <MultiImportedHashesPointer<T, I>>::insert(gateway_id, 1u32);
 
         <IsHaltedMap<T, I>>::insert(gateway_id, is_halted);
@@ -624,10 +713,61 @@ pub mod pallet {
             <BestFinalizedMap<T, I>>::insert(gateway_id, hash);
         }
 
-        *buffer_index = (*buffer_index + 1) % T::HeadersToStore::get(); // prevents overflows
+        *buffer_index = (*buffer_index + 1) % headers_to_store::<T, I>(gateway_id); // prevents overflows
         Ok(())
     }
 
+    /// Number of headers kept in the ring buffer of a gateway
+    pub(crate) fn headers_to_store<T: Config<I>, I: 'static>(gateway_id: ChainId) -> u32 {
+        <HeadersToStoreMap<T, I>>::get(gateway_id).unwrap_or_else(T::HeadersToStore::get)
+    }
+
+    /// Lays out the ring buffer of a gateway for a new number of headers to keep. The newest
+    /// headers are kept in their order, while the ones not fitting anymore are pruned.
+    pub(crate) fn resize_header_ring<T: Config<I>, I: 'static>(
+        gateway_id: ChainId,
+        new_headers_to_store: u32,
+    ) {
+        let current_headers_to_store = headers_to_store::<T, I>(gateway_id);
+        let pointer = <MultiImportedHashesPointer<T, I>>::get(gateway_id).unwrap_or_default();
+
+        // starting at the pointer, the buffer is iterated from the oldest to the newest header
+        let mut hashes: Vec<BridgedBlockHash<T, I>> = (0..current_headers_to_store)
+            .filter_map(|offset| {
+                <MultiImportedHashes<T, I>>::take(
+                    gateway_id,
+                    (pointer + offset) % current_headers_to_store,
+                )
+            })
+            .collect();
+
+        let pruned = hashes.len().saturating_sub(new_headers_to_store as usize);
+        for hash in hashes.drain(..pruned) {
+            <MultiImportedHeaders<T, I>>::remove(gateway_id, hash);
+            <MultiImportedRoots<T, I>>::remove(gateway_id, hash);
+        }
+
+        for (index, hash) in hashes.iter().enumerate() {
+            <MultiImportedHashes<T, I>>::insert(gateway_id, index as u32, hash);
+        }
+        <MultiImportedHashesPointer<T, I>>::insert(
+            gateway_id,
+            hashes.len() as u32 % new_headers_to_store,
+        );
+        <HeadersToStoreMap<T, I>>::insert(gateway_id, new_headers_to_store);
+    }
+
+    /// The oldest header of a gateway that wasn't pruned yet
+    pub(crate) fn oldest_imported_header<T: Config<I>, I: 'static>(
+        gateway_id: ChainId,
+    ) -> Option<BridgedHeader<T, I>> {
+        let pointer = <MultiImportedHashesPointer<T, I>>::get(gateway_id)?;
+        // until the buffer wrapped around, the slot at the pointer is empty
+        let hash = <MultiImportedHashes<T, I>>::get(gateway_id, pointer)
+            .or_else(|| <MultiImportedHashes<T, I>>::get(gateway_id, 0))?;
+        <MultiImportedHeaders<T, I>>::get(gateway_id, hash)
+    }
+
     /// Ensure that the pallet is in operational mode (not halted).
     pub fn ensure_operational_single<T: Config<I>, I: 'static>(
         gateway_id: ChainId,
@@ -731,6 +871,13 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         }
     }
 
+    /// Owner named by the encoded registration data, if it decodes.
+    pub fn registration_owner(encoded_registration_data: &[u8]) -> Option<T::AccountId> {
+        GrandpaRegistrationData::<T::AccountId>::decode(&mut &*encoded_registration_data)
+            .ok()
+            .map(|registration_data| registration_data.owner)
+    }
+
     /// Change `PalletOwner`.
     ///
     /// May only be called either by root, or by `PalletOwner`.
@@ -779,6 +926,39 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         Ok(())
     }
 
+    /// Change the number of headers kept for a gateway. Shrinking it prunes the oldest headers
+    /// right away. `current_headers_to_store` bounds the number of headers kept so far, which
+    /// the cost of the resize depends on. Returns the number of headers kept so far.
+    ///
+    /// May only be called by root.
+    pub fn set_headers_to_store(
+        origin: T::Origin,
+        gateway_id: ChainId,
+        headers_to_store: u32,
+        current_headers_to_store: u32,
+    ) -> Result<u32, DispatchError> {
+        ensure_root(origin)?;
+        ensure!(
+            <InstantiatedGatewaysMap<T, I>>::get().contains(&gateway_id),
+            Error::<T, I>::UnknownGateway
+        );
+        ensure!(headers_to_store > 0, Error::<T, I>::InvalidHeadersToStore);
+        let kept_headers = headers_to_store::<T, I>(gateway_id);
+        ensure!(
+            kept_headers <= current_headers_to_store,
+            Error::<T, I>::InvalidHeadersToStore
+        );
+
+        resize_header_ring::<T, I>(gateway_id, headers_to_store);
+        log::info!(
+            "Keeping {:?} headers of gateway {:?}",
+            headers_to_store,
+            gateway_id
+        );
+
+        Ok(kept_headers)
+    }
+
     pub fn submit_headers(
         origin: OriginFor<T>,
         gateway_id: ChainId,
@@ -811,6 +991,37 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         }
     }
 
+    /// Imports the heads of several parachains of `relay_chain_id`, proven with a single storage proof.
+    /// The batch may hold at most `parachains_count` heads.
+    /// Returns the gateways that were updated along with their new heights.
+    pub fn submit_parachain_heads(
+        origin: OriginFor<T>,
+        relay_chain_id: ChainId,
+        encoded_heads_data: Vec<u8>,
+        parachains_count: u32,
+    ) -> Result<Vec<(ChainId, Vec<u8>)>, DispatchError> {
+        ensure!(
+            Some(relay_chain_id) == <RelayChainId<T, I>>::get(),
+            Error::<T, I>::ParachainRelaychainMismatch
+        );
+        ensure_operational_single::<T, I>(relay_chain_id)?;
+        ensure_signed(origin)?;
+        let data: ParachainHeadsData<BridgedHeader<T, I>> =
+            Decode::decode(&mut &*encoded_heads_data)
+                .map_err(|_| Error::<T, I>::HeaderDataDecodingError)?;
+        ensure!(
+            data.parachains.len() <= parachains_count as usize,
+            Error::<T, I>::TooManyParachainHeads
+        );
+
+        submit_parachain_heads::<T, I>(
+            relay_chain_id,
+            data.relay_block_hash,
+            data.parachains,
+            data.proof,
+        )
+    }
+
     pub fn confirm_and_decode_payload_params(
         gateway_id: ChainId,
         encoded_inclusion_data: Vec<u8>,
@@ -822,11 +1033,17 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
             Decode::decode(&mut &*encoded_inclusion_data)
                 .map_err(|_| Error::<T, I>::InclusionDataDecodeError)?;
 
+        ensure_header_not_pruned::<T, I>(
+            gateway_id,
+            inclusion_data.block_hash,
+            &submission_target_height,
+        )?;
+
         // ensures old equal side_effects can't be replayed
         executed_after_creation::<T, I>(gateway_id, submission_target_height)?;
 
         match &side_effect_id {
-            b"tran" => verify_event_storage_proof::<T, I>(
+            b"tran" | b"rord" | b"ecmt" | b"ervt" => verify_event_storage_proof::<T, I>(
                 gateway_id,
                 inclusion_data,
                 value_abi_unsigned_type,
@@ -843,6 +1060,30 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         None
     }
 
+    /// Latest verified head of every registered parachain, as
+    /// `(gateway_id, parachain, header_hash, height)` with hash and height SCALE encoded
+    pub fn get_latest_parachain_heads() -> Vec<(ChainId, Parachain, Vec<u8>, Vec<u8>)> {
+        <InstantiatedGatewaysMap<T, I>>::get()
+            .into_iter()
+            .filter_map(|gateway_id| {
+                let parachain = <ParachainIdMap<T, I>>::get(gateway_id)?;
+                let header_hash = <BestFinalizedMap<T, I>>::get(gateway_id)?;
+                let header = <MultiImportedHeaders<T, I>>::get(gateway_id, header_hash)?;
+                Some((
+                    gateway_id,
+                    parachain,
+                    header_hash.encode(),
+                    header.number().encode(),
+                ))
+            })
+            .collect()
+    }
+
+    /// Height of the oldest header that storage proofs can still be verified against
+    pub fn get_oldest_provable_height(gateway_id: ChainId) -> Option<Vec<u8>> {
+        oldest_imported_header::<T, I>(gateway_id).map(|header| header.number().encode())
+    }
+
     pub fn get_latest_finalized_height(gateway_id: ChainId) -> Option<Vec<u8>> {
         if let Some(header_hash) = <BestFinalizedMap<T, I>>::get(gateway_id) {
             if let Some(header) = <MultiImportedHeaders<T, I>>::get(gateway_id, header_hash) {
@@ -872,6 +1113,27 @@ pub(crate) fn verify_storage_proof<T: Config<I>, I: 'static>(
     }
 }
 
+/// Verifies a storage proof holding several entries. Returns the encoded entries in the order of the keys
+pub(crate) fn verify_storage_proof_for_keys<T: Config<I>, I: 'static>(
+    gateway_id: ChainId,
+    block_hash: BridgedBlockHash<T, I>,
+    keys: Vec<Vec<u8>>,
+    proof: StorageProof,
+    trie_type: ProofTriePointer,
+) -> Result<Vec<Vec<u8>>, DispatchError> {
+    let root = get_header_roots::<T, I>(block_hash, gateway_id, trie_type)?;
+    let db = proof.into_memory_db::<BridgedBlockHasher<T, I>>();
+    keys.iter()
+        .map(|key| {
+            match read_trie_value::<LayoutV1<BridgedBlockHasher<T, I>>, _>(&db, &root, key.as_ref())
+            {
+                Ok(Some(value)) => Ok(value),
+                _ => Err(Error::<T, I>::InvalidStorageProof.into()),
+            }
+        })
+        .collect()
+}
+
 /// returns the specified header root from a specific header
 pub(crate) fn get_header_roots<T: pallet::Config<I>, I>(
     block_hash: BridgedBlockHash<T, I>,
@@ -946,6 +1208,27 @@ fn can_init_para_chain<T: Config<I>, I: 'static>(
     }
 }
 
+/// Headers are pruned oldest first. So if the block the side effect was executed in is unknown,
+/// while the side effect was submitted before the oldest header still available, it was pruned.
+fn ensure_header_not_pruned<T: Config<I>, I: 'static>(
+    gateway_id: ChainId,
+    block_hash: BridgedBlockHash<T, I>,
+    submission_target_height: &[u8],
+) -> Result<(), DispatchError> {
+    if <MultiImportedRoots<T, I>>::contains_key(gateway_id, block_hash) {
+        return Ok(())
+    }
+
+    let submission_target: BridgedBlockNumber<T, I> =
+        Decode::decode(&mut &*submission_target_height)
+            .map_err(|_| Error::<T, I>::BlockHeightConversionError)?;
+    match oldest_imported_header::<T, I>(gateway_id) {
+        Some(oldest) if submission_target < *oldest.number() =>
+            Err(Error::<T, I>::HeaderPruned.into()),
+        _ => Ok(()),
+    }
+}
+
 /// Ensure that the SideEffect was executed after it was created.
 fn executed_after_creation<T: Config<I>, I: 'static>(
     gateway_id: ChainId,
@@ -1029,6 +1312,18 @@ pub(crate) fn verify_header_storage_proof<T: Config<I>, I: 'static>(
     proof: StorageProof,
     parachain: Parachain,
 ) -> Result<BridgedHeader<T, I>, DispatchError> {
+    let encoded_header_vec = verify_storage_proof::<T, I>(
+        parachain.relay_chain_id,
+        relay_block_hash,
+        parachain_head_storage_key(&parachain),
+        proof,
+        ProofTriePointer::State,
+    )?;
+    decode_parachain_head::<T, I>(encoded_header_vec)
+}
+
+/// Storage key of the parachains head in the relaychains `Paras::Heads` map
+pub(crate) fn parachain_head_storage_key(parachain: &Parachain) -> Vec<u8> {
     // partial StorageKey for Paras_Heads. We now need to append the parachain_id as LE-u32 to generate the parachains StorageKey
     // This is a bit unclean, but it makes no sense to hash the StorageKey for each exec
     let mut key: Vec<u8> = [
@@ -1038,16 +1333,14 @@ pub(crate) fn verify_header_storage_proof<T: Config<I>, I: 'static>(
     .to_vec();
     let mut arg = Twox64Concat::hash(parachain.id.encode().as_ref());
     key.append(&mut arg); // complete storage key
+    key
+}
 
-    // ToDo not very concise
-    let encoded_header_vec = verify_storage_proof::<T, I>(
-        parachain.relay_chain_id,
-        relay_block_hash,
-        key,
-        proof,
-        ProofTriePointer::State,
-    )?;
-    let encoded_header: Vec<u8> = Decode::decode(&mut &encoded_header_vec[..])
+/// Decodes a `Paras::Heads` entry, which holds the SCALE encoded header as `HeadData`
+pub(crate) fn decode_parachain_head<T: Config<I>, I: 'static>(
+    encoded_head_data: Vec<u8>,
+) -> Result<BridgedHeader<T, I>, DispatchError> {
+    let encoded_header: Vec<u8> = Decode::decode(&mut &encoded_head_data[..])
         .map_err(|_| Error::<T, I>::HeaderDecodingError)?;
     let header: BridgedHeader<T, I> =
         Decode::decode(&mut &*encoded_header).map_err(|_| Error::<T, I>::HeaderDecodingError)?;
@@ -2044,4 +2337,462 @@ mod tests {
             );
         })
     }
+
+    fn initialize_relaychain_with_parachains() {
+        let _ = initialize_relaychain(Origin::root());
+        let _ = initialize_parachain(Origin::root());
+        let init_data = GrandpaRegistrationData::<AccountId> {
+            authorities: None,
+            first_header: test_header(0).encode(),
+            authority_set_id: None,
+            owner: 1u64,
+            parachain: Some(Parachain {
+                relay_chain_id: *b"pdot",
+                id: 2000,
+            }),
+        };
+        let _ = initialize_custom_parachain(Origin::root(), *b"acal", init_data);
+    }
+
+    /// Imports a relaychain header whose state holds the given `Paras::Heads` entries and returns
+    /// its hash along with a proof of all of them
+    fn craft_parachain_heads_proof(
+        heads: Vec<(u32, TestHeader)>,
+    ) -> (sp_core::H256, sp_trie::StorageProof) {
+        use sp_state_machine::{backend::Backend, prove_read, InMemoryBackend};
+        let state_version = sp_runtime::StateVersion::default();
+
+        let keys: Vec<Vec<u8>> = heads
+            .iter()
+            .map(|(id, _)| {
+                parachain_head_storage_key(&Parachain {
+                    relay_chain_id: *b"pdot",
+                    id: *id,
+                })
+            })
+            .collect();
+        let entries = keys
+            .iter()
+            .zip(heads.iter())
+            .map(|(key, (_, header))| (key.clone(), Some(header.encode().encode())))
+            .collect();
+        let backend =
+            <InMemoryBackend<sp_core::Blake2Hasher>>::from((vec![(None, entries)], state_version));
+        let state_root = backend.storage_root(sp_std::iter::empty(), state_version).0;
+        let proof = sp_trie::StorageProof::new(
+            prove_read(
+                backend,
+                &keys.iter().map(|key| &key[..]).collect::<Vec<_>>(),
+            )
+            .unwrap()
+            .iter_nodes(),
+        );
+
+        let relay_block_hash = test_header(1).hash();
+        <MultiImportedRoots<TestRuntime>>::insert(
+            *b"pdot",
+            relay_block_hash,
+            (sp_core::H256::default(), state_root),
+        );
+
+        (relay_block_hash, proof)
+    }
+
+    #[test]
+    fn submits_heads_of_several_parachains_with_one_proof() {
+        run_test(|| {
+            initialize_relaychain_with_parachains();
+            let headers = test_header_range(3);
+            let (relay_block_hash, proof) = craft_parachain_heads_proof(vec![
+                (0, headers[3].clone()),
+                (2000, headers[1].clone()),
+            ]);
+
+            let data = ParachainHeadsData::<TestHeader> {
+                relay_block_hash,
+                parachains: vec![
+                    ParachainHeadRange {
+                        gateway_id: *b"moon",
+                        range: headers[1..3].to_vec(),
+                    },
+                    ParachainHeadRange {
+                        gateway_id: *b"acal",
+                        range: vec![],
+                    },
+                ],
+                proof,
+            };
+
+            assert_ok!(
+                Pallet::<TestRuntime>::submit_parachain_heads(
+                    Origin::signed(1),
+                    *b"pdot",
+                    data.encode(),
+                    2
+                ),
+                vec![
+                    (*b"moon", 3u32.to_be_bytes().to_vec()),
+                    (*b"acal", 1u32.to_be_bytes().to_vec())
+                ]
+            );
+            assert_eq!(
+                <BestFinalizedMap<TestRuntime>>::get(*b"moon"),
+                Some(headers[3].hash())
+            );
+            assert_eq!(
+                <BestFinalizedMap<TestRuntime>>::get(*b"acal"),
+                Some(headers[1].hash())
+            );
+            assert_eq!(
+                Pallet::<TestRuntime>::get_latest_parachain_heads(),
+                vec![
+                    (
+                        *b"moon",
+                        Parachain {
+                            relay_chain_id: *b"pdot",
+                            id: 0
+                        },
+                        headers[3].hash().encode(),
+                        3u64.encode(),
+                    ),
+                    (
+                        *b"acal",
+                        Parachain {
+                            relay_chain_id: *b"pdot",
+                            id: 2000
+                        },
+                        headers[1].hash().encode(),
+                        1u64.encode(),
+                    ),
+                ]
+            );
+        })
+    }
+
+    #[test]
+    fn skips_parachain_heads_that_were_already_imported() {
+        run_test(|| {
+            initialize_relaychain_with_parachains();
+            let headers = test_header_range(1);
+            let (relay_block_hash, proof) = craft_parachain_heads_proof(vec![
+                (0, headers[0].clone()),
+                (2000, headers[1].clone()),
+            ]);
+
+            let data = ParachainHeadsData::<TestHeader> {
+                relay_block_hash,
+                parachains: vec![
+                    ParachainHeadRange {
+                        gateway_id: *b"moon",
+                        range: vec![],
+                    },
+                    ParachainHeadRange {
+                        gateway_id: *b"acal",
+                        range: vec![],
+                    },
+                ],
+                proof,
+            };
+
+            assert_ok!(
+                Pallet::<TestRuntime>::submit_parachain_heads(
+                    Origin::signed(1),
+                    *b"pdot",
+                    data.encode(),
+                    2
+                ),
+                vec![(*b"acal", 1u32.to_be_bytes().to_vec())]
+            );
+        })
+    }
+
+    #[test]
+    fn rejects_parachain_heads_missing_from_the_proof() {
+        run_test(|| {
+            initialize_relaychain_with_parachains();
+            let headers = test_header_range(1);
+            let (relay_block_hash, proof) =
+                craft_parachain_heads_proof(vec![(0, headers[1].clone())]);
+
+            let data = ParachainHeadsData::<TestHeader> {
+                relay_block_hash,
+                parachains: vec![
+                    ParachainHeadRange {
+                        gateway_id: *b"moon",
+                        range: vec![],
+                    },
+                    ParachainHeadRange {
+                        gateway_id: *b"acal",
+                        range: vec![],
+                    },
+                ],
+                proof,
+            };
+
+            assert_noop!(
+                Pallet::<TestRuntime>::submit_parachain_heads(
+                    Origin::signed(1),
+                    *b"pdot",
+                    data.encode(),
+                    2
+                ),
+                Error::<TestRuntime>::InvalidStorageProof
+            );
+        })
+    }
+
+    #[test]
+    fn rejects_more_parachain_heads_than_declared() {
+        run_test(|| {
+            initialize_relaychain_with_parachains();
+            let headers = test_header_range(1);
+            let (relay_block_hash, proof) = craft_parachain_heads_proof(vec![
+                (0, headers[1].clone()),
+                (2000, headers[1].clone()),
+            ]);
+
+            let data = ParachainHeadsData::<TestHeader> {
+                relay_block_hash,
+                parachains: vec![
+                    ParachainHeadRange {
+                        gateway_id: *b"moon",
+                        range: vec![],
+                    },
+                    ParachainHeadRange {
+                        gateway_id: *b"acal",
+                        range: vec![],
+                    },
+                ],
+                proof,
+            };
+
+            assert_noop!(
+                Pallet::<TestRuntime>::submit_parachain_heads(
+                    Origin::signed(1),
+                    *b"pdot",
+                    data.encode(),
+                    1
+                ),
+                Error::<TestRuntime>::TooManyParachainHeads
+            );
+        })
+    }
+
+    #[test]
+    fn rejects_parachain_heads_of_another_relaychain() {
+        run_test(|| {
+            initialize_relaychain_with_parachains();
+            let headers = test_header_range(1);
+            let (relay_block_hash, proof) =
+                craft_parachain_heads_proof(vec![(0, headers[1].clone())]);
+
+            let data = ParachainHeadsData::<TestHeader> {
+                relay_block_hash,
+                parachains: vec![ParachainHeadRange {
+                    gateway_id: *b"moon",
+                    range: vec![],
+                }],
+                proof,
+            };
+
+            assert_noop!(
+                Pallet::<TestRuntime>::submit_parachain_heads(
+                    Origin::signed(1),
+                    *b"ksma",
+                    data.encode(),
+                    1
+                ),
+                Error::<TestRuntime>::ParachainRelaychainMismatch
+            );
+        })
+    }
+
+    #[test]
+    fn initial_parachain_header_is_pruned_from_the_ring_buffer() {
+        run_test(|| {
+            initialize_relaychain_with_parachains();
+            let genesis_hash = test_header(0).hash();
+            assert_eq!(
+                <MultiImportedHashes<TestRuntime>>::get(*b"acal", 0),
+                Some(genesis_hash)
+            );
+
+            // HeadersToStore is 5, the head of the range overwrites the initial header
+            let headers = test_header_range(5);
+            let (relay_block_hash, proof) =
+                craft_parachain_heads_proof(vec![(2000, headers[5].clone())]);
+            let data = ParachainHeadsData::<TestHeader> {
+                relay_block_hash,
+                parachains: vec![ParachainHeadRange {
+                    gateway_id: *b"acal",
+                    range: headers[1..5].to_vec(),
+                }],
+                proof,
+            };
+            assert_ok!(Pallet::<TestRuntime>::submit_parachain_heads(
+                Origin::signed(1),
+                *b"pdot",
+                data.encode(),
+                1
+            ));
+
+            assert!(!<MultiImportedHeaders<TestRuntime>>::contains_key(
+                *b"acal",
+                genesis_hash
+            ));
+            assert!(!<MultiImportedRoots<TestRuntime>>::contains_key(
+                *b"acal",
+                genesis_hash
+            ));
+            assert!(<MultiImportedHeaders<TestRuntime>>::contains_key(
+                *b"acal",
+                headers[5].hash()
+            ));
+        })
+    }
+
+    #[test]
+    fn shrinking_headers_to_store_prunes_the_oldest_headers() {
+        let default_gateway: ChainId = *b"pdot";
+
+        run_test(|| {
+            let _ = initialize_relaychain(Origin::root());
+            let headers = test_header_range(7);
+            assert_ok!(submit_headers(1, 5));
+            assert_eq!(
+                Pallet::<TestRuntime>::get_oldest_provable_height(default_gateway),
+                Some(1u64.encode())
+            );
+
+            assert_eq!(
+                Pallet::<TestRuntime>::set_headers_to_store(Origin::root(), default_gateway, 3, 5),
+                Ok(5)
+            );
+            // [3, 4, 5]
+            //  ^
+            for header in &headers[1..3] {
+                assert!(!<MultiImportedHeaders<TestRuntime>>::contains_key(
+                    default_gateway,
+                    header.hash()
+                ));
+                assert!(!<MultiImportedRoots<TestRuntime>>::contains_key(
+                    default_gateway,
+                    header.hash()
+                ));
+            }
+            assert_eq!(
+                Pallet::<TestRuntime>::get_oldest_provable_height(default_gateway),
+                Some(3u64.encode())
+            );
+
+            assert_ok!(submit_headers(6, 7));
+            // [6, 7, 5]
+            //        ^
+            assert!(!<MultiImportedHeaders<TestRuntime>>::contains_key(
+                default_gateway,
+                headers[4].hash()
+            ));
+            assert!(<MultiImportedHeaders<TestRuntime>>::contains_key(
+                default_gateway,
+                headers[5].hash()
+            ));
+            assert_eq!(
+                Pallet::<TestRuntime>::get_oldest_provable_height(default_gateway),
+                Some(5u64.encode())
+            );
+        })
+    }
+
+    #[test]
+    fn growing_headers_to_store_keeps_headers_longer() {
+        let default_gateway: ChainId = *b"pdot";
+
+        run_test(|| {
+            let _ = initialize_relaychain(Origin::root());
+            let headers = test_header_range(7);
+            assert_ok!(submit_headers(1, 5));
+
+            assert_eq!(
+                Pallet::<TestRuntime>::set_headers_to_store(Origin::root(), default_gateway, 7, 5),
+                Ok(5)
+            );
+            assert_ok!(submit_headers(6, 7));
+
+            for header in &headers[1..] {
+                assert!(<MultiImportedHeaders<TestRuntime>>::contains_key(
+                    default_gateway,
+                    header.hash()
+                ));
+            }
+            assert_eq!(
+                Pallet::<TestRuntime>::get_headers_to_store(default_gateway),
+                Some(7)
+            );
+            assert_eq!(
+                Pallet::<TestRuntime>::get_oldest_provable_height(default_gateway),
+                Some(1u64.encode())
+            );
+        })
+    }
+
+    #[test]
+    fn only_root_can_set_headers_to_store_of_known_gateways() {
+        let default_gateway: ChainId = *b"pdot";
+
+        run_test(|| {
+            let _ = initialize_relaychain(Origin::root());
+
+            assert_noop!(
+                Pallet::<TestRuntime>::set_headers_to_store(
+                    Origin::signed(1),
+                    default_gateway,
+                    3,
+                    5
+                ),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                Pallet::<TestRuntime>::set_headers_to_store(Origin::root(), default_gateway, 0, 5),
+                Error::<TestRuntime>::InvalidHeadersToStore
+            );
+            assert_noop!(
+                Pallet::<TestRuntime>::set_headers_to_store(Origin::root(), *b"ksma", 3, 5),
+                Error::<TestRuntime>::UnknownGateway
+            );
+            // 5 headers are kept, more than declared
+            assert_noop!(
+                Pallet::<TestRuntime>::set_headers_to_store(Origin::root(), default_gateway, 3, 4),
+                Error::<TestRuntime>::InvalidHeadersToStore
+            );
+        })
+    }
+
+    #[test]
+    fn rejects_confirmation_against_pruned_header() {
+        let default_gateway: ChainId = *b"pdot";
+
+        run_test(|| {
+            let _ = initialize_relaychain(Origin::root());
+            let headers = test_header_range(10);
+            assert_ok!(submit_headers(1, 5));
+            assert_ok!(submit_headers(6, 10));
+
+            let inclusion_data = InclusionData::<TestHeader> {
+                encoded_payload: vec![],
+                proof: sp_trie::StorageProof::new(vec![]),
+                block_hash: headers[2].hash(),
+            };
+
+            assert_noop!(
+                Pallet::<TestRuntime>::confirm_and_decode_payload_params(
+                    default_gateway,
+                    inclusion_data.encode(),
+                    1u64.encode(),
+                    b"uint64",
+                    *b"tran",
+                ),
+                Error::<TestRuntime>::HeaderPruned
+            );
+        })
+    }
 }
@@ -181,3 +181,69 @@ pub fn brute_seed_block_1(gateway_id: [u8; 4]) {
 
     <BestFinalizedMap<TestRuntime>>::insert::<[u8; 4], H256>(gateway_id, block_hash_1);
 }
+
+/// Brute imports the roots of header 1 of the gateway, whose `System::Events` hold `encoded_events`,
+/// followed by header 2 as the best finalized one. Returns the inclusion data of `encoded_payload`
+/// emitted in header 1, ready to be confirmed at submission target height 1.
+#[cfg(feature = "testing")]
+pub fn brute_seed_block_1_with_events<T: Config<I>, I: 'static>(
+    gateway_id: [u8; 4],
+    encoded_events: Vec<u8>,
+    encoded_payload: Vec<u8>,
+) -> crate::types::InclusionData<crate::BridgedHeader<T, I>> {
+    brute_seed_block_with_events::<T, I>(gateway_id, 1, encoded_events, encoded_payload)
+}
+
+/// Brute imports the roots of header `height` of the gateway, whose `System::Events` hold `encoded_events`,
+/// followed by header `height + 1` as the best finalized one. Returns the inclusion data of `encoded_payload`
+/// emitted in header `height`, ready to be confirmed at submission target heights up to `height`.
+#[cfg(feature = "testing")]
+pub fn brute_seed_block_with_events<T: Config<I>, I: 'static>(
+    gateway_id: [u8; 4],
+    height: u32,
+    encoded_events: Vec<u8>,
+    encoded_payload: Vec<u8>,
+) -> crate::types::InclusionData<crate::BridgedHeader<T, I>> {
+    use sp_runtime::traits::Header as HeaderT;
+    use sp_state_machine::{backend::Backend, prove_read, InMemoryBackend};
+
+    let state_version = sp_runtime::StateVersion::default();
+    let events_key = [
+        sp_io::hashing::twox_128(b"System"),
+        sp_io::hashing::twox_128(b"Events"),
+    ]
+    .concat();
+    let backend = <InMemoryBackend<crate::BridgedBlockHasher<T, I>>>::from((
+        vec![(None, vec![(events_key.clone(), Some(encoded_events))])],
+        state_version,
+    ));
+    let state_root = backend.storage_root(sp_std::iter::empty(), state_version).0;
+    let proof = sp_trie::StorageProof::new(
+        prove_read(backend, &[&events_key[..]])
+            .unwrap()
+            .iter_nodes(),
+    );
+
+    let mut header =
+        crate::bridges::test_utils::test_header::<crate::BridgedHeader<T, I>>(height.into());
+    header.set_state_root(state_root);
+    let block_hash = header.hash();
+    <crate::MultiImportedRoots<T, I>>::insert(
+        gateway_id,
+        block_hash,
+        (*header.extrinsics_root(), state_root),
+    );
+
+    let best_header = crate::bridges::test_utils::test_header::<crate::BridgedHeader<T, I>>(
+        height.saturating_add(1).into(),
+    );
+    let best_block_hash = best_header.hash();
+    <MultiImportedHeaders<T, I>>::insert(gateway_id, best_block_hash, best_header);
+    <BestFinalizedMap<T, I>>::insert(gateway_id, best_block_hash);
+
+    crate::types::InclusionData {
+        encoded_payload,
+        proof,
+        block_hash,
+    }
+}
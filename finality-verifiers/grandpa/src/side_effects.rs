@@ -28,13 +28,59 @@ pub enum MultiTransferEventStub<T: frame_system::Config, Balance, CurrencyId> {
     },
 }
 
+/// Remote order emitted on a gateway to request an Xtx on Circuit.
+///     Side effects are passed SCALE encoded, as expected by Circuit.
+///     Nonce is incremented by the remote pallet with every order of the requester.
+#[derive(Encode, Decode)]
+pub enum RemoteOrderEventStub<T: frame_system::Config> {
+    Ordered {
+        requester: T::AccountId,
+        side_effects: Vec<u8>,
+        sequential: bool,
+        nonce: u32,
+    },
+}
+
+/// Events emitted by the Circuit pallet on a gateway once the escrowed SFX is committed or reverted.
+///     Escrow echoes the SCALE encoded arguments of the SFX (target, amount, asset...) it released.
+#[derive(Encode, Decode)]
+pub enum EscrowEventStub<T: frame_system::Config> {
+    Committed {
+        xtx_id: T::Hash,
+        sfx_id: T::Hash,
+        executor: T::AccountId,
+        sfx_args: Vec<Vec<u8>>,
+    },
+    Reverted {
+        xtx_id: T::Hash,
+        sfx_id: T::Hash,
+        executor: T::AccountId,
+        sfx_args: Vec<Vec<u8>>,
+    },
+}
+
+fn escrow_event_params<T: frame_system::Config>(
+    xtx_id: T::Hash,
+    sfx_id: T::Hash,
+    executor: T::AccountId,
+    mut sfx_args: Vec<Vec<u8>>,
+) -> Vec<Vec<u8>> {
+    let mut params = vec![xtx_id.encode(), sfx_id.encode(), executor.encode()];
+    params.append(&mut sfx_args);
+    params
+}
+
 pub(crate) fn decode_event<T: Config<I>, I: 'static>(
     id: &[u8; 4],
     mut encoded_event: Vec<u8>,
     value_abi_unsigned_type: &[u8],
 ) -> Result<(Vec<Vec<u8>>, Vec<u8>), DispatchError> {
-    // the first byte is the pallet index, which we don't need
-    let _ = encoded_event.remove(0);
+    // the first byte is the pallet index, which is returned as the source of events emitted by
+    // the Circuit pallets on the gateway, so that Circuit can check it against XDNS
+    if encoded_event.is_empty() {
+        return Err(Error::<T, I>::EventDecodingFailed.into())
+    }
+    let pallet_index = encoded_event.remove(0);
     match &id {
         &b"tran" => {
             // Assume that the different Pallet ID Circuit vs Target wouldn't matter for decoding on Circuit.
@@ -111,6 +157,47 @@ pub(crate) fn decode_event<T: Config<I>, I: 'static>(
             },
             &_ => Err(Error::<T, I>::EventDecodingFailed.into()),
         },
+        &b"rord" => match Decode::decode(&mut &encoded_event[..]) {
+            Ok(RemoteOrderEventStub::<T>::Ordered {
+                requester,
+                side_effects,
+                sequential,
+                nonce,
+            }) => Ok((
+                vec![
+                    requester.encode(),
+                    side_effects,
+                    sequential.encode(),
+                    nonce.encode(),
+                ],
+                vec![pallet_index],
+            )),
+            _ => Err(Error::<T, I>::EventDecodingFailed.into()),
+        },
+        &b"ecmt" => match Decode::decode(&mut &encoded_event[..]) {
+            Ok(EscrowEventStub::<T>::Committed {
+                xtx_id,
+                sfx_id,
+                executor,
+                sfx_args,
+            }) => Ok((
+                escrow_event_params::<T>(xtx_id, sfx_id, executor, sfx_args),
+                vec![pallet_index],
+            )),
+            _ => Err(Error::<T, I>::EventDecodingFailed.into()),
+        },
+        &b"ervt" => match Decode::decode(&mut &encoded_event[..]) {
+            Ok(EscrowEventStub::<T>::Reverted {
+                xtx_id,
+                sfx_id,
+                executor,
+                sfx_args,
+            }) => Ok((
+                escrow_event_params::<T>(xtx_id, sfx_id, executor, sfx_args),
+                vec![pallet_index],
+            )),
+            _ => Err(Error::<T, I>::EventDecodingFailed.into()),
+        },
         &_ => Err(Error::<T, I>::UnkownSideEffect.into()),
     }
 }
@@ -278,4 +365,75 @@ pub mod tests {
             )
         );
     }
+
+    #[test]
+    fn successfully_parses_encoded_remote_order_event() {
+        let requester: AccountId32 =
+            hex!("0909090909090909090909090909090909090909090909090909090909090909").into();
+
+        let mut encoded_remote_order_event = RemoteOrderEventStub::<TestRuntime>::Ordered {
+            requester: requester.clone(),
+            side_effects: vec![1, 2, 3],
+            sequential: true,
+            nonce: 7,
+        }
+        .encode();
+
+        let mut encoded_event = vec![4];
+        encoded_event.append(&mut encoded_remote_order_event);
+
+        let res = decode_event::<TestRuntime, ()>(b"rord", encoded_event, b"uint64").unwrap();
+
+        assert_eq!(
+            res,
+            (
+                vec![requester.encode(), vec![1, 2, 3], vec![1], 7u32.encode()],
+                vec![4]
+            )
+        );
+    }
+
+    #[test]
+    fn rejects_empty_encoded_event() {
+        assert!(decode_event::<TestRuntime, ()>(b"rord", vec![], b"uint64").is_err());
+    }
+
+    #[test]
+    fn successfully_parses_encoded_escrow_commit_event_and_rejects_it_as_revert() {
+        let executor: AccountId32 =
+            hex!("0909090909090909090909090909090909090909090909090909090909090909").into();
+        let xtx_id = H256::repeat_byte(6);
+        let sfx_id = H256::repeat_byte(7);
+        let to = vec![2u8; 32];
+        let amount = 100u64.encode();
+
+        let mut encoded_escrow_event = EscrowEventStub::<TestRuntime>::Committed {
+            xtx_id,
+            sfx_id,
+            executor: executor.clone(),
+            sfx_args: vec![to.clone(), amount.clone()],
+        }
+        .encode();
+
+        let mut encoded_event = vec![4];
+        encoded_event.append(&mut encoded_escrow_event);
+
+        let res =
+            decode_event::<TestRuntime, ()>(b"ecmt", encoded_event.clone(), b"uint64").unwrap();
+
+        assert_eq!(
+            res,
+            (
+                vec![
+                    xtx_id.encode(),
+                    sfx_id.encode(),
+                    executor.encode(),
+                    to,
+                    amount
+                ],
+                vec![4]
+            )
+        );
+        assert!(decode_event::<TestRuntime, ()>(b"ervt", encoded_event, b"uint64").is_err());
+    }
 }
@@ -39,6 +39,21 @@ pub struct ParachainHeaderData<Header: sp_runtime::traits::Header> {
     pub proof: StorageProof,
 }
 
+/// Headers of a single parachain in a batch, ordered parent first. They link the best finalized
+/// header to the head, which is read from the relaychains storage proof.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug)]
+pub struct ParachainHeadRange<Header: sp_runtime::traits::Header> {
+    pub gateway_id: ChainId,
+    pub range: Vec<Header>,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug)]
+pub struct ParachainHeadsData<Header: sp_runtime::traits::Header> {
+    pub relay_block_hash: Header::Hash, // relaychain header hash that contains the parachains heads
+    pub parachains: Vec<ParachainHeadRange<Header>>,
+    pub proof: StorageProof, // proves the `Paras::Heads` entries of all parachains in the batch
+}
+
 #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug)]
 pub struct InclusionData<Header: sp_runtime::traits::Header> {
     /// this is the item we're proving to be included in a specfic block (e.g. event, storage entry, etc)
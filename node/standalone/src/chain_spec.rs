@@ -369,6 +369,7 @@ fn testnet_genesis(
         contracts_registry: Default::default(),
         account_manager: Default::default(),
         clock: Default::default(),
+        executors: Default::default(),
         three_vm: Default::default(), // TODO: genesis for this needs to be setup for the function pointers\
         evm: EvmConfig {
             // We need _some_ code inserted at the precompile address so that
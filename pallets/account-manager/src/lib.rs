@@ -31,6 +31,7 @@ mod tests;
 mod benchmarking;
 
 pub mod manager;
+pub mod migrations;
 pub mod monetary;
 pub mod transaction;
 pub mod weights;
@@ -86,8 +87,12 @@ pub mod pallet {
 
     // Simple declaration of the `Pallet` type. It is placeholder we use to implement traits and
     // method.
+    /// The current storage version, bumped by the migrations of `crate::migrations`
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
     #[pallet::generate_store(pub (super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(PhantomData<T>);
 
@@ -117,7 +122,11 @@ pub mod pallet {
         RoundInfo<T::BlockNumber>,
         Identity,
         T::Hash, // sfx_id
-        Settlement<T::AccountId, <T::Currency as Currency<T::AccountId>>::Balance>,
+        Settlement<
+            T::AccountId,
+            <T::Currency as Currency<T::AccountId>>::Balance,
+            <T::Assets as Inspect<T::AccountId>>::AssetId,
+        >,
     >;
 
     #[pallet::call]
@@ -178,6 +187,20 @@ pub mod pallet {
     // Pallet implements [`Hooks`] trait to define some logic to execute in some context.
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_runtime_upgrade() -> Weight {
+            <crate::migrations::v1::MigrateToV1<T> as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade()
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<(), &'static str> {
+            <crate::migrations::v1::MigrateToV1<T> as frame_support::traits::OnRuntimeUpgrade>::pre_upgrade()
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            <crate::migrations::v1::MigrateToV1<T> as frame_support::traits::OnRuntimeUpgrade>::post_upgrade()
+        }
+
         // `on_finalize` is executed at the end of block after all extrinsic are dispatched.
         fn on_finalize(_n: T::BlockNumber) {
             // Perform necessary data/state clean up here.
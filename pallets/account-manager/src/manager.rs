@@ -9,7 +9,7 @@ use frame_support::{
     traits::{fungibles::Inspect, Get},
 };
 use sp_runtime::{
-    traits::{CheckedAdd, CheckedDiv, CheckedMul, Zero},
+    traits::{CheckedAdd, CheckedDiv, CheckedMul, Saturating, Zero},
     ArithmeticError, DispatchError,
 };
 use sp_std::{prelude::*, vec};
@@ -25,9 +25,10 @@ use t3rn_primitives::{
 use crate::monetary::Monetary;
 use pallet_xbi_portal::sabi::Sabi;
 
-pub struct ActiveSetClaimablePerRound<Account, Balance> {
+pub struct ActiveSetClaimablePerRound<Account, Balance, AssetId> {
     pub executor: Account,
     pub claimable: Balance,
+    pub maybe_asset_id: Option<AssetId>,
 }
 
 pub fn percent_ratio<BalanceOf: Zero + CheckedDiv + CheckedMul + From<u8>>(
@@ -203,10 +204,15 @@ impl<T: Config>
             SettlementsPerRound::<T>::insert(
                 T::Clock::current_round(),
                 charge_id,
-                Settlement::<T::AccountId, BalanceOf<T>> {
+                Settlement::<
+                    T::AccountId,
+                    BalanceOf<T>,
+                    <T::Assets as Inspect<T::AccountId>>::AssetId,
+                > {
                     requester: charge.payee,
                     recipient,
                     settlement_amount: recipient_rewards + recipient_bonus,
+                    maybe_asset_id: charge.maybe_asset_id,
                     outcome,
                     source: charge.source,
                     role: charge.role,
@@ -243,27 +249,61 @@ impl<T: Config>
         }
     }
 
-    /// Collect claimable (only SFX execution rewards) for Executors and Stakers submitted by Circuit at the duration of the current Round
+    /// Collect claimable (only SFX execution rewards) for Executors and Stakers submitted by Circuit at the duration of the current Round.
+    ///     Claimable are collected separately for each asset the rewards were settled in.
     fn on_collect_claimable(
         _n: T::BlockNumber,
         r: RoundInfo<T::BlockNumber>,
-    ) -> Result<Vec<ClaimableArtifacts<T::AccountId, BalanceOf<T>>>, DispatchError> {
-        let mut claimable_artifacts: Vec<ClaimableArtifacts<T::AccountId, BalanceOf<T>>> = vec![];
-        let mut active_set_claimables: Vec<ActiveSetClaimablePerRound<T::AccountId, BalanceOf<T>>> =
-            T::Executors::active_set()
-                .into_iter()
-                .map(|executor: T::AccountId| ActiveSetClaimablePerRound {
-                    executor,
-                    claimable: Zero::zero(),
-                })
-                .collect::<Vec<ActiveSetClaimablePerRound<T::AccountId, BalanceOf<T>>>>();
+    ) -> Result<
+        Vec<
+            ClaimableArtifacts<
+                T::AccountId,
+                BalanceOf<T>,
+                <T::Assets as Inspect<T::AccountId>>::AssetId,
+            >,
+        >,
+        DispatchError,
+    > {
+        let mut claimable_artifacts: Vec<
+            ClaimableArtifacts<
+                T::AccountId,
+                BalanceOf<T>,
+                <T::Assets as Inspect<T::AccountId>>::AssetId,
+            >,
+        > = vec![];
+        let active_set = T::Executors::active_set();
+        let mut active_set_claimables: Vec<
+            ActiveSetClaimablePerRound<
+                T::AccountId,
+                BalanceOf<T>,
+                <T::Assets as Inspect<T::AccountId>>::AssetId,
+            >,
+        > = active_set
+            .iter()
+            .map(|executor: &T::AccountId| ActiveSetClaimablePerRound {
+                executor: executor.clone(),
+                claimable: Zero::zero(),
+                maybe_asset_id: None,
+            })
+            .collect();
 
         for settlement in SettlementsPerRound::<T>::iter_prefix_values(r) {
-            // fixme: test that actually updates active_set_claimables or are the references wrong
-            for active_set_claimable in active_set_claimables.iter_mut() {
-                if active_set_claimable.executor == settlement.recipient {
-                    active_set_claimable.claimable += settlement.settlement_amount;
-                }
+            if !active_set.contains(&settlement.recipient) {
+                continue
+            }
+            match active_set_claimables
+                .iter_mut()
+                .find(|active_set_claimable| {
+                    active_set_claimable.executor == settlement.recipient
+                        && active_set_claimable.maybe_asset_id == settlement.maybe_asset_id
+                }) {
+                Some(active_set_claimable) =>
+                    active_set_claimable.claimable += settlement.settlement_amount,
+                None => active_set_claimables.push(ActiveSetClaimablePerRound {
+                    executor: settlement.recipient,
+                    claimable: settlement.settlement_amount,
+                    maybe_asset_id: settlement.maybe_asset_id,
+                }),
             }
         }
 
@@ -301,6 +341,7 @@ impl<T: Config>
                 beneficiary: active_set_claimable.executor.clone(),
                 role: CircuitRole::Executor,
                 total_round_claim: round_claim,
+                maybe_asset_id: active_set_claimable.maybe_asset_id,
                 benefit_source: BenefitSource::TrafficRewards,
             });
 
@@ -344,6 +385,7 @@ impl<T: Config>
                     beneficiary: nominated_stake.staker,
                     role: CircuitRole::Staker,
                     total_round_claim: total_claim,
+                    maybe_asset_id: active_set_claimable.maybe_asset_id,
                     benefit_source: BenefitSource::TrafficRewards,
                 });
             }
@@ -352,6 +394,26 @@ impl<T: Config>
         Ok(claimable_artifacts)
     }
 
+    fn on_collect_settlements(
+        r: RoundInfo<T::BlockNumber>,
+        source: BenefitSource,
+    ) -> Vec<(T::AccountId, BalanceOf<T>)> {
+        let mut settled: Vec<(T::AccountId, BalanceOf<T>)> = vec![];
+        for settlement in SettlementsPerRound::<T>::iter_prefix_values(r) {
+            if settlement.source != source || settlement.maybe_asset_id.is_some() {
+                continue
+            }
+            match settled
+                .iter_mut()
+                .find(|(recipient, _)| *recipient == settlement.recipient)
+            {
+                Some((_, amount)) => *amount = amount.saturating_add(settlement.settlement_amount),
+                None => settled.push((settlement.recipient, settlement.settlement_amount)),
+            }
+        }
+        settled
+    }
+
     fn can_withdraw(
         payee: &T::AccountId,
         amount: BalanceOf<T>,
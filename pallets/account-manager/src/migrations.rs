@@ -0,0 +1,98 @@
+//! Storage migrations of the Account Manager pallet - settlements carrying their asset.
+
+use crate::{BalanceOf, Config, Pallet, SettlementsPerRound};
+use codec::{Decode, Encode};
+use frame_support::{
+    traits::{fungibles::Inspect, Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+    weights::Weight,
+};
+use sp_std::marker::PhantomData;
+use t3rn_primitives::{
+    account_manager::{Outcome, Settlement},
+    claimable::{BenefitSource, CircuitRole},
+};
+
+#[cfg(feature = "try-runtime")]
+use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+pub mod v1 {
+    use super::*;
+
+    /// Settlement as stored before settlements were made in the requested reward asset.
+    #[derive(Encode, Decode)]
+    pub struct OldSettlement<Account, Balance> {
+        pub requester: Account,
+        pub recipient: Account,
+        pub settlement_amount: Balance,
+        pub outcome: Outcome,
+        pub source: BenefitSource,
+        pub role: CircuitRole,
+    }
+
+    /// Translates SettlementsPerRound to settlements carrying their asset.
+    ///     All settlements made before were paid out in the native currency, which is what
+    ///     `maybe_asset_id: None` stands for, so none of them is dropped.
+    pub struct MigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() != 0 {
+                return T::DbWeight::get().reads(1)
+            }
+
+            let mut translated: u64 = 0;
+            <SettlementsPerRound<T>>::translate_values::<
+                OldSettlement<T::AccountId, BalanceOf<T>>,
+                _,
+            >(|old| {
+                translated = translated.saturating_add(1);
+                Some(Settlement {
+                    requester: old.requester,
+                    recipient: old.recipient,
+                    settlement_amount: old.settlement_amount,
+                    maybe_asset_id: None::<<T::Assets as Inspect<T::AccountId>>::AssetId>,
+                    outcome: old.outcome,
+                    source: old.source,
+                    role: old.role,
+                })
+            });
+            StorageVersion::new(1).put::<Pallet<T>>();
+
+            log::info!(
+                target: "runtime::account-manager",
+                "Translated {} settlements to the native asset",
+                translated
+            );
+
+            T::DbWeight::get()
+                .reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<(), &'static str> {
+            if Pallet::<T>::on_chain_storage_version() == 0 {
+                Self::set_temp_storage(
+                    <SettlementsPerRound<T>>::iter_keys().count() as u32,
+                    "settlements",
+                );
+            }
+            Ok(())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            if let Some(settlements) = Self::get_temp_storage::<u32>("settlements") {
+                frame_support::ensure!(
+                    <SettlementsPerRound<T>>::iter().count() as u32 == settlements,
+                    "Settlements lost while translating SettlementsPerRound"
+                );
+                frame_support::ensure!(
+                    <SettlementsPerRound<T>>::iter_values()
+                        .all(|settlement| settlement.maybe_asset_id.is_none()),
+                    "Translated settlements must be in the native asset"
+                );
+            }
+            Ok(())
+        }
+    }
+}
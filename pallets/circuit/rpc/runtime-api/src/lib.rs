@@ -0,0 +1,26 @@
+//! Runtime API definition required by Circuit RPC extensions.
+//!
+//! This API should be imported and implemented by the runtime,
+//! of a node that wants to use the custom RPC extension
+//! adding Circuit access methods.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{alloc::vec::Vec, Codec};
+pub use pallet_circuit::state::{CircuitStatus, SideEffectStatusResponse, XtxStatusResponse};
+
+sp_api::decl_runtime_apis! {
+    /// The API to interact with pallet Circuit
+    pub trait CircuitRuntimeApi<AccountId, Balance, BlockNumber, Hash> where
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+        Hash: Codec,
+    {
+        /// Returns the Xtx with its steps, per-SFX confirmation state, best bids and timeouts
+        fn get_xtx_status(xtx_id: Hash) -> Option<XtxStatusResponse<AccountId, BlockNumber, Balance, Hash>>;
+
+        /// Returns SFX available for bidding of up to `limit` Xtx following `start_after`
+        fn fetch_open_side_effects(start_after: Option<Hash>, limit: u32) -> Vec<SideEffectStatusResponse<AccountId, BlockNumber, Balance, Hash>>;
+    }
+}
@@ -0,0 +1,107 @@
+//! RPC interface for the Circuit pallet.
+
+use codec::Codec;
+use jsonrpsee::{
+    core::{async_trait, Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::CallError,
+};
+pub use pallet_circuit_rpc_runtime_api::CircuitRuntimeApi;
+use pallet_circuit_rpc_runtime_api::{SideEffectStatusResponse, XtxStatusResponse};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+    generic::BlockId,
+    traits::{Block as BlockT, MaybeDisplay},
+};
+use std::sync::Arc;
+
+const RUNTIME_ERROR: i64 = 1;
+
+#[rpc(client, server)]
+pub trait CircuitApi<AccountId, Balance, BlockNumber, Hash> {
+    /// Returns the Xtx with its steps, per-SFX confirmation state, best bids and timeouts
+    #[method(name = "circuit_getXtxStatus")]
+    fn get_xtx_status(
+        &self,
+        xtx_id: Hash,
+    ) -> RpcResult<XtxStatusResponse<AccountId, BlockNumber, Balance, Hash>>;
+
+    /// Returns SFX available for bidding of up to `limit` Xtx following `start_after`
+    #[method(name = "circuit_fetchOpenSideEffects")]
+    fn fetch_open_side_effects(
+        &self,
+        start_after: Option<Hash>,
+        limit: u32,
+    ) -> RpcResult<Vec<SideEffectStatusResponse<AccountId, BlockNumber, Balance, Hash>>>;
+}
+
+/// A struct that implements the [`CircuitApiServer`].
+pub struct Circuit<C, P> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> Circuit<C, P> {
+    /// Create new `Circuit` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl<C, Block, AccountId, Balance, BlockNumber, Hash>
+    CircuitApiServer<AccountId, Balance, BlockNumber, Hash> for Circuit<C, Block>
+where
+    AccountId: Codec + MaybeDisplay,
+    Balance: Codec + MaybeDisplay,
+    BlockNumber: Codec + MaybeDisplay,
+    Hash: Codec + MaybeDisplay,
+    Block: BlockT,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: CircuitRuntimeApi<Block, AccountId, Balance, BlockNumber, Hash>,
+{
+    fn get_xtx_status(
+        &self,
+        xtx_id: Hash,
+    ) -> RpcResult<XtxStatusResponse<AccountId, BlockNumber, Balance, Hash>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(self.client.info().best_hash);
+
+        let result: Option<XtxStatusResponse<AccountId, BlockNumber, Balance, Hash>> = api
+            .get_xtx_status(&at, xtx_id)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        match result {
+            Some(xtx_status) => Ok(xtx_status),
+            None => Err("Xtx doesn't exist"),
+        }
+        .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn fetch_open_side_effects(
+        &self,
+        start_after: Option<Hash>,
+        limit: u32,
+    ) -> RpcResult<Vec<SideEffectStatusResponse<AccountId, BlockNumber, Balance, Hash>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(self.client.info().best_hash);
+
+        let result = api
+            .fetch_open_side_effects(&at, start_after, limit)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(result)
+    }
+}
+
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> JsonRpseeError {
+    JsonRpseeError::Call(CallError::Custom(jsonrpsee::types::ErrorObject::owned(
+        RUNTIME_ERROR as i32,
+        "Runtime Error - Circuit RPC",
+        Some(format!("{:?}", err)),
+    )))
+}
@@ -1 +1,179 @@
+//! Benchmarking setup for pallet-circuit
 
+use super::*;
+use crate::Pallet as Circuit;
+use frame_benchmarking::{account, benchmarks};
+use sp_runtime::traits::One;
+
+const USER_SEED: u32 = 999666;
+/// SFX of the Xtx step which timeout is benchmarked
+const STEP_SFX_CNT: u32 = 10;
+
+/// Seeds Xtx at the given status with a single step of `sfx_cnt` SFX neither bid for nor confirmed
+fn seed_xtx<T: Config>(
+    requester_nonce: u32,
+    status: CircuitStatus,
+    sfx_cnt: u32,
+) -> XExecSignalId<T> {
+    let requester: T::AccountId = account("requester", requester_nonce, USER_SEED);
+    let mut xtx = XExecSignal::<T::AccountId, T::BlockNumber>::new(
+        &requester,
+        requester_nonce,
+        frame_system::Pallet::<T>::block_number(),
+        None,
+        (0, 1),
+    );
+    xtx.status = status;
+    let xtx_id = xtx.generate_id::<T>();
+
+    let step = (0..sfx_cnt)
+        .map(|index| FullSideEffect {
+            input: SideEffect {
+                target: [0u8; 4],
+                max_reward: Zero::zero(),
+                encoded_action: b"tran".to_vec(),
+                encoded_args: vec![],
+                signature: vec![],
+                enforce_executor: None,
+                insurance: Zero::zero(),
+                reward_asset_id: None,
+            },
+            confirmed: None,
+            security_lvl: SecurityLvl::Optimistic,
+            submission_target_height: vec![],
+            best_bid: None,
+            index,
+        })
+        .collect::<Vec<_>>();
+
+    <XExecSignals<T>>::insert(xtx_id, xtx);
+    <FullSideEffects<T>>::insert(xtx_id, vec![step]);
+    <LocalXtxStates<T>>::insert(xtx_id, LocalState::new());
+    xtx_id
+}
+
+/// Seeds Xtx as `seed_xtx` does, with every SFX of the step won by an executor
+fn seed_won_xtx<T: Config>(
+    requester_nonce: u32,
+    status: CircuitStatus,
+    sfx_cnt: u32,
+) -> XExecSignalId<T> {
+    let xtx_id = seed_xtx::<T>(requester_nonce, status, sfx_cnt);
+    let requester: T::AccountId = account("requester", requester_nonce, USER_SEED);
+    let executor: T::AccountId = account("executor", requester_nonce, USER_SEED);
+    <FullSideEffects<T>>::mutate(xtx_id, |steps| {
+        for fsx in steps.iter_mut().flatten().flatten() {
+            fsx.best_bid = Some(SFXBid::new_none_optimistic(
+                Zero::zero(),
+                Zero::zero(),
+                executor.clone(),
+                requester.clone(),
+                None,
+            ));
+        }
+    });
+    xtx_id
+}
+
+/// Queues the timeout of Xtx at the next block, right after the last visited one
+fn queue_timeout<T: Config>(xtx_id: XExecSignalId<T>, kind: XtxTimeoutKind) -> T::BlockNumber {
+    let now = frame_system::Pallet::<T>::block_number();
+    let n = now.saturating_add(One::one());
+    <XtxTimeoutsVisitedUntil<T>>::put(now);
+    <XtxTimeoutsQueue<T>>::insert(n, (xtx_id, kind), ());
+    n
+}
+
+benchmarks! {
+    on_initialize_visit_timeouts_block {
+        let now = frame_system::Pallet::<T>::block_number();
+        let n = now.saturating_add(One::one());
+        <XtxTimeoutsVisitedUntil<T>>::put(now);
+    }: {
+        Circuit::<T>::process_xtx_timeouts_queue(n, Weight::MAX)
+    }
+    verify {
+        assert_eq!(<XtxTimeoutsVisitedUntil<T>>::get(), n);
+    }
+
+    on_initialize_bidding_timeout {
+        let xtx_id = seed_xtx::<T>(0, CircuitStatus::PendingBidding, STEP_SFX_CNT);
+        let n = queue_timeout::<T>(xtx_id, XtxTimeoutKind::Bidding);
+    }: {
+        Circuit::<T>::process_xtx_timeouts_queue(n, Weight::MAX)
+    }
+    verify {
+        assert!(<XtxTimeoutsQueue<T>>::get(n, (xtx_id, XtxTimeoutKind::Bidding)).is_none());
+    }
+
+    on_initialize_execution_timeout {
+        let xtx_id = seed_xtx::<T>(0, CircuitStatus::Ready, STEP_SFX_CNT);
+        let n = queue_timeout::<T>(xtx_id, XtxTimeoutKind::Execution);
+    }: {
+        Circuit::<T>::process_xtx_timeouts_queue(n, Weight::MAX)
+    }
+    verify {
+        assert_eq!(
+            <XExecSignals<T>>::get(xtx_id).map(|xtx| xtx.status),
+            Some(CircuitStatus::RevertTimedOut)
+        );
+    }
+
+    on_initialize_scheduled_step {
+        let xtx_id = seed_xtx::<T>(0, CircuitStatus::Scheduled, STEP_SFX_CNT);
+        let n = queue_timeout::<T>(xtx_id, XtxTimeoutKind::ScheduledStep);
+    }: {
+        Circuit::<T>::process_xtx_timeouts_queue(n, Weight::MAX)
+    }
+    verify {
+        assert!(<XtxTimeoutsQueue<T>>::get(n, (xtx_id, XtxTimeoutKind::ScheduledStep)).is_none());
+    }
+
+    on_initialize_escrow_finalization_timeout {
+        let xtx_id = seed_won_xtx::<T>(0, CircuitStatus::FinishedAllSteps, STEP_SFX_CNT);
+        for fsx in <FullSideEffects<T>>::get(xtx_id).unwrap_or_default().iter().flatten() {
+            <PendingEscrowFinalizations<T>>::insert(
+                xtx_id,
+                fsx.generate_id::<SystemHashing<T>, T>(xtx_id),
+                Outcome::Commit,
+            );
+        }
+        let n = queue_timeout::<T>(xtx_id, XtxTimeoutKind::EscrowFinalization);
+    }: {
+        Circuit::<T>::process_xtx_timeouts_queue(n, Weight::MAX)
+    }
+    verify {
+        assert_eq!(<PendingEscrowFinalizations<T>>::iter_prefix(xtx_id).count(), 0);
+    }
+
+    on_initialize_sfx_execution_timeout {
+        let xtx_id = seed_won_xtx::<T>(0, CircuitStatus::PendingExecution, STEP_SFX_CNT);
+        let n = queue_timeout::<T>(xtx_id, XtxTimeoutKind::SFXExecution);
+        for fsx in <FullSideEffects<T>>::get(xtx_id).unwrap_or_default().iter().flatten() {
+            <SFXExecutionDeadlines<T>>::insert(fsx.generate_id::<SystemHashing<T>, T>(xtx_id), n);
+        }
+    }: {
+        Circuit::<T>::process_xtx_timeouts_queue(n, Weight::MAX)
+    }
+    verify {
+        assert!(<FullSideEffects<T>>::get(xtx_id)
+            .unwrap_or_default()
+            .iter()
+            .flatten()
+            .all(|fsx| fsx.best_bid.is_none()));
+    }
+
+    on_initialize_xdns_pins_release {
+        let xtx_id = seed_xtx::<T>(0, CircuitStatus::FinishedAllSteps, STEP_SFX_CNT);
+        <XtxXdnsRecordVersions<T>>::insert(
+            xtx_id,
+            (0..STEP_SFX_CNT).map(|i| (i.to_le_bytes(), 0u32, [0u8; 32])).collect::<Vec<_>>(),
+        );
+        let n = queue_timeout::<T>(xtx_id, XtxTimeoutKind::XdnsPinsRelease);
+    }: {
+        Circuit::<T>::process_xtx_timeouts_queue(n, Weight::MAX)
+    }
+    verify {
+        assert!(<XtxXdnsRecordVersions<T>>::get(xtx_id).is_none());
+    }
+}
@@ -30,6 +30,7 @@ use crate::{optimistic::Optimistic, state::*};
 use codec::{Decode, Encode};
 use frame_support::{
     dispatch::{Dispatchable, GetDispatchInfo},
+    storage::{with_transaction, TransactionOutcome},
     traits::{Currency, ExistenceRequirement::AllowDeath, Get},
     weights::Weight,
     RuntimeDebug,
@@ -45,10 +46,12 @@ use pallet_xbi_portal::{
 };
 use pallet_xbi_portal_enter::t3rn_sfx::xbi_result_2_sfx_confirmation;
 use sp_runtime::{
-    traits::{CheckedAdd, Zero},
-    KeyTypeId,
+    traits::{CheckedAdd, One, Saturating, Zero},
+    DispatchError, KeyTypeId,
 };
-use sp_std::{boxed::Box, convert::TryInto, vec, vec::Vec};
+use sp_std::{boxed::Box, collections::btree_map::BTreeMap, convert::TryInto, vec, vec::Vec};
+pub use xcm::latest::MultiLocation;
+use xcm_executor::traits::Convert as XcmConvert;
 
 pub use t3rn_primitives::{
     abi::{GatewayABIConfig, HasherAlgo as HA, Type},
@@ -56,15 +59,17 @@ pub use t3rn_primitives::{
     circuit::{XExecSignalId, XExecStepSideEffectId},
     circuit_portal::CircuitPortal,
     claimable::{BenefitSource, CircuitRole},
-    executors::Executors,
+    executors::{Executors, ReputationOutcome},
+    match_format::{compile_side_effects_dfd, match_dfd_arg, match_signature},
     portal::Portal,
+    protocol::SideEffectProtocol,
     side_effect::{
-        ConfirmedSideEffect, FullSideEffect, HardenedSideEffect, SFXBid, SecurityLvl, SideEffect,
-        SideEffectId,
+        ConfirmationOutcome, ConfirmedSideEffect, FullSideEffect, HardenedSideEffect, SFXBid,
+        SecurityLvl, SideEffect, SideEffectId,
     },
     transfers::EscrowedBalanceOf,
-    volatile::LocalState,
-    xdns::Xdns,
+    volatile::{LocalState, Volatile},
+    xdns::{ethereum_transfer_token, GatewayStatus, Xdns},
     xtx::{Xtx, XtxId},
     GatewayType, *,
 };
@@ -84,6 +89,7 @@ pub mod tests;
 mod benchmarking;
 
 pub mod escrow;
+pub mod migrations;
 pub mod optimistic;
 pub mod state;
 pub mod weights;
@@ -96,6 +102,16 @@ pub mod weights;
 /// The keys can be inserted manually via RPC (see `author_insertKey`).
 pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"circ");
 
+/// Identifier of the remote order event proven by light clients for `on_remote_gateway_trigger`.
+pub const REMOTE_ORDER_SFX_ID: [u8; 4] = *b"rord";
+
+/// Identifiers of the remote escrow events proven by light clients for `finalize_escrowed_side_effect`.
+pub const ESCROW_COMMIT_SFX_ID: [u8; 4] = *b"ecmt";
+pub const ESCROW_REVERT_SFX_ID: [u8; 4] = *b"ervt";
+
+/// Max. number of Xtx which open SFX are served by a single page of `fetch_open_side_effects`.
+pub const MAX_OPEN_XTX_PER_PAGE: u32 = 100;
+
 pub type SystemHashing<T> = <T as frame_system::Config>::Hashing;
 pub type EscrowCurrencyOf<T> = <<T as pallet::Config>::Escrowed as EscrowTrait<T>>::Currency;
 
@@ -159,12 +175,44 @@ pub mod pallet {
     pub type SFX2XTXLinksMap<T> =
         StorageMap<_, Identity, SideEffectId<T>, XExecSignalId<T>, OptionQuery>;
 
-    /// Current Circuit's context of active Xtx used for the on_initialize clock to discover
-    ///     the ones pending for execution too long, that eventually need to be killed
+    /// Block-indexed queue of Xtx timeouts used for the on_initialize clock to discover
+    ///     Xtx pending for bids or execution too long. Entries are drained once their block is reached,
+    ///     up to the on_initialize weight budget. Keyed by the timeout kind too, so that different
+    ///     timeouts of the same Xtx due at the same block are all kept.
+    ///
+    #[pallet::storage]
+    #[pallet::getter(fn get_xtx_timeouts_queue)]
+    pub type XtxTimeoutsQueue<T> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        <T as frame_system::Config>::BlockNumber,
+        Identity,
+        (XExecSignalId<T>, XtxTimeoutKind),
+        (),
+        OptionQuery,
+    >;
+
+    /// Blocks of XtxTimeoutsQueue left with expired entries after the weight budget ran out.
+    ///     Drained first in the next block's on_initialize.
+    ///
+    #[pallet::storage]
+    #[pallet::getter(fn get_immediate_xtx_timeouts_queue)]
+    pub type ImmediateXtxTimeoutsQueue<T> =
+        StorageValue<_, Vec<<T as frame_system::Config>::BlockNumber>, ValueQuery>;
+
+    /// The latest block of XtxTimeoutsQueue visited by on_initialize.
+    ///
+    #[pallet::storage]
+    #[pallet::getter(fn get_xtx_timeouts_visited_until)]
+    pub type XtxTimeoutsVisitedUntil<T> =
+        StorageValue<_, <T as frame_system::Config>::BlockNumber, ValueQuery>;
+
+    /// Block the bidding timeout of Xtx pending for bids is queued at in XtxTimeoutsQueue,
+    ///     so that it can be dequeued once the Xtx leaves bidding before the timeout.
     ///
     #[pallet::storage]
-    #[pallet::getter(fn get_active_timing_links)]
-    pub type PendingXtxTimeoutsMap<T> = StorageMap<
+    #[pallet::getter(fn get_xtx_bidding_timeouts_at)]
+    pub type XtxBiddingTimeoutsAt<T> = StorageMap<
         _,
         Identity,
         XExecSignalId<T>,
@@ -172,15 +220,54 @@ pub mod pallet {
         OptionQuery,
     >;
 
-    /// Temporary bids for SFX executions. Cleaned out each Config::BidsInterval, where are moved from
-    ///     PendingSFXBids to FSX::accepted_bids
+    /// Escrowed SFX awaiting the proof of remote escrow contract emitting the commit or revert event,
+    ///     after their Xtx finished or got reverted. Insurance of executors is released once proven.
     ///
     #[pallet::storage]
-    #[pallet::getter(fn get_pending_xtx_bids_timeouts)]
-    pub type PendingXtxBidsTimeoutsMap<T> = StorageMap<
+    #[pallet::getter(fn get_pending_escrow_finalizations)]
+    pub type PendingEscrowFinalizations<T> = StorageDoubleMap<
         _,
         Identity,
         XExecSignalId<T>,
+        Identity,
+        SideEffectId<T>,
+        Outcome,
+        OptionQuery,
+    >;
+
+    /// Bidding phase of Xtx accepting sealed bids only.
+    ///
+    #[pallet::storage]
+    #[pallet::getter(fn get_sealed_bidding_phase)]
+    pub type SealedBiddingPhases<T> =
+        StorageMap<_, Identity, XExecSignalId<T>, SealedBiddingPhase, OptionQuery>;
+
+    /// Sealed bids of executors committed for SFX, kept until the end of reveal phase.
+    ///
+    #[pallet::storage]
+    #[pallet::getter(fn get_sealed_sfx_bids)]
+    pub type SealedSFXBids<T> = StorageDoubleMap<
+        _,
+        Identity,
+        SideEffectId<T>,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        SealedSFXBid<
+            <T as frame_system::Config>::Hash,
+            EscrowedBalanceOf<T, <T as Config>::Escrowed>,
+        >,
+        OptionQuery,
+    >;
+
+    /// Deadlines for winning executors of optimistic SFX to get their execution confirmed.
+    ///     Once passed, the winning bid of unconfirmed SFX is voided and SFX reopens for bidding.
+    ///
+    #[pallet::storage]
+    #[pallet::getter(fn get_sfx_execution_deadline)]
+    pub type SFXExecutionDeadlines<T> = StorageMap<
+        _,
+        Identity,
+        SideEffectId<T>,
         <T as frame_system::Config>::BlockNumber,
         OptionQuery,
     >;
@@ -240,6 +327,38 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Versions and fingerprints of the XDNS records of target gateways Xtx were validated against.
+    ///     Bids and confirmations are rejected once the fingerprint of any of these records changed.
+    ///
+    #[pallet::storage]
+    #[pallet::getter(fn get_xtx_xdns_record_versions)]
+    pub type XtxXdnsRecordVersions<T> =
+        StorageMap<_, Identity, XExecSignalId<T>, Vec<(ChainId, u32, [u8; 32])>, OptionQuery>;
+
+    /// Versions of the custom side effect interfaces Xtx were validated against.
+    ///     Confirmations resolve these versions even after the interfaces got upgraded.
+    ///
+    #[pallet::storage]
+    #[pallet::getter(fn get_xtx_side_effect_interface_versions)]
+    pub type XtxSideEffectInterfaceVersions<T> =
+        StorageMap<_, Identity, XExecSignalId<T>, Vec<(ChainId, [u8; 4], u32)>, OptionQuery>;
+
+    /// Origins allowed to open Xtx over XCM with `on_xcm_trigger`.
+    ///     Set by governance with `set_trigger_auth_rights`.
+    ///
+    #[pallet::storage]
+    #[pallet::getter(fn get_trigger_auth_rights)]
+    pub type TriggerAuthRights<T> =
+        StorageMap<_, Blake2_128Concat, MultiLocation, bool, ValueQuery>;
+
+    /// Remote order events already turned into Xtx by `on_remote_gateway_trigger`,
+    ///     keyed by the hash of gateway id, requester and the remote order nonce. Guards against replays.
+    ///
+    #[pallet::storage]
+    #[pallet::getter(fn get_consumed_remote_triggers)]
+    pub type ConsumedRemoteTriggers<T> =
+        StorageMap<_, Identity, <T as frame_system::Config>::Hash, bool, ValueQuery>;
+
     /// Handles queued signals
     ///
     /// This operation is performed lazily in `on_initialize`.
@@ -270,14 +389,26 @@ pub mod pallet {
         #[pallet::constant]
         type XtxTimeoutDefault: Get<Self::BlockNumber>;
 
-        /// The Circuit's Xtx timeout check interval
+        /// The Circuit's period for executors to prove finalization of remote escrow
         #[pallet::constant]
-        type XtxTimeoutCheckInterval: Get<Self::BlockNumber>;
+        type EscrowFinalizationPeriod: Get<Self::BlockNumber>;
 
         /// The Circuit's SFX Bidding Period
         #[pallet::constant]
         type SFXBiddingPeriod: Get<Self::BlockNumber>;
 
+        /// The Circuit's period for executors to reveal their sealed bids
+        #[pallet::constant]
+        type SFXBidRevealPeriod: Get<Self::BlockNumber>;
+
+        /// The Circuit's period for winning executors to confirm execution of optimistic SFX
+        #[pallet::constant]
+        type SFXExecutionPeriod: Get<Self::BlockNumber>;
+
+        /// The Circuit's period for challenging confirmations of optimistic SFX, counted from their receipt
+        #[pallet::constant]
+        type ChallengeWindow: Get<Self::BlockNumber>;
+
         /// The Circuit's deletion queue limit - preventing potential
         ///     delay when queue is too long in on_initialize
         #[pallet::constant]
@@ -332,6 +463,12 @@ pub mod pallet {
         /// A type that gives access to the new portal functionality
         type Portal: Portal<Self>;
 
+        /// Origin of inbound XCM `Transact` messages, resolving to the sender's location
+        type XcmOrigin: EnsureOrigin<Self::Origin, Success = MultiLocation>;
+
+        /// Converts the location of XCM sender into the local account of a requester
+        type LocationToAccountId: XcmConvert<MultiLocation, Self::AccountId>;
+
         /// The maximum number of signals that can be queued for handling.
         ///
         /// When a signal from 3vm is requested, we add it to the queue to be handled by on_initialize
@@ -349,8 +486,12 @@ pub mod pallet {
         type SignalQueueDepth: Get<u32>;
     }
 
+    /// The current storage version, bumped by the migrations of `crate::migrations`
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
     #[pallet::generate_store(pub (super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
 
@@ -362,123 +503,28 @@ pub mod pallet {
         // This function must return the weight consumed by `on_initialize` and `on_finalize`.
         fn on_initialize(n: T::BlockNumber) -> Weight {
             let weight = Self::process_signal_queue();
-            // Check every XtxTimeoutCheckInterval blocks
-
-            // what happens if the weight for the block is consumed, do these timeouts need to wait
-            // for the next check interval to handle them? maybe we need an immediate queue
-            //
 
-            // Check for expiring bids each block
-            <PendingXtxBidsTimeoutsMap<T>>::iter()
-                .find(|(_xtx_id, bidding_timeouts_at)| {
-                    // ToDo consider moving xtx_bids to xtx_ctx in order to self update to always determine status
-                    bidding_timeouts_at <= &frame_system::Pallet::<T>::block_number()
-                })
-                .map(|(xtx_id, _bidding_timeouts_at)| {
-                    let mut local_ctx = match Self::setup(
-                        CircuitStatus::PendingBidding,
-                        &Self::account_id(),
-                        Some(xtx_id),
-                    ) {
-                        Ok(value) => value,
-                        Err(error) => {
-                            log::error!("Could not setup local ctx: {:?}", error);
-                            return
-                        }
-                    };
-
-                    // Ensure Circuit::PendingBidding status
-                    if local_ctx.xtx.status != CircuitStatus::PendingBidding {
-                        Self::kill(&mut local_ctx, CircuitStatus::DroppedAtBidding);
-                        return
-                    }
-
-                    let current_step = local_ctx.xtx.steps_cnt.0;
-                    for mut fsx in local_ctx.full_side_effects[current_step as usize].iter_mut() {
-                        let sfx_id = fsx.generate_id::<SystemHashing<T>, T>(local_ctx.xtx_id);
-                        if let Some(best_sfx_bid) = <PendingSFXBids<T>>::get(xtx_id, sfx_id) {
-                            fsx.best_bid = Some(best_sfx_bid);
-                        } else {
-                            // error - some FSX don't have bids
-                            Self::kill(&mut local_ctx, CircuitStatus::DroppedAtBidding);
-                            Self::emit_status_update(
-                                local_ctx.xtx_id,
-                                Some(local_ctx.xtx),
-                                None,
-                            );
-                            return
-                        }
-                    }
-
-                    let status_change = match Self::update(&mut local_ctx) {
-                        Ok(value) => value,
-                        Err(error) => {
-                            log::error!("Could not update local ctx change: {:?}", error);
-                            return
-                        },
-                    };
+            // Split the on_initialize weight distribution 50-50 between signals and timeouts
+            let max_weight = <T as frame_system::Config>::BlockWeights::get().max_block / 2;
 
-                    Self::square_up(&mut local_ctx, None)
-                        .expect("Expect Bonding Bids at square up to be infallible since funds of requester have been reserved at the SFX submission");
-
-                    Self::apply(
-                        &mut local_ctx,
-                        status_change,
-                    );
-
-                    Self::emit_status_update(
-                        local_ctx.xtx_id,
-                        Some(local_ctx.xtx),
-                        None,
-                    );
-                });
-            // Go over pending Bids to discover whether
-
-            // Scenario 1: all the timeout s can be handled in the block space
-            // Scenario 2: all but 5 timeouts can be handled
-            //     - add the 5 timeouts to an immediate queue for the next block
-            if n % T::XtxTimeoutCheckInterval::get() == T::BlockNumber::from(0u8) {
-                let mut deletion_counter: u32 = 0;
-                // Go over all unfinished Xtx to find those that timed out
-                <PendingXtxTimeoutsMap<T>>::iter()
-                    .find(|(_xtx_id, timeout_at)| {
-                        timeout_at <= &frame_system::Pallet::<T>::block_number()
-                    })
-                    .map(|(xtx_id, _timeout_at)| {
-                        if deletion_counter > T::DeletionQueueLimit::get() {
-                            return
-                        }
-                        let mut local_xtx_ctx = match Self::setup(
-                            CircuitStatus::Ready,
-                            &Self::account_id(),
-                            Some(xtx_id),
-                        ) {
-                            Ok(value) => value,
-                            Err(error) => {
-                                log::error!("Could not setup local xtx ctx: {:?}", error);
-                                return
-                            },
-                        };
+            weight.saturating_add(Self::process_xtx_timeouts_queue(
+                n,
+                max_weight.saturating_sub(weight),
+            ))
+        }
 
-                        Self::kill(&mut local_xtx_ctx, CircuitStatus::RevertTimedOut);
+        fn on_runtime_upgrade() -> Weight {
+            <crate::migrations::v1::MigrateToV1<T> as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade()
+        }
 
-                        Self::emit_status_update(
-                            local_xtx_ctx.xtx_id,
-                            Some(local_xtx_ctx.xtx),
-                            None,
-                        );
-                        if let Some(v) = deletion_counter.checked_add(1) {
-                            deletion_counter = v;
-                        } else {
-                            return
-                        }
-                    });
-            }
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<(), &'static str> {
+            <crate::migrations::v1::MigrateToV1<T> as frame_support::traits::OnRuntimeUpgrade>::pre_upgrade()
+        }
 
-            // Anything that needs to be done at the start of the block.
-            // We don't do anything here.
-            // ToDo: Do active xtx signals overview and Cancel if time elapsed
-            weight
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            <crate::migrations::v1::MigrateToV1<T> as frame_support::traits::OnRuntimeUpgrade>::post_upgrade()
         }
 
         fn on_finalize(_n: T::BlockNumber) {
@@ -611,7 +657,13 @@ pub mod pallet {
             // ToDo: Align whether 3vm wants enfore side effects sequence into steps
             let sequential = false;
             // Validate: Side Effects
-            Self::validate(&side_effects, &mut local_xtx_ctx, &requester, sequential)?;
+            Self::validate(
+                &side_effects,
+                &mut local_xtx_ctx,
+                &requester,
+                sequential,
+                None,
+            )?;
 
             // Account fees and charges
             Self::square_up(&mut local_xtx_ctx, None)?;
@@ -652,15 +704,98 @@ pub mod pallet {
             )
         }
 
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::on_local_trigger())]
-        pub fn on_xcm_trigger(_origin: OriginFor<T>) -> DispatchResultWithPostInfo {
-            // ToDo: Check TriggerAuthRights for local triggers
-            unimplemented!();
+        /// Opens an Xtx from an inbound XCM `Transact`. The requester is the local account
+        ///     derived from the sender's location, which must hold TriggerAuthRights.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::on_xcm_trigger())]
+        pub fn on_xcm_trigger(
+            origin: OriginFor<T>,
+            side_effects: Vec<SideEffect<T::AccountId, EscrowedBalanceOf<T, T::Escrowed>>>,
+            sequential: bool,
+        ) -> DispatchResultWithPostInfo {
+            let location = T::XcmOrigin::ensure_origin(origin)?;
+
+            if !<TriggerAuthRights<T>>::get(&location) {
+                return Err(Error::<T>::XcmTriggerUnauthorized.into())
+            }
+
+            let requester = T::LocationToAccountId::convert_ref(&location)
+                .map_err(|_| Error::<T>::XcmTriggerLocationConversionFailed)?;
+
+            Self::do_trigger(&requester, side_effects, sequential, None)
         }
 
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::on_local_trigger())]
-        pub fn on_remote_gateway_trigger(_origin: OriginFor<T>) -> DispatchResultWithPostInfo {
-            unimplemented!();
+        /// Grants or revokes the rights to open Xtx over XCM for a given location. Root only access.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_trigger_auth_rights())]
+        pub fn set_trigger_auth_rights(
+            origin: OriginFor<T>,
+            location: MultiLocation,
+            allowed: bool,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            if allowed {
+                <TriggerAuthRights<T>>::insert(&location, true);
+            } else {
+                <TriggerAuthRights<T>>::remove(&location);
+            }
+
+            Self::deposit_event(Event::TriggerAuthRightsUpdated(location, allowed));
+
+            Ok(().into())
+        }
+
+        /// Opens an Xtx from a remote order event emitted on a registered gateway.
+        ///     The event inclusion is proven against the light client headers via Portal,
+        ///     and the Xtx is requested on behalf of the account that emitted the order.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::on_remote_gateway_trigger())]
+        pub fn on_remote_gateway_trigger(
+            origin: OriginFor<T>,
+            gateway_id: ChainId,
+            submission_target_height: Vec<u8>,
+            encoded_inclusion_data: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            // Authorize: Retrieve relayer of the remote order
+            let _relayer = Self::authorize(origin, CircuitRole::Relayer)?;
+
+            let (params, source) = <T as Config>::Portal::confirm_and_decode_payload_params(
+                gateway_id,
+                submission_target_height,
+                encoded_inclusion_data,
+                REMOTE_ORDER_SFX_ID,
+            )
+            .map_err(|_| Error::<T>::RemoteTriggerInclusionProofFailed)?;
+
+            // Expect params: [requester, encoded side effects, sequential, nonce]
+            if params.len() != 4 {
+                return Err(Error::<T>::RemoteTriggerDecodingFailed.into())
+            }
+
+            // Anyone can emit a look-alike event on the gateway - only accept orders of the Circuit pallet
+            Self::ensure_emitted_by_circuit_pallet(&gateway_id, &source)?;
+
+            // Remote requester signs with the same key on both ends, so it controls the same account on Circuit.
+            let requester = T::AccountId::decode(&mut &params[0][..])
+                .map_err(|_| Error::<T>::RemoteTriggerDecodingFailed)?;
+            let side_effects: Vec<SideEffect<T::AccountId, EscrowedBalanceOf<T, T::Escrowed>>> =
+                Decode::decode(&mut &params[1][..])
+                    .map_err(|_| Error::<T>::RemoteTriggerDecodingFailed)?;
+            let sequential = bool::decode(&mut &params[2][..])
+                .map_err(|_| Error::<T>::RemoteTriggerDecodingFailed)?;
+            let nonce = u32::decode(&mut &params[3][..])
+                .map_err(|_| Error::<T>::RemoteTriggerDecodingFailed)?;
+
+            // Equal orders of the same requester are told apart by the nonce of the remote pallet
+            let remote_order_hash =
+                SystemHashing::<T>::hash(&(gateway_id, &requester, nonce).encode()[..]);
+            if <ConsumedRemoteTriggers<T>>::get(remote_order_hash) {
+                return Err(Error::<T>::RemoteTriggerAlreadyConsumed.into())
+            }
+
+            Self::do_trigger(&requester, side_effects, sequential, None)?;
+
+            <ConsumedRemoteTriggers<T>>::insert(remote_order_hash, true);
+
+            Ok(().into())
         }
 
         #[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_xtx())]
@@ -676,11 +811,15 @@ pub mod pallet {
                 return Err(Error::<T>::UnauthorizedCancellation.into())
             }
 
-            // Drop cancellation in case some bids have already been posted
-            if Self::get_current_step_fsx(&local_ctx)
-                .iter()
-                .any(|fsx| fsx.best_bid.is_some())
-            {
+            // Drop cancellation in case some bids have already been posted or committed
+            if Self::get_current_step_fsx(&local_ctx).iter().any(|fsx| {
+                fsx.best_bid.is_some()
+                    || <SealedSFXBids<T>>::iter_prefix(
+                        fsx.generate_id::<SystemHashing<T>, T>(local_ctx.xtx_id),
+                    )
+                    .next()
+                    .is_some()
+            }) {
                 return Err(Error::<T>::UnauthorizedCancellation.into())
             }
 
@@ -689,6 +828,42 @@ pub mod pallet {
             Ok(().into())
         }
 
+        /// Reverts a pending Xtx right away once any of its unconfirmed SFX targets an inactive
+        ///     gateway, instead of waiting for its execution timeout. Callable by anyone.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_xtx())]
+        pub fn revert_on_inactive_gateway(
+            origin: OriginFor<T>,
+            xtx_id: T::Hash,
+        ) -> DispatchResultWithPostInfo {
+            Self::authorize(origin, CircuitRole::Requester)?;
+            let mut local_ctx: LocalXtxCtx<T> =
+                Self::setup(CircuitStatus::Ready, &Self::account_id(), Some(xtx_id))?;
+
+            if local_ctx.xtx.status >= CircuitStatus::FinishedAllSteps {
+                return Err(Error::<T>::NoPendingSideEffectOnInactiveGateway.into())
+            }
+
+            let inactive_gateway = local_ctx
+                .full_side_effects
+                .iter()
+                .flatten()
+                .filter(|fsx| fsx.confirmed.is_none())
+                .map(|fsx| fsx.input.target)
+                .find(|target| {
+                    <T as Config>::Xdns::get_gateway_status(target) == Ok(GatewayStatus::Inactive)
+                })
+                .ok_or(Error::<T>::NoPendingSideEffectOnInactiveGateway)?;
+
+            Self::kill(&mut local_ctx, CircuitStatus::RevertGatewayInactive);
+
+            Self::deposit_event(Event::XTransactionXtxRevertedOnInactiveGateway(
+                xtx_id,
+                inactive_gateway,
+            ));
+
+            Ok(().into())
+        }
+
         #[pallet::weight(<T as pallet::Config>::WeightInfo::on_extrinsic_trigger())]
         pub fn on_extrinsic_trigger(
             origin: OriginFor<T>,
@@ -697,31 +872,135 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
             // Authorize: Retrieve sender of the transaction.
             let requester = Self::authorize(origin, CircuitRole::Requester)?;
-            // Setup: new xtx context
+
+            Self::do_trigger(&requester, side_effects, sequential, None)
+        }
+
+        /// Opens an Xtx with each of its steps held until the corresponding delay_steps_at block,
+        ///     before it opens for bidding. Steps are built as for on_extrinsic_trigger.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::on_extrinsic_trigger_scheduled())]
+        pub fn on_extrinsic_trigger_scheduled(
+            origin: OriginFor<T>,
+            side_effects: Vec<SideEffect<T::AccountId, EscrowedBalanceOf<T, T::Escrowed>>>,
+            sequential: bool,
+            delay_steps_at: Vec<T::BlockNumber>,
+        ) -> DispatchResultWithPostInfo {
+            // Authorize: Retrieve sender of the transaction.
+            let requester = Self::authorize(origin, CircuitRole::Requester)?;
+
             let mut local_xtx_ctx: LocalXtxCtx<T> =
                 Self::setup(CircuitStatus::Requested, &requester, None)?;
 
-            // Validate: Side Effects
-            Self::validate(&side_effects, &mut local_xtx_ctx, &requester, sequential).map_err(
-                |e| {
-                    log::error!("Self::validate hit an error -- {:?}", e);
-                    Error::<T>::SideEffectsValidationFailed
-                },
-            )?;
+            Self::validate(
+                &side_effects,
+                &mut local_xtx_ctx,
+                &requester,
+                sequential,
+                None,
+            )
+            .map_err(|e| {
+                log::error!("Self::validate hit an error -- {:?}", e);
+                Error::<T>::SideEffectsValidationFailed
+            })?;
 
-            // Account fees and charges
-            Self::square_up(&mut local_xtx_ctx, None)?;
+            Self::schedule_steps(&mut local_xtx_ctx, delay_steps_at)?;
 
-            // Update local context
-            let status_change = Self::update(&mut local_xtx_ctx)?;
+            Self::do_request(&requester, local_xtx_ctx, &side_effects)
+        }
 
-            // Apply: all necessary changes to state in 1 go
-            let (_, _added_full_side_effects) = Self::apply(&mut local_xtx_ctx, status_change);
+        /// Opens an Xtx with side effects scheduled by the data-flow-dependency graph, e.g. "(2(0,1))"
+        ///     for SFX 0 and 1 executed in parallel, followed by SFX 2. SFX arguments can refer to
+        ///     the confirmed outputs of their predecessors - see `match_format::DFD_ARG_MARKER`.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::on_extrinsic_trigger_with_dfd())]
+        pub fn on_extrinsic_trigger_with_dfd(
+            origin: OriginFor<T>,
+            side_effects: Vec<SideEffect<T::AccountId, EscrowedBalanceOf<T, T::Escrowed>>>,
+            side_effects_dfd: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            // Authorize: Retrieve sender of the transaction.
+            let requester = Self::authorize(origin, CircuitRole::Requester)?;
 
-            // Emit: From Circuit events
-            Self::emit_sfx(local_xtx_ctx.xtx_id, &requester, &side_effects);
+            let side_effects_steps =
+                compile_side_effects_dfd(side_effects_dfd, side_effects.len() as u32).map_err(
+                    |e| {
+                        log::error!("Side effects DFD can't be compiled -- {:?}", e);
+                        Error::<T>::SideEffectsValidationFailed
+                    },
+                )?;
 
-            Ok(().into())
+            Self::do_trigger(&requester, side_effects, false, Some(side_effects_steps))
+        }
+
+        /// Opens an Xtx with requester's ordered steps of side effects. Each step is bid on,
+        ///     executed and confirmed before the next one opens. SFX arguments can refer to
+        ///     the confirmed outputs of SFX at the earlier steps as for on_extrinsic_trigger_with_dfd,
+        ///     with SFX indexed in order of the flattened steps.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::on_extrinsic_trigger_with_steps())]
+        pub fn on_extrinsic_trigger_with_steps(
+            origin: OriginFor<T>,
+            side_effects_steps: Vec<
+                Vec<SideEffect<T::AccountId, EscrowedBalanceOf<T, T::Escrowed>>>,
+            >,
+        ) -> DispatchResultWithPostInfo {
+            // Authorize: Retrieve sender of the transaction.
+            let requester = Self::authorize(origin, CircuitRole::Requester)?;
+
+            ensure!(
+                !side_effects_steps.is_empty()
+                    && side_effects_steps.iter().all(|step| !step.is_empty()),
+                Error::<T>::SideEffectsStepsInvalid
+            );
+
+            // SFX are indexed in order of the flattened steps
+            let mut next_index: u32 = 0;
+            let steps = side_effects_steps
+                .iter()
+                .map(|step| {
+                    let indexes = (next_index..next_index.saturating_add(step.len() as u32))
+                        .collect::<Vec<u32>>();
+                    next_index = next_index.saturating_add(step.len() as u32);
+                    indexes
+                })
+                .collect::<Vec<Vec<u32>>>();
+
+            Self::do_trigger(
+                &requester,
+                side_effects_steps.into_iter().flatten().collect(),
+                false,
+                Some(steps),
+            )
+        }
+
+        /// Opens an Xtx accepting sealed bids only - executors commit hashes of their bids during
+        ///     the bidding period and reveal them within the following SFXBidRevealPeriod.
+        ///     Steps are built as for on_extrinsic_trigger.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::on_extrinsic_trigger_sealed_bids())]
+        pub fn on_extrinsic_trigger_sealed_bids(
+            origin: OriginFor<T>,
+            side_effects: Vec<SideEffect<T::AccountId, EscrowedBalanceOf<T, T::Escrowed>>>,
+            sequential: bool,
+        ) -> DispatchResultWithPostInfo {
+            // Authorize: Retrieve sender of the transaction.
+            let requester = Self::authorize(origin, CircuitRole::Requester)?;
+
+            let mut local_xtx_ctx: LocalXtxCtx<T> =
+                Self::setup(CircuitStatus::Requested, &requester, None)?;
+
+            Self::validate(
+                &side_effects,
+                &mut local_xtx_ctx,
+                &requester,
+                sequential,
+                None,
+            )
+            .map_err(|e| {
+                log::error!("Self::validate hit an error -- {:?}", e);
+                Error::<T>::SideEffectsValidationFailed
+            })?;
+
+            <SealedBiddingPhases<T>>::insert(local_xtx_ctx.xtx_id, SealedBiddingPhase::Commit);
+
+            Self::do_request(&requester, local_xtx_ctx, &side_effects)
         }
 
         #[pallet::weight(<T as pallet::Config>::WeightInfo::bid_sfx())]
@@ -737,6 +1016,12 @@ pub mod pallet {
             let xtx_id = <Self as Store>::SFX2XTXLinksMap::get(sfx_id)
                 .ok_or(Error::<T>::LocalSideEffectExecutionNotApplicable)?;
 
+            if <SealedBiddingPhases<T>>::contains_key(xtx_id) {
+                return Err(Error::<T>::BiddingRejectedSealedBidsOnly.into())
+            }
+
+            Self::ensure_xdns_records_unchanged(xtx_id)?;
+
             // Setup: retrieve local xtx context
             let mut local_ctx: LocalXtxCtx<T> =
                 Self::setup(CircuitStatus::PendingBidding, &executor, Some(xtx_id))?;
@@ -769,44 +1054,178 @@ pub mod pallet {
             Ok(().into())
         }
 
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::execute_side_effects_with_xbi())]
-        pub fn execute_side_effects_with_xbi(
+        /// Commits the hash of encoded (executor, sfx_id, bid_amount, salt) for SFX of Xtx accepting sealed bids,
+        ///     reserving the deposit of SFX max_reward + insurance until the bid is revealed.
+        ///     Binding the commitment to its executor and SFX keeps it from being copied by other executors.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::commit_sfx_bid())]
+        pub fn commit_sfx_bid(
             origin: OriginFor<T>, // Active relayer
-            xtx_id: XExecSignalId<T>,
-            side_effect: SideEffect<
-                <T as frame_system::Config>::AccountId,
-                EscrowedBalanceOf<T, <T as Config>::Escrowed>,
-            >,
-            max_exec_cost: u128,
-            max_notifications_cost: u128,
+            sfx_id: SideEffectId<T>,
+            bid_hash: T::Hash,
         ) -> DispatchResultWithPostInfo {
-            let sfx_id = side_effect.generate_id::<SystemHashing<T>>(xtx_id.as_ref(), 0u32);
-
-            if T::XBIPortal::get_status(sfx_id) != XBIStatus::UnknownId {
-                return Err(Error::<T>::SideEffectIsAlreadyScheduledToExecuteOverXBI.into())
-            }
             // Authorize: Retrieve sender of the transaction.
             let executor = Self::authorize(origin, CircuitRole::Executor)?;
 
-            // Setup: retrieve local xtx context
-            let mut local_ctx: LocalXtxCtx<T> =
-                Self::setup(CircuitStatus::PendingExecution, &executor, Some(xtx_id))?;
+            let xtx_id = <Self as Store>::SFX2XTXLinksMap::get(sfx_id)
+                .ok_or(Error::<T>::LocalSideEffectExecutionNotApplicable)?;
 
-            let xbi =
-                sfx_2_xbi::<T, T::Escrowed>(
-                    &side_effect,
-                    XBIMetadata::new_with_default_timeouts(
-                        XbiId::<T>::local_hash_2_xbi_id(sfx_id)?,
-                        T::Xdns::get_gateway_para_id(&side_effect.target)?,
-                        T::SelfParaId::get(),
-                        max_exec_cost,
-                        max_notifications_cost,
-                        Some(Sabi::account_bytes_2_account_32(executor.encode()).map_err(
-                            |_| Error::<T>::FailedToCreateXBIMetadataDueToWrongAccountConversion,
-                        )?),
-                    ),
-                )
-                .map_err(|_e| Error::<T>::FailedToConvertSFX2XBI)?;
+            if <SealedBiddingPhases<T>>::get(xtx_id) != Some(SealedBiddingPhase::Commit) {
+                return Err(Error::<T>::SealedBiddingCommitInactive.into())
+            }
+
+            Self::ensure_xdns_records_unchanged(xtx_id)?;
+
+            let local_ctx: LocalXtxCtx<T> =
+                Self::setup(CircuitStatus::PendingBidding, &executor, Some(xtx_id))?;
+
+            if local_ctx.xtx.status != CircuitStatus::PendingBidding {
+                return Err(Error::<T>::BiddingInactive.into())
+            }
+
+            if <SealedSFXBids<T>>::contains_key(sfx_id, &executor) {
+                return Err(Error::<T>::SealedBidAlreadyCommitted.into())
+            }
+
+            let fsx = Self::recover_fsx_by_id(sfx_id, &local_ctx)?;
+            let deposit = fsx
+                .input
+                .max_reward
+                .checked_add(&fsx.input.insurance)
+                .ok_or(Error::<T>::ArithmeticErrorOverflow)?;
+
+            <T as Config>::AccountManager::withdraw_immediately(
+                &executor,
+                deposit,
+                fsx.input.reward_asset_id,
+            )
+            .map_err(|_e| Error::<T>::BiddingRejectedExecutorNotEnoughBalance)?;
+
+            <SealedSFXBids<T>>::insert(
+                sfx_id,
+                &executor,
+                SealedSFXBid {
+                    bid_hash,
+                    deposit,
+                    is_revealed: false,
+                },
+            );
+
+            Self::deposit_event(Event::SFXSealedBidCommitted(sfx_id, executor));
+
+            Ok(().into())
+        }
+
+        /// Reveals the sealed bid committed for SFX, returning its deposit. Revealed bid competes
+        ///     as of bid_sfx - the lowest of the revealed bids wins once reveal phase is over.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::reveal_sfx_bid())]
+        pub fn reveal_sfx_bid(
+            origin: OriginFor<T>, // Active relayer
+            sfx_id: SideEffectId<T>,
+            bid_amount: EscrowedBalanceOf<T, T::Escrowed>,
+            salt: T::Hash,
+        ) -> DispatchResultWithPostInfo {
+            // Authorize: Retrieve sender of the transaction.
+            let executor = Self::authorize(origin, CircuitRole::Executor)?;
+
+            let xtx_id = <Self as Store>::SFX2XTXLinksMap::get(sfx_id)
+                .ok_or(Error::<T>::LocalSideEffectExecutionNotApplicable)?;
+
+            if <SealedBiddingPhases<T>>::get(xtx_id) != Some(SealedBiddingPhase::Reveal) {
+                return Err(Error::<T>::SealedBiddingRevealInactive.into())
+            }
+
+            let mut sealed_bid =
+                <SealedSFXBids<T>>::get(sfx_id, &executor).ok_or(Error::<T>::SealedBidNotFound)?;
+
+            if sealed_bid.is_revealed {
+                return Err(Error::<T>::SealedBidAlreadyRevealed.into())
+            }
+
+            if SystemHashing::<T>::hash(&(&executor, sfx_id, bid_amount, salt).encode())
+                != sealed_bid.bid_hash
+            {
+                return Err(Error::<T>::SealedBidRevealMismatch.into())
+            }
+
+            let mut local_ctx: LocalXtxCtx<T> =
+                Self::setup(CircuitStatus::PendingBidding, &executor, Some(xtx_id))?;
+
+            let fsx = Self::recover_fsx_by_id(sfx_id, &local_ctx)?;
+            <T as Config>::AccountManager::deposit_immediately(
+                &executor,
+                sealed_bid.deposit,
+                fsx.input.reward_asset_id,
+            );
+            sealed_bid.is_revealed = true;
+            <SealedSFXBids<T>>::insert(sfx_id, &executor, sealed_bid);
+
+            let current_accepted_bid =
+                crate::Pallet::<T>::storage_read_sfx_accepted_bid(&mut local_ctx, sfx_id);
+
+            match Optimistic::<T>::try_bid_4_sfx(
+                &mut local_ctx,
+                &executor,
+                bid_amount,
+                sfx_id,
+                current_accepted_bid,
+            ) {
+                Ok(accepted_as_best_bid) => crate::Pallet::<T>::storage_write_new_sfx_accepted_bid(
+                    &mut local_ctx,
+                    sfx_id,
+                    accepted_as_best_bid,
+                ),
+                // Lower bid has already been revealed - the commitment is honoured nonetheless
+                Err(Error::<T>::BiddingRejectedBetterBidFound) => {},
+                Err(err) => return Err(err.into()),
+            }
+
+            Self::deposit_event(Event::SFXSealedBidRevealed(
+                sfx_id,
+                executor.clone(),
+                bid_amount,
+            ));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::execute_side_effects_with_xbi())]
+        pub fn execute_side_effects_with_xbi(
+            origin: OriginFor<T>, // Active relayer
+            xtx_id: XExecSignalId<T>,
+            side_effect: SideEffect<
+                <T as frame_system::Config>::AccountId,
+                EscrowedBalanceOf<T, <T as Config>::Escrowed>,
+            >,
+            max_exec_cost: u128,
+            max_notifications_cost: u128,
+        ) -> DispatchResultWithPostInfo {
+            let sfx_id = side_effect.generate_id::<SystemHashing<T>>(xtx_id.as_ref(), 0u32);
+
+            if T::XBIPortal::get_status(sfx_id) != XBIStatus::UnknownId {
+                return Err(Error::<T>::SideEffectIsAlreadyScheduledToExecuteOverXBI.into())
+            }
+            // Authorize: Retrieve sender of the transaction.
+            let executor = Self::authorize(origin, CircuitRole::Executor)?;
+
+            // Setup: retrieve local xtx context
+            let mut local_ctx: LocalXtxCtx<T> =
+                Self::setup(CircuitStatus::PendingExecution, &executor, Some(xtx_id))?;
+
+            let xbi =
+                sfx_2_xbi::<T, T::Escrowed>(
+                    &side_effect,
+                    XBIMetadata::new_with_default_timeouts(
+                        XbiId::<T>::local_hash_2_xbi_id(sfx_id)?,
+                        T::Xdns::get_gateway_para_id(&side_effect.target)?,
+                        T::SelfParaId::get(),
+                        max_exec_cost,
+                        max_notifications_cost,
+                        Some(Sabi::account_bytes_2_account_32(executor.encode()).map_err(
+                            |_| Error::<T>::FailedToCreateXBIMetadataDueToWrongAccountConversion,
+                        )?),
+                    ),
+                )
+                .map_err(|_e| Error::<T>::FailedToConvertSFX2XBI)?;
 
             // Use encoded XBI hash as ID for the executor's charge
             let charge_id = T::Hashing::hash(&xbi.encode()[..]);
@@ -827,9 +1246,9 @@ pub mod pallet {
                 pallet::Call::<T>::on_xbi_sfx_resolved { sfx_id }.into(),
             )?;
 
-            let status_change = Self::update(&mut local_ctx)?;
-
-            Self::apply(&mut local_ctx, status_change);
+            if let Some(status_change) = Self::update_or_revert(&mut local_ctx)? {
+                Self::apply(&mut local_ctx, status_change);
+            }
 
             Ok(().into())
         }
@@ -868,7 +1287,13 @@ pub mod pallet {
 
             Self::confirm(&mut local_xtx_ctx, &executor, &sfx_id, &confirmation)?;
 
-            let status_change = Self::update(&mut local_xtx_ctx)?;
+            let status_change = match Self::update_or_revert(&mut local_xtx_ctx)? {
+                Some(status_change) => status_change,
+                None => {
+                    Self::deposit_event(Event::SideEffectConfirmed(sfx_id));
+                    return Ok(().into())
+                },
+            };
 
             // Apply: all necessary changes to state in 1 go
             let (maybe_xtx_changed, assert_full_side_effects_changed) =
@@ -885,6 +1310,201 @@ pub mod pallet {
 
             Ok(().into())
         }
+
+        /// Anyone can challenge a confirmed optimistic SFX by proving its confirmation doesn't hold -
+        ///     inclusion proof is invalid, proves a different event or the confirmed values mismatch SFX arguments.
+        /// Successful challenge reverts the Xtx for misbehaviour and slashes the executor's insurance
+        ///     and reserved bond - the bond rewards the challenger in the SFX reward asset.
+        ///     Bonds of the other executors are released.
+        /// Confirmations can be challenged once, within ChallengeWindow after they were received.
+        ///     Confirmations which can't be verified anymore - their gateway is inactive, its headers
+        ///     are pruned or its XDNS record changed since the Xtx was validated - are never slashed.
+        #[pallet::weight(< T as Config >::WeightInfo::challenge_side_effect_confirmation())]
+        pub fn challenge_side_effect_confirmation(
+            origin: OriginFor<T>,
+            sfx_id: SideEffectId<T>,
+        ) -> DispatchResultWithPostInfo {
+            let challenger = ensure_signed(origin)?;
+            let xtx_id = <Self as Store>::SFX2XTXLinksMap::get(sfx_id)
+                .ok_or(Error::<T>::ChallengeSideEffectNotFound)?;
+
+            let mut local_xtx_ctx: LocalXtxCtx<T> =
+                Self::setup(CircuitStatus::PendingExecution, &challenger, Some(xtx_id))?;
+
+            if local_xtx_ctx.xtx.status > CircuitStatus::FinishedAllSteps {
+                return Err(Error::<T>::ChallengeXtxAlreadyResolved.into())
+            }
+
+            let (step, index) = local_xtx_ctx
+                .full_side_effects
+                .iter()
+                .enumerate()
+                .find_map(|(step, fsx_step)| {
+                    fsx_step
+                        .iter()
+                        .position(|fsx| fsx.generate_id::<SystemHashing<T>, T>(xtx_id) == sfx_id)
+                        .map(|index| (step, index))
+                })
+                .ok_or(Error::<T>::ChallengeSideEffectNotFound)?;
+
+            let fsx = local_xtx_ctx.full_side_effects[step][index].clone();
+            let (confirmation, sfx_bid) = match (&fsx.confirmed, &fsx.best_bid) {
+                (Some(confirmation), Some(sfx_bid))
+                    if fsx.security_lvl == SecurityLvl::Optimistic =>
+                    (confirmation.clone(), sfx_bid.clone()),
+                _ => return Err(Error::<T>::ChallengeSideEffectNotOptimisticallyConfirmed.into()),
+            };
+            // Proven misbehaviour is kept as the confirmation's err
+            if confirmation.err.is_some() {
+                return Err(Error::<T>::ChallengeSideEffectAlreadyChallenged.into())
+            }
+            if frame_system::Pallet::<T>::block_number()
+                > confirmation
+                    .received_at
+                    .saturating_add(T::ChallengeWindow::get())
+            {
+                return Err(Error::<T>::ChallengeWindowClosed.into())
+            }
+
+            let misbehaviour = Self::verify_confirmation(&local_xtx_ctx, &fsx, &sfx_id)?
+                .ok_or(Error::<T>::ChallengeFailedConfirmationValid)?;
+
+            if let Some(confirmed) = local_xtx_ctx.full_side_effects[step][index]
+                .confirmed
+                .as_mut()
+            {
+                confirmed.err = Some(misbehaviour);
+            }
+
+            let challenger_reward =
+                Optimistic::<T>::try_slash_misbehaved(&mut local_xtx_ctx, &challenger)?;
+            T::Executors::update_reputation(&sfx_bid.executor, ReputationOutcome::Misbehaved);
+
+            if local_xtx_ctx.xtx.steps_cnt.0 < local_xtx_ctx.xtx.steps_cnt.1 {
+                Self::kill(&mut local_xtx_ctx, CircuitStatus::RevertMisbehaviour);
+            } else {
+                // Rewards of all steps are already settled - only the bonds were left to slash
+                local_xtx_ctx.xtx.status = CircuitStatus::RevertMisbehaviour;
+                Self::apply(
+                    &mut local_xtx_ctx,
+                    (
+                        CircuitStatus::RevertMisbehaviour,
+                        CircuitStatus::RevertMisbehaviour,
+                    ),
+                );
+            }
+
+            Self::deposit_event(Event::SideEffectMisbehaviourProven(
+                sfx_id,
+                confirmation.executioner,
+                challenger,
+                challenger_reward,
+            ));
+            Self::emit_status_update(
+                local_xtx_ctx.xtx_id,
+                Some(local_xtx_ctx.xtx),
+                Some(local_xtx_ctx.full_side_effects),
+            );
+
+            Ok(().into())
+        }
+
+        /// Proves that the remote escrow contract emitted the commit or revert event of escrowed SFX,
+        ///     matching the outcome of its Xtx. Releases executor's insurance.
+        #[pallet::weight(< T as Config >::WeightInfo::finalize_escrowed_side_effect())]
+        pub fn finalize_escrowed_side_effect(
+            origin: OriginFor<T>,
+            sfx_id: SideEffectId<T>,
+            encoded_inclusion_data: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let _relayer = Self::authorize(origin, CircuitRole::Relayer)?;
+            let xtx_id = <Self as Store>::SFX2XTXLinksMap::get(sfx_id)
+                .ok_or(Error::<T>::EscrowFinalizationNotPending)?;
+            let outcome = <Self as Store>::PendingEscrowFinalizations::get(xtx_id, sfx_id)
+                .ok_or(Error::<T>::EscrowFinalizationNotPending)?;
+
+            let fsx = <Self as Store>::FullSideEffects::get(xtx_id)
+                .ok_or(Error::<T>::SetupFailedXtxStorageArtifactsNotFound)?
+                .into_iter()
+                .flatten()
+                .find(|fsx| fsx.generate_id::<SystemHashing<T>, T>(xtx_id) == sfx_id)
+                .ok_or(Error::<T>::SetupFailedXtxStorageArtifactsNotFound)?;
+            let sfx_bid = fsx
+                .best_bid
+                .clone()
+                .ok_or(Error::<T>::InvalidFSXBidStateLocated)?;
+
+            let mut side_effect_id: [u8; 4] = [0, 0, 0, 0];
+            side_effect_id.copy_from_slice(
+                fsx.input
+                    .encoded_action
+                    .get(0..4)
+                    .ok_or(Error::<T>::EscrowFinalizationSideEffectMalformed)?,
+            );
+            let side_effect_interface = Self::fetch_pinned_side_effect_interface(
+                xtx_id,
+                &fsx.input.target,
+                side_effect_id,
+            )?;
+            let (escrow_events, escrow_event_id) = match outcome {
+                Outcome::Commit => (
+                    side_effect_interface.get_reversible_commit(),
+                    ESCROW_COMMIT_SFX_ID,
+                ),
+                _ => (
+                    side_effect_interface.get_reversible_revert(),
+                    ESCROW_REVERT_SFX_ID,
+                ),
+            };
+
+            let (params, source) = <T as Config>::Portal::confirm_and_decode_payload_params(
+                fsx.input.target,
+                fsx.submission_target_height.clone(),
+                encoded_inclusion_data,
+                escrow_event_id,
+            )
+            .map_err(|_| Error::<T>::EscrowFinalizationInclusionProofFailed)?;
+            Self::ensure_emitted_by_circuit_pallet(&fsx.input.target, &source)?;
+
+            if !Self::matches_escrow_event(
+                &escrow_events,
+                &side_effect_interface.get_arguments_2_state_mapper(),
+                &params,
+                &xtx_id,
+                &sfx_id,
+                &sfx_bid.executor,
+                &fsx.input.encoded_args,
+            ) {
+                return Err(Error::<T>::EscrowFinalizationEventMismatch.into())
+            }
+
+            <T as Config>::AccountManager::deposit_immediately(
+                &sfx_bid.executor,
+                sfx_bid.insurance,
+                sfx_bid.reward_asset_id,
+            );
+            <Self as Store>::PendingEscrowFinalizations::remove(xtx_id, sfx_id);
+
+            Self::deposit_event(Event::SideEffectEscrowFinalized(sfx_id, outcome.clone()));
+
+            // All escrowed SFX are committed on remote targets - Xtx is Committed.
+            //  Xtx without escrowed SFX, or with escrow interfaces declaring no commit events,
+            //  have nothing left to finalize on remote targets and remain FinishedAllSteps.
+            if outcome == Outcome::Commit
+                && <Self as Store>::PendingEscrowFinalizations::iter_prefix(xtx_id)
+                    .next()
+                    .is_none()
+            {
+                <Self as Store>::XExecSignals::mutate(xtx_id, |maybe_xtx| {
+                    if let Some(xtx) = maybe_xtx {
+                        xtx.status = CircuitStatus::Committed;
+                    }
+                });
+                Self::deposit_event(Event::XTransactionXtxCommitted(xtx_id));
+            }
+
+            Ok(().into())
+        }
     }
 
     use pallet_xbi_portal::xbi_abi::{
@@ -947,6 +1567,45 @@ pub mod pallet {
         XTransactionXtxRevertedAfterTimeOut(XExecSignalId<T>),
         // Listeners - users + SDK + UI to know whether their request is accepted for exec and finished
         XTransactionXtxDroppedAtBidding(XExecSignalId<T>),
+        // Listeners - users + SDK + UI to know whether the next step of their request is held until its scheduled block
+        XTransactionStepScheduled(XExecSignalId<T>),
+        // Listeners - users + SDK + UI to know whether their request is committed on all of the remote escrows
+        XTransactionXtxCommitted(XExecSignalId<T>),
+        // Listeners - executors to know that the remote escrow of SFX is proven to be committed or reverted
+        SideEffectEscrowFinalized(SideEffectId<T>, Outcome),
+        // Listeners - executors to know that they were slashed for not finalizing remote escrow of SFX in time
+        SideEffectEscrowFinalizationTimedOut(
+            SideEffectId<T>,
+            <T as frame_system::Config>::AccountId,
+        ),
+        // Listeners - users + SDK + UI to know whether their request was reverted as one of its target gateways became inactive
+        XTransactionXtxRevertedOnInactiveGateway(XExecSignalId<T>, ChainId),
+        // Listeners - users + SDK + UI to know whether their request was reverted due to executor's misbehaviour
+        XTransactionXtxRevertedAfterMisbehaviour(XExecSignalId<T>),
+        // Listeners - users + SDK + UI to know whether their request was reverted as its next step couldn't be built out of the confirmed outputs
+        XTransactionXtxRevertedOnDfdSubstitutionFailure(XExecSignalId<T>),
+        // Listeners - executors to know that the sealed bids for SFX of Xtx can now be revealed
+        XTransactionBidRevealOpened(XExecSignalId<T>),
+        // Sealed bid of executor was committed for SFX
+        SFXSealedBidCommitted(SideEffectId<T>, <T as frame_system::Config>::AccountId),
+        // Sealed bid of executor was revealed for SFX
+        SFXSealedBidRevealed(
+            SideEffectId<T>,
+            <T as frame_system::Config>::AccountId,
+            EscrowedBalanceOf<T, T::Escrowed>,
+        ),
+        // Sealed bid of executor wasn't revealed in time and the deposit was forfeited
+        SFXSealedBidForfeited(SideEffectId<T>, <T as frame_system::Config>::AccountId),
+        // Listeners - executors to know that SFX weren't executed in time by the winning executor and are open for bids again
+        SideEffectsReopenedForBidding(XExecSignalId<T>, Vec<SideEffectId<T>>),
+        // Confirmation of SFX was proven invalid. Accounts here are the misbehaving executor and the challenger,
+        //  balance is the challenger's reward.
+        SideEffectMisbehaviourProven(
+            SideEffectId<T>,
+            <T as frame_system::Config>::AccountId,
+            <T as frame_system::Config>::AccountId,
+            EscrowedBalanceOf<T, T::Escrowed>,
+        ),
         // Listeners - executioners/relayers to know new challenges and perform offline risk/reward calc
         //  of whether side effect is worth picking up
         NewSideEffectsAvailable(
@@ -985,6 +1644,8 @@ pub mod pallet {
                 >,
             >,
         ),
+        // Governance updated the rights of XCM location to open Xtx with on_xcm_trigger
+        TriggerAuthRightsUpdated(MultiLocation, bool),
         EscrowTransfer(
             // ToDo: Inspect if Xtx needs to be here and how to process from protocol
             T::AccountId,                                  // from
@@ -1014,6 +1675,7 @@ pub mod pallet {
         BiddingRejectedExecutorNotEnoughBalance,
         BiddingRejectedBidTooHigh,
         BiddingRejectedBetterBidFound,
+        BiddingRejectedSealedBidsOnly,
         BiddingFailedExecutorsBalanceTooLowToReserve,
         InsuranceBondAlreadyDeposited,
         SetupFailed,
@@ -1051,14 +1713,42 @@ pub mod pallet {
         UnsupportedRole,
         InvalidLocalTrigger,
         SignalQueueFull,
+        XcmTriggerUnauthorized,
+        XcmTriggerLocationConversionFailed,
+        RemoteTriggerInclusionProofFailed,
+        RemoteTriggerDecodingFailed,
+        RemoteTriggerAlreadyConsumed,
+        RemoteEventEmittedByUnexpectedPallet,
+        ScheduleStepsInvalid,
+        SideEffectsStepsInvalid,
+        SideEffectsDfdSubstitutionFailed,
+        ChallengeSideEffectNotFound,
+        ChallengeSideEffectNotOptimisticallyConfirmed,
+        ChallengeXtxAlreadyResolved,
+        ChallengeFailedConfirmationValid,
+        ChallengeSideEffectAlreadyChallenged,
+        ChallengeWindowClosed,
+        ChallengeUnverifiableGatewayInactive,
+        ChallengeUnverifiableHeaderPruned,
+        ChallengeUnverifiableXdnsRecordChanged,
+        EscrowFinalizationNotPending,
+        EscrowFinalizationInclusionProofFailed,
+        EscrowFinalizationEventMismatch,
+        EscrowFinalizationSideEffectMalformed,
+        SealedBiddingCommitInactive,
+        SealedBiddingRevealInactive,
+        SealedBidAlreadyCommitted,
+        SealedBidAlreadyRevealed,
+        SealedBidNotFound,
+        SealedBidRevealMismatch,
         ArithmeticErrorOverflow,
         ArithmeticErrorUnderflow,
         ArithmeticErrorDivisionByZero,
+        NoPendingSideEffectOnInactiveGateway,
+        XdnsRecordChangedSinceValidation,
     }
 }
 
-pub fn get_xtx_status() {}
-
 /// Payload used by this example crate to hold price
 /// data required to submit a transaction.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
@@ -1114,6 +1804,7 @@ impl<T: Config> Pallet<T> {
             CircuitStatus::Ready
             | CircuitStatus::PendingExecution
             | CircuitStatus::PendingBidding
+            | CircuitStatus::Scheduled
             | CircuitStatus::Finished => {
                 if let Some(id) = xtx_id {
                     let xtx = <Self as Store>::XExecSignals::get(id)
@@ -1148,7 +1839,8 @@ impl<T: Config> Pallet<T> {
             },
             CircuitStatus::FinishedAllSteps => Err(Error::<T>::SetupFailedXtxAlreadyFinished),
             CircuitStatus::RevertKill => Err(Error::<T>::SetupFailedXtxRevertedTimeout),
-            CircuitStatus::RevertMisbehaviour => Err(Error::<T>::SetupFailedXtxReverted),
+            CircuitStatus::RevertMisbehaviour | CircuitStatus::RevertGatewayInactive =>
+                Err(Error::<T>::SetupFailedXtxReverted),
             CircuitStatus::Committed => Err(Error::<T>::SetupFailedXtxAlreadyFinished),
             CircuitStatus::Reverted => Err(Error::<T>::SetupFailedXtxReverted),
             CircuitStatus::RevertTimedOut => Err(Error::<T>::SetupFailedXtxRevertedTimeout),
@@ -1156,6 +1848,133 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Opens a new Xtx on behalf of requester, running the side effects through the
+    ///     validate -> square_up -> update -> apply pipeline.
+    fn do_trigger(
+        requester: &T::AccountId,
+        side_effects: Vec<SideEffect<T::AccountId, EscrowedBalanceOf<T, T::Escrowed>>>,
+        sequential: bool,
+        side_effects_steps: Option<Vec<Vec<u32>>>,
+    ) -> DispatchResultWithPostInfo {
+        // Setup: new xtx context
+        let mut local_xtx_ctx: LocalXtxCtx<T> =
+            Self::setup(CircuitStatus::Requested, requester, None)?;
+
+        // Validate: Side Effects
+        Self::validate(
+            &side_effects,
+            &mut local_xtx_ctx,
+            requester,
+            sequential,
+            side_effects_steps,
+        )
+        .map_err(|e| {
+            log::error!("Self::validate hit an error -- {:?}", e);
+            Error::<T>::SideEffectsValidationFailed
+        })?;
+
+        Self::do_request(requester, local_xtx_ctx, &side_effects)
+    }
+
+    /// Charges the requester and stores the validated Xtx, emitting the new side effects.
+    fn do_request(
+        requester: &T::AccountId,
+        mut local_xtx_ctx: LocalXtxCtx<T>,
+        side_effects: &Vec<SideEffect<T::AccountId, EscrowedBalanceOf<T, T::Escrowed>>>,
+    ) -> DispatchResultWithPostInfo {
+        // Account fees and charges
+        Self::square_up(&mut local_xtx_ctx, None)?;
+
+        // Update local context
+        let status_change = Self::update(&mut local_xtx_ctx)?;
+
+        // Apply: all necessary changes to state in 1 go
+        let (_, _added_full_side_effects) = Self::apply(&mut local_xtx_ctx, status_change);
+
+        // Emit: From Circuit events
+        Self::emit_sfx(local_xtx_ctx.xtx_id, requester, side_effects);
+
+        Ok(().into())
+    }
+
+    /// Holds each step of validated Xtx until its delay_steps_at block. Xtx timeout
+    ///     is counted from the last scheduled step.
+    fn schedule_steps(
+        local_ctx: &mut LocalXtxCtx<T>,
+        delay_steps_at: Vec<T::BlockNumber>,
+    ) -> Result<(), Error<T>> {
+        if delay_steps_at.len() != local_ctx.full_side_effects.len()
+            || delay_steps_at
+                .windows(2)
+                .any(|delays| delays[0] > delays[1])
+        {
+            return Err(Error::<T>::ScheduleStepsInvalid)
+        }
+
+        if let Some(last_delay) = delay_steps_at.last() {
+            local_ctx.xtx.timeouts_at = T::XtxTimeoutDefault::get()
+                .checked_add(last_delay)
+                .ok_or(Error::<T>::ArithmeticErrorOverflow)?;
+        }
+        local_ctx.xtx.delay_steps_at = Some(delay_steps_at);
+
+        Ok(())
+    }
+
+    /// Returns the block the current step is scheduled at if it hasn't been reached yet.
+    fn get_current_step_delay(local_ctx: &LocalXtxCtx<T>) -> Option<T::BlockNumber> {
+        local_ctx
+            .xtx
+            .delay_steps_at
+            .as_ref()
+            .and_then(|delays| delays.get(local_ctx.xtx.steps_cnt.0 as usize))
+            .filter(|delay| **delay > frame_system::Pallet::<T>::block_number())
+            .copied()
+    }
+
+    fn queue_bidding_timeout(xtx_id: XExecSignalId<T>, timeouts_at: T::BlockNumber) {
+        <XtxTimeoutsQueue<T>>::insert(timeouts_at, (xtx_id, XtxTimeoutKind::Bidding), ());
+        <XtxBiddingTimeoutsAt<T>>::insert(xtx_id, timeouts_at);
+    }
+
+    /// Removes the pending bidding timeout of Xtx.
+    fn dequeue_bidding_timeout(xtx_id: XExecSignalId<T>) {
+        if let Some(timeouts_at) = <XtxBiddingTimeoutsAt<T>>::take(xtx_id) {
+            <XtxTimeoutsQueue<T>>::remove(timeouts_at, (xtx_id, XtxTimeoutKind::Bidding));
+        }
+    }
+
+    /// Finished Xtx keep their XDNS pins until their confirmations can't be challenged
+    ///     and their escrow finalizations are closed.
+    fn queue_xdns_pins_release(xtx_id: XExecSignalId<T>) {
+        let released_at = frame_system::Pallet::<T>::block_number()
+            .saturating_add(T::ChallengeWindow::get().max(T::EscrowFinalizationPeriod::get()))
+            .saturating_add(One::one());
+        <XtxTimeoutsQueue<T>>::insert(released_at, (xtx_id, XtxTimeoutKind::XdnsPinsRelease), ());
+    }
+
+    /// Queues the current step to open at its scheduled block, or the end of its bidding otherwise.
+    fn queue_current_step(local_ctx: &LocalXtxCtx<T>) {
+        if let Some(opens_at) = Self::get_current_step_delay(local_ctx) {
+            <XtxTimeoutsQueue<T>>::insert(
+                opens_at,
+                (local_ctx.xtx_id, XtxTimeoutKind::ScheduledStep),
+                (),
+            )
+        } else if let Some(v) =
+            T::SFXBiddingPeriod::get().checked_add(&frame_system::Pallet::<T>::block_number())
+        {
+            // Sealed bids are committed throughout the bidding period
+            if <SealedBiddingPhases<T>>::contains_key(local_ctx.xtx_id) {
+                <SealedBiddingPhases<T>>::insert(local_ctx.xtx_id, SealedBiddingPhase::Commit);
+            }
+            Self::queue_bidding_timeout(local_ctx.xtx_id, v)
+        } else {
+            // TODO: return an error if checked_add fails
+            log::error!("Could not get `SFX bidding period` plus `block number`.");
+        }
+    }
+
     // Updates local xtx context without touching the storage.
     fn update(
         mut local_ctx: &mut LocalXtxCtx<T>,
@@ -1188,17 +2007,54 @@ impl<T: Config> Pallet<T> {
                         local_ctx.xtx.status = CircuitStatus::FinishedAllSteps;
                         return Ok((current_status, CircuitStatus::FinishedAllSteps))
                     }
+
+                    // Next step opens - resolve its arguments from the confirmed predecessors
+                    Self::substitute_dfd_args(local_ctx).map_err(|e| {
+                        log::error!("Could not substitute DFD args of next step: {:?}", e);
+                        Error::<T>::SideEffectsDfdSubstitutionFailed
+                    })?;
                 }
             },
             _ => {},
         }
 
-        let new_status = CircuitStatus::determine_xtx_status(&local_ctx.full_side_effects)?;
+        // Steps before the current one are all Finished - Xtx past its first step follows the open step
+        let current_step = local_ctx.xtx.steps_cnt.0;
+        let mut new_status = match local_ctx.full_side_effects.get(current_step as usize) {
+            Some(step) if current_step > 0 => CircuitStatus::determine_step_status(step)?,
+            _ => CircuitStatus::determine_xtx_status(&local_ctx.full_side_effects)?,
+        };
+        // Hold the step opening for bids until its scheduled block
+        if new_status == CircuitStatus::PendingBidding
+            && Self::get_current_step_delay(local_ctx).is_some()
+        {
+            new_status = CircuitStatus::Scheduled;
+        }
         local_ctx.xtx.status = new_status.clone();
 
         Ok((current_status, new_status))
     }
 
+    /// Updates Xtx as `update` does. Xtx which next step can't be built out of the confirmed outputs
+    ///     of its predecessors is reverted right away, keeping the confirmations - returns None then.
+    fn update_or_revert(
+        local_ctx: &mut LocalXtxCtx<T>,
+    ) -> Result<Option<(CircuitStatus, CircuitStatus)>, Error<T>> {
+        match Self::update(local_ctx) {
+            Err(Error::<T>::SideEffectsDfdSubstitutionFailed) => {
+                <FullSideEffects<T>>::insert(local_ctx.xtx_id, local_ctx.full_side_effects.clone());
+                Self::kill(local_ctx, CircuitStatus::RevertKill);
+
+                Self::deposit_event(Event::XTransactionXtxRevertedOnDfdSubstitutionFailure(
+                    local_ctx.xtx_id,
+                ));
+                Self::emit_status_update(local_ctx.xtx_id, Some(local_ctx.xtx.clone()), None);
+                Ok(None)
+            },
+            status_change => status_change.map(Some),
+        }
+    }
+
     /// Returns: Returns changes written to the state if there are any.
     ///     For now only returns Xtx and FullSideEffects that changed.
     fn apply(
@@ -1281,21 +2137,13 @@ impl<T: Config> Pallet<T> {
                     local_ctx.xtx_id,
                     local_ctx.local_state.clone(),
                 );
-                <PendingXtxTimeoutsMap<T>>::insert::<XExecSignalId<T>, T::BlockNumber>(
-                    local_ctx.xtx_id,
+                Self::pin_xdns_record_versions(local_ctx);
+                <XtxTimeoutsQueue<T>>::insert(
                     local_ctx.xtx.timeouts_at,
+                    (local_ctx.xtx_id, XtxTimeoutKind::Execution),
+                    (),
                 );
-                // TODO: return an error if checked_add fails
-                if let Some(v) = T::SFXBiddingPeriod::get()
-                    .checked_add(&frame_system::Pallet::<T>::block_number())
-                {
-                    <PendingXtxBidsTimeoutsMap<T>>::insert::<XExecSignalId<T>, T::BlockNumber>(
-                        local_ctx.xtx_id,
-                        v,
-                    )
-                } else {
-                    log::error!("Could not get `SFX bidding period` plus `block number`.");
-                };
+                Self::queue_current_step(local_ctx);
                 <XExecSignals<T>>::insert::<
                     XExecSignalId<T>,
                     XExecSignal<T::AccountId, T::BlockNumber>,
@@ -1315,11 +2163,16 @@ impl<T: Config> Pallet<T> {
                         <Self as Store>::XExecSignals::mutate(local_ctx.xtx_id, |x| {
                             *x = Some(local_ctx.xtx.clone())
                         });
+                        Self::queue_sfx_execution_deadlines(local_ctx);
                     },
                     CircuitStatus::DroppedAtBidding => {
                         // Clean all associated Xtx entries
                         <Self as Store>::XExecSignals::remove(local_ctx.xtx_id);
-                        <Self as Store>::PendingXtxTimeoutsMap::remove(local_ctx.xtx_id);
+                        Self::release_xdns_pins(local_ctx.xtx_id);
+                        <Self as Store>::XtxTimeoutsQueue::remove(
+                            local_ctx.xtx.timeouts_at,
+                            (local_ctx.xtx_id, XtxTimeoutKind::Execution),
+                        );
                         <Self as Store>::LocalXtxStates::remove(local_ctx.xtx_id);
                         <Self as Store>::FullSideEffects::remove(local_ctx.xtx_id);
                         for fsx_step in &local_ctx.full_side_effects {
@@ -1332,36 +2185,73 @@ impl<T: Config> Pallet<T> {
                     },
                     _ => {},
                 }
-                // Always clean temporary PendingSFXBids and TimeoutsMap after bidding
+                // Always clean temporary PendingSFXBids after bidding.
                 <Self as Store>::PendingSFXBids::remove_prefix(local_ctx.xtx_id, None);
-                <Self as Store>::PendingXtxBidsTimeoutsMap::remove(local_ctx.xtx_id);
                 (
                     Some(local_ctx.xtx.clone()),
                     Some(local_ctx.full_side_effects.to_vec()),
                 )
             },
-            CircuitStatus::RevertTimedOut => {
+            CircuitStatus::Scheduled => {
+                <Self as Store>::XExecSignals::mutate(local_ctx.xtx_id, |x| {
+                    *x = Some(local_ctx.xtx.clone())
+                });
+                Self::queue_current_step(local_ctx);
+                (Some(local_ctx.xtx.clone()), None)
+            },
+            CircuitStatus::RevertTimedOut
+            | CircuitStatus::RevertKill
+            | CircuitStatus::RevertGatewayInactive => {
                 <Self as Store>::XExecSignals::mutate(local_ctx.xtx_id, |x| {
                     *x = Some(local_ctx.xtx.clone())
                 });
 
-                <Self as Store>::PendingXtxTimeoutsMap::remove(local_ctx.xtx_id);
+                <Self as Store>::XtxTimeoutsQueue::remove(
+                    local_ctx.xtx.timeouts_at,
+                    (local_ctx.xtx_id, XtxTimeoutKind::Execution),
+                );
+                Self::open_escrow_finalizations(local_ctx, Outcome::Revert);
                 (
                     Some(local_ctx.xtx.clone()),
                     Some(local_ctx.full_side_effects.clone()),
                 )
             },
-            // fixme: Separate for Bonded
-            CircuitStatus::Ready | CircuitStatus::PendingExecution | CircuitStatus::Finished => {
-                match new_status {
-                    CircuitStatus::FinishedAllSteps => {
-                        // todo: cleanup all of the local storage
-                        // TODO cleanup sfx2xtx map
-                        <Self as Store>::XExecSignals::mutate(local_ctx.xtx_id, |x| {
-                            *x = Some(local_ctx.xtx.clone())
-                        });
+            CircuitStatus::RevertMisbehaviour => {
+                // Keep the proven misbehaviour recorded within the confirmation of FSX
+                <Self as Store>::FullSideEffects::mutate(local_ctx.xtx_id, |x| {
+                    *x = Some(local_ctx.full_side_effects.clone())
+                });
+                <Self as Store>::XExecSignals::mutate(local_ctx.xtx_id, |x| {
+                    *x = Some(local_ctx.xtx.clone())
+                });
 
-                        <Self as Store>::PendingXtxTimeoutsMap::remove(local_ctx.xtx_id);
+                <Self as Store>::XtxTimeoutsQueue::remove(
+                    local_ctx.xtx.timeouts_at,
+                    (local_ctx.xtx_id, XtxTimeoutKind::Execution),
+                );
+                Self::open_escrow_finalizations(local_ctx, Outcome::Revert);
+                (
+                    Some(local_ctx.xtx.clone()),
+                    Some(local_ctx.full_side_effects.clone()),
+                )
+            },
+            // fixme: Separate for Bonded
+            CircuitStatus::Ready | CircuitStatus::PendingExecution | CircuitStatus::Finished => {
+                match new_status {
+                    CircuitStatus::FinishedAllSteps => {
+                        // todo: cleanup all of the local storage
+                        // TODO cleanup sfx2xtx map
+                        <Self as Store>::XExecSignals::mutate(local_ctx.xtx_id, |x| {
+                            *x = Some(local_ctx.xtx.clone())
+                        });
+
+                        <Self as Store>::XtxTimeoutsQueue::remove(
+                            local_ctx.xtx.timeouts_at,
+                            (local_ctx.xtx_id, XtxTimeoutKind::Execution),
+                        );
+                        <SealedBiddingPhases<T>>::remove(local_ctx.xtx_id);
+                        Self::open_escrow_finalizations(local_ctx, Outcome::Commit);
+                        Self::queue_xdns_pins_release(local_ctx.xtx_id);
                         (
                             Some(local_ctx.xtx.clone()),
                             Some(local_ctx.full_side_effects.clone()),
@@ -1372,6 +2262,16 @@ impl<T: Config> Pallet<T> {
                         <Self as Store>::FullSideEffects::mutate(local_ctx.xtx_id, |x| {
                             *x = Some(local_ctx.full_side_effects.clone())
                         });
+                        <Self as Store>::LocalXtxStates::insert(
+                            local_ctx.xtx_id,
+                            local_ctx.local_state.clone(),
+                        );
+                        // Previous step is finished - open bidding for the next one
+                        if new_status == CircuitStatus::PendingBidding
+                            || new_status == CircuitStatus::Scheduled
+                        {
+                            Self::queue_current_step(local_ctx);
+                        }
 
                         <Self as Store>::XExecSignals::mutate(local_ctx.xtx_id, |x| {
                             *x = Some(local_ctx.xtx.clone())
@@ -1394,7 +2294,13 @@ impl<T: Config> Pallet<T> {
                     *x = Some(local_ctx.xtx.clone())
                 });
 
-                <Self as Store>::PendingXtxTimeoutsMap::remove(local_ctx.xtx_id);
+                <Self as Store>::XtxTimeoutsQueue::remove(
+                    local_ctx.xtx.timeouts_at,
+                    (local_ctx.xtx_id, XtxTimeoutKind::Execution),
+                );
+                <SealedBiddingPhases<T>>::remove(local_ctx.xtx_id);
+                Self::open_escrow_finalizations(local_ctx, Outcome::Commit);
+                Self::queue_xdns_pins_release(local_ctx.xtx_id);
                 (
                     Some(local_ctx.xtx.clone()),
                     Some(local_ctx.full_side_effects.clone()),
@@ -1441,6 +2347,8 @@ impl<T: Config> Pallet<T> {
     ) {
         if let Some(xtx) = maybe_xtx {
             match xtx.status {
+                CircuitStatus::Scheduled =>
+                    Self::deposit_event(Event::XTransactionStepScheduled(xtx_id)),
                 CircuitStatus::PendingBidding =>
                     Self::deposit_event(Event::XTransactionReceivedForExec(xtx_id)),
                 CircuitStatus::Ready =>
@@ -1453,6 +2361,8 @@ impl<T: Config> Pallet<T> {
                     Self::deposit_event(Event::XTransactionXtxRevertedAfterTimeOut(xtx_id)),
                 CircuitStatus::DroppedAtBidding =>
                     Self::deposit_event(Event::XTransactionXtxDroppedAtBidding(xtx_id)),
+                CircuitStatus::RevertMisbehaviour =>
+                    Self::deposit_event(Event::XTransactionXtxRevertedAfterMisbehaviour(xtx_id)),
                 _ => {},
             }
             if xtx.status >= CircuitStatus::PendingExecution {
@@ -1466,6 +2376,12 @@ impl<T: Config> Pallet<T> {
     fn kill(local_ctx: &mut LocalXtxCtx<T>, cause: CircuitStatus) {
         local_ctx.xtx.status = cause.clone();
 
+        Self::dequeue_bidding_timeout(local_ctx.xtx_id);
+        Self::release_xdns_pins(local_ctx.xtx_id);
+        if <SealedBiddingPhases<T>>::take(local_ctx.xtx_id).is_some() {
+            Self::settle_sealed_bids(local_ctx, false);
+        }
+
         match cause {
             CircuitStatus::RevertTimedOut => {
                 if let Err(err) = Optimistic::<T>::try_slash(local_ctx) {
@@ -1484,6 +2400,16 @@ impl<T: Config> Pallet<T> {
         Self::apply(local_ctx, (cause.clone(), cause));
     }
 
+    /// Max rewards of scheduled Xtx are reserved upfront for all of their steps, as the steps open
+    ///     for bidding long after the submission. Other Xtx reserve them for the first step only.
+    fn get_steps_cnt_with_reserved_max_rewards(local_ctx: &LocalXtxCtx<T>) -> usize {
+        if local_ctx.xtx.delay_steps_at.is_some() {
+            local_ctx.full_side_effects.len()
+        } else {
+            1
+        }
+    }
+
     fn square_up(
         local_ctx: &mut LocalXtxCtx<T>,
         maybe_xbi_execution_charge: Option<(
@@ -1509,9 +2435,16 @@ impl<T: Config> Pallet<T> {
                 )
             }
         };
+        let reserved_steps_cnt = Self::get_steps_cnt_with_reserved_max_rewards(local_ctx);
+        let current_step = local_ctx.xtx.steps_cnt.0 as usize;
         match local_ctx.xtx.status {
             CircuitStatus::Requested => {
-                for fsx in Self::get_current_step_fsx(local_ctx).iter() {
+                for fsx in local_ctx
+                    .full_side_effects
+                    .iter()
+                    .take(reserved_steps_cnt)
+                    .flatten()
+                {
                     if !<T as Config>::AccountManager::can_withdraw(
                         &requester,
                         fsx.input.max_reward,
@@ -1520,7 +2453,12 @@ impl<T: Config> Pallet<T> {
                         return Err(Error::<T>::XtxChargeFailedRequesterBalanceTooLow)
                     }
                 }
-                for fsx in Self::get_current_step_fsx(local_ctx).iter() {
+                for fsx in local_ctx
+                    .full_side_effects
+                    .iter()
+                    .take(reserved_steps_cnt)
+                    .flatten()
+                {
                     <T as Config>::AccountManager::withdraw_immediately(
                         &requester,
                         fsx.input.max_reward,
@@ -1529,13 +2467,26 @@ impl<T: Config> Pallet<T> {
                     .expect("Ensured can withdraw in can_withdraw loop over FSX")
                 }
             },
-            CircuitStatus::DroppedAtBidding => {
-                unreserve_requester_xtx_max_rewards(Self::get_current_step_fsx(local_ctx));
-            },
+            CircuitStatus::DroppedAtBidding =>
+                for step in local_ctx
+                    .full_side_effects
+                    .iter()
+                    .take(reserved_steps_cnt)
+                    .skip(current_step)
+                {
+                    unreserve_requester_xtx_max_rewards(step);
+                },
             CircuitStatus::Ready => {
-                let current_step_sfx = Self::get_current_step_fsx(local_ctx);
+                // SFX confirmed before the rest of their step got reopened for bidding are already charged
+                let current_step_sfx: Vec<_> = Self::get_current_step_fsx(local_ctx)
+                    .iter()
+                    .filter(|fsx| fsx.confirmed.is_none())
+                    .cloned()
+                    .collect();
                 // Unreserve the max_rewards and replace with possibly lower bids of executor in following loop
-                unreserve_requester_xtx_max_rewards(current_step_sfx);
+                if current_step < reserved_steps_cnt {
+                    unreserve_requester_xtx_max_rewards(&current_step_sfx);
+                }
                 for fsx in current_step_sfx.iter() {
                     let charge_id = fsx.generate_id::<SystemHashing<T>, T>(local_ctx.xtx_id);
                     let bid_4_fsx: &SFXBid<T::AccountId, EscrowedBalanceOf<T, T::Escrowed>, u32> =
@@ -1580,8 +2531,19 @@ impl<T: Config> Pallet<T> {
             // todo: distinct between RevertTimedOut to iterate over all steps vs single step for Revert
             CircuitStatus::RevertTimedOut
             | CircuitStatus::Reverted
-            | CircuitStatus::RevertMisbehaviour => {
-                Optimistic::<T>::try_slash(local_ctx);
+            | CircuitStatus::RevertMisbehaviour
+            | CircuitStatus::RevertKill
+            | CircuitStatus::RevertGatewayInactive => {
+                match local_ctx.xtx.status {
+                    // Target gateways becoming inactive is beyond executors' control,
+                    //  so their bonds are released instead of slashed
+                    CircuitStatus::RevertGatewayInactive => Optimistic::<T>::try_unbond(local_ctx)?,
+                    // Bonds were already settled by try_slash_misbehaved at the challenge
+                    CircuitStatus::RevertMisbehaviour => {},
+                    _ => {
+                        Optimistic::<T>::try_slash(local_ctx);
+                    },
+                }
                 for fsx in Self::get_current_step_fsx(local_ctx).iter() {
                     let charge_id = fsx.generate_id::<SystemHashing<T>, T>(local_ctx.xtx_id);
                     <T as Config>::AccountManager::finalize_infallible(
@@ -1591,6 +2553,27 @@ impl<T: Config> Pallet<T> {
                         None,
                     );
                 }
+                // Max rewards reserved for steps that never got past bidding go back to requester
+                if current_step < reserved_steps_cnt {
+                    for fsx in Self::get_current_step_fsx(local_ctx)
+                        .iter()
+                        .filter(|fsx| fsx.best_bid.is_none())
+                    {
+                        <T as Config>::AccountManager::deposit_immediately(
+                            &requester,
+                            fsx.input.max_reward,
+                            fsx.input.reward_asset_id,
+                        )
+                    }
+                }
+                for step in local_ctx
+                    .full_side_effects
+                    .iter()
+                    .take(reserved_steps_cnt)
+                    .skip(current_step + 1)
+                {
+                    unreserve_requester_xtx_max_rewards(step);
+                }
             },
             CircuitStatus::Finished | CircuitStatus::FinishedAllSteps => {
                 Optimistic::<T>::try_unbond(local_ctx)?;
@@ -1637,6 +2620,7 @@ impl<T: Config> Pallet<T> {
         local_ctx: &mut LocalXtxCtx<T>,
         _requester: &T::AccountId,
         _sequential: bool,
+        side_effects_steps: Option<Vec<Vec<u32>>>,
     ) -> Result<(), &'static str> {
         let mut full_side_effects: Vec<
             FullSideEffect<T::AccountId, T::BlockNumber, EscrowedBalanceOf<T, T::Escrowed>>,
@@ -1659,6 +2643,23 @@ impl<T: Config> Pallet<T> {
         }
 
         for (index, sfx) in side_effects.iter().enumerate() {
+            if <T as Config>::Xdns::get_gateway_status(&sfx.target)? == GatewayStatus::Inactive {
+                return Err("SFX validate failed - target gateway is inactive")
+            }
+            // Transfers on Ethereum are confirmed with the Transfer logs of the gateway's ERC-20 token contract,
+            //  native ether transfers emit no log to prove them with
+            if sfx.encoded_action.get(0..4) == Some(&b"tran"[..])
+                && matches!(
+                    <T as Config>::Xdns::get_gateway_vendor(&sfx.target)?,
+                    GatewayVendor::Ethereum | GatewayVendor::EthereumBeacon
+                )
+                && ethereum_transfer_token(&<T as Config>::Xdns::get_gateway_security_coordinates(
+                    &sfx.target,
+                )?)
+                .is_none()
+            {
+                return Err("SFX validate failed - Ethereum gateway names no token contract to confirm transfers with")
+            }
             let gateway_abi = <T as Config>::Xdns::get_abi(sfx.target)?;
             let gateway_type = <T as Config>::Xdns::get_gateway_type_unsafe(&sfx.target);
 
@@ -1668,18 +2669,29 @@ impl<T: Config> Pallet<T> {
                 .use_protocol
                 .notice_gateway(sfx.target, allowed_side_effects);
 
-            local_ctx
-                .use_protocol
-                .validate_args::<T::AccountId, T::BlockNumber, EscrowedBalanceOf<T, T::Escrowed>, SystemHashing<T>>(
-                    sfx.clone(),
-                    gateway_abi,
-                    &mut local_ctx.local_state,
-                    local_ctx.xtx_id.as_ref(),
-                    index as u32
-                ).map_err(|e| {
-                log::debug!(target: "runtime::circuit", "validate -- error validating side effects {:?}", e);
-                e
-            })?;
+            // Arguments referring to DFD predecessors are validated once substituted with confirmed outputs
+            if sfx
+                .encoded_args
+                .iter()
+                .any(|arg| match_dfd_arg(arg).is_some())
+            {
+                if side_effects_steps.is_none() {
+                    return Err("SFX validate failed - only SFX of requester's steps can refer to predecessors' outputs")
+                }
+            } else {
+                local_ctx
+                    .use_protocol
+                    .validate_args::<T::AccountId, T::BlockNumber, EscrowedBalanceOf<T, T::Escrowed>, SystemHashing<T>>(
+                        sfx.clone(),
+                        gateway_abi,
+                        &mut local_ctx.local_state,
+                        local_ctx.xtx_id.as_ref(),
+                        index as u32
+                    ).map_err(|e| {
+                    log::debug!(target: "runtime::circuit", "validate -- error validating side effects {:?}", e);
+                    e
+                })?;
+            }
 
             if let Some(next) = side_effects.get(index + 1) {
                 if sfx.reward_asset_id != next.reward_asset_id {
@@ -1725,6 +2737,41 @@ impl<T: Config> Pallet<T> {
                 index: index as u32,
             });
         }
+        // Requester's steps, given directly or compiled from DFD: SFX become executable once all of
+        //     their predecessors are confirmed
+        if let Some(compiled_steps) = side_effects_steps {
+            let mut scheduled_at_step: BTreeMap<u32, usize> = BTreeMap::new();
+            for (step_cnt, step) in compiled_steps.iter().enumerate() {
+                for index in step.iter() {
+                    scheduled_at_step.insert(*index, step_cnt);
+                }
+            }
+            // Arguments can only refer to outputs of SFX scheduled at the earlier steps
+            for fsx in full_side_effects.iter() {
+                for arg in fsx.input.encoded_args.iter() {
+                    if let Some((predecessor_index, _param_index)) = match_dfd_arg(arg) {
+                        match (
+                            scheduled_at_step.get(&predecessor_index),
+                            scheduled_at_step.get(&fsx.index),
+                        ) {
+                            (Some(predecessor_step), Some(step)) if predecessor_step < step => {},
+                            _ => return Err("SFX validate failed - argument refers to SFX not preceding it in DFD"),
+                        }
+                    }
+                }
+            }
+
+            local_ctx.full_side_effects = compiled_steps
+                .iter()
+                .map(|step| {
+                    step.iter()
+                        .map(|index| full_side_effects[*index as usize].clone())
+                        .collect()
+                })
+                .collect();
+            return Ok(())
+        }
+
         // Circuit's automatic side effect ordering: execute escrowed asap, then line up optimistic ones
         full_side_effects.sort_by(|a, b| b.security_lvl.partial_cmp(&a.security_lvl).unwrap());
 
@@ -1757,6 +2804,59 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Substitutes arguments of the current step's SFX referring to DFD predecessors
+    ///     with their confirmed outputs kept in local state, and validates them.
+    fn substitute_dfd_args(local_ctx: &mut LocalXtxCtx<T>) -> Result<(), &'static str> {
+        let xtx_id = local_ctx.xtx_id;
+        let sfx_ids: BTreeMap<u32, SideEffectId<T>> = local_ctx
+            .full_side_effects
+            .iter()
+            .flatten()
+            .map(|fsx| (fsx.index, fsx.generate_id::<SystemHashing<T>, T>(xtx_id)))
+            .collect();
+
+        let current_step = local_ctx
+            .full_side_effects
+            .get_mut(local_ctx.xtx.steps_cnt.0 as usize)
+            .ok_or("Current step not found")?;
+
+        for fsx in current_step.iter_mut() {
+            let mut is_substituted = false;
+            for arg in fsx.input.encoded_args.iter_mut() {
+                if let Some((predecessor_index, param_index)) = match_dfd_arg(arg) {
+                    let predecessor_id = sfx_ids
+                        .get(&predecessor_index)
+                        .ok_or("DFD predecessor not found")?;
+                    *arg = local_ctx
+                        .local_state
+                        .get((*predecessor_id, param_index))
+                        .ok_or("DFD predecessor's output not confirmed")?
+                        .clone();
+                    is_substituted = true;
+                }
+            }
+
+            if is_substituted {
+                let gateway_abi = <T as Config>::Xdns::get_abi(fsx.input.target)?;
+                local_ctx.use_protocol.notice_gateway(
+                    fsx.input.target,
+                    <T as Config>::Xdns::allowed_side_effects(&fsx.input.target),
+                );
+                local_ctx
+                    .use_protocol
+                    .validate_args::<T::AccountId, T::BlockNumber, EscrowedBalanceOf<T, T::Escrowed>, SystemHashing<T>>(
+                        fsx.input.clone(),
+                        gateway_abi,
+                        &mut local_ctx.local_state,
+                        xtx_id.as_ref(),
+                        fsx.index,
+                    )?;
+            }
+        }
+
+        Ok(())
+    }
+
     fn confirm(
         local_ctx: &mut LocalXtxCtx<T>,
         _relayer: &T::AccountId,
@@ -1814,6 +2914,13 @@ impl<T: Config> Pallet<T> {
             }
         }
 
+        // Confirmation is received now - misbehaviour can only be recorded by its challenge
+        let confirmation = &ConfirmedSideEffect {
+            err: None,
+            received_at: frame_system::Pallet::<T>::block_number(),
+            ..confirmation.clone()
+        };
+
         // confirm order of current season, by passing the side_effects of it to confirm order.
         let fsx = confirm_order::<T>(
             local_ctx.xtx_id,
@@ -1827,6 +2934,9 @@ impl<T: Config> Pallet<T> {
         let mut side_effect_id: [u8; 4] = [0, 0, 0, 0];
         side_effect_id.copy_from_slice(&fsx.input.encoded_action[0..4]);
 
+        // SFX are confirmed against the XDNS records the Xtx was validated with
+        Self::ensure_xdns_records_unchanged(local_ctx.xtx_id)?;
+
         // confirm the payload is included in the specified block, and return the SideEffect params as defined in XDNS.
         // this could be multiple events!
         let (params, source) = <T as Config>::Portal::confirm_and_decode_payload_params(
@@ -1836,17 +2946,20 @@ impl<T: Config> Pallet<T> {
             side_effect_id,
         )
         .map_err(|_| "SideEffect confirmation failed!")?;
-        // ToDo: handle misbehaviour
+        // Misbehaviour of optimistic confirmations is proven later on with challenge_side_effect_confirmation
         log::debug!("SFX confirmation params: {:?}", params);
 
-        let side_effect_interface =
-            <T as Config>::Xdns::fetch_side_effect_interface(side_effect_id);
+        let side_effect_interface = Self::fetch_pinned_side_effect_interface(
+            local_ctx.xtx_id,
+            &fsx.input.target,
+            side_effect_id,
+        );
 
         log::debug!("Found SFX interface!");
 
         confirmation_plug::<T>(
             &Box::new(side_effect_interface.unwrap()),
-            params,
+            params.clone(),
             source,
             &local_ctx.local_state,
             Some(sfx_id.as_ref().to_vec()),
@@ -1856,9 +2969,157 @@ impl<T: Config> Pallet<T> {
         .map_err(|_| "Execution can't be confirmed.")?;
         log::debug!("confirmation plug ok");
 
+        // Expose confirmed values to the following steps under (sfx_id, param index)
+        for (index, param) in params.into_iter().enumerate() {
+            local_ctx
+                .local_state
+                .insert((*sfx_id, index as u32), param)
+                .map_err(|e| {
+                    log::debug!(
+                        "Confirmed param {:?} not stored in local state: {:?}",
+                        index,
+                        e
+                    );
+                    "Confirmed SFX output can't be stored in local state"
+                })?;
+        }
+
+        // Deadlines of optimistic SFX are enforced by on_sfx_execution_timeout - confirmation made it on time
+        if let Some(sfx_bid) = &fsx.best_bid {
+            T::Executors::update_reputation(&sfx_bid.executor, ReputationOutcome::ConfirmedOnTime);
+        }
+
         Ok(())
     }
 
+    /// Re-verifies confirmation of FSX against its inclusion proof and the SFX arguments.
+    ///     Returns the misbehaviour found or None if the confirmation holds.
+    /// Returns the misbehaviour positively proven against the confirmation of SFX, or None if it holds.
+    ///     Errors if the confirmation can no longer be verified against the data it was made with.
+    fn verify_confirmation(
+        local_ctx: &LocalXtxCtx<T>,
+        fsx: &FullSideEffect<
+            <T as frame_system::Config>::AccountId,
+            <T as frame_system::Config>::BlockNumber,
+            EscrowedBalanceOf<T, <T as Config>::Escrowed>,
+        >,
+        sfx_id: &SideEffectId<T>,
+    ) -> Result<Option<ConfirmationOutcome>, Error<T>> {
+        let misbehaviour = |key: &[u8], expected: Vec<u8>, received: Vec<u8>| {
+            Ok(Some(ConfirmationOutcome::MisbehaviourMalformedValues {
+                key: key.to_vec(),
+                expected,
+                received,
+            }))
+        };
+        let confirmation = match fsx.confirmed.as_ref() {
+            Some(confirmation) => confirmation,
+            None => return Ok(None),
+        };
+
+        if let Some(sfx_bid) = &fsx.best_bid {
+            if sfx_bid.executor != confirmation.executioner {
+                return misbehaviour(
+                    b"executor",
+                    sfx_bid.executor.encode(),
+                    confirmation.executioner.encode(),
+                )
+            }
+        }
+
+        if <T as Config>::Xdns::get_gateway_status(&fsx.input.target)
+            .map_err(|_| Error::<T>::ChallengeUnverifiableGatewayInactive)?
+            == GatewayStatus::Inactive
+        {
+            return Err(Error::<T>::ChallengeUnverifiableGatewayInactive)
+        }
+        Self::ensure_xdns_records_unchanged(local_ctx.xtx_id)
+            .map_err(|_| Error::<T>::ChallengeUnverifiableXdnsRecordChanged)?;
+
+        let mut side_effect_id: [u8; 4] = [0, 0, 0, 0];
+        side_effect_id.copy_from_slice(
+            fsx.input
+                .encoded_action
+                .get(0..4)
+                .ok_or(Error::<T>::ChallengeUnverifiableXdnsRecordChanged)?,
+        );
+
+        let (params, source) = match <T as Config>::Portal::confirm_and_decode_payload_params(
+            fsx.input.target,
+            fsx.submission_target_height.clone(),
+            confirmation.inclusion_data.clone(),
+            side_effect_id,
+        ) {
+            Ok(decoded) => decoded,
+            Err(_) if Self::is_pruned_at(&fsx.input.target, &fsx.submission_target_height) =>
+                return Err(Error::<T>::ChallengeUnverifiableHeaderPruned),
+            Err(_) =>
+                return misbehaviour(
+                    b"inclusion_data",
+                    fsx.submission_target_height.clone(),
+                    confirmation.inclusion_data.clone(),
+                ),
+        };
+
+        let side_effect_interface = Self::fetch_pinned_side_effect_interface(
+            local_ctx.xtx_id,
+            &fsx.input.target,
+            side_effect_id,
+        )
+        .map_err(|_| Error::<T>::ChallengeUnverifiableXdnsRecordChanged)?;
+
+        match side_effect_interface.find_confirmed_arg_mismatch(&fsx.input.encoded_args, &params) {
+            Ok(Some((key, expected, received))) => return misbehaviour(&key, expected, received),
+            Ok(None) => {},
+            Err(_) => return misbehaviour(b"params", vec![], params.encode()),
+        }
+
+        let security_coordinates =
+            <T as Config>::Xdns::get_gateway_security_coordinates(&fsx.input.target)
+                .map_err(|_| Error::<T>::ChallengeUnverifiableXdnsRecordChanged)?;
+        if confirmation_plug::<T>(
+            &Box::new(side_effect_interface),
+            params.clone(),
+            source,
+            &local_ctx.local_state,
+            Some(sfx_id.as_ref().to_vec()),
+            fsx.security_lvl.clone(),
+            security_coordinates,
+        )
+        .is_err()
+        {
+            return misbehaviour(b"params", vec![], params.encode())
+        }
+
+        Ok(None)
+    }
+
+    /// Whether headers of the gateway at the given height are already pruned, so that inclusion
+    ///     proofs submitted against them can't be verified anymore. Unknown provable range counts as pruned.
+    fn is_pruned_at(gateway_id: &ChainId, height: &[u8]) -> bool {
+        // Heights are SCALE encoded unsigned integers - little endian of the gateway's block number size
+        let as_number = |encoded: &[u8]| -> Option<u128> {
+            if encoded.is_empty() || encoded.len() > 16 {
+                return None
+            }
+            Some(
+                encoded
+                    .iter()
+                    .rev()
+                    .fold(0u128, |number, byte| (number << 8) | *byte as u128),
+            )
+        };
+
+        match <T as Config>::Portal::get_oldest_provable_height(*gateway_id) {
+            Ok(Some(oldest)) => match (as_number(height), as_number(&oldest)) {
+                (Some(height), Some(oldest)) => height < oldest,
+                _ => true,
+            },
+            Ok(None) => false,
+            Err(_) => true,
+        }
+    }
+
     // ToDo: This should be called as a 3vm trait injection @Don
     pub fn exec_in_xtx_ctx(
         _xtx_id: T::Hash,
@@ -1965,6 +3226,745 @@ impl<T: Config> Pallet<T> {
         processed_weight
     }
 
+    /// Drains expired entries of XtxTimeoutsQueue up to max_weight and DeletionQueueLimit entries per block.
+    ///     Blocks left over from the previous block go first, then every block since the last visited one up to n.
+    ///     Blocks that couldn't be fully drained are moved to ImmediateXtxTimeoutsQueue.
+    pub(crate) fn process_xtx_timeouts_queue(n: T::BlockNumber, max_weight: Weight) -> Weight {
+        let db_weight = T::DbWeight::get();
+        // Read & write of ImmediateXtxTimeoutsQueue and XtxTimeoutsVisitedUntil
+        let mut processed_weight = db_weight.reads_writes(2 as Weight, 2 as Weight);
+        let mut processed_cnt: u32 = 0;
+        let mut leftover_blocks: Vec<T::BlockNumber> = vec![];
+
+        let mut drain_block = |block: T::BlockNumber, processed_weight: &mut Weight| -> bool {
+            let mut expired = <XtxTimeoutsQueue<T>>::drain_prefix(block);
+            loop {
+                if processed_cnt >= T::DeletionQueueLimit::get()
+                    || processed_weight.saturating_add(Self::max_xtx_timeout_weight()) > max_weight
+                {
+                    return false
+                }
+                match expired.next() {
+                    Some(((xtx_id, XtxTimeoutKind::Bidding), ())) => {
+                        Self::on_bidding_timeout(xtx_id);
+                        *processed_weight = processed_weight
+                            .saturating_add(T::WeightInfo::on_initialize_bidding_timeout());
+                    },
+                    Some(((xtx_id, XtxTimeoutKind::Execution), ())) => {
+                        Self::on_execution_timeout(xtx_id);
+                        *processed_weight = processed_weight
+                            .saturating_add(T::WeightInfo::on_initialize_execution_timeout());
+                    },
+                    Some(((xtx_id, XtxTimeoutKind::ScheduledStep), ())) => {
+                        Self::on_scheduled_step(xtx_id);
+                        *processed_weight = processed_weight
+                            .saturating_add(T::WeightInfo::on_initialize_scheduled_step());
+                    },
+                    Some(((xtx_id, XtxTimeoutKind::EscrowFinalization), ())) => {
+                        Self::on_escrow_finalization_timeout(xtx_id);
+                        *processed_weight = processed_weight.saturating_add(
+                            T::WeightInfo::on_initialize_escrow_finalization_timeout(),
+                        );
+                    },
+                    Some(((xtx_id, XtxTimeoutKind::SFXExecution), ())) => {
+                        Self::on_sfx_execution_timeout(xtx_id);
+                        *processed_weight = processed_weight
+                            .saturating_add(T::WeightInfo::on_initialize_sfx_execution_timeout());
+                    },
+                    Some(((xtx_id, XtxTimeoutKind::XdnsPinsRelease), ())) => {
+                        Self::release_xdns_pins(xtx_id);
+                        *processed_weight = processed_weight
+                            .saturating_add(T::WeightInfo::on_initialize_xdns_pins_release());
+                    },
+                    None => return true,
+                }
+                processed_cnt = processed_cnt.saturating_add(1);
+            }
+        };
+
+        for block in <ImmediateXtxTimeoutsQueue<T>>::get() {
+            if !drain_block(block, &mut processed_weight) {
+                leftover_blocks.push(block);
+            }
+        }
+
+        let mut visited_until = <XtxTimeoutsVisitedUntil<T>>::get();
+        while visited_until < n {
+            let block = visited_until.saturating_add(One::one());
+            processed_weight = processed_weight
+                .saturating_add(T::WeightInfo::on_initialize_visit_timeouts_block());
+            if processed_weight > max_weight {
+                break
+            }
+            if !drain_block(block, &mut processed_weight) {
+                leftover_blocks.push(block);
+            }
+            visited_until = block;
+        }
+
+        <XtxTimeoutsVisitedUntil<T>>::put(visited_until);
+        <ImmediateXtxTimeoutsQueue<T>>::put(leftover_blocks);
+
+        processed_weight
+    }
+
+    /// Pins the versions of XDNS records of all gateways targeted by the Xtx
+    ///     and of the custom side effect interfaces it uses.
+    fn pin_xdns_record_versions(local_ctx: &LocalXtxCtx<T>) {
+        let mut versions: Vec<(ChainId, u32, [u8; 32])> = vec![];
+        let mut interface_versions: Vec<(ChainId, [u8; 4], u32)> = vec![];
+        for fsx in local_ctx.full_side_effects.iter().flatten() {
+            let target = fsx.input.target;
+            if let Some(action) = fsx.input.encoded_action.get(0..4) {
+                let mut side_effect_id: [u8; 4] = [0, 0, 0, 0];
+                side_effect_id.copy_from_slice(action);
+                if !interface_versions
+                    .iter()
+                    .any(|(gateway_id, id, _)| *gateway_id == target && *id == side_effect_id)
+                {
+                    if let Some(version) =
+                        <T as Config>::Xdns::pin_side_effect_interface(&target, side_effect_id)
+                    {
+                        interface_versions.push((target, side_effect_id, version));
+                    }
+                }
+            }
+            if versions
+                .iter()
+                .any(|(gateway_id, _, _)| *gateway_id == target)
+            {
+                continue
+            }
+            if let (Ok(version), Ok(fingerprint)) = (
+                <T as Config>::Xdns::get_gateway_record_version(&target),
+                <T as Config>::Xdns::get_gateway_record_fingerprint(&target),
+            ) {
+                <T as Config>::Xdns::pin_gateway(&target);
+                versions.push((target, version, fingerprint));
+            }
+        }
+        <XtxXdnsRecordVersions<T>>::insert(local_ctx.xtx_id, versions);
+        if !interface_versions.is_empty() {
+            <XtxSideEffectInterfaceVersions<T>>::insert(local_ctx.xtx_id, interface_versions);
+        }
+    }
+
+    /// Releases the XDNS record and side effect interface versions pinned by the Xtx
+    fn release_xdns_pins(xtx_id: XExecSignalId<T>) {
+        for (gateway_id, _, _) in <XtxXdnsRecordVersions<T>>::take(xtx_id).unwrap_or_default() {
+            <T as Config>::Xdns::unpin_gateway(&gateway_id);
+        }
+        for (gateway_id, side_effect_id, version) in
+            <XtxSideEffectInterfaceVersions<T>>::take(xtx_id).unwrap_or_default()
+        {
+            <T as Config>::Xdns::unpin_side_effect_interface(&gateway_id, side_effect_id, version);
+        }
+    }
+
+    /// Resolves the side effect interface in the version the Xtx was validated against
+    fn fetch_pinned_side_effect_interface(
+        xtx_id: XExecSignalId<T>,
+        gateway_id: &ChainId,
+        side_effect_id: [u8; 4],
+    ) -> Result<Box<dyn SideEffectProtocol>, DispatchError> {
+        match <XtxSideEffectInterfaceVersions<T>>::get(xtx_id)
+            .unwrap_or_default()
+            .into_iter()
+            .find(|(target, id, _)| target == gateway_id && *id == side_effect_id)
+        {
+            Some((_, _, version)) => <T as Config>::Xdns::fetch_side_effect_interface_version(
+                gateway_id,
+                side_effect_id,
+                version,
+            ),
+            None => <T as Config>::Xdns::fetch_side_effect_interface(gateway_id, side_effect_id),
+        }
+    }
+
+    /// Xtx validated against XDNS records whose confirmation fields changed since can't accept any more bids
+    ///     nor confirmations. Updates of other fields, e.g. the url, bump the version but keep the Xtx going.
+    fn ensure_xdns_records_unchanged(xtx_id: XExecSignalId<T>) -> Result<(), Error<T>> {
+        for (gateway_id, _, fingerprint) in
+            <XtxXdnsRecordVersions<T>>::get(xtx_id).unwrap_or_default()
+        {
+            if <T as Config>::Xdns::get_gateway_record_fingerprint(&gateway_id) != Ok(fingerprint) {
+                return Err(Error::<T>::XdnsRecordChangedSinceValidation)
+            }
+        }
+        Ok(())
+    }
+
+    /// Events proven by Substrate gateways carry the index of the pallet that emitted them, which has to
+    ///     match the Circuit pallet on the gateway - first byte of its XDNS security coordinates.
+    fn ensure_emitted_by_circuit_pallet(
+        gateway_id: &ChainId,
+        source: &[u8],
+    ) -> Result<(), Error<T>> {
+        let security_coordinates =
+            <T as Config>::Xdns::get_gateway_security_coordinates(gateway_id)
+                .map_err(|_| Error::<T>::RemoteEventEmittedByUnexpectedPallet)?;
+        match (security_coordinates.first(), source.first()) {
+            (Some(expected), Some(emitted)) if expected == emitted => Ok(()),
+            _ => Err(Error::<T>::RemoteEventEmittedByUnexpectedPallet),
+        }
+    }
+
+    fn max_xtx_timeout_weight() -> Weight {
+        T::WeightInfo::on_initialize_bidding_timeout()
+            .max(T::WeightInfo::on_initialize_execution_timeout())
+            .max(T::WeightInfo::on_initialize_scheduled_step())
+            .max(T::WeightInfo::on_initialize_escrow_finalization_timeout())
+            .max(T::WeightInfo::on_initialize_sfx_execution_timeout())
+            .max(T::WeightInfo::on_initialize_xdns_pins_release())
+    }
+
+    /// Escrowed SFX executed on remote targets now await the proof of their escrow being committed or reverted.
+    ///     Only SFX which interface declares the matching escrow events are awaited.
+    fn open_escrow_finalizations(local_ctx: &LocalXtxCtx<T>, outcome: Outcome) {
+        let mut opened = false;
+        for fsx in local_ctx.full_side_effects.iter().flatten() {
+            if fsx.security_lvl != SecurityLvl::Escrow
+                || fsx.confirmed.is_none()
+                || !fsx.is_bid_resolved()
+            {
+                continue
+            }
+            let mut side_effect_id: [u8; 4] = [0, 0, 0, 0];
+            match fsx.input.encoded_action.get(0..4) {
+                Some(action) => side_effect_id.copy_from_slice(action),
+                None => continue,
+            }
+            let escrow_events = match Self::fetch_pinned_side_effect_interface(
+                local_ctx.xtx_id,
+                &fsx.input.target,
+                side_effect_id,
+            ) {
+                Ok(interface) => match outcome {
+                    Outcome::Commit => interface.get_reversible_commit(),
+                    _ => interface.get_reversible_revert(),
+                },
+                Err(_) => continue,
+            };
+            if escrow_events.is_empty() {
+                continue
+            }
+            <PendingEscrowFinalizations<T>>::insert(
+                local_ctx.xtx_id,
+                fsx.generate_id::<SystemHashing<T>, T>(local_ctx.xtx_id),
+                outcome.clone(),
+            );
+            opened = true;
+        }
+
+        if opened {
+            <XtxTimeoutsQueue<T>>::insert(
+                frame_system::Pallet::<T>::block_number()
+                    .saturating_add(T::EscrowFinalizationPeriod::get()),
+                (local_ctx.xtx_id, XtxTimeoutKind::EscrowFinalization),
+                (),
+            );
+        }
+    }
+
+    /// Checks decoded params of the remote escrow event against any of the escrow events declared by SFX interface.
+    ///     Event fields named "xtx_id", "sfx_id" and "executor" must match the finalized SFX and its executor.
+    ///     Any other field must be named after an SFX argument (e.g. "to", "value", "asset") and match its input.
+    fn matches_escrow_event(
+        escrow_events: &[Vec<u8>],
+        arg_names: &[Vec<u8>],
+        params: &[Vec<u8>],
+        xtx_id: &XExecSignalId<T>,
+        sfx_id: &SideEffectId<T>,
+        executor: &T::AccountId,
+        sfx_args: &[Vec<u8>],
+    ) -> bool {
+        escrow_events.iter().any(|escrow_event| {
+            let event_args = match match_signature(escrow_event.clone()) {
+                Ok((_event_name, event_args)) => event_args,
+                Err(_) => return false,
+            };
+            event_args.len() == params.len()
+                && event_args
+                    .iter()
+                    .zip(params.iter())
+                    .all(|(event_arg, param)| match &event_arg[..] {
+                        b"xtx_id" => *param == xtx_id.encode(),
+                        b"sfx_id" => *param == sfx_id.encode(),
+                        b"executor" => *param == executor.encode(),
+                        arg_name => arg_names
+                            .iter()
+                            .position(|name| &name[..] == arg_name)
+                            .and_then(|i| sfx_args.get(i))
+                            .map_or(false, |sfx_arg| sfx_arg == param),
+                    })
+        })
+    }
+
+    /// Executors didn't prove the finalization of remote escrow in time - slash their insurance.
+    fn on_escrow_finalization_timeout(xtx_id: XExecSignalId<T>) {
+        let full_side_effects = <FullSideEffects<T>>::get(xtx_id).unwrap_or_default();
+
+        for (sfx_id, _outcome) in <PendingEscrowFinalizations<T>>::drain_prefix(xtx_id) {
+            let maybe_sfx_bid = full_side_effects
+                .iter()
+                .flatten()
+                .find(|fsx| fsx.generate_id::<SystemHashing<T>, T>(xtx_id) == sfx_id)
+                .and_then(|fsx| fsx.best_bid.clone());
+
+            if let Some(sfx_bid) = maybe_sfx_bid {
+                <T as Config>::AccountManager::deposit_immediately(
+                    &T::SelfAccountId::get(),
+                    sfx_bid.insurance,
+                    sfx_bid.reward_asset_id,
+                );
+                Self::deposit_event(Event::SideEffectEscrowFinalizationTimedOut(
+                    sfx_id,
+                    sfx_bid.executor,
+                ));
+            }
+        }
+    }
+
+    /// Scheduled block of the current step is reached - open it for bidding.
+    fn on_scheduled_step(xtx_id: XExecSignalId<T>) {
+        let mut local_ctx =
+            match Self::setup(CircuitStatus::Scheduled, &Self::account_id(), Some(xtx_id)) {
+                Ok(value) => value,
+                Err(error) => {
+                    log::error!("Could not setup local ctx: {:?}", error);
+                    return
+                },
+            };
+
+        if local_ctx.xtx.status != CircuitStatus::Scheduled {
+            return
+        }
+
+        let status_change = match Self::update_or_revert(&mut local_ctx) {
+            Ok(Some(value)) => value,
+            Ok(None) => return,
+            Err(error) => {
+                log::error!("Could not update local ctx change: {:?}", error);
+                return
+            },
+        };
+
+        Self::apply(&mut local_ctx, status_change);
+
+        Self::emit_status_update(local_ctx.xtx_id, Some(local_ctx.xtx), None);
+    }
+
+    /// Bidding period is over - accept the best bids for the current step,
+    ///     or drop the Xtx if any of the FSX haven't received a bid.
+    fn on_bidding_timeout(xtx_id: XExecSignalId<T>) {
+        // The timeout has just been drained from XtxTimeoutsQueue
+        <XtxBiddingTimeoutsAt<T>>::remove(xtx_id);
+
+        let mut local_ctx = match Self::setup(
+            CircuitStatus::PendingBidding,
+            &Self::account_id(),
+            Some(xtx_id),
+        ) {
+            Ok(value) => value,
+            Err(error) => {
+                // Xtx could have been cancelled at bidding already
+                log::error!("Could not setup local ctx: {:?}", error);
+                return
+            },
+        };
+
+        // Xtx could have been reverted in the meantime and is already squared up
+        if local_ctx.xtx.status >= CircuitStatus::Committed {
+            return
+        }
+
+        // Ensure Circuit::PendingBidding status
+        if local_ctx.xtx.status != CircuitStatus::PendingBidding {
+            Self::kill(&mut local_ctx, CircuitStatus::DroppedAtBidding);
+            return
+        }
+
+        match <SealedBiddingPhases<T>>::get(xtx_id) {
+            // Commit phase is over - open the reveal phase
+            Some(SealedBiddingPhase::Commit) => {
+                <SealedBiddingPhases<T>>::insert(xtx_id, SealedBiddingPhase::Reveal);
+                Self::queue_bidding_timeout(
+                    xtx_id,
+                    frame_system::Pallet::<T>::block_number()
+                        .saturating_add(T::SFXBidRevealPeriod::get()),
+                );
+                Self::deposit_event(Event::XTransactionBidRevealOpened(xtx_id));
+                return
+            },
+            // Reveal phase is over - the lowest revealed bids are already the best bids
+            Some(SealedBiddingPhase::Reveal) => {
+                Self::settle_sealed_bids(&local_ctx, true);
+                <SealedBiddingPhases<T>>::insert(xtx_id, SealedBiddingPhase::Commit);
+            },
+            None => {},
+        }
+
+        let current_step = local_ctx.xtx.steps_cnt.0;
+        let is_step_reopened = local_ctx.full_side_effects[current_step as usize]
+            .iter()
+            .any(|fsx| fsx.confirmed.is_some());
+        for mut fsx in local_ctx.full_side_effects[current_step as usize].iter_mut() {
+            // Keep the bids of SFX confirmed before the step got reopened for bidding
+            if fsx.confirmed.is_some() {
+                continue
+            }
+            let sfx_id = fsx.generate_id::<SystemHashing<T>, T>(local_ctx.xtx_id);
+            if let Some(best_sfx_bid) = <PendingSFXBids<T>>::get(xtx_id, sfx_id) {
+                fsx.best_bid = Some(best_sfx_bid);
+            } else if current_step > 0 || is_step_reopened {
+                // Some SFX are already executed - leave the Xtx to be reverted at its timeout
+                log::debug!(
+                    "Missing bids for step {:?} of xtx {:?}",
+                    current_step,
+                    xtx_id
+                );
+                return
+            } else {
+                // error - some FSX don't have bids
+                Self::kill(&mut local_ctx, CircuitStatus::DroppedAtBidding);
+                Self::emit_status_update(local_ctx.xtx_id, Some(local_ctx.xtx), None);
+                return
+            }
+        }
+
+        let status_change = match Self::update_or_revert(&mut local_ctx) {
+            Ok(Some(value)) => value,
+            Ok(None) => return,
+            Err(error) => {
+                log::error!("Could not update local ctx change: {:?}", error);
+                return
+            },
+        };
+
+        Self::square_up(&mut local_ctx, None)
+            .expect("Expect Bonding Bids at square up to be infallible since funds of requester have been reserved at the SFX submission");
+
+        Self::apply(&mut local_ctx, status_change);
+
+        Self::emit_status_update(local_ctx.xtx_id, Some(local_ctx.xtx), None);
+    }
+
+    /// Winning executors of optimistic SFX in the step that just got Ready have to confirm them
+    ///     before SFXExecutionPeriod passes. Deadlines past the Xtx timeout are left to the Xtx revert.
+    fn queue_sfx_execution_deadlines(local_ctx: &LocalXtxCtx<T>) {
+        let deadline =
+            frame_system::Pallet::<T>::block_number().saturating_add(T::SFXExecutionPeriod::get());
+        if deadline >= local_ctx.xtx.timeouts_at {
+            return
+        }
+        let mut queued = false;
+        for fsx in Self::get_current_step_fsx(local_ctx).iter() {
+            if fsx.security_lvl == SecurityLvl::Optimistic
+                && fsx.confirmed.is_none()
+                && fsx.is_bid_resolved()
+            {
+                <SFXExecutionDeadlines<T>>::insert(
+                    fsx.generate_id::<SystemHashing<T>, T>(local_ctx.xtx_id),
+                    deadline,
+                );
+                queued = true;
+            }
+        }
+        if queued {
+            <XtxTimeoutsQueue<T>>::insert(
+                deadline,
+                (local_ctx.xtx_id, XtxTimeoutKind::SFXExecution),
+                (),
+            );
+        }
+    }
+
+    /// Winning executors didn't confirm their SFX in time - void their bids, slash the insurance
+    ///     and bond, and reopen the SFX for bidding. SFX already confirmed in the step stay confirmed.
+    fn on_sfx_execution_timeout(xtx_id: XExecSignalId<T>) {
+        let mut local_ctx = match Self::setup(
+            CircuitStatus::PendingExecution,
+            &Self::account_id(),
+            Some(xtx_id),
+        ) {
+            Ok(value) => value,
+            Err(error) => {
+                log::error!("Could not setup local ctx: {:?}", error);
+                return
+            },
+        };
+
+        let now = frame_system::Pallet::<T>::block_number();
+        let is_step_executing = local_ctx.xtx.status == CircuitStatus::Ready
+            || local_ctx.xtx.status == CircuitStatus::PendingExecution;
+        let current_step = local_ctx.xtx.steps_cnt.0 as usize;
+        let mut expired_sfx: Vec<SideEffectId<T>> = vec![];
+        for (step_cnt, step) in local_ctx.full_side_effects.iter().enumerate() {
+            for fsx in step.iter() {
+                let sfx_id = fsx.generate_id::<SystemHashing<T>, T>(xtx_id);
+                match <SFXExecutionDeadlines<T>>::get(sfx_id) {
+                    Some(deadline) if deadline <= now => {
+                        <SFXExecutionDeadlines<T>>::remove(sfx_id);
+                        if is_step_executing
+                            && step_cnt == current_step
+                            && fsx.confirmed.is_none()
+                            && fsx.is_bid_resolved()
+                        {
+                            expired_sfx.push(sfx_id);
+                        }
+                    },
+                    _ => {},
+                }
+            }
+        }
+        if expired_sfx.is_empty() {
+            return
+        }
+
+        // Max rewards of reopened SFX are reserved again, as are at the Xtx submission
+        let requester = local_ctx.xtx.requester.clone();
+        let mut max_rewards_per_asset: BTreeMap<Option<u32>, EscrowedBalanceOf<T, T::Escrowed>> =
+            BTreeMap::new();
+        if current_step < Self::get_steps_cnt_with_reserved_max_rewards(&local_ctx) {
+            for fsx in Self::get_current_step_fsx(&local_ctx)
+                .iter()
+                .filter(|fsx| expired_sfx.contains(&fsx.generate_id::<SystemHashing<T>, T>(xtx_id)))
+            {
+                let max_rewards = max_rewards_per_asset
+                    .entry(fsx.input.reward_asset_id)
+                    .or_insert_with(Zero::zero);
+                *max_rewards = max_rewards.saturating_add(fsx.input.max_reward);
+            }
+        }
+        if max_rewards_per_asset.iter().any(|(asset_id, max_rewards)| {
+            !<T as Config>::AccountManager::can_withdraw(&requester, *max_rewards, *asset_id)
+        }) {
+            // Leave the Xtx to be reverted at its timeout
+            log::error!(
+                "Could not reopen SFX of xtx {:?} for bidding - requester balance too low",
+                xtx_id
+            );
+            return
+        }
+
+        // Slashes, reserves and reputation changes are only kept once the reopened Xtx is updated
+        let status_change = with_transaction(|| {
+            if let Err(err) = Optimistic::<T>::try_slash_unconfirmed(&mut local_ctx) {
+                return TransactionOutcome::Rollback(Err(DispatchError::from(err)))
+            }
+            for (asset_id, max_rewards) in max_rewards_per_asset.iter() {
+                if let Err(err) = <T as Config>::AccountManager::withdraw_immediately(
+                    &requester,
+                    *max_rewards,
+                    *asset_id,
+                ) {
+                    return TransactionOutcome::Rollback(Err(err))
+                }
+            }
+
+            for fsx in local_ctx.full_side_effects[current_step].iter_mut() {
+                let sfx_id = fsx.generate_id::<SystemHashing<T>, T>(xtx_id);
+                if !expired_sfx.contains(&sfx_id) {
+                    continue
+                }
+                <T as Config>::AccountManager::finalize_infallible(
+                    sfx_id,
+                    Outcome::Revert,
+                    None,
+                    None,
+                );
+                if let Some(sfx_bid) = &fsx.best_bid {
+                    T::Executors::update_reputation(&sfx_bid.executor, ReputationOutcome::TimedOut);
+                }
+                fsx.best_bid = None;
+            }
+
+            match Self::update_or_revert(&mut local_ctx) {
+                Ok(status_change) => TransactionOutcome::Commit(Ok(status_change)),
+                Err(err) => TransactionOutcome::Rollback(Err(DispatchError::from(err))),
+            }
+        });
+        let status_change = match status_change {
+            Ok(Some(value)) => value,
+            Ok(None) => return,
+            Err(error) => {
+                log::error!(
+                    "Could not reopen SFX of xtx {:?} for bidding: {:?}",
+                    xtx_id,
+                    error
+                );
+                return
+            },
+        };
+
+        Self::apply(&mut local_ctx, status_change);
+
+        Self::deposit_event(Event::SideEffectsReopenedForBidding(xtx_id, expired_sfx));
+        Self::emit_status_update(local_ctx.xtx_id, Some(local_ctx.xtx), None);
+    }
+
+    /// Clears sealed bids committed for SFX of the current step. Deposits of bids that haven't
+    ///     been revealed are either forfeited or returned to executors.
+    fn settle_sealed_bids(local_ctx: &LocalXtxCtx<T>, forfeit_unrevealed: bool) {
+        let current_step_fsx = local_ctx
+            .full_side_effects
+            .get(local_ctx.xtx.steps_cnt.0 as usize)
+            .cloned()
+            .unwrap_or_default();
+
+        for fsx in current_step_fsx.iter() {
+            let sfx_id = fsx.generate_id::<SystemHashing<T>, T>(local_ctx.xtx_id);
+            for (executor, sealed_bid) in <SealedSFXBids<T>>::drain_prefix(sfx_id) {
+                if sealed_bid.is_revealed {
+                    continue
+                }
+                if forfeit_unrevealed {
+                    <T as Config>::AccountManager::deposit_immediately(
+                        &T::SelfAccountId::get(),
+                        sealed_bid.deposit,
+                        fsx.input.reward_asset_id,
+                    );
+                    Self::deposit_event(Event::SFXSealedBidForfeited(sfx_id, executor));
+                } else {
+                    <T as Config>::AccountManager::deposit_immediately(
+                        &executor,
+                        sealed_bid.deposit,
+                        fsx.input.reward_asset_id,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Xtx didn't finish before its timeout - revert it.
+    fn on_execution_timeout(xtx_id: XExecSignalId<T>) {
+        let mut local_xtx_ctx =
+            match Self::setup(CircuitStatus::Ready, &Self::account_id(), Some(xtx_id)) {
+                Ok(value) => value,
+                Err(error) => {
+                    log::error!("Could not setup local xtx ctx: {:?}", error);
+                    return
+                },
+            };
+
+        Self::kill(&mut local_xtx_ctx, CircuitStatus::RevertTimedOut);
+
+        Self::emit_status_update(local_xtx_ctx.xtx_id, Some(local_xtx_ctx.xtx), None);
+    }
+
+    /// Returns the Xtx with its steps, best bids and timeouts, as served by the runtime API.
+    pub fn get_xtx_status(
+        xtx_id: XExecSignalId<T>,
+    ) -> Option<
+        XtxStatusResponse<T::AccountId, T::BlockNumber, EscrowedBalanceOf<T, T::Escrowed>, T::Hash>,
+    > {
+        let xtx = <XExecSignals<T>>::get(xtx_id)?;
+        let steps = <FullSideEffects<T>>::get(xtx_id)
+            .unwrap_or_default()
+            .iter()
+            .map(|step| {
+                step.iter()
+                    .map(|fsx| Self::get_side_effect_status(xtx_id, fsx))
+                    .collect()
+            })
+            .collect();
+
+        Some(XtxStatusResponse {
+            xtx_id,
+            requester: xtx.requester,
+            status: xtx.status,
+            steps_cnt: xtx.steps_cnt,
+            timeouts_at: xtx.timeouts_at,
+            delay_steps_at: xtx.delay_steps_at,
+            steps,
+        })
+    }
+
+    /// Returns SFX of the current steps of Xtx pending for bids, as served by the runtime API.
+    ///     Xtx open for bids are looked up in XtxBiddingTimeoutsAt, ordered by their id.
+    ///     Serves up to `limit` Xtx (capped at MAX_OPEN_XTX_PER_PAGE) following `start_after` -
+    ///     the next page starts after the xtx_id of the last SFX returned.
+    pub fn fetch_open_side_effects(
+        start_after: Option<XExecSignalId<T>>,
+        limit: u32,
+    ) -> Vec<
+        SideEffectStatusResponse<
+            T::AccountId,
+            T::BlockNumber,
+            EscrowedBalanceOf<T, T::Escrowed>,
+            T::Hash,
+        >,
+    > {
+        let open_xtx_ids = match start_after {
+            Some(xtx_id) => <XtxBiddingTimeoutsAt<T>>::iter_keys_from(
+                <XtxBiddingTimeoutsAt<T>>::hashed_key_for(xtx_id),
+            ),
+            None => <XtxBiddingTimeoutsAt<T>>::iter_keys(),
+        };
+
+        open_xtx_ids
+            .take(limit.min(MAX_OPEN_XTX_PER_PAGE) as usize)
+            .filter_map(|xtx_id| {
+                <XExecSignals<T>>::get(xtx_id)
+                    .filter(|xtx| xtx.status == CircuitStatus::PendingBidding)
+                    .map(|xtx| (xtx_id, xtx))
+            })
+            .flat_map(|(xtx_id, xtx)| {
+                <FullSideEffects<T>>::get(xtx_id)
+                    .and_then(|steps| steps.get(xtx.steps_cnt.0 as usize).cloned())
+                    .unwrap_or_default()
+                    .iter()
+                    .filter(|fsx| fsx.confirmed.is_none())
+                    .map(|fsx| Self::get_side_effect_status(xtx_id, fsx))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    fn get_side_effect_status(
+        xtx_id: XExecSignalId<T>,
+        fsx: &FullSideEffect<T::AccountId, T::BlockNumber, EscrowedBalanceOf<T, T::Escrowed>>,
+    ) -> SideEffectStatusResponse<
+        T::AccountId,
+        T::BlockNumber,
+        EscrowedBalanceOf<T, T::Escrowed>,
+        T::Hash,
+    > {
+        let sfx_id = fsx.generate_id::<SystemHashing<T>, T>(xtx_id);
+        SideEffectStatusResponse {
+            xtx_id,
+            sfx_id,
+            index: fsx.index,
+            target: fsx.input.target,
+            encoded_action: fsx.input.encoded_action.clone(),
+            encoded_args: fsx.input.encoded_args.clone(),
+            max_reward: fsx.input.max_reward,
+            insurance: fsx.input.insurance,
+            reward_asset_id: fsx.input.reward_asset_id,
+            enforce_executor: fsx.input.enforce_executor.clone(),
+            security_lvl: fsx.security_lvl.clone(),
+            best_bid: fsx
+                .best_bid
+                .clone()
+                .or_else(|| <PendingSFXBids<T>>::get(xtx_id, sfx_id)),
+            execution_deadline: <SFXExecutionDeadlines<T>>::get(sfx_id),
+            confirmation_outcome: fsx.confirmed.as_ref().map(|confirmed| {
+                confirmed
+                    .err
+                    .clone()
+                    .unwrap_or(ConfirmationOutcome::Success)
+            }),
+            confirmed_by: fsx
+                .confirmed
+                .as_ref()
+                .map(|confirmed| confirmed.executioner.clone()),
+            confirmed_at: fsx
+                .confirmed
+                .as_ref()
+                .map(|confirmed| confirmed.received_at),
+        }
+    }
+
     pub(self) fn get_current_step_fsx(
         local_ctx: &LocalXtxCtx<T>,
     ) -> &Vec<
@@ -0,0 +1,101 @@
+//! Storage migrations of the Circuit pallet - Xtx timeouts moved to the block-indexed queue.
+
+use crate::{
+    state::XtxTimeoutKind, Config, Pallet, XExecSignalId, XtxBiddingTimeoutsAt, XtxTimeoutsQueue,
+    XtxTimeoutsVisitedUntil,
+};
+use frame_support::{
+    traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+    weights::Weight,
+    Identity,
+};
+use sp_runtime::traits::{One, Saturating};
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+pub mod v1 {
+    use super::*;
+
+    #[frame_support::storage_alias]
+    pub type PendingXtxTimeoutsMap<T: Config> =
+        StorageMap<Pallet<T>, Identity, XExecSignalId<T>, <T as frame_system::Config>::BlockNumber>;
+
+    #[frame_support::storage_alias]
+    pub type PendingXtxBidsTimeoutsMap<T: Config> =
+        StorageMap<Pallet<T>, Identity, XExecSignalId<T>, <T as frame_system::Config>::BlockNumber>;
+
+    /// Moves the Xtx timeouts of PendingXtxTimeoutsMap and PendingXtxBidsTimeoutsMap to XtxTimeoutsQueue
+    ///     and makes on_initialize start visiting it from the current block, instead of the genesis one.
+    ///     Timeouts already overdue are queued at the current block.
+    pub struct MigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() != 0 {
+                return T::DbWeight::get().reads(1)
+            }
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut moved: u64 = 0;
+
+            let mut queue = |timeouts_at: T::BlockNumber, xtx_id, kind: XtxTimeoutKind| {
+                let timeouts_at = timeouts_at.max(now);
+                if kind == XtxTimeoutKind::Bidding {
+                    <XtxBiddingTimeoutsAt<T>>::insert(xtx_id, timeouts_at);
+                }
+                <XtxTimeoutsQueue<T>>::insert(timeouts_at, (xtx_id, kind), ());
+                moved = moved.saturating_add(1);
+            };
+            for (xtx_id, timeouts_at) in PendingXtxTimeoutsMap::<T>::drain() {
+                queue(timeouts_at, xtx_id, XtxTimeoutKind::Execution);
+            }
+            for (xtx_id, timeouts_at) in PendingXtxBidsTimeoutsMap::<T>::drain() {
+                queue(timeouts_at, xtx_id, XtxTimeoutKind::Bidding);
+            }
+
+            // on_initialize of the current block visits it next
+            <XtxTimeoutsVisitedUntil<T>>::put(now.saturating_sub(One::one()));
+            StorageVersion::new(1).put::<Pallet<T>>();
+
+            log::info!(target: "runtime::circuit", "Moved {} Xtx timeouts to XtxTimeoutsQueue", moved);
+
+            T::DbWeight::get().reads_writes(
+                moved.saturating_add(1),
+                moved.saturating_mul(3).saturating_add(2),
+            )
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<(), &'static str> {
+            if Pallet::<T>::on_chain_storage_version() == 0 {
+                let timeouts = PendingXtxTimeoutsMap::<T>::iter().count() as u32;
+                let bids_timeouts = PendingXtxBidsTimeoutsMap::<T>::iter().count() as u32;
+                Self::set_temp_storage(timeouts.saturating_add(bids_timeouts), "timeouts");
+            }
+            Ok(())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            if let Some(timeouts) = Self::get_temp_storage::<u32>("timeouts") {
+                frame_support::ensure!(
+                    PendingXtxTimeoutsMap::<T>::iter().next().is_none()
+                        && PendingXtxBidsTimeoutsMap::<T>::iter().next().is_none(),
+                    "Xtx timeouts left behind in the retired maps"
+                );
+                frame_support::ensure!(
+                    <XtxTimeoutsQueue<T>>::iter().count() as u32 >= timeouts,
+                    "Xtx timeouts missing in XtxTimeoutsQueue"
+                );
+                frame_support::ensure!(
+                    <XtxTimeoutsVisitedUntil<T>>::get()
+                        >= frame_system::Pallet::<T>::block_number().saturating_sub(One::one()),
+                    "XtxTimeoutsVisitedUntil not seeded with the current block"
+                );
+            }
+            Ok(())
+        }
+    }
+}
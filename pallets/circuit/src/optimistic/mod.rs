@@ -3,7 +3,11 @@ use frame_support::traits::fungible::Inspect;
 use sp_runtime::traits::Zero;
 
 use sp_std::marker::PhantomData;
-use t3rn_primitives::{side_effect::SFXBid, transfers::EscrowedBalanceOf};
+use t3rn_primitives::{
+    executors::{Executors, ReputationOutcome},
+    side_effect::SFXBid,
+    transfers::EscrowedBalanceOf,
+};
 
 pub struct Optimistic<T: Config> {
     _phantom: PhantomData<T>,
@@ -23,17 +27,24 @@ impl<T: Config> Optimistic<T> {
         }
         let fsx = crate::Pallet::<T>::recover_fsx_by_id(sfx_id, local_ctx)?;
         let (sfx_max_reward, sfx_security_lvl) = (fsx.input.max_reward, fsx.security_lvl.clone());
-        // Check if bid doesn't go below dust
-        if bid < <T::Escrowed as EscrowTrait<T>>::Currency::minimum_balance() {
+        // Check if bid doesn't go below dust - native dust only applies to bids in native currency
+        let bid_dust = if fsx.input.reward_asset_id.is_none() {
+            <T::Escrowed as EscrowTrait<T>>::Currency::minimum_balance()
+        } else {
+            Zero::zero()
+        };
+        if bid < bid_dust {
             return Err(Error::<T>::BiddingRejectedBidBelowDust)
         }
         // Check if bid is attractive enough for requester
         if bid > sfx_max_reward {
             return Err(Error::<T>::BiddingRejectedBidTooHigh)
         }
-        // Check if bid beats the previous ones
+        // Check if bid beats the previous ones - bid prices are weighted by executors' reputation
         if let Some(current_best_bid) = &current_accepted_bid {
-            if current_best_bid.bid <= bid {
+            let current_best_weighted_bid = T::Executors::reputation(&current_best_bid.executor)
+                .weigh_bid(current_best_bid.bid);
+            if current_best_weighted_bid <= T::Executors::reputation(executor).weigh_bid(bid) {
                 return Err(Error::<T>::BiddingRejectedBetterBidFound)
             }
         }
@@ -112,6 +123,9 @@ impl<T: Config> Optimistic<T> {
                 >,
             >>(),
         );
+        // Executors with a strong track record are required to reserve less
+        let total_xtx_step_optimistic_rewards_of_others = T::Executors::reputation(executor)
+            .scale_bond(total_xtx_step_optimistic_rewards_of_others);
 
         if total_xtx_step_optimistic_rewards_of_others > Zero::zero() {
             <T as Config>::AccountManager::withdraw_immediately(
@@ -126,6 +140,8 @@ impl<T: Config> Optimistic<T> {
         Ok(sfx_bid.clone())
     }
 
+    /// Releases insurance, reserved bond and bid of executors of optimistic SFX of the current step,
+    ///     once the Xtx reverted for reasons beyond their control.
     pub fn try_unbond(local_ctx: &mut LocalXtxCtx<T>) -> Result<(), Error<T>> {
         let optimistic_fsx_in_step = crate::Pallet::<T>::get_current_step_fsx_by_security_lvl(
             local_ctx,
@@ -134,17 +150,24 @@ impl<T: Config> Optimistic<T> {
         for fsx in optimistic_fsx_in_step {
             if fsx.is_bid_resolved() {
                 let sfx_bid = fsx.expect_sfx_bid();
-                let (insurance, reserved_bond) =
-                    (*sfx_bid.get_insurance(), *sfx_bid.expect_reserved_bond());
+                let insurance = *sfx_bid.get_insurance();
+                let reserved_bond = if let Some(bond) = sfx_bid.get_reserved_bond() {
+                    *bond
+                } else {
+                    Zero::zero()
+                };
 
-                let checked_insurance = if let Some(v) = insurance.checked_add(&reserved_bond) {
+                let checked_refund = if let Some(v) = insurance
+                    .checked_add(&reserved_bond)
+                    .and_then(|insurance_plus_bond| insurance_plus_bond.checked_add(&sfx_bid.bid))
+                {
                     v
                 } else {
                     return Err(Error::<T>::ArithmeticErrorOverflow)
                 };
                 <T as Config>::AccountManager::deposit_immediately(
                     &sfx_bid.executor,
-                    checked_insurance,
+                    checked_refund,
                     sfx_bid.reward_asset_id,
                 )
             }
@@ -153,7 +176,73 @@ impl<T: Config> Optimistic<T> {
         Ok(())
     }
 
-    pub fn try_slash(local_ctx: &mut LocalXtxCtx<T>) -> Result<(), Error<T>> {
+    /// Slashes insurance and reserved bond of executors whose confirmations of optimistic SFX
+    ///     were proven misbehaving, rewarding the challenger with the reserved bond. Releases the ones
+    ///     of the other executors of steps up to the current one. Returns the challenger's reward.
+    pub fn try_slash_misbehaved(
+        local_ctx: &mut LocalXtxCtx<T>,
+        challenger: &T::AccountId,
+    ) -> Result<EscrowedBalanceOf<T, T::Escrowed>, Error<T>> {
+        // Challenged confirmations may come from the steps finished before the current one
+        let optimistic_fsx_until_current_step: Vec<_> = local_ctx
+            .full_side_effects
+            .iter()
+            .take((local_ctx.xtx.steps_cnt.0 as usize).saturating_add(1))
+            .flatten()
+            .filter(|fsx| fsx.security_lvl == SecurityLvl::Optimistic)
+            .cloned()
+            .collect();
+        let mut challenger_reward: EscrowedBalanceOf<T, T::Escrowed> = Zero::zero();
+        for fsx in optimistic_fsx_until_current_step {
+            if fsx.is_bid_resolved() {
+                let sfx_bid = fsx.expect_sfx_bid();
+                let insurance = *sfx_bid.get_insurance();
+                let reserved_bond = if let Some(bond) = sfx_bid.get_reserved_bond() {
+                    *bond
+                } else {
+                    Zero::zero()
+                };
+
+                if fsx
+                    .confirmed
+                    .as_ref()
+                    .map_or(false, |confirmed| confirmed.err.is_some())
+                {
+                    <T as Config>::AccountManager::deposit_immediately(
+                        &T::SelfAccountId::get(),
+                        insurance,
+                        sfx_bid.reward_asset_id,
+                    );
+                    if reserved_bond > Zero::zero() {
+                        <T as Config>::AccountManager::deposit_immediately(
+                            challenger,
+                            reserved_bond,
+                            sfx_bid.reward_asset_id,
+                        );
+                    }
+                    challenger_reward = challenger_reward.saturating_add(reserved_bond);
+                } else {
+                    let insurance_plus_bond = if let Some(v) = insurance.checked_add(&reserved_bond)
+                    {
+                        v
+                    } else {
+                        return Err(Error::<T>::ArithmeticErrorOverflow)
+                    };
+                    <T as Config>::AccountManager::deposit_immediately(
+                        &sfx_bid.executor,
+                        insurance_plus_bond,
+                        sfx_bid.reward_asset_id,
+                    )
+                }
+            }
+        }
+
+        Ok(challenger_reward)
+    }
+
+    /// Slashes insurance and reserved bond of executors which won the bids for optimistic SFX
+    ///     of the current step but didn't get them successfully confirmed.
+    pub fn try_slash_unconfirmed(local_ctx: &mut LocalXtxCtx<T>) -> Result<(), Error<T>> {
         let optimistic_fsx_in_step = &crate::Pallet::<T>::get_current_step_fsx_by_security_lvl(
             local_ctx,
             SecurityLvl::Optimistic,
@@ -183,9 +272,20 @@ impl<T: Config> Optimistic<T> {
                     &T::SelfAccountId::get(),
                     slashed_reserve,
                     sfx_bid.reward_asset_id,
-                )
+                );
+                T::Executors::update_reputation(&sfx_bid.executor, ReputationOutcome::Slashed);
             }
         }
+        Ok(())
+    }
+
+    pub fn try_slash(local_ctx: &mut LocalXtxCtx<T>) -> Result<(), Error<T>> {
+        Self::try_slash_unconfirmed(local_ctx)?;
+
+        let optimistic_fsx_in_step = &crate::Pallet::<T>::get_current_step_fsx_by_security_lvl(
+            local_ctx,
+            SecurityLvl::Optimistic,
+        );
 
         // Single reserved_bond consists out of Sum(N) sfxN.max_rewards, where N isn't executors' SFX index.
         // Repatriation therefore should always suffice to cover up the losses on targets by getting
@@ -195,8 +295,12 @@ impl<T: Config> Optimistic<T> {
             // Look for valid FSX cases to repatriate
             if fsx.is_successfully_confirmed() && fsx.is_bid_resolved() {
                 let sfx_bid = fsx.expect_sfx_bid();
-                let (insurance, reserved_bond) =
-                    (*sfx_bid.get_insurance(), *sfx_bid.expect_reserved_bond());
+                let insurance = *sfx_bid.get_insurance();
+                let reserved_bond = if let Some(bond) = sfx_bid.get_reserved_bond() {
+                    *bond
+                } else {
+                    Zero::zero()
+                };
 
                 // First unlock honest executor  and the reward to honest executors
                 // since the reserved bond was slashed and should always suffice.
@@ -229,8 +333,12 @@ impl<T: Config> Optimistic<T> {
             for fsx in phase {
                 if fsx.is_bid_resolved() {
                     let sfx_bid = fsx.expect_sfx_bid();
-                    let (insurance, reserved_bond) =
-                        (*sfx_bid.get_insurance(), *sfx_bid.expect_reserved_bond());
+                    let insurance = *sfx_bid.get_insurance();
+                    let reserved_bond = if let Some(bond) = sfx_bid.get_reserved_bond() {
+                        *bond
+                    } else {
+                        Zero::zero()
+                    };
 
                     <T as Config>::AccountManager::deposit_immediately(
                         &sfx_bid.executor,
@@ -9,6 +9,7 @@ use sp_runtime::{traits::Zero, RuntimeDebug};
 use sp_std::{default::Default, fmt::Debug};
 use t3rn_primitives::{
     circuit::{XExecSignalId, XExecStepSideEffectId},
+    side_effect::{ConfirmationOutcome, SFXBid, SecurityLvl, TargetId},
     transfers::EscrowedBalanceOf,
 };
 
@@ -19,6 +20,7 @@ type SystemHashing<T> = <T as frame_system::Config>::Hashing;
 
 /// Status of Circuit storage items:
 /// Requested - default
+/// Requested -> Scheduled - step is held until its delay_steps_at block, then opens for bidding
 /// Requested -> Validated - successfully passed the validation
 /// Option<Validated -> PendingInsurance>: If there are some side effects that request insurance,
 ///         the status will stay in PendingInsurance until all insurance deposits are committed
@@ -28,7 +30,11 @@ type SystemHashing<T> = <T as frame_system::Config>::Hashing;
 /// Circuit::Apply -> called internally - based on the side effects confirmations decides:
 ///     Ready -> Committed: All of the side effects have been successfully confirmed
 ///     Ready -> Reverted: Some of the side effects failed and the Xtx was reverted
-#[derive(Clone, Eq, PartialEq, PartialOrd, Encode, Decode, RuntimeDebug, TypeInfo)]
+/// Ready/PendingExecution -> RevertGatewayInactive - a target gateway of unconfirmed side effects
+///     became inactive, executors' bonds are released instead of slashed
+/// Variants are appended to keep the encoding of stored Xtx, statuses are ordered by `progress`.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub enum CircuitStatus {
     Requested,
     PendingBidding,
@@ -42,6 +48,51 @@ pub enum CircuitStatus {
     RevertTimedOut,
     RevertKill,
     RevertMisbehaviour,
+    RevertGatewayInactive,
+    Scheduled,
+}
+
+impl PartialOrd for CircuitStatus {
+    fn partial_cmp(&self, other: &Self) -> Option<sp_std::cmp::Ordering> {
+        self.progress().partial_cmp(&other.progress())
+    }
+}
+
+/// Kinds of Xtx timeouts kept in the block-indexed XtxTimeoutsQueue:
+/// Bidding - bidding period is over, best bids are accepted or Xtx is DroppedAtBidding.
+///     For Xtx with sealed bids the commit phase turns into the reveal phase first.
+/// Execution - Xtx didn't finish in time and is RevertTimedOut
+/// ScheduledStep - delay_steps_at block of the current step is reached, the step opens for bidding
+/// EscrowFinalization - executors didn't prove the commit or revert of remote escrow and are slashed
+/// SFXExecution - winning executors didn't confirm optimistic SFX in time, SFX reopen for bidding
+/// XdnsPinsRelease - confirmations of the finished Xtx can't be challenged nor its escrow finalized anymore,
+///     the XDNS record and side effect interface versions it pinned are released
+#[derive(Clone, Eq, PartialEq, PartialOrd, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum XtxTimeoutKind {
+    Bidding,
+    Execution,
+    ScheduledStep,
+    EscrowFinalization,
+    SFXExecution,
+    XdnsPinsRelease,
+}
+
+/// Phases of bidding for Xtx accepting sealed bids only:
+/// Commit - executors commit hashes of their bids and salts, reserving the deposit
+/// Reveal - executors reveal bids matching their commits, the lowest one wins
+#[derive(Clone, Eq, PartialEq, PartialOrd, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum SealedBiddingPhase {
+    Commit,
+    Reveal,
+}
+
+/// Bid committed as hash of the encoded (bid, salt). Deposit of max_reward + insurance
+///     is returned once revealed, or forfeited otherwise.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct SealedSFXBid<Hash, Balance> {
+    pub bid_hash: Hash,
+    pub deposit: Balance,
+    pub is_revealed: bool,
 }
 
 #[derive(Clone, Eq, PartialEq, PartialOrd, Encode, Decode, RuntimeDebug, TypeInfo)]
@@ -61,6 +112,26 @@ pub enum InsuranceEnact {
 }
 
 impl CircuitStatus {
+    /// Position of the status in the Xtx lifecycle - Scheduled steps are yet to open for bidding
+    fn progress(&self) -> u8 {
+        match self {
+            CircuitStatus::Requested => 0,
+            CircuitStatus::Scheduled => 1,
+            CircuitStatus::PendingBidding => 2,
+            CircuitStatus::Ready => 3,
+            CircuitStatus::PendingExecution => 4,
+            CircuitStatus::Finished => 5,
+            CircuitStatus::FinishedAllSteps => 6,
+            CircuitStatus::Committed => 7,
+            CircuitStatus::DroppedAtBidding => 8,
+            CircuitStatus::Reverted => 9,
+            CircuitStatus::RevertTimedOut => 10,
+            CircuitStatus::RevertKill => 11,
+            CircuitStatus::RevertMisbehaviour => 12,
+            CircuitStatus::RevertGatewayInactive => 13,
+        }
+    }
+
     fn determine_fsx_bidding_status<T: Config>(
         fsx: FullSideEffect<T::AccountId, T::BlockNumber, EscrowedBalanceOf<T, T::Escrowed>>,
     ) -> Result<CircuitStatus, Error<T>> {
@@ -271,3 +342,41 @@ impl<
         (id, signal)
     }
 }
+
+/// The Xtx with its steps and timeouts as returned by the RPC endpoint
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct XtxStatusResponse<AccountId, BlockNumber, Balance, Hash> {
+    pub xtx_id: Hash,
+    pub requester: AccountId,
+    pub status: CircuitStatus,
+    pub steps_cnt: (u32, u32),
+    /// Block at which the Xtx is reverted unless finished
+    pub timeouts_at: BlockNumber,
+    pub delay_steps_at: Option<Vec<BlockNumber>>,
+    pub steps: Vec<Vec<SideEffectStatusResponse<AccountId, BlockNumber, Balance, Hash>>>,
+}
+
+/// The SFX with its best bid and confirmation state as returned by the RPC endpoint
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct SideEffectStatusResponse<AccountId, BlockNumber, Balance, Hash> {
+    pub xtx_id: Hash,
+    pub sfx_id: Hash,
+    pub index: u32,
+    pub target: TargetId,
+    pub encoded_action: Vec<u8>,
+    pub encoded_args: Vec<Vec<u8>>,
+    pub max_reward: Balance,
+    pub insurance: Balance,
+    pub reward_asset_id: Option<u32>,
+    pub enforce_executor: Option<AccountId>,
+    pub security_lvl: SecurityLvl,
+    /// Accepted bid once the bidding is over, otherwise the best of pending bids
+    pub best_bid: Option<SFXBid<AccountId, Balance, u32>>,
+    /// Block until which the winning executor has to confirm the execution
+    pub execution_deadline: Option<BlockNumber>,
+    pub confirmation_outcome: Option<ConfirmationOutcome>,
+    pub confirmed_by: Option<AccountId>,
+    pub confirmed_at: Option<BlockNumber>,
+}
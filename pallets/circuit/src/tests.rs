@@ -26,7 +26,9 @@ use t3rn_sdk_primitives::{
 
 use codec::{Decode, Encode};
 use frame_support::{
-    assert_err, assert_noop, assert_ok, dispatch::PostDispatchInfo, traits::Currency,
+    assert_err, assert_noop, assert_ok,
+    dispatch::PostDispatchInfo,
+    traits::{Currency, Get},
 };
 
 use frame_system::{pallet_prelude::OriginFor, EventRecord, Phase};
@@ -40,9 +42,11 @@ use std::{convert::TryInto, fs};
 use t3rn_primitives::{
     abi::*,
     circuit::{LocalStateExecutionView, LocalTrigger, OnLocalTrigger},
+    executors::{ExecutorReputation, ReputationOutcome},
+    match_format::DFD_ARG_MARKER,
     side_effect::*,
     volatile::LocalState,
-    xdns::AllowedSideEffect,
+    xdns::{AllowedSideEffect, GatewayStatus, Xdns},
     xtx::XtxId,
     Balance, ChainId, GatewayGenesisConfig, GatewaySysProps, GatewayType, GatewayVendor,
 };
@@ -55,7 +59,9 @@ use pallet_xbi_portal::{
 };
 use pallet_xbi_portal_enter::t3rn_sfx::xbi_2_sfx;
 
-use circuit_runtime_pallets::pallet_circuit::Error as circuit_error;
+use circuit_runtime_pallets::pallet_circuit::{
+    Error as circuit_error, MultiLocation, XtxTimeoutsQueue,
+};
 
 pub const ALICE: AccountId32 = AccountId32::new([1u8; 32]);
 pub const BOB_RELAYER: AccountId32 = AccountId32::new([2u8; 32]);
@@ -272,8 +278,11 @@ pub fn place_winning_bid_and_advance_3_blocks(
     );
 
     assert_eq!(
-        Circuit::get_pending_xtx_bids_timeouts(xtx_id).unwrap(),
-        System::block_number() + 3
+        Circuit::get_xtx_timeouts_queue(
+            System::block_number() + 3,
+            (xtx_id, XtxTimeoutKind::Bidding)
+        ),
+        Some(())
     );
 
     let three_blocks_ahead = System::block_number() + 3;
@@ -389,6 +398,207 @@ fn on_extrinsic_trigger_works_with_empty_side_effects() {
     });
 }
 
+#[test]
+fn on_xcm_trigger_rejects_location_without_trigger_auth_rights() {
+    let origin = Origin::signed(ALICE);
+
+    let mut ext = TestExternalities::new_empty();
+
+    ext.execute_with(|| {
+        assert_noop!(
+            Circuit::on_xcm_trigger(origin, vec![], true),
+            circuit_error::<Runtime>::XcmTriggerUnauthorized
+        );
+    });
+}
+
+#[test]
+fn on_xcm_trigger_works_with_empty_side_effects_after_trigger_auth_rights_granted() {
+    let origin = Origin::signed(ALICE);
+    let alice_location: MultiLocation = xcm::latest::Junction::AccountId32 {
+        network: xcm::latest::NetworkId::Any,
+        id: ALICE.into(),
+    }
+    .into();
+
+    let mut ext = TestExternalities::new_empty();
+
+    ext.execute_with(|| {
+        let _ = Balances::deposit_creating(&ALICE, 1 + 2);
+
+        assert_noop!(
+            Circuit::set_trigger_auth_rights(origin.clone(), alice_location.clone(), true),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(Circuit::set_trigger_auth_rights(
+            Origin::root(),
+            alice_location.clone(),
+            true
+        ));
+        assert!(Circuit::get_trigger_auth_rights(&alice_location));
+
+        assert_ok!(Circuit::on_xcm_trigger(origin.clone(), vec![], true));
+
+        assert_ok!(Circuit::set_trigger_auth_rights(
+            Origin::root(),
+            alice_location.clone(),
+            false
+        ));
+        assert!(!Circuit::get_trigger_auth_rights(&alice_location));
+
+        assert_noop!(
+            Circuit::on_xcm_trigger(origin, vec![], true),
+            circuit_error::<Runtime>::XcmTriggerUnauthorized
+        );
+    });
+}
+
+#[test]
+fn on_remote_gateway_trigger_rejects_order_without_valid_inclusion_proof() {
+    let origin = Origin::signed(ALICE);
+
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 1 + 2);
+
+            // Unknown gateway
+            assert_noop!(
+                Circuit::on_remote_gateway_trigger(origin.clone(), *b"xxxx", 1u32.encode(), vec![]),
+                circuit_error::<Runtime>::RemoteTriggerInclusionProofFailed
+            );
+
+            // Registered gateway, malformed inclusion data
+            assert_noop!(
+                Circuit::on_remote_gateway_trigger(origin, [0u8; 4], 1u32.encode(), vec![0, 1, 2]),
+                circuit_error::<Runtime>::RemoteTriggerInclusionProofFailed
+            );
+        });
+}
+
+/// Encodes `Ordered` event of the Circuit pallet at `pallet_index` on the remote gateway
+fn encode_remote_order_event(
+    pallet_index: u8,
+    requester: AccountId32,
+    side_effects: Vec<SideEffect<AccountId32, Balance>>,
+    nonce: u32,
+) -> Vec<u8> {
+    let mut encoded_event = vec![pallet_index, 0];
+    encoded_event.append(&mut (requester, side_effects.encode(), true, nonce).encode());
+    encoded_event
+}
+
+#[test]
+fn on_remote_gateway_trigger_opens_xtx_for_remote_requester_and_rejects_replays() {
+    const CIRCUIT_PALLET_INDEX: u8 = 100;
+
+    let transfer_protocol_box =
+        Box::new(t3rn_protocol::side_effects::standards::get_transfer_interface());
+    let mut local_state = LocalState::new();
+    let valid_transfer_side_effect = produce_and_validate_side_effect(
+        vec![
+            (Type::Address(32), ArgVariant::A),
+            (Type::Address(32), ArgVariant::B),
+            (Type::Uint(128), ArgVariant::A),
+            (Type::OptionalInsurance, ArgVariant::A), // empty bytes instead of insurance
+        ],
+        &mut local_state,
+        transfer_protocol_box,
+        ALICE,
+        FIRST_REQUESTER_NONCE,
+        FIRST_SFX_INDEX,
+    );
+
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 1 + 2);
+            System::set_block_number(1);
+
+            assert_ok!(XDNS::update_security_coordinates(
+                Origin::root(),
+                [0, 0, 0, 0],
+                vec![CIRCUIT_PALLET_INDEX],
+            ));
+
+            let remote_order = encode_remote_order_event(
+                CIRCUIT_PALLET_INDEX,
+                ALICE,
+                vec![valid_transfer_side_effect.clone()],
+                0,
+            );
+            let mut encoded_events = vec![8, 1, 2, 3];
+            encoded_events.extend_from_slice(&remote_order);
+            let inclusion_data =
+                pallet_grandpa_finality_verifier::mock::brute_seed_block_1_with_events::<
+                    Runtime,
+                    (),
+                >([0, 0, 0, 0], encoded_events, remote_order);
+
+            // Relayed by Bob on behalf of Alice, who emitted the order on the gateway
+            assert_ok!(Circuit::on_remote_gateway_trigger(
+                Origin::signed(BOB_RELAYER),
+                [0, 0, 0, 0],
+                1u32.encode(),
+                inclusion_data.encode(),
+            ));
+
+            let xtx_id: sp_core::H256 = generate_xtx_id::<Hashing>(ALICE, FIRST_REQUESTER_NONCE);
+            let xtx = Circuit::get_x_exec_signals(xtx_id).unwrap();
+            assert_eq!(xtx.requester, ALICE);
+            assert_eq!(xtx.status, CircuitStatus::PendingBidding);
+
+            assert_noop!(
+                Circuit::on_remote_gateway_trigger(
+                    Origin::signed(BOB_RELAYER),
+                    [0, 0, 0, 0],
+                    1u32.encode(),
+                    inclusion_data.encode(),
+                ),
+                circuit_error::<Runtime>::RemoteTriggerAlreadyConsumed
+            );
+        });
+}
+
+#[test]
+fn on_remote_gateway_trigger_rejects_order_emitted_by_unexpected_pallet() {
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            System::set_block_number(1);
+
+            assert_ok!(XDNS::update_security_coordinates(
+                Origin::root(),
+                [0, 0, 0, 0],
+                vec![100],
+            ));
+
+            let remote_order = encode_remote_order_event(101, ALICE, vec![], 0);
+            let inclusion_data =
+                pallet_grandpa_finality_verifier::mock::brute_seed_block_1_with_events::<
+                    Runtime,
+                    (),
+                >([0, 0, 0, 0], remote_order.clone(), remote_order);
+
+            assert_noop!(
+                Circuit::on_remote_gateway_trigger(
+                    Origin::signed(BOB_RELAYER),
+                    [0, 0, 0, 0],
+                    1u32.encode(),
+                    inclusion_data.encode(),
+                ),
+                circuit_error::<Runtime>::RemoteEventEmittedByUnexpectedPallet
+            );
+        });
+}
+
 #[test]
 fn on_extrinsic_trigger_works_raw_insured_side_effect() {
     let origin = Origin::signed(ALICE); // Only sudo access to register new gateways for now
@@ -867,6 +1077,181 @@ fn circuit_handles_single_bid_for_transfer_sfx() {
         });
 }
 
+#[test]
+fn circuit_serves_xtx_status_with_pending_bids_and_open_side_effects() {
+    let origin = Origin::signed(ALICE); // Only sudo access to register new gateways for now
+
+    let transfer_protocol_box =
+        Box::new(t3rn_protocol::side_effects::standards::get_transfer_interface());
+
+    let mut local_state = LocalState::new();
+
+    let valid_transfer_side_effect = produce_and_validate_side_effect(
+        vec![
+            (Type::Address(32), ArgVariant::A),
+            (Type::Address(32), ArgVariant::B),
+            (Type::Uint(128), ArgVariant::A),
+            (Type::OptionalInsurance, ArgVariant::A), // insurance = 1, reward = 1
+        ],
+        &mut local_state,
+        transfer_protocol_box,
+        ALICE,
+        FIRST_REQUESTER_NONCE,
+        FIRST_SFX_INDEX,
+    );
+
+    let side_effects = vec![valid_transfer_side_effect.clone()];
+
+    const BID_AMOUNT: Balance = 1;
+
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+            let _ = Balances::deposit_creating(&BOB_RELAYER, 1_000_000);
+
+            System::set_block_number(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            assert_ok!(Circuit::on_extrinsic_trigger(origin, side_effects, false,));
+
+            let (xtx_id, sfx_id) = set_ids(
+                valid_transfer_side_effect.clone(),
+                ALICE,
+                FIRST_REQUESTER_NONCE,
+                FIRST_SFX_INDEX,
+            );
+
+            let open_side_effects = Circuit::fetch_open_side_effects(None, 10);
+            assert_eq!(open_side_effects.len(), 1);
+            assert_eq!(open_side_effects[0].xtx_id, xtx_id);
+            assert_eq!(open_side_effects[0].sfx_id, sfx_id);
+            assert_eq!(open_side_effects[0].best_bid, None);
+            // Next page starts after the last Xtx served
+            assert_eq!(Circuit::fetch_open_side_effects(Some(xtx_id), 10), vec![]);
+            assert_eq!(Circuit::fetch_open_side_effects(None, 0), vec![]);
+
+            assert_ok!(Circuit::bid_sfx(
+                Origin::signed(BOB_RELAYER),
+                sfx_id,
+                BID_AMOUNT,
+            ));
+
+            let xtx_status = Circuit::get_xtx_status(xtx_id).unwrap();
+            assert_eq!(xtx_status.status, CircuitStatus::PendingBidding);
+            assert_eq!(xtx_status.timeouts_at, 401u32);
+            assert_eq!(xtx_status.steps_cnt, (0, 1));
+            assert_eq!(
+                xtx_status.steps[0][0].best_bid.as_ref().unwrap().executor,
+                BOB_RELAYER
+            );
+            assert_eq!(xtx_status.steps[0][0].confirmation_outcome, None);
+
+            assert_eq!(Circuit::get_xtx_status(sfx_id), None);
+        });
+}
+
+#[test]
+fn circuit_handles_single_bid_for_transfer_sfx_rewarded_in_foreign_asset() {
+    let origin = Origin::signed(ALICE); // Only sudo access to register new gateways for now
+
+    let transfer_protocol_box =
+        Box::new(t3rn_protocol::side_effects::standards::get_transfer_interface());
+
+    let mut local_state = LocalState::new();
+
+    const FOREIGN_ASSET_A: u32 = 1;
+    const ASSET_BALANCE: Balance = 1_000;
+    const REQUESTED_INSURANCE_AMOUNT: Balance = 1;
+    const BID_AMOUNT: Balance = 1;
+
+    let mut valid_transfer_side_effect = produce_and_validate_side_effect(
+        vec![
+            (Type::Address(32), ArgVariant::A),
+            (Type::Address(32), ArgVariant::B),
+            (Type::Uint(128), ArgVariant::A),
+            (Type::OptionalInsurance, ArgVariant::A), // insurance = 1, reward = 1
+        ],
+        &mut local_state,
+        transfer_protocol_box,
+        ALICE,
+        FIRST_REQUESTER_NONCE,
+        FIRST_SFX_INDEX,
+    );
+    valid_transfer_side_effect.reward_asset_id = Some(FOREIGN_ASSET_A);
+
+    let side_effects = vec![valid_transfer_side_effect.clone()];
+
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            assert_ok!(Assets::force_create(
+                Origin::root(),
+                FOREIGN_ASSET_A,
+                sp_runtime::MultiAddress::Id(CHARLIE), /* owner */
+                true,                                  /* is_sufficient */
+                1
+            ));
+            for account in [&ALICE, &BOB_RELAYER] {
+                assert_ok!(<Assets as frame_support::traits::fungibles::Mutate<
+                    AccountId32,
+                >>::mint_into(
+                    FOREIGN_ASSET_A, account, ASSET_BALANCE
+                ));
+            }
+            // Native balances are only there to keep accounts alive
+            let _ = Balances::deposit_creating(&ALICE, 1);
+            let _ = Balances::deposit_creating(&BOB_RELAYER, 1);
+
+            System::set_block_number(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            assert_ok!(Circuit::on_extrinsic_trigger(origin, side_effects, false));
+
+            // Max reward is reserved in the foreign asset
+            assert_eq!(
+                Assets::balance(FOREIGN_ASSET_A, &ALICE),
+                ASSET_BALANCE - valid_transfer_side_effect.max_reward
+            );
+            assert_eq!(Balances::free_balance(&ALICE), 1);
+
+            let (xtx_id, side_effect_a_id) = set_ids(
+                valid_transfer_side_effect.clone(),
+                ALICE,
+                FIRST_REQUESTER_NONCE,
+                FIRST_SFX_INDEX,
+            );
+
+            assert_ok!(Circuit::bid_sfx(
+                Origin::signed(BOB_RELAYER),
+                side_effect_a_id,
+                BID_AMOUNT,
+            ));
+
+            assert_eq!(
+                Circuit::get_pending_sfx_bids(xtx_id, side_effect_a_id).unwrap(),
+                SFXBid {
+                    bid: BID_AMOUNT,
+                    requester: ALICE,
+                    executor: BOB_RELAYER,
+                    reserved_bond: None,
+                    insurance: REQUESTED_INSURANCE_AMOUNT,
+                    reward_asset_id: Some(FOREIGN_ASSET_A),
+                }
+            );
+            // Bid and insurance are withdrawn in the foreign asset
+            assert_eq!(
+                Assets::balance(FOREIGN_ASSET_A, &BOB_RELAYER),
+                ASSET_BALANCE - BID_AMOUNT - REQUESTED_INSURANCE_AMOUNT
+            );
+            assert_eq!(Balances::free_balance(&BOB_RELAYER), 1);
+        });
+}
+
 #[test]
 fn circuit_handles_dropped_at_bidding() {
     let origin = Origin::signed(ALICE); // Only sudo access to register new gateways for now
@@ -976,9 +1361,20 @@ fn circuit_handles_dropped_at_bidding() {
         })
 }
 
+fn make_gateway_inactive(gateway_id: ChainId) {
+    assert_ok!(XDNS::update_ttl(Origin::root(), gateway_id, 0));
+    Timestamp::set_timestamp(
+        <Runtime as pallet_xdns::Config>::GatewayInactivityThreshold::get() + 1,
+    );
+    assert_eq!(
+        XDNS::get_gateway_status(&gateway_id),
+        Ok(GatewayStatus::Inactive)
+    );
+}
+
 #[test]
-fn circuit_selects_best_bid_out_of_3_for_transfer_sfx() {
-    let origin = Origin::signed(ALICE); // Only sudo access to register new gateways for now
+fn on_extrinsic_trigger_rejects_side_effects_targeting_inactive_gateway() {
+    let origin = Origin::signed(ALICE);
 
     let transfer_protocol_box =
         Box::new(t3rn_protocol::side_effects::standards::get_transfer_interface());
@@ -990,7 +1386,7 @@ fn circuit_selects_best_bid_out_of_3_for_transfer_sfx() {
             (Type::Address(32), ArgVariant::A),
             (Type::Address(32), ArgVariant::B),
             (Type::Uint(128), ArgVariant::A),
-            (Type::OptionalInsurance, ArgVariant::C), // insurance = 3, max_reward/reward = 3
+            (Type::OptionalInsurance, ArgVariant::A), // insurance = 1, reward = 1
         ],
         &mut local_state,
         transfer_protocol_box,
@@ -999,539 +1395,1637 @@ fn circuit_selects_best_bid_out_of_3_for_transfer_sfx() {
         FIRST_SFX_INDEX,
     );
 
-    let side_effects = vec![valid_transfer_side_effect.clone()];
-
-    const REQUESTED_INSURANCE_AMOUNT: Balance = 3;
-    const BID_AMOUNT_A: Balance = 1;
-    const BID_AMOUNT_B: Balance = 2;
-    const BID_AMOUNT_C: Balance = 3;
-    const MAX_REWARD: Balance = 3;
-
-    const INITIAL_BALANCE: Balance = 10;
-
-    const REQUESTER: AccountId32 = ALICE;
-    const BID_WINNER: AccountId32 = BOB_RELAYER;
-    const BID_LOOSER: AccountId32 = CHARLIE;
-
-    const BIDDING_BLOCK_NO: BlockNumber = 1;
-    const BIDDING_TIMEOUT: BlockNumber = 3;
     ExtBuilder::default()
         .with_standard_side_effects()
         .with_default_xdns_records()
         .build()
         .execute_with(|| {
-            let _ = Balances::deposit_creating(&REQUESTER, INITIAL_BALANCE);
-            let _ = Balances::deposit_creating(&BID_WINNER, INITIAL_BALANCE);
-            let _ = Balances::deposit_creating(&BID_LOOSER, INITIAL_BALANCE);
+            let _ = Balances::deposit_creating(&ALICE, 1 + 2);
 
-            System::set_block_number(BIDDING_BLOCK_NO);
+            System::set_block_number(1);
             brute_seed_block_1([0, 0, 0, 0]);
+            make_gateway_inactive([0, 0, 0, 0]);
 
-            assert_ok!(Circuit::on_extrinsic_trigger(origin, side_effects, true,));
-            // Requester should have offered SFX::max_reward reserved
-            assert_eq!(
-                Balances::free_balance(&REQUESTER),
-                INITIAL_BALANCE - MAX_REWARD
+            assert_noop!(
+                Circuit::on_extrinsic_trigger(origin, vec![valid_transfer_side_effect], true),
+                circuit_error::<Runtime>::SideEffectsValidationFailed
             );
+        });
+}
 
-            let (xtx_id, side_effect_a_id) = set_ids(
-                valid_transfer_side_effect.clone(),
+#[test]
+fn on_extrinsic_trigger_rejects_transfers_on_ethereum_gateway_without_token_contract() {
+    let origin = Origin::signed(ALICE);
+
+    let transfer_protocol_box =
+        Box::new(t3rn_protocol::side_effects::standards::get_transfer_interface());
+
+    let mut local_state = LocalState::new();
+
+    let valid_transfer_side_effect = produce_and_validate_side_effect(
+        vec![
+            (Type::Address(32), ArgVariant::A),
+            (Type::Address(32), ArgVariant::B),
+            (Type::Uint(128), ArgVariant::A),
+            (Type::OptionalInsurance, ArgVariant::A), // insurance = 1, reward = 1
+        ],
+        &mut local_state,
+        transfer_protocol_box,
+        ALICE,
+        FIRST_REQUESTER_NONCE,
+        FIRST_SFX_INDEX,
+    );
+
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 1 + 2);
+
+            System::set_block_number(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+            // Security coordinates name the t3rn gateway contract only - native ether transfers can't be proven
+            pallet_xdns::XDNSRegistry::<Runtime>::mutate([0, 0, 0, 0], |record| {
+                let record = record.as_mut().unwrap();
+                record.gateway_vendor = GatewayVendor::Ethereum;
+                record.security_coordinates = vec![3u8; 20];
+            });
+
+            assert_noop!(
+                Circuit::on_extrinsic_trigger(origin, vec![valid_transfer_side_effect], true),
+                circuit_error::<Runtime>::SideEffectsValidationFailed
+            );
+        });
+}
+
+#[test]
+fn circuit_reverts_pending_xtx_once_its_gateway_becomes_inactive() {
+    let origin = Origin::signed(ALICE);
+
+    let transfer_protocol_box =
+        Box::new(t3rn_protocol::side_effects::standards::get_transfer_interface());
+
+    let mut local_state = LocalState::new();
+
+    let valid_transfer_side_effect = produce_and_validate_side_effect(
+        vec![
+            (Type::Address(32), ArgVariant::A),
+            (Type::Address(32), ArgVariant::B),
+            (Type::Uint(128), ArgVariant::A),
+            (Type::OptionalInsurance, ArgVariant::A), // insurance = 1, reward = 1
+        ],
+        &mut local_state,
+        transfer_protocol_box,
+        ALICE,
+        FIRST_REQUESTER_NONCE,
+        FIRST_SFX_INDEX,
+    );
+
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 1 + 2);
+
+            System::set_block_number(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            assert_ok!(Circuit::on_extrinsic_trigger(
+                origin,
+                vec![valid_transfer_side_effect.clone()],
+                true,
+            ));
+
+            let (xtx_id, _side_effect_a_id) = set_ids(
+                valid_transfer_side_effect,
                 ALICE,
                 FIRST_REQUESTER_NONCE,
                 FIRST_SFX_INDEX,
             );
 
-            assert_eq!(
-                Circuit::get_x_exec_signals(xtx_id).unwrap(),
-                XExecSignal {
-                    requester: REQUESTER,
-                    timeouts_at: 401u32,
-                    delay_steps_at: None,
-                    status: CircuitStatus::PendingBidding,
-                    requester_nonce: FIRST_REQUESTER_NONCE,
-                    steps_cnt: (0, 1),
-                }
-            );
-
-            assert_eq!(
-                Circuit::get_full_side_effects(xtx_id).unwrap(),
-                vec![vec![FullSideEffect {
-                    input: valid_transfer_side_effect.clone(),
-                    confirmed: None,
-                    best_bid: None,
-                    security_lvl: SecurityLvl::Optimistic,
-                    submission_target_height: vec![0],
-                    index: FIRST_SFX_INDEX,
-                }]]
+            // Gateway is still active - nothing to fail fast on
+            assert_noop!(
+                Circuit::revert_on_inactive_gateway(Origin::signed(BOB_RELAYER), xtx_id),
+                circuit_error::<Runtime>::NoPendingSideEffectOnInactiveGateway
             );
 
+            make_gateway_inactive([0, 0, 0, 0]);
             System::reset_events();
-            // Bob opens bid with bid = max_reward, the highest possible
-            assert_ok!(Circuit::bid_sfx(
-                Origin::signed(BID_WINNER),
-                side_effect_a_id,
-                BID_AMOUNT_C,
+
+            assert_ok!(Circuit::revert_on_inactive_gateway(
+                Origin::signed(BOB_RELAYER),
+                xtx_id
             ));
-            let events = System::events();
 
             assert_eq!(
-                events[1],
-                EventRecord {
-                    phase: Phase::Initialization,
-                    event: Event::Circuit(circuit_runtime_pallets::pallet_circuit::Event::<
-                        Runtime,
-                    >::SFXNewBidReceived(
-                        side_effect_a_id,
-                        BID_WINNER,
-                        BID_AMOUNT_C
-                    )),
-                    topics: vec![]
-                }
+                Circuit::get_x_exec_signals(xtx_id).unwrap().status,
+                CircuitStatus::RevertGatewayInactive
             );
-
-            // Reserve insurance + bid amounts of the current winner
+            assert!(System::events().iter().any(|record| {
+                record.event
+                == Event::Circuit(circuit_runtime_pallets::pallet_circuit::Event::<
+                    Runtime,
+                >::XTransactionXtxRevertedOnInactiveGateway(
+                    xtx_id, [0, 0, 0, 0]
+                ))
+            }));
             assert_eq!(
-                Balances::free_balance(&BID_WINNER),
-                INITIAL_BALANCE - (BID_AMOUNT_C + REQUESTED_INSURANCE_AMOUNT)
+                XtxTimeoutsQueue::<Runtime>::get(401u32, (xtx_id, XtxTimeoutKind::Execution)),
+                None
             );
+        });
+}
 
-            // Charlie bids better offer
-            assert_ok!(Circuit::bid_sfx(
-                Origin::signed(BID_LOOSER),
-                side_effect_a_id,
-                BID_AMOUNT_B,
+#[test]
+fn circuit_releases_accepted_bids_when_reverting_on_inactive_gateway() {
+    let origin = Origin::signed(ALICE);
+
+    let transfer_protocol_box =
+        Box::new(t3rn_protocol::side_effects::standards::get_transfer_interface());
+
+    let mut local_state = LocalState::new();
+
+    let valid_transfer_side_effect = produce_and_validate_side_effect(
+        vec![
+            (Type::Address(32), ArgVariant::A),
+            (Type::Address(32), ArgVariant::B),
+            (Type::Uint(128), ArgVariant::A),
+            (Type::OptionalInsurance, ArgVariant::A), // insurance = 1, reward = 1
+        ],
+        &mut local_state,
+        transfer_protocol_box,
+        ALICE,
+        FIRST_REQUESTER_NONCE,
+        FIRST_SFX_INDEX,
+    );
+
+    const REQUESTED_INSURANCE_AMOUNT: Balance = 1;
+    const BID_AMOUNT: Balance = 1;
+
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 1 + 2);
+            let _ =
+                Balances::deposit_creating(&BOB_RELAYER, REQUESTED_INSURANCE_AMOUNT + BID_AMOUNT);
+
+            System::set_block_number(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            assert_ok!(Circuit::on_extrinsic_trigger(
+                origin,
+                vec![valid_transfer_side_effect.clone()],
+                true,
             ));
 
-            // Reserve insurance + bid amounts of the current winner
-            assert_eq!(
-                Balances::free_balance(&BID_LOOSER),
-                INITIAL_BALANCE - (BID_AMOUNT_B + REQUESTED_INSURANCE_AMOUNT)
-            );
-            // Unreserve insurance + bid amounts of the previous bidder
-            assert_eq!(Balances::free_balance(&BID_WINNER), INITIAL_BALANCE);
-            // Bidding with the same amount should not be accepted
-            assert_err!(
-                Circuit::bid_sfx(Origin::signed(BID_WINNER), side_effect_a_id, BID_AMOUNT_B,),
-                circuit_error::<Runtime>::BiddingRejectedBetterBidFound,
+            let (xtx_id, side_effect_a_id) = set_ids(
+                valid_transfer_side_effect,
+                ALICE,
+                FIRST_REQUESTER_NONCE,
+                FIRST_SFX_INDEX,
             );
 
-            // Bob submits the winning bid
-            assert_ok!(Circuit::bid_sfx(
-                Origin::signed(BID_WINNER),
+            place_winning_bid_and_advance_3_blocks(
+                BOB_RELAYER,
+                xtx_id,
                 side_effect_a_id,
-                BID_AMOUNT_A,
+                BID_AMOUNT,
+            );
+            // The only SFX of the step doesn't require a bond on top of the insurance
+            assert_eq!(
+                Circuit::get_full_side_effects(xtx_id).unwrap()[0][0]
+                    .best_bid
+                    .as_ref()
+                    .unwrap()
+                    .reserved_bond,
+                None
+            );
+            assert_eq!(Balances::free_balance(&BOB_RELAYER), 0);
+
+            make_gateway_inactive([0, 0, 0, 0]);
+
+            assert_ok!(Circuit::revert_on_inactive_gateway(
+                Origin::signed(BOB_RELAYER),
+                xtx_id
             ));
-            // Reserve insurance + bid amounts of the current winner
+
             assert_eq!(
-                Balances::free_balance(&BID_WINNER),
-                INITIAL_BALANCE - (BID_AMOUNT_A + REQUESTED_INSURANCE_AMOUNT)
+                Circuit::get_x_exec_signals(xtx_id).unwrap().status,
+                CircuitStatus::RevertGatewayInactive
             );
-            // Unreserve insurance + bid amounts of the previous bidder
-            assert_eq!(Balances::free_balance(&BID_LOOSER), INITIAL_BALANCE);
+            // Executor isn't at fault for the gateway becoming inactive - nothing gets slashed
+            assert_eq!(
+                Balances::free_balance(&BOB_RELAYER),
+                REQUESTED_INSURANCE_AMOUNT + BID_AMOUNT
+            );
+        });
+}
 
-            let expected_bonded_sfx_bid = SFXBid {
-                bid: BID_AMOUNT_A,
-                requester: REQUESTER,
-                executor: BID_WINNER,
-                reserved_bond: None,
-                insurance: REQUESTED_INSURANCE_AMOUNT,
-                reward_asset_id: None,
-            };
+#[test]
+fn circuit_rejects_bids_once_the_pinned_xdns_record_changed() {
+    let origin = Origin::signed(ALICE);
+
+    let transfer_protocol_box =
+        Box::new(t3rn_protocol::side_effects::standards::get_transfer_interface());
+
+    let mut local_state = LocalState::new();
+
+    let valid_transfer_side_effect = produce_and_validate_side_effect(
+        vec![
+            (Type::Address(32), ArgVariant::A),
+            (Type::Address(32), ArgVariant::B),
+            (Type::Uint(128), ArgVariant::A),
+            (Type::OptionalInsurance, ArgVariant::A), // insurance = 1, reward = 1
+        ],
+        &mut local_state,
+        transfer_protocol_box,
+        ALICE,
+        FIRST_REQUESTER_NONCE,
+        FIRST_SFX_INDEX,
+    );
+
+    const REQUESTED_INSURANCE_AMOUNT: Balance = 1;
+    const BID_AMOUNT: Balance = 1;
+
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 1 + 2);
+            let _ =
+                Balances::deposit_creating(&BOB_RELAYER, REQUESTED_INSURANCE_AMOUNT + BID_AMOUNT);
+
+            System::set_block_number(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            assert_ok!(Circuit::on_extrinsic_trigger(
+                origin,
+                vec![valid_transfer_side_effect.clone()],
+                true,
+            ));
+
+            let (xtx_id, side_effect_a_id) = set_ids(
+                valid_transfer_side_effect,
+                ALICE,
+                FIRST_REQUESTER_NONCE,
+                FIRST_SFX_INDEX,
+            );
+
+            let version = XDNS::get_gateway_record_version(&[0, 0, 0, 0]).unwrap();
+            let fingerprint = XDNS::get_gateway_record_fingerprint(&[0, 0, 0, 0]).unwrap();
+            assert_eq!(
+                Circuit::get_xtx_xdns_record_versions(xtx_id),
+                Some(vec![([0, 0, 0, 0], version, fingerprint)])
+            );
+
+            assert_noop!(
+                XDNS::update_security_coordinates(Origin::root(), [0, 0, 0, 0], vec![1, 2, 3]),
+                pallet_xdns::Error::<Runtime>::GatewayHasPendingXtx
+            );
+
+            // the url isn't part of the fingerprint
+            assert_ok!(XDNS::update_url(
+                Origin::root(),
+                [0, 0, 0, 0],
+                b"ws://new".to_vec()
+            ));
+            assert_eq!(
+                XDNS::get_gateway_record_version(&[0, 0, 0, 0]),
+                Ok(version + 1)
+            );
+            assert_eq!(
+                XDNS::get_gateway_record_fingerprint(&[0, 0, 0, 0]),
+                Ok(fingerprint)
+            );
+
+            assert_ok!(XDNS::purge_xdns_record(Origin::root(), ALICE, [0, 0, 0, 0]));
+
+            assert_noop!(
+                Circuit::bid_sfx(Origin::signed(BOB_RELAYER), side_effect_a_id, BID_AMOUNT),
+                circuit_error::<Runtime>::XdnsRecordChangedSinceValidation
+            );
+        });
+}
+
+#[test]
+fn circuit_selects_best_bid_out_of_3_for_transfer_sfx() {
+    let origin = Origin::signed(ALICE); // Only sudo access to register new gateways for now
+
+    let transfer_protocol_box =
+        Box::new(t3rn_protocol::side_effects::standards::get_transfer_interface());
+
+    let mut local_state = LocalState::new();
+
+    let valid_transfer_side_effect = produce_and_validate_side_effect(
+        vec![
+            (Type::Address(32), ArgVariant::A),
+            (Type::Address(32), ArgVariant::B),
+            (Type::Uint(128), ArgVariant::A),
+            (Type::OptionalInsurance, ArgVariant::C), // insurance = 3, max_reward/reward = 3
+        ],
+        &mut local_state,
+        transfer_protocol_box,
+        ALICE,
+        FIRST_REQUESTER_NONCE,
+        FIRST_SFX_INDEX,
+    );
+
+    let side_effects = vec![valid_transfer_side_effect.clone()];
+
+    const REQUESTED_INSURANCE_AMOUNT: Balance = 3;
+    const BID_AMOUNT_A: Balance = 1;
+    const BID_AMOUNT_B: Balance = 2;
+    const BID_AMOUNT_C: Balance = 3;
+    const MAX_REWARD: Balance = 3;
+
+    const INITIAL_BALANCE: Balance = 10;
+
+    const REQUESTER: AccountId32 = ALICE;
+    const BID_WINNER: AccountId32 = BOB_RELAYER;
+    const BID_LOOSER: AccountId32 = CHARLIE;
+
+    const BIDDING_BLOCK_NO: BlockNumber = 1;
+    const BIDDING_TIMEOUT: BlockNumber = 3;
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&REQUESTER, INITIAL_BALANCE);
+            let _ = Balances::deposit_creating(&BID_WINNER, INITIAL_BALANCE);
+            let _ = Balances::deposit_creating(&BID_LOOSER, INITIAL_BALANCE);
+
+            System::set_block_number(BIDDING_BLOCK_NO);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            assert_ok!(Circuit::on_extrinsic_trigger(origin, side_effects, true,));
+            // Requester should have offered SFX::max_reward reserved
+            assert_eq!(
+                Balances::free_balance(&REQUESTER),
+                INITIAL_BALANCE - MAX_REWARD
+            );
+
+            let (xtx_id, side_effect_a_id) = set_ids(
+                valid_transfer_side_effect.clone(),
+                ALICE,
+                FIRST_REQUESTER_NONCE,
+                FIRST_SFX_INDEX,
+            );
+
+            assert_eq!(
+                Circuit::get_x_exec_signals(xtx_id).unwrap(),
+                XExecSignal {
+                    requester: REQUESTER,
+                    timeouts_at: 401u32,
+                    delay_steps_at: None,
+                    status: CircuitStatus::PendingBidding,
+                    requester_nonce: FIRST_REQUESTER_NONCE,
+                    steps_cnt: (0, 1),
+                }
+            );
+
+            assert_eq!(
+                Circuit::get_full_side_effects(xtx_id).unwrap(),
+                vec![vec![FullSideEffect {
+                    input: valid_transfer_side_effect.clone(),
+                    confirmed: None,
+                    best_bid: None,
+                    security_lvl: SecurityLvl::Optimistic,
+                    submission_target_height: vec![0],
+                    index: FIRST_SFX_INDEX,
+                }]]
+            );
+
+            System::reset_events();
+            // Bob opens bid with bid = max_reward, the highest possible
+            assert_ok!(Circuit::bid_sfx(
+                Origin::signed(BID_WINNER),
+                side_effect_a_id,
+                BID_AMOUNT_C,
+            ));
+            let events = System::events();
+
+            assert_eq!(
+                events[1],
+                EventRecord {
+                    phase: Phase::Initialization,
+                    event: Event::Circuit(circuit_runtime_pallets::pallet_circuit::Event::<
+                        Runtime,
+                    >::SFXNewBidReceived(
+                        side_effect_a_id,
+                        BID_WINNER,
+                        BID_AMOUNT_C
+                    )),
+                    topics: vec![]
+                }
+            );
+
+            // Reserve insurance + bid amounts of the current winner
+            assert_eq!(
+                Balances::free_balance(&BID_WINNER),
+                INITIAL_BALANCE - (BID_AMOUNT_C + REQUESTED_INSURANCE_AMOUNT)
+            );
+
+            // Charlie bids better offer
+            assert_ok!(Circuit::bid_sfx(
+                Origin::signed(BID_LOOSER),
+                side_effect_a_id,
+                BID_AMOUNT_B,
+            ));
+
+            // Reserve insurance + bid amounts of the current winner
+            assert_eq!(
+                Balances::free_balance(&BID_LOOSER),
+                INITIAL_BALANCE - (BID_AMOUNT_B + REQUESTED_INSURANCE_AMOUNT)
+            );
+            // Unreserve insurance + bid amounts of the previous bidder
+            assert_eq!(Balances::free_balance(&BID_WINNER), INITIAL_BALANCE);
+            // Bidding with the same amount should not be accepted
+            assert_err!(
+                Circuit::bid_sfx(Origin::signed(BID_WINNER), side_effect_a_id, BID_AMOUNT_B,),
+                circuit_error::<Runtime>::BiddingRejectedBetterBidFound,
+            );
+
+            // Bob submits the winning bid
+            assert_ok!(Circuit::bid_sfx(
+                Origin::signed(BID_WINNER),
+                side_effect_a_id,
+                BID_AMOUNT_A,
+            ));
+            // Reserve insurance + bid amounts of the current winner
+            assert_eq!(
+                Balances::free_balance(&BID_WINNER),
+                INITIAL_BALANCE - (BID_AMOUNT_A + REQUESTED_INSURANCE_AMOUNT)
+            );
+            // Unreserve insurance + bid amounts of the previous bidder
+            assert_eq!(Balances::free_balance(&BID_LOOSER), INITIAL_BALANCE);
+
+            let expected_bonded_sfx_bid = SFXBid {
+                bid: BID_AMOUNT_A,
+                requester: REQUESTER,
+                executor: BID_WINNER,
+                reserved_bond: None,
+                insurance: REQUESTED_INSURANCE_AMOUNT,
+                reward_asset_id: None,
+            };
+
+            assert_eq!(
+                Circuit::get_pending_sfx_bids(xtx_id, side_effect_a_id).unwrap(),
+                expected_bonded_sfx_bid
+            );
+
+            assert_eq!(
+                Circuit::get_x_exec_signals(xtx_id).unwrap(),
+                XExecSignal {
+                    requester: REQUESTER,
+                    timeouts_at: 401u32,
+                    delay_steps_at: None,
+                    status: CircuitStatus::PendingBidding,
+                    requester_nonce: FIRST_REQUESTER_NONCE,
+                    steps_cnt: (0, 1),
+                }
+            );
+
+            System::set_block_number(BIDDING_BLOCK_NO + BIDDING_TIMEOUT);
+
+            <Circuit as frame_support::traits::OnInitialize<BlockNumber>>::on_initialize(
+                BIDDING_BLOCK_NO + BIDDING_TIMEOUT,
+            );
+
+            assert_eq!(
+                Circuit::get_x_exec_signals(xtx_id),
+                Some(XExecSignal {
+                    requester: REQUESTER,
+                    timeouts_at: 401u32,
+                    delay_steps_at: None,
+                    status: CircuitStatus::Ready,
+                    requester_nonce: FIRST_REQUESTER_NONCE,
+                    steps_cnt: (0, 1),
+                })
+            );
+        });
+}
+
+#[test]
+fn circuit_handles_swap_with_insurance() {
+    let origin = Origin::signed(ALICE); // Only sudo access to register new gateways for now
+
+    let ext = ExtBuilder::default();
+
+    let mut local_state = LocalState::new();
+    let swap_protocol_box = Box::new(t3rn_protocol::side_effects::standards::get_swap_interface());
+    let valid_swap_side_effect = produce_and_validate_side_effect(
+        vec![
+            (Type::Address(32), ArgVariant::A),       // caller
+            (Type::Address(32), ArgVariant::B),       // to
+            (Type::Uint(128), ArgVariant::A),         // amount_from
+            (Type::Uint(128), ArgVariant::B),         // amount_to
+            (Type::Bytes(4), ArgVariant::A),          // asset_from
+            (Type::Bytes(4), ArgVariant::B),          // asset_to
+            (Type::OptionalInsurance, ArgVariant::A), // insurance
+        ],
+        &mut local_state,
+        swap_protocol_box,
+        ALICE,
+        FIRST_REQUESTER_NONCE,
+        FIRST_SFX_INDEX,
+    );
+
+    let side_effects = vec![valid_swap_side_effect.clone()];
+
+    let sequential = true;
+
+    ext.with_default_xdns_records()
+        .with_standard_side_effects()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 1 + 2); // Alice should have at least: fee (1) + insurance reward (2)(for VariantA)
+            let _ = Balances::deposit_creating(&BOB_RELAYER, 1 + 1); // Bob should have at least: insurance deposit (1)(for VariantA)
+
+            System::set_block_number(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            assert_ok!(Circuit::on_extrinsic_trigger(
+                origin,
+                side_effects,
+                sequential,
+            ));
+
+            let (xtx_id, side_effect_a_id) = set_ids(
+                valid_swap_side_effect.clone(),
+                ALICE,
+                FIRST_REQUESTER_NONCE,
+                FIRST_SFX_INDEX,
+            );
+
+            assert_eq!(
+                Circuit::get_x_exec_signals(xtx_id).unwrap(),
+                XExecSignal {
+                    requester: AccountId32::new(hex!(
+                        "0101010101010101010101010101010101010101010101010101010101010101"
+                    )),
+                    timeouts_at: 401u32,
+                    delay_steps_at: None,
+                    status: CircuitStatus::PendingBidding,
+                    requester_nonce: FIRST_REQUESTER_NONCE,
+                    steps_cnt: (0, 1),
+                }
+            );
+
+            assert_eq!(
+                Circuit::get_full_side_effects(xtx_id).unwrap(),
+                vec![vec![FullSideEffect {
+                    input: valid_swap_side_effect.clone(),
+                    confirmed: None,
+                    best_bid: None,
+                    security_lvl: SecurityLvl::Optimistic,
+                    submission_target_height: vec![0],
+                    index: FIRST_SFX_INDEX,
+                }]]
+            );
+
+            place_winning_bid_and_advance_3_blocks(
+                BOB_RELAYER,
+                xtx_id,
+                side_effect_a_id,
+                1 as Balance,
+            );
+
+            assert_eq!(
+                Circuit::get_x_exec_signals(xtx_id).unwrap(),
+                XExecSignal {
+                    requester: AccountId32::new(hex!(
+                        "0101010101010101010101010101010101010101010101010101010101010101"
+                    )),
+                    timeouts_at: 401u32,
+                    delay_steps_at: None,
+                    status: CircuitStatus::Ready,
+                    requester_nonce: FIRST_REQUESTER_NONCE,
+                    steps_cnt: (0, 1),
+                }
+            );
+        });
+}
+
+#[test]
+fn circuit_handles_add_liquidity_without_insurance() {
+    let origin = Origin::signed(ALICE);
+
+    let ext = ExtBuilder::default();
+    let mut local_state = LocalState::new();
+
+    let add_liquidity_protocol_box =
+        Box::new(t3rn_protocol::side_effects::standards::get_add_liquidity_interface());
+
+    let valid_add_liquidity_side_effect = produce_and_validate_side_effect(
+        vec![
+            (Type::Address(32), ArgVariant::A),       // argument_0: caller
+            (Type::Address(32), ArgVariant::B),       // argument_1: to
+            (Type::Bytes(4), ArgVariant::A),          // argument_2: asset_left
+            (Type::Bytes(4), ArgVariant::B),          // argument_3: asset_right
+            (Type::Bytes(4), ArgVariant::C),          // argument_4: liquidity_token
+            (Type::Uint(128), ArgVariant::A),         // argument_5: amount_left
+            (Type::Uint(128), ArgVariant::B),         // argument_6: amount_right
+            (Type::Uint(128), ArgVariant::A),         // argument_7: amount_liquidity_token
+            (Type::OptionalInsurance, ArgVariant::A), // argument_8: no insurance, empty bytes
+        ],
+        &mut local_state,
+        add_liquidity_protocol_box,
+        ALICE,
+        FIRST_REQUESTER_NONCE,
+        FIRST_SFX_INDEX,
+    );
+
+    let side_effects = vec![valid_add_liquidity_side_effect.clone()];
+
+    let sequential = true;
+
+    ext.with_default_xdns_records()
+        .with_standard_side_effects()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 1 + 2);
+            let _ = Balances::deposit_creating(&BOB_RELAYER, 1);
+
+            System::set_block_number(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            assert_ok!(Circuit::on_extrinsic_trigger(
+                origin,
+                side_effects,
+                sequential,
+            ));
+
+            let (xtx_id, side_effect_a_id) = set_ids(
+                valid_add_liquidity_side_effect.clone(),
+                ALICE,
+                FIRST_REQUESTER_NONCE,
+                FIRST_SFX_INDEX,
+            );
+
+            assert_eq!(
+                Circuit::get_pending_sfx_bids(xtx_id, side_effect_a_id),
+                None
+            );
+        });
+}
+
+#[test]
+fn circuit_handles_add_liquidity_with_insurance() {
+    let origin = Origin::signed(ALICE);
+
+    let ext = ExtBuilder::default();
+    let mut local_state = LocalState::new();
+
+    let add_liquidity_protocol_box =
+        Box::new(t3rn_protocol::side_effects::standards::get_add_liquidity_interface());
+
+    let valid_add_liquidity_side_effect = produce_and_validate_side_effect(
+        vec![
+            (Type::Address(32), ArgVariant::A),       // argument_0: caller
+            (Type::Address(32), ArgVariant::B),       // argument_1: to
+            (Type::Bytes(4), ArgVariant::A),          // argument_2: asset_left
+            (Type::Bytes(4), ArgVariant::B),          // argument_3: asset_right
+            (Type::Bytes(4), ArgVariant::A),          // argument_4: liquidity_token
+            (Type::Uint(128), ArgVariant::A),         // argument_5: amount_left
+            (Type::Uint(128), ArgVariant::B),         // argument_6: amount_right
+            (Type::Uint(128), ArgVariant::A),         // argument_7: amount_liquidity_token
+            (Type::OptionalInsurance, ArgVariant::A), // argument_8: Variant A insurance = 1, reward = 2
+        ],
+        &mut local_state,
+        add_liquidity_protocol_box,
+        ALICE,
+        FIRST_REQUESTER_NONCE,
+        FIRST_SFX_INDEX,
+    );
+
+    let side_effects = vec![valid_add_liquidity_side_effect.clone()];
+
+    let sequential = true;
+
+    ext.with_default_xdns_records()
+        .with_standard_side_effects()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 1 + 2); // Alice should have at least: fee (1) + insurance reward (2)(for VariantA)
+            let _ = Balances::deposit_creating(&BOB_RELAYER, 1 + 1); // Bob should have at least: insurance deposit (1)(for VariantA)
+
+            System::set_block_number(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            assert_ok!(Circuit::on_extrinsic_trigger(
+                origin,
+                side_effects,
+                sequential,
+            ));
+
+            let (xtx_id, side_effect_a_id) = set_ids(
+                valid_add_liquidity_side_effect.clone(),
+                ALICE,
+                FIRST_REQUESTER_NONCE,
+                FIRST_SFX_INDEX,
+            );
+
+            assert_eq!(
+                Circuit::get_x_exec_signals(xtx_id).unwrap(),
+                XExecSignal {
+                    requester: AccountId32::new(hex!(
+                        "0101010101010101010101010101010101010101010101010101010101010101"
+                    )),
+                    timeouts_at: 401u32,
+                    delay_steps_at: None,
+                    status: CircuitStatus::PendingBidding,
+                    requester_nonce: FIRST_REQUESTER_NONCE,
+                    steps_cnt: (0, 1),
+                }
+            );
+
+            assert_eq!(
+                Circuit::get_full_side_effects(xtx_id).unwrap(),
+                vec![vec![FullSideEffect {
+                    input: valid_add_liquidity_side_effect.clone(),
+                    confirmed: None,
+                    best_bid: None,
+                    security_lvl: SecurityLvl::Optimistic,
+                    submission_target_height: vec![0],
+                    index: FIRST_SFX_INDEX,
+                }]]
+            );
+
+            place_winning_bid_and_advance_3_blocks(
+                BOB_RELAYER,
+                xtx_id,
+                side_effect_a_id,
+                1 as Balance,
+            );
+
+            assert_eq!(
+                Circuit::get_x_exec_signals(xtx_id).unwrap(),
+                XExecSignal {
+                    requester: AccountId32::new(hex!(
+                        "0101010101010101010101010101010101010101010101010101010101010101"
+                    )),
+                    timeouts_at: 401u32,
+                    delay_steps_at: None,
+                    status: CircuitStatus::Ready,
+                    requester_nonce: FIRST_REQUESTER_NONCE,
+                    steps_cnt: (0, 1),
+                }
+            );
+        });
+}
+
+// fn successfully_confirm_optimistic(side_effect: SideEffect<AccountId32, Balance>) {
+//
+//     let from = side_effect.encoded_args[0].clone();
+//     let to = side_effect.encoded_args[1].clone();
+//     let amount = side_effect.encoded_args[2].clone();
+//
+//     let mut encoded_balance_transfer_event_1 = pallet_balances::Event::<Runtime>::Transfer {
+//         from: from.into(), // variant A
+//         to: to.into(), // variant B (dest)
+//         amount: amount.into(), // variant A
+//     }
+//         .encode();
+//
+//     // Adding 4 since Balances Pallet = 4 in construct_runtime! enum
+//     let mut encoded_event_1 = vec![4];
+//     encoded_event_1.append(&mut encoded_balance_transfer_event_1);
+//     let confirmation_transfer_1 =
+//         ConfirmedSideEffect::<AccountId32, BlockNumber, Balance> {
+//             err: None,
+//             output: None,
+//             encoded_effect: encoded_event_1,
+//             inclusion_proof: None,
+//             executioner: BOB_RELAYER,
+//             received_at: 0,
+//             cost: None,
+//         };
+//
+//     assert_ok!(Circuit::confirm_side_effect(
+//         origin_relayer_bob.clone(),
+//         xtx_id.clone(),
+//         valid_transfer_side_effect_1,
+//         confirmation_transfer_1,
+//         None,
+//         None,
+//     ));
+//
+// }
+
+fn successfully_bond_optimistic(
+    side_effect: SideEffect<AccountId32, Balance>,
+    sfx_index: u32,
+    xtx_id: XtxId<Runtime>,
+    relayer: AccountId32,
+    submitter: AccountId32,
+) {
+    let optional_insurance = side_effect.encoded_args[3].clone();
+
+    assert!(
+        optional_insurance.len() == 32,
+        "Wrong test value - optimistic transfer assumes optimistic arguments"
+    );
+
+    assert_ok!(Circuit::bid_sfx(
+        Origin::signed(relayer.clone()),
+        side_effect.generate_id::<circuit_runtime_pallets::pallet_circuit::SystemHashing<Runtime>>(
+            &xtx_id.0, sfx_index
+        ),
+        2 as Balance,
+    ));
+
+    let [insurance, reward]: [u128; 2] = Decode::decode(&mut &optional_insurance[..]).unwrap();
+
+    let created_sfx_bid = Circuit::get_pending_sfx_bids(
+        xtx_id,
+        side_effect.generate_id::<circuit_runtime_pallets::pallet_circuit::SystemHashing<Runtime>>(
+            &xtx_id.0, sfx_index,
+        ),
+    )
+    .unwrap();
+
+    assert_eq!(created_sfx_bid.insurance, insurance as Balance);
+    // assert_eq!(created_sfx_bid.reserved_bond, Some(insurance as Balance));
+    assert_eq!(created_sfx_bid.bid, reward as Balance);
+    assert_eq!(
+        created_sfx_bid.requester,
+        Decode::decode(&mut &submitter.encode()[..]).unwrap()
+    );
+    assert_eq!(created_sfx_bid.executor, relayer);
+}
+
+#[test]
+fn two_dirty_transfers_are_allocated_to_2_steps_and_can_be_submitted() {
+    let origin = Origin::signed(ALICE); // Only sudo access to register new gateways for now
+
+    let _origin_relayer_bob = Origin::signed(BOB_RELAYER); // Only sudo access to register new gateways for now
+
+    let transfer_protocol_box =
+        Box::new(t3rn_protocol::side_effects::standards::get_transfer_interface());
+
+    let mut local_state = LocalState::new();
+    let valid_transfer_side_effect_1 = produce_and_validate_side_effect(
+        vec![
+            (Type::Address(32), ArgVariant::A),
+            (Type::Address(32), ArgVariant::B),
+            (Type::Uint(128), ArgVariant::A),
+            (Type::OptionalInsurance, ArgVariant::A), // empty bytes instead of insurance
+        ],
+        &mut local_state,
+        transfer_protocol_box.clone(),
+        ALICE,
+        FIRST_REQUESTER_NONCE,
+        FIRST_SFX_INDEX,
+    );
+
+    let valid_transfer_side_effect_2 = produce_and_validate_side_effect(
+        vec![
+            (Type::Address(32), ArgVariant::B),
+            (Type::Address(32), ArgVariant::A),
+            (Type::Uint(128), ArgVariant::A),
+            (Type::OptionalInsurance, ArgVariant::A), // empty bytes instead of insurance
+        ],
+        &mut local_state,
+        transfer_protocol_box,
+        ALICE,
+        SECOND_REQUESTER_NONCE,
+        SECOND_SFX_INDEX,
+    );
+
+    let side_effects = vec![valid_transfer_side_effect_1, valid_transfer_side_effect_2];
+
+    let sequential = true;
+
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 10);
+
+            System::set_block_number(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            assert_ok!(Circuit::on_extrinsic_trigger(
+                origin,
+                side_effects,
+                sequential,
+            ));
+
+            let events = System::events();
+            assert_eq!(events.len(), 7);
+        });
+}
+
+#[test]
+fn two_dirty_transfers_are_allocated_to_2_steps_and_can_be_confirmed() {
+    let origin = Origin::signed(ALICE); // Only sudo access to register new gateways for now
+
+    let _origin_relayer_bob = Origin::signed(BOB_RELAYER); // Only sudo access to register new gateways for now
+
+    let transfer_protocol_box =
+        Box::new(t3rn_protocol::side_effects::standards::get_transfer_interface());
+
+    let mut local_state = LocalState::new();
+    let valid_transfer_side_effect_1 = produce_and_validate_side_effect(
+        vec![
+            (Type::Address(32), ArgVariant::A),
+            (Type::Address(32), ArgVariant::B),
+            (Type::Uint(128), ArgVariant::A),
+            (Type::OptionalInsurance, ArgVariant::A), // empty bytes instead of insurance
+        ],
+        &mut local_state,
+        transfer_protocol_box.clone(),
+        ALICE,
+        FIRST_REQUESTER_NONCE,
+        FIRST_SFX_INDEX,
+    );
+
+    let valid_transfer_side_effect_2 = produce_and_validate_side_effect(
+        vec![
+            (Type::Address(32), ArgVariant::B),
+            (Type::Address(32), ArgVariant::A),
+            (Type::Uint(128), ArgVariant::A),
+            (Type::OptionalInsurance, ArgVariant::A), // empty bytes instead of insurance
+        ],
+        &mut local_state,
+        transfer_protocol_box,
+        ALICE,
+        SECOND_REQUESTER_NONCE,
+        SECOND_SFX_INDEX,
+    );
+
+    let side_effects = vec![valid_transfer_side_effect_1, valid_transfer_side_effect_2];
+
+    let sequential = true;
+
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+
+            System::set_block_number(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            assert_ok!(Circuit::on_extrinsic_trigger(
+                origin,
+                side_effects,
+                sequential,
+            ));
+        });
+}
+
+#[test]
+fn challenge_side_effect_confirmation_rejects_unconfirmed_side_effects() {
+    let origin = Origin::signed(ALICE); // Only sudo access to register new gateways for now
+
+    let transfer_protocol_box =
+        Box::new(t3rn_protocol::side_effects::standards::get_transfer_interface());
+
+    let mut local_state = LocalState::new();
+    let valid_transfer_side_effect = produce_and_validate_side_effect(
+        vec![
+            (Type::Address(32), ArgVariant::A),
+            (Type::Address(32), ArgVariant::B),
+            (Type::Uint(128), ArgVariant::A),
+            (Type::OptionalInsurance, ArgVariant::A), // empty bytes instead of insurance
+        ],
+        &mut local_state,
+        transfer_protocol_box,
+        ALICE,
+        FIRST_REQUESTER_NONCE,
+        FIRST_SFX_INDEX,
+    );
+
+    let side_effects = vec![valid_transfer_side_effect.clone()];
+
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+
+            System::set_block_number(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            assert_noop!(
+                Circuit::challenge_side_effect_confirmation(
+                    Origin::signed(BOB_RELAYER),
+                    sp_core::H256::repeat_byte(1),
+                ),
+                circuit_error::<Runtime>::ChallengeSideEffectNotFound
+            );
+
+            assert_ok!(Circuit::on_extrinsic_trigger(origin, side_effects, false,));
+
+            let xtx_id: sp_core::H256 = generate_xtx_id::<Hashing>(ALICE, FIRST_REQUESTER_NONCE);
+            let sfx_id = valid_transfer_side_effect
+                .generate_id::<circuit_runtime_pallets::pallet_circuit::SystemHashing<Runtime>>(
+                &xtx_id.0,
+                FIRST_SFX_INDEX,
+            );
+
+            assert_noop!(
+                Circuit::challenge_side_effect_confirmation(Origin::signed(BOB_RELAYER), sfx_id),
+                circuit_error::<Runtime>::ChallengeSideEffectNotOptimisticallyConfirmed
+            );
+        });
+}
+
+#[test]
+fn finalize_escrowed_side_effect_rejects_side_effects_not_awaiting_escrow_finalization() {
+    let origin = Origin::signed(ALICE); // Only sudo access to register new gateways for now
+
+    let transfer_protocol_box =
+        Box::new(t3rn_protocol::side_effects::standards::get_transfer_interface());
+
+    let mut local_state = LocalState::new();
+    let valid_transfer_side_effect = produce_and_validate_side_effect(
+        vec![
+            (Type::Address(32), ArgVariant::A),
+            (Type::Address(32), ArgVariant::B),
+            (Type::Uint(128), ArgVariant::A),
+            (Type::OptionalInsurance, ArgVariant::A), // empty bytes instead of insurance
+        ],
+        &mut local_state,
+        transfer_protocol_box,
+        ALICE,
+        FIRST_REQUESTER_NONCE,
+        FIRST_SFX_INDEX,
+    );
+
+    let side_effects = vec![valid_transfer_side_effect.clone()];
+
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+
+            System::set_block_number(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            assert_ok!(Circuit::on_extrinsic_trigger(origin, side_effects, false));
+
+            let xtx_id: sp_core::H256 = generate_xtx_id::<Hashing>(ALICE, FIRST_REQUESTER_NONCE);
+            let sfx_id = valid_transfer_side_effect
+                .generate_id::<circuit_runtime_pallets::pallet_circuit::SystemHashing<Runtime>>(
+                &xtx_id.0,
+                FIRST_SFX_INDEX,
+            );
+
+            // Xtx is still pending for bids - there is no remote escrow to finalize yet
+            assert_eq!(
+                Circuit::get_pending_escrow_finalizations(xtx_id, sfx_id),
+                None
+            );
+            assert_noop!(
+                Circuit::finalize_escrowed_side_effect(
+                    Origin::signed(BOB_RELAYER),
+                    sfx_id,
+                    vec![0, 1, 2]
+                ),
+                circuit_error::<Runtime>::EscrowFinalizationNotPending
+            );
+        });
+}
+
+#[test]
+fn scheduled_xtx_opens_for_bidding_at_its_delay_steps_at_block() {
+    let origin = Origin::signed(ALICE); // Only sudo access to register new gateways for now
+
+    let transfer_protocol_box =
+        Box::new(t3rn_protocol::side_effects::standards::get_transfer_interface());
+
+    let mut local_state = LocalState::new();
+    let valid_transfer_side_effect = produce_and_validate_side_effect(
+        vec![
+            (Type::Address(32), ArgVariant::A),
+            (Type::Address(32), ArgVariant::B),
+            (Type::Uint(128), ArgVariant::A),
+            (Type::OptionalInsurance, ArgVariant::A), // empty bytes instead of insurance
+        ],
+        &mut local_state,
+        transfer_protocol_box,
+        ALICE,
+        FIRST_REQUESTER_NONCE,
+        FIRST_SFX_INDEX,
+    );
+
+    let side_effects = vec![valid_transfer_side_effect];
+
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 1_000_000);
 
-            assert_eq!(
-                Circuit::get_pending_sfx_bids(xtx_id, side_effect_a_id).unwrap(),
-                expected_bonded_sfx_bid
-            );
+            System::set_block_number(1);
+            brute_seed_block_1([0, 0, 0, 0]);
 
-            assert_eq!(
-                Circuit::get_x_exec_signals(xtx_id).unwrap(),
-                XExecSignal {
-                    requester: REQUESTER,
-                    timeouts_at: 401u32,
-                    delay_steps_at: None,
-                    status: CircuitStatus::PendingBidding,
-                    requester_nonce: FIRST_REQUESTER_NONCE,
-                    steps_cnt: (0, 1),
-                }
+            // Single step needs a single delay
+            assert_noop!(
+                Circuit::on_extrinsic_trigger_scheduled(
+                    origin.clone(),
+                    side_effects.clone(),
+                    false,
+                    vec![10u32, 20u32],
+                ),
+                circuit_error::<Runtime>::ScheduleStepsInvalid
             );
 
-            System::set_block_number(BIDDING_BLOCK_NO + BIDDING_TIMEOUT);
+            assert_ok!(Circuit::on_extrinsic_trigger_scheduled(
+                origin,
+                side_effects,
+                false,
+                vec![10u32],
+            ));
 
-            <Circuit as frame_support::traits::OnInitialize<BlockNumber>>::on_initialize(
-                BIDDING_BLOCK_NO + BIDDING_TIMEOUT,
-            );
+            let xtx_id: sp_core::H256 = generate_xtx_id::<Hashing>(ALICE, FIRST_REQUESTER_NONCE);
 
             assert_eq!(
                 Circuit::get_x_exec_signals(xtx_id),
                 Some(XExecSignal {
-                    requester: REQUESTER,
-                    timeouts_at: 401u32,
-                    delay_steps_at: None,
-                    status: CircuitStatus::Ready,
+                    requester: AccountId32::new(hex!(
+                        "0101010101010101010101010101010101010101010101010101010101010101"
+                    )),
+                    timeouts_at: 410u32, // 400 offset + scheduled block 10
+                    delay_steps_at: Some(vec![10u32]),
+                    status: CircuitStatus::Scheduled,
                     requester_nonce: FIRST_REQUESTER_NONCE,
                     steps_cnt: (0, 1),
                 })
             );
+            assert_eq!(
+                Circuit::get_xtx_timeouts_queue(10u32, (xtx_id, XtxTimeoutKind::ScheduledStep)),
+                Some(())
+            );
+
+            System::set_block_number(10);
+            <Circuit as frame_support::traits::OnInitialize<BlockNumber>>::on_initialize(10);
+
+            assert_eq!(
+                Circuit::get_x_exec_signals(xtx_id).unwrap().status,
+                CircuitStatus::PendingBidding
+            );
+            assert_eq!(
+                Circuit::get_xtx_timeouts_queue(13u32, (xtx_id, XtxTimeoutKind::Bidding)),
+                Some(())
+            );
         });
 }
 
 #[test]
-fn circuit_handles_swap_with_insurance() {
+fn two_dirty_transfers_are_allocated_to_steps_by_dfd() {
     let origin = Origin::signed(ALICE); // Only sudo access to register new gateways for now
 
-    let ext = ExtBuilder::default();
+    let transfer_protocol_box =
+        Box::new(t3rn_protocol::side_effects::standards::get_transfer_interface());
 
     let mut local_state = LocalState::new();
-    let swap_protocol_box = Box::new(t3rn_protocol::side_effects::standards::get_swap_interface());
-    let valid_swap_side_effect = produce_and_validate_side_effect(
+    let valid_transfer_side_effect_1 = produce_and_validate_side_effect(
         vec![
-            (Type::Address(32), ArgVariant::A),       // caller
-            (Type::Address(32), ArgVariant::B),       // to
-            (Type::Uint(128), ArgVariant::A),         // amount_from
-            (Type::Uint(128), ArgVariant::B),         // amount_to
-            (Type::Bytes(4), ArgVariant::A),          // asset_from
-            (Type::Bytes(4), ArgVariant::B),          // asset_to
-            (Type::OptionalInsurance, ArgVariant::A), // insurance
+            (Type::Address(32), ArgVariant::A),
+            (Type::Address(32), ArgVariant::B),
+            (Type::Uint(128), ArgVariant::A),
+            (Type::OptionalInsurance, ArgVariant::A), // empty bytes instead of insurance
         ],
         &mut local_state,
-        swap_protocol_box,
+        transfer_protocol_box.clone(),
         ALICE,
         FIRST_REQUESTER_NONCE,
         FIRST_SFX_INDEX,
     );
 
-    let side_effects = vec![valid_swap_side_effect.clone()];
+    let valid_transfer_side_effect_2 = produce_and_validate_side_effect(
+        vec![
+            (Type::Address(32), ArgVariant::B),
+            (Type::Address(32), ArgVariant::A),
+            (Type::Uint(128), ArgVariant::A),
+            (Type::OptionalInsurance, ArgVariant::A), // empty bytes instead of insurance
+        ],
+        &mut local_state,
+        transfer_protocol_box,
+        ALICE,
+        SECOND_REQUESTER_NONCE,
+        SECOND_SFX_INDEX,
+    );
 
-    let sequential = true;
+    let side_effects = vec![valid_transfer_side_effect_1, valid_transfer_side_effect_2];
 
-    ext.with_default_xdns_records()
+    ExtBuilder::default()
         .with_standard_side_effects()
+        .with_default_xdns_records()
         .build()
         .execute_with(|| {
-            let _ = Balances::deposit_creating(&ALICE, 1 + 2); // Alice should have at least: fee (1) + insurance reward (2)(for VariantA)
-            let _ = Balances::deposit_creating(&BOB_RELAYER, 1 + 1); // Bob should have at least: insurance deposit (1)(for VariantA)
+            let _ = Balances::deposit_creating(&ALICE, 1_000_000);
 
             System::set_block_number(1);
             brute_seed_block_1([0, 0, 0, 0]);
 
-            assert_ok!(Circuit::on_extrinsic_trigger(
+            // SFX 0 is never scheduled
+            assert_noop!(
+                Circuit::on_extrinsic_trigger_with_dfd(
+                    origin.clone(),
+                    side_effects.clone(),
+                    b"(1)".to_vec(),
+                ),
+                circuit_error::<Runtime>::SideEffectsValidationFailed
+            );
+
+            // SFX 1 executes first, SFX 0 once SFX 1 is confirmed
+            assert_ok!(Circuit::on_extrinsic_trigger_with_dfd(
                 origin,
                 side_effects,
-                sequential,
+                b"(0(1))".to_vec(),
             ));
 
-            let (xtx_id, side_effect_a_id) = set_ids(
-                valid_swap_side_effect.clone(),
-                ALICE,
-                FIRST_REQUESTER_NONCE,
-                FIRST_SFX_INDEX,
-            );
+            let xtx_id: sp_core::H256 = generate_xtx_id::<Hashing>(ALICE, FIRST_REQUESTER_NONCE);
 
-            assert_eq!(
-                Circuit::get_x_exec_signals(xtx_id).unwrap(),
-                XExecSignal {
-                    requester: AccountId32::new(hex!(
-                        "0101010101010101010101010101010101010101010101010101010101010101"
-                    )),
-                    timeouts_at: 401u32,
-                    delay_steps_at: None,
-                    status: CircuitStatus::PendingBidding,
-                    requester_nonce: FIRST_REQUESTER_NONCE,
-                    steps_cnt: (0, 1),
-                }
-            );
+            let full_side_effects = Circuit::get_full_side_effects(xtx_id).unwrap();
+            assert_eq!(full_side_effects.len(), 2);
+            assert_eq!(full_side_effects[0][0].index, SECOND_SFX_INDEX);
+            assert_eq!(full_side_effects[1][0].index, FIRST_SFX_INDEX);
+        });
+}
 
-            assert_eq!(
-                Circuit::get_full_side_effects(xtx_id).unwrap(),
-                vec![vec![FullSideEffect {
-                    input: valid_swap_side_effect.clone(),
-                    confirmed: None,
-                    best_bid: None,
-                    security_lvl: SecurityLvl::Optimistic,
-                    submission_target_height: vec![0],
-                    index: FIRST_SFX_INDEX,
-                }]]
-            );
+#[test]
+fn three_dirty_transfers_are_allocated_to_requested_steps_of_many_sfx() {
+    let origin = Origin::signed(ALICE); // Only sudo access to register new gateways for now
 
-            place_winning_bid_and_advance_3_blocks(
-                BOB_RELAYER,
-                xtx_id,
-                side_effect_a_id,
-                1 as Balance,
+    let transfer_protocol_box =
+        Box::new(t3rn_protocol::side_effects::standards::get_transfer_interface());
+
+    let mut local_state = LocalState::new();
+    let valid_transfer_side_effect_1 = produce_and_validate_side_effect(
+        vec![
+            (Type::Address(32), ArgVariant::A),
+            (Type::Address(32), ArgVariant::B),
+            (Type::Uint(128), ArgVariant::A),
+            (Type::OptionalInsurance, ArgVariant::A), // empty bytes instead of insurance
+        ],
+        &mut local_state,
+        transfer_protocol_box.clone(),
+        ALICE,
+        FIRST_REQUESTER_NONCE,
+        FIRST_SFX_INDEX,
+    );
+
+    let valid_transfer_side_effect_2 = produce_and_validate_side_effect(
+        vec![
+            (Type::Address(32), ArgVariant::B),
+            (Type::Address(32), ArgVariant::A),
+            (Type::Uint(128), ArgVariant::A),
+            (Type::OptionalInsurance, ArgVariant::A), // empty bytes instead of insurance
+        ],
+        &mut local_state,
+        transfer_protocol_box.clone(),
+        ALICE,
+        FIRST_REQUESTER_NONCE,
+        SECOND_SFX_INDEX,
+    );
+
+    let valid_transfer_side_effect_3 = produce_and_validate_side_effect(
+        vec![
+            (Type::Address(32), ArgVariant::A),
+            (Type::Address(32), ArgVariant::B),
+            (Type::Uint(128), ArgVariant::A),
+            (Type::OptionalInsurance, ArgVariant::A), // empty bytes instead of insurance
+        ],
+        &mut local_state,
+        transfer_protocol_box,
+        ALICE,
+        FIRST_REQUESTER_NONCE,
+        THIRD_SFX_INDEX,
+    );
+
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+
+            System::set_block_number(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            assert_noop!(
+                Circuit::on_extrinsic_trigger_with_steps(
+                    origin.clone(),
+                    vec![vec![valid_transfer_side_effect_1.clone()], vec![]],
+                ),
+                circuit_error::<Runtime>::SideEffectsStepsInvalid
             );
 
+            // SFX 0 and 1 execute in parallel, SFX 2 once both are confirmed
+            assert_ok!(Circuit::on_extrinsic_trigger_with_steps(
+                origin,
+                vec![
+                    vec![valid_transfer_side_effect_1, valid_transfer_side_effect_2],
+                    vec![valid_transfer_side_effect_3],
+                ],
+            ));
+
+            let xtx_id: sp_core::H256 = generate_xtx_id::<Hashing>(ALICE, FIRST_REQUESTER_NONCE);
+
+            let xtx = Circuit::get_x_exec_signals(xtx_id).unwrap();
+            assert_eq!(xtx.steps_cnt, (0, 2));
+            assert_eq!(xtx.status, CircuitStatus::PendingBidding);
+
+            let full_side_effects = Circuit::get_full_side_effects(xtx_id).unwrap();
+            assert_eq!(full_side_effects.len(), 2);
             assert_eq!(
-                Circuit::get_x_exec_signals(xtx_id).unwrap(),
-                XExecSignal {
-                    requester: AccountId32::new(hex!(
-                        "0101010101010101010101010101010101010101010101010101010101010101"
-                    )),
-                    timeouts_at: 401u32,
-                    delay_steps_at: None,
-                    status: CircuitStatus::Ready,
-                    requester_nonce: FIRST_REQUESTER_NONCE,
-                    steps_cnt: (0, 1),
-                }
+                full_side_effects[0]
+                    .iter()
+                    .map(|fsx| fsx.index)
+                    .collect::<Vec<u32>>(),
+                vec![FIRST_SFX_INDEX, SECOND_SFX_INDEX]
             );
+            assert_eq!(full_side_effects[1].len(), 1);
+            assert_eq!(full_side_effects[1][0].index, THIRD_SFX_INDEX);
         });
 }
 
-#[test]
-fn circuit_handles_add_liquidity_without_insurance() {
-    let origin = Origin::signed(ALICE);
+/// Opens Xtx of 2 dirty transfers scheduled by "(1(0))" - destination of the 2nd one refers to output
+///     `dfd_param_index` of the 1st one - and confirms the 1st transfer executed by Bob,
+///     as done by `executioner`.
+fn open_dfd_xtx_and_confirm_its_first_step(
+    dfd_param_index: u32,
+    executioner: AccountId32,
+) -> (sp_core::H256, SideEffect<AccountId32, Balance>) {
+    let transfer_protocol_box =
+        Box::new(t3rn_protocol::side_effects::standards::get_transfer_interface());
 
-    let ext = ExtBuilder::default();
     let mut local_state = LocalState::new();
+    let valid_transfer_side_effect_1 = produce_and_validate_side_effect(
+        vec![
+            (Type::Address(32), ArgVariant::A),
+            (Type::Address(32), ArgVariant::B),
+            (Type::Uint(128), ArgVariant::A),
+            (Type::OptionalInsurance, ArgVariant::A), // insurance = 1, reward = 1
+        ],
+        &mut local_state,
+        transfer_protocol_box.clone(),
+        ALICE,
+        FIRST_REQUESTER_NONCE,
+        FIRST_SFX_INDEX,
+    );
 
-    let add_liquidity_protocol_box =
-        Box::new(t3rn_protocol::side_effects::standards::get_add_liquidity_interface());
-
-    let valid_add_liquidity_side_effect = produce_and_validate_side_effect(
+    let mut dependent_transfer_side_effect_2 = produce_and_validate_side_effect(
         vec![
-            (Type::Address(32), ArgVariant::A),       // argument_0: caller
-            (Type::Address(32), ArgVariant::B),       // argument_1: to
-            (Type::Bytes(4), ArgVariant::A),          // argument_2: asset_left
-            (Type::Bytes(4), ArgVariant::B),          // argument_3: asset_right
-            (Type::Bytes(4), ArgVariant::C),          // argument_4: liquidity_token
-            (Type::Uint(128), ArgVariant::A),         // argument_5: amount_left
-            (Type::Uint(128), ArgVariant::B),         // argument_6: amount_right
-            (Type::Uint(128), ArgVariant::A),         // argument_7: amount_liquidity_token
-            (Type::OptionalInsurance, ArgVariant::A), // argument_8: no insurance, empty bytes
+            (Type::Address(32), ArgVariant::B),
+            (Type::Address(32), ArgVariant::A),
+            (Type::Uint(128), ArgVariant::A),
+            (Type::OptionalInsurance, ArgVariant::A), // insurance = 1, reward = 1
         ],
         &mut local_state,
-        add_liquidity_protocol_box,
+        transfer_protocol_box,
+        ALICE,
+        FIRST_REQUESTER_NONCE,
+        SECOND_SFX_INDEX,
+    );
+    let mut dfd_arg = DFD_ARG_MARKER.to_vec();
+    dfd_arg.append(&mut (FIRST_SFX_INDEX, dfd_param_index).encode());
+    dependent_transfer_side_effect_2.encoded_args[1] = dfd_arg;
+
+    let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+    let _ = Balances::deposit_creating(&BOB_RELAYER, 1_000_000);
+    System::set_block_number(1);
+
+    // Transferred amounts are confirmed as u128
+    assert_ok!(XDNS::update_gateway_abi(
+        Origin::root(),
+        [0, 0, 0, 0],
+        GatewayABIConfig {
+            value_type_size: 16,
+            ..Default::default()
+        },
+    ));
+    // Xtx is submitted at the gateway's height 2
+    pallet_grandpa_finality_verifier::mock::brute_seed_block_with_events::<Runtime, ()>(
+        [0, 0, 0, 0],
+        1,
+        vec![],
+        vec![],
+    );
+
+    assert_ok!(Circuit::on_extrinsic_trigger_with_dfd(
+        Origin::signed(ALICE),
+        vec![
+            valid_transfer_side_effect_1.clone(),
+            dependent_transfer_side_effect_2
+        ],
+        b"(1(0))".to_vec(),
+    ));
+
+    let (xtx_id, sfx_id) = set_ids(
+        valid_transfer_side_effect_1.clone(),
         ALICE,
         FIRST_REQUESTER_NONCE,
         FIRST_SFX_INDEX,
     );
+    place_winning_bid_and_advance_3_blocks(BOB_RELAYER, xtx_id, sfx_id, 1);
+
+    // Balances::Transfer { from: Bob, to, amount } emitted at the gateway's height 3
+    let mut transfer_event = vec![4, 2];
+    transfer_event.append(&mut BOB_RELAYER.encode());
+    transfer_event.extend_from_slice(&valid_transfer_side_effect_1.encoded_args[1]);
+    transfer_event.extend_from_slice(&valid_transfer_side_effect_1.encoded_args[2]);
+    let inclusion_data = pallet_grandpa_finality_verifier::mock::brute_seed_block_with_events::<
+        Runtime,
+        (),
+    >([0, 0, 0, 0], 3, transfer_event.clone(), transfer_event);
 
-    let side_effects = vec![valid_add_liquidity_side_effect.clone()];
+    assert_ok!(Circuit::confirm_side_effect(
+        Origin::signed(BOB_RELAYER),
+        sfx_id,
+        ConfirmedSideEffect::<AccountId32, BlockNumber, Balance> {
+            err: None,
+            output: None,
+            executioner,
+            received_at: 0,
+            cost: None,
+            inclusion_data: inclusion_data.encode(),
+        },
+    ));
 
-    let sequential = true;
+    (xtx_id, valid_transfer_side_effect_1)
+}
 
-    ext.with_default_xdns_records()
+#[test]
+fn dfd_arg_of_next_step_is_substituted_with_confirmed_output_of_predecessor() {
+    ExtBuilder::default()
         .with_standard_side_effects()
+        .with_default_xdns_records()
         .build()
         .execute_with(|| {
-            let _ = Balances::deposit_creating(&ALICE, 1 + 2);
-            let _ = Balances::deposit_creating(&BOB_RELAYER, 1);
-
-            System::set_block_number(1);
-            brute_seed_block_1([0, 0, 0, 0]);
-
-            assert_ok!(Circuit::on_extrinsic_trigger(
-                origin,
-                side_effects,
-                sequential,
-            ));
+            // Output 1 of confirmed transfer is its destination
+            let (xtx_id, valid_transfer_side_effect_1) =
+                open_dfd_xtx_and_confirm_its_first_step(1, BOB_RELAYER);
 
-            let (xtx_id, side_effect_a_id) = set_ids(
-                valid_add_liquidity_side_effect.clone(),
-                ALICE,
-                FIRST_REQUESTER_NONCE,
-                FIRST_SFX_INDEX,
-            );
+            let xtx = Circuit::get_x_exec_signals(xtx_id).unwrap();
+            assert_eq!(xtx.steps_cnt, (1, 2));
+            assert_eq!(xtx.status, CircuitStatus::PendingBidding);
 
+            let full_side_effects = Circuit::get_full_side_effects(xtx_id).unwrap();
+            assert!(full_side_effects[0][0].confirmed.is_some());
             assert_eq!(
-                Circuit::get_pending_sfx_bids(xtx_id, side_effect_a_id),
-                None
+                full_side_effects[1][0].input.encoded_args[1],
+                valid_transfer_side_effect_1.encoded_args[1]
             );
         });
 }
 
 #[test]
-fn circuit_handles_add_liquidity_with_insurance() {
-    let origin = Origin::signed(ALICE);
-
-    let ext = ExtBuilder::default();
-    let mut local_state = LocalState::new();
-
-    let add_liquidity_protocol_box =
-        Box::new(t3rn_protocol::side_effects::standards::get_add_liquidity_interface());
-
-    let valid_add_liquidity_side_effect = produce_and_validate_side_effect(
-        vec![
-            (Type::Address(32), ArgVariant::A),       // argument_0: caller
-            (Type::Address(32), ArgVariant::B),       // argument_1: to
-            (Type::Bytes(4), ArgVariant::A),          // argument_2: asset_left
-            (Type::Bytes(4), ArgVariant::B),          // argument_3: asset_right
-            (Type::Bytes(4), ArgVariant::A),          // argument_4: liquidity_token
-            (Type::Uint(128), ArgVariant::A),         // argument_5: amount_left
-            (Type::Uint(128), ArgVariant::B),         // argument_6: amount_right
-            (Type::Uint(128), ArgVariant::A),         // argument_7: amount_liquidity_token
-            (Type::OptionalInsurance, ArgVariant::A), // argument_8: Variant A insurance = 1, reward = 2
-        ],
-        &mut local_state,
-        add_liquidity_protocol_box,
-        ALICE,
-        FIRST_REQUESTER_NONCE,
-        FIRST_SFX_INDEX,
-    );
-
-    let side_effects = vec![valid_add_liquidity_side_effect.clone()];
-
-    let sequential = true;
-
-    ext.with_default_xdns_records()
+fn xtx_reverts_once_dfd_arg_of_next_step_cant_be_substituted() {
+    ExtBuilder::default()
         .with_standard_side_effects()
+        .with_default_xdns_records()
         .build()
         .execute_with(|| {
-            let _ = Balances::deposit_creating(&ALICE, 1 + 2); // Alice should have at least: fee (1) + insurance reward (2)(for VariantA)
-            let _ = Balances::deposit_creating(&BOB_RELAYER, 1 + 1); // Bob should have at least: insurance deposit (1)(for VariantA)
-
-            System::set_block_number(1);
-            brute_seed_block_1([0, 0, 0, 0]);
+            // Confirmed transfer has no output 9
+            let (xtx_id, _) = open_dfd_xtx_and_confirm_its_first_step(9, BOB_RELAYER);
 
-            assert_ok!(Circuit::on_extrinsic_trigger(
-                origin,
-                side_effects,
-                sequential,
-            ));
+            assert_eq!(
+                Circuit::get_x_exec_signals(xtx_id).unwrap().status,
+                CircuitStatus::RevertKill
+            );
+            assert!(Circuit::get_full_side_effects(xtx_id).unwrap()[0][0]
+                .confirmed
+                .is_some());
+            assert!(System::events().iter().any(|record| {
+                record.event
+                    == Event::Circuit(circuit_runtime_pallets::pallet_circuit::Event::<
+                        Runtime,
+                    >::XTransactionXtxRevertedOnDfdSubstitutionFailure(
+                        xtx_id
+                    ))
+            }));
+        });
+}
 
-            let (xtx_id, side_effect_a_id) = set_ids(
-                valid_add_liquidity_side_effect.clone(),
+#[test]
+fn challenge_side_effect_confirmation_never_slashes_valid_or_unverifiable_confirmations() {
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let (xtx_id, valid_transfer_side_effect_1) = open_dfd_xtx_and_confirm_its_first_step(1, BOB_RELAYER);
+            let (_, sfx_id) = set_ids(
+                valid_transfer_side_effect_1,
                 ALICE,
                 FIRST_REQUESTER_NONCE,
                 FIRST_SFX_INDEX,
             );
+            let confirmed_at = System::block_number();
 
-            assert_eq!(
-                Circuit::get_x_exec_signals(xtx_id).unwrap(),
-                XExecSignal {
-                    requester: AccountId32::new(hex!(
-                        "0101010101010101010101010101010101010101010101010101010101010101"
-                    )),
-                    timeouts_at: 401u32,
-                    delay_steps_at: None,
-                    status: CircuitStatus::PendingBidding,
-                    requester_nonce: FIRST_REQUESTER_NONCE,
-                    steps_cnt: (0, 1),
-                }
+            assert_noop!(
+                Circuit::challenge_side_effect_confirmation(Origin::signed(CHARLIE), sfx_id),
+                circuit_error::<Runtime>::ChallengeFailedConfirmationValid
             );
 
-            assert_eq!(
-                Circuit::get_full_side_effects(xtx_id).unwrap(),
-                vec![vec![FullSideEffect {
-                    input: valid_add_liquidity_side_effect.clone(),
-                    confirmed: None,
-                    best_bid: None,
-                    security_lvl: SecurityLvl::Optimistic,
-                    submission_target_height: vec![0],
-                    index: FIRST_SFX_INDEX,
-                }]]
+            // Confirmation can't be verified against the headers of inactive gateway anymore
+            make_gateway_inactive([0, 0, 0, 0]);
+            assert_noop!(
+                Circuit::challenge_side_effect_confirmation(Origin::signed(CHARLIE), sfx_id),
+                circuit_error::<Runtime>::ChallengeUnverifiableGatewayInactive
             );
 
-            place_winning_bid_and_advance_3_blocks(
-                BOB_RELAYER,
-                xtx_id,
-                side_effect_a_id,
-                1 as Balance,
+            System::set_block_number(
+                confirmed_at + <Runtime as circuit_runtime_pallets::pallet_circuit::Config>::ChallengeWindow::get() + 1,
+            );
+            assert_noop!(
+                Circuit::challenge_side_effect_confirmation(Origin::signed(CHARLIE), sfx_id),
+                circuit_error::<Runtime>::ChallengeWindowClosed
             );
 
             assert_eq!(
-                Circuit::get_x_exec_signals(xtx_id).unwrap(),
-                XExecSignal {
-                    requester: AccountId32::new(hex!(
-                        "0101010101010101010101010101010101010101010101010101010101010101"
-                    )),
-                    timeouts_at: 401u32,
-                    delay_steps_at: None,
-                    status: CircuitStatus::Ready,
-                    requester_nonce: FIRST_REQUESTER_NONCE,
-                    steps_cnt: (0, 1),
-                }
+                Circuit::get_x_exec_signals(xtx_id).unwrap().status,
+                CircuitStatus::PendingBidding
             );
         });
 }
 
-// fn successfully_confirm_optimistic(side_effect: SideEffect<AccountId32, Balance>) {
-//
-//     let from = side_effect.encoded_args[0].clone();
-//     let to = side_effect.encoded_args[1].clone();
-//     let amount = side_effect.encoded_args[2].clone();
-//
-//     let mut encoded_balance_transfer_event_1 = pallet_balances::Event::<Runtime>::Transfer {
-//         from: from.into(), // variant A
-//         to: to.into(), // variant B (dest)
-//         amount: amount.into(), // variant A
-//     }
-//         .encode();
-//
-//     // Adding 4 since Balances Pallet = 4 in construct_runtime! enum
-//     let mut encoded_event_1 = vec![4];
-//     encoded_event_1.append(&mut encoded_balance_transfer_event_1);
-//     let confirmation_transfer_1 =
-//         ConfirmedSideEffect::<AccountId32, BlockNumber, Balance> {
-//             err: None,
-//             output: None,
-//             encoded_effect: encoded_event_1,
-//             inclusion_proof: None,
-//             executioner: BOB_RELAYER,
-//             received_at: 0,
-//             cost: None,
-//         };
-//
-//     assert_ok!(Circuit::confirm_side_effect(
-//         origin_relayer_bob.clone(),
-//         xtx_id.clone(),
-//         valid_transfer_side_effect_1,
-//         confirmation_transfer_1,
-//         None,
-//         None,
-//     ));
-//
-// }
-
-fn successfully_bond_optimistic(
-    side_effect: SideEffect<AccountId32, Balance>,
-    sfx_index: u32,
-    xtx_id: XtxId<Runtime>,
-    relayer: AccountId32,
-    submitter: AccountId32,
-) {
-    let optional_insurance = side_effect.encoded_args[3].clone();
-
-    assert!(
-        optional_insurance.len() == 32,
-        "Wrong test value - optimistic transfer assumes optimistic arguments"
-    );
+#[test]
+fn challenge_of_confirmation_from_finished_step_slashes_its_insurance() {
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            // Bob won the bid, but the confirmation names Alice as the executioner
+            let (xtx_id, valid_transfer_side_effect_1) =
+                open_dfd_xtx_and_confirm_its_first_step(1, ALICE);
+            let (_, sfx_id) = set_ids(
+                valid_transfer_side_effect_1,
+                ALICE,
+                FIRST_REQUESTER_NONCE,
+                FIRST_SFX_INDEX,
+            );
+            // Confirmation is of the finished step - Xtx moved on to the 2nd one
+            assert_eq!(
+                Circuit::get_x_exec_signals(xtx_id).unwrap().steps_cnt,
+                (1, 2)
+            );
 
-    assert_ok!(Circuit::bid_sfx(
-        Origin::signed(relayer.clone()),
-        side_effect.generate_id::<circuit_runtime_pallets::pallet_circuit::SystemHashing<Runtime>>(
-            &xtx_id.0, sfx_index
-        ),
-        2 as Balance,
-    ));
+            let circuit_account =
+                <Runtime as circuit_runtime_pallets::pallet_circuit::Config>::SelfAccountId::get();
+            let circuit_balance = Balances::free_balance(&circuit_account);
+            let bob_balance = Balances::free_balance(&BOB_RELAYER);
 
-    let [insurance, reward]: [u128; 2] = Decode::decode(&mut &optional_insurance[..]).unwrap();
+            assert_ok!(Circuit::challenge_side_effect_confirmation(
+                Origin::signed(CHARLIE),
+                sfx_id
+            ));
 
-    let created_sfx_bid = Circuit::get_pending_sfx_bids(
-        xtx_id,
-        side_effect.generate_id::<circuit_runtime_pallets::pallet_circuit::SystemHashing<Runtime>>(
-            &xtx_id.0, sfx_index,
-        ),
-    )
-    .unwrap();
+            assert_eq!(
+                Circuit::get_x_exec_signals(xtx_id).unwrap().status,
+                CircuitStatus::RevertMisbehaviour
+            );
+            // Single optimistic SFX of its step has no reserved bond - insurance = 1 is slashed
+            assert_eq!(
+                Balances::free_balance(&circuit_account),
+                circuit_balance + 1
+            );
+            assert_eq!(Balances::free_balance(&BOB_RELAYER), bob_balance);
+            assert_eq!(Balances::free_balance(&CHARLIE), 0);
+            assert!(System::events().iter().any(|record| {
+                record.event
+                    == Event::Circuit(circuit_runtime_pallets::pallet_circuit::Event::<
+                        Runtime,
+                    >::SideEffectMisbehaviourProven(
+                        sfx_id, ALICE, CHARLIE, 0
+                    ))
+            }));
 
-    assert_eq!(created_sfx_bid.insurance, insurance as Balance);
-    // assert_eq!(created_sfx_bid.reserved_bond, Some(insurance as Balance));
-    assert_eq!(created_sfx_bid.bid, reward as Balance);
-    assert_eq!(
-        created_sfx_bid.requester,
-        Decode::decode(&mut &submitter.encode()[..]).unwrap()
-    );
-    assert_eq!(created_sfx_bid.executor, relayer);
+            assert_noop!(
+                Circuit::challenge_side_effect_confirmation(Origin::signed(CHARLIE), sfx_id),
+                circuit_error::<Runtime>::ChallengeXtxAlreadyResolved
+            );
+        });
 }
 
+// ToDo: Order for multiple should now be fixed - verify t3rn#261 is solved
 #[test]
-fn two_dirty_transfers_are_allocated_to_2_steps_and_can_be_submitted() {
+#[ignore]
+fn circuit_handles_transfer_dirty_and_optimistic_and_swap() {
     let origin = Origin::signed(ALICE); // Only sudo access to register new gateways for now
 
-    let _origin_relayer_bob = Origin::signed(BOB_RELAYER); // Only sudo access to register new gateways for now
-
     let transfer_protocol_box =
         Box::new(t3rn_protocol::side_effects::standards::get_transfer_interface());
+    let swap_protocol_box = Box::new(t3rn_protocol::side_effects::standards::get_swap_interface());
 
     let mut local_state = LocalState::new();
     let valid_transfer_side_effect_1 = produce_and_validate_side_effect(
@@ -1550,19 +3044,40 @@ fn two_dirty_transfers_are_allocated_to_2_steps_and_can_be_submitted() {
 
     let valid_transfer_side_effect_2 = produce_and_validate_side_effect(
         vec![
-            (Type::Address(32), ArgVariant::B),
             (Type::Address(32), ArgVariant::A),
+            (Type::Address(32), ArgVariant::B),
             (Type::Uint(128), ArgVariant::A),
-            (Type::OptionalInsurance, ArgVariant::A), // empty bytes instead of insurance
+            (Type::OptionalInsurance, ArgVariant::A),
         ],
         &mut local_state,
         transfer_protocol_box,
         ALICE,
-        SECOND_REQUESTER_NONCE,
-        SECOND_SFX_INDEX,
+        SECOND_REQUESTER_NONCE,
+        SECOND_SFX_INDEX,
+    );
+
+    let valid_swap_side_effect = produce_and_validate_side_effect(
+        vec![
+            (Type::Address(32), ArgVariant::A),       // caller
+            (Type::Address(32), ArgVariant::B),       // to
+            (Type::Uint(128), ArgVariant::A),         // amount_from
+            (Type::Uint(128), ArgVariant::B),         // amount_to
+            (Type::Bytes(4), ArgVariant::A),          // asset_from
+            (Type::Bytes(4), ArgVariant::B),          // asset_to
+            (Type::OptionalInsurance, ArgVariant::A), // no insurance
+        ],
+        &mut local_state,
+        swap_protocol_box,
+        ALICE,
+        THIRD_REQUESTER_NONCE,
+        THIRD_SFX_INDEX,
     );
 
-    let side_effects = vec![valid_transfer_side_effect_1, valid_transfer_side_effect_2];
+    let side_effects = vec![
+        valid_transfer_side_effect_1,
+        valid_transfer_side_effect_2,
+        valid_swap_side_effect,
+    ];
 
     let sequential = true;
 
@@ -1571,7 +3086,7 @@ fn two_dirty_transfers_are_allocated_to_2_steps_and_can_be_submitted() {
         .with_default_xdns_records()
         .build()
         .execute_with(|| {
-            let _ = Balances::deposit_creating(&ALICE, 10);
+            let _ = Balances::deposit_creating(&ALICE, 1_000_000);
 
             System::set_block_number(1);
             brute_seed_block_1([0, 0, 0, 0]);
@@ -1581,53 +3096,37 @@ fn two_dirty_transfers_are_allocated_to_2_steps_and_can_be_submitted() {
                 side_effects,
                 sequential,
             ));
-
-            let events = System::events();
-            assert_eq!(events.len(), 7);
         });
 }
 
 #[test]
-fn two_dirty_transfers_are_allocated_to_2_steps_and_can_be_confirmed() {
+fn circuit_cancels_xtx_with_bids_after_timeout() {
     let origin = Origin::signed(ALICE); // Only sudo access to register new gateways for now
 
     let _origin_relayer_bob = Origin::signed(BOB_RELAYER); // Only sudo access to register new gateways for now
 
     let transfer_protocol_box =
         Box::new(t3rn_protocol::side_effects::standards::get_transfer_interface());
+    let _swap_protocol_box = Box::new(t3rn_protocol::side_effects::standards::get_swap_interface());
 
     let mut local_state = LocalState::new();
-    let valid_transfer_side_effect_1 = produce_and_validate_side_effect(
+    let valid_transfer_side_effect = produce_and_validate_side_effect(
         vec![
             (Type::Address(32), ArgVariant::A),
             (Type::Address(32), ArgVariant::B),
             (Type::Uint(128), ArgVariant::A),
-            (Type::OptionalInsurance, ArgVariant::A), // empty bytes instead of insurance
+            (Type::OptionalInsurance, ArgVariant::A),
         ],
         &mut local_state,
-        transfer_protocol_box.clone(),
+        transfer_protocol_box,
         ALICE,
         FIRST_REQUESTER_NONCE,
         FIRST_SFX_INDEX,
     );
 
-    let valid_transfer_side_effect_2 = produce_and_validate_side_effect(
-        vec![
-            (Type::Address(32), ArgVariant::B),
-            (Type::Address(32), ArgVariant::A),
-            (Type::Uint(128), ArgVariant::A),
-            (Type::OptionalInsurance, ArgVariant::A), // empty bytes instead of insurance
-        ],
-        &mut local_state,
-        transfer_protocol_box,
-        ALICE,
-        SECOND_REQUESTER_NONCE,
-        SECOND_SFX_INDEX,
-    );
-
-    let side_effects = vec![valid_transfer_side_effect_1, valid_transfer_side_effect_2];
+    let side_effects = vec![valid_transfer_side_effect.clone()];
 
-    let sequential = true;
+    let sequential = false;
 
     ExtBuilder::default()
         .with_standard_side_effects()
@@ -1644,35 +3143,204 @@ fn two_dirty_transfers_are_allocated_to_2_steps_and_can_be_confirmed() {
                 side_effects,
                 sequential,
             ));
+
+            let _events = System::events();
+            // assert_eq!(events.len(), 8);
+
+            // let xtx_id: sp_core::H256 =
+            //     hex!("9946104f0d553532303b8a763d5828d75ed4493c585c948d10b7e9317ade6331").into();
+
+            let xtx_id: sp_core::H256 = generate_xtx_id::<Hashing>(ALICE, FIRST_REQUESTER_NONCE);
+
+            // The tiemout links that will be checked at on_initialize are there
+            assert_eq!(
+                Circuit::get_xtx_timeouts_queue(401u32, (xtx_id, XtxTimeoutKind::Execution)),
+                Some(())
+            ); // 100 offset + current block height 1 = 101
+
+            assert_eq!(
+                Circuit::get_x_exec_signals(xtx_id),
+                Some(XExecSignal {
+                    requester: AccountId32::new(hex!(
+                        "0101010101010101010101010101010101010101010101010101010101010101"
+                    )),
+                    timeouts_at: 401u32, // 400 offset + current block height 1 = 101
+                    delay_steps_at: None,
+                    status: CircuitStatus::PendingBidding,
+                    requester_nonce: FIRST_REQUESTER_NONCE,
+                    steps_cnt: (0, 1),
+                })
+            );
+
+            // let xtx_id: sp_core::H256 = generate_xtx_id::<Hashing>(ALICE, FIRST_REQUESTER_NONCE);
+            //
+            // let sfx_id_a = valid_transfer_side_effect
+            //     .generate_id::<circuit_runtime_pallets::pallet_circuit::SystemHashing<Runtime>>(
+            //         &xtx_id.0,
+            //         FIRST_SFX_INDEX,
+            //     );
+            //
+
+            let sfx_id = valid_transfer_side_effect
+                .generate_id::<circuit_runtime_pallets::pallet_circuit::SystemHashing<Runtime>>(
+                &xtx_id.0,
+                FIRST_SFX_INDEX,
+            );
+
+            place_winning_bid_and_advance_3_blocks(ALICE, xtx_id, sfx_id, 1);
+
+            System::set_block_number(410);
+
+            <Circuit as frame_support::traits::OnInitialize<BlockNumber>>::on_initialize(410);
+
+            assert_eq!(
+                Circuit::get_x_exec_signals(xtx_id),
+                Some(XExecSignal {
+                    requester: AccountId32::new(hex!(
+                        "0101010101010101010101010101010101010101010101010101010101010101"
+                    )),
+                    timeouts_at: 401u32,
+                    delay_steps_at: None,
+                    status: CircuitStatus::RevertTimedOut,
+                    requester_nonce: FIRST_REQUESTER_NONCE,
+                    steps_cnt: (0, 1),
+                })
+            );
+
+            assert_eq!(
+                Circuit::get_xtx_timeouts_queue(401u32, (xtx_id, XtxTimeoutKind::Execution)),
+                None
+            );
+
+            // Emits event notifying about cancellation
+            let mut events = System::events();
+            // assert_eq!(events.len(), 9);
+            assert_eq!(
+                events.pop(),
+                Some(
+                    EventRecord {
+                        phase: Phase::Initialization,
+                        event: Event::Circuit(circuit_runtime_pallets::pallet_circuit::Event::<
+                            Runtime,
+                        >::XTransactionXtxRevertedAfterTimeOut(
+                            xtx_id
+                        )),
+                        topics: vec![]
+                    }
+                ),
+            );
+            // Voids all associated side effects with Xtx by setting their confirmation to Err
         });
 }
 
-// ToDo: Order for multiple should now be fixed - verify t3rn#261 is solved
 #[test]
-#[ignore]
-fn circuit_handles_transfer_dirty_and_optimistic_and_swap() {
+fn circuit_reopens_sfx_for_bidding_after_winning_executor_misses_execution_deadline() {
     let origin = Origin::signed(ALICE); // Only sudo access to register new gateways for now
 
     let transfer_protocol_box =
         Box::new(t3rn_protocol::side_effects::standards::get_transfer_interface());
-    let swap_protocol_box = Box::new(t3rn_protocol::side_effects::standards::get_swap_interface());
 
     let mut local_state = LocalState::new();
-    let valid_transfer_side_effect_1 = produce_and_validate_side_effect(
+    let valid_transfer_side_effect = produce_and_validate_side_effect(
         vec![
             (Type::Address(32), ArgVariant::A),
             (Type::Address(32), ArgVariant::B),
             (Type::Uint(128), ArgVariant::A),
-            (Type::OptionalInsurance, ArgVariant::A), // empty bytes instead of insurance
+            (Type::OptionalInsurance, ArgVariant::A),
         ],
         &mut local_state,
-        transfer_protocol_box.clone(),
+        transfer_protocol_box,
         ALICE,
         FIRST_REQUESTER_NONCE,
         FIRST_SFX_INDEX,
     );
 
-    let valid_transfer_side_effect_2 = produce_and_validate_side_effect(
+    let side_effects = vec![valid_transfer_side_effect.clone()];
+
+    let sequential = false;
+
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+            let _ = Balances::deposit_creating(&BOB_RELAYER, 1_000_000);
+
+            System::set_block_number(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            assert_ok!(Circuit::on_extrinsic_trigger(
+                origin,
+                side_effects,
+                sequential,
+            ));
+
+            let (xtx_id, sfx_id) = set_ids(
+                valid_transfer_side_effect.clone(),
+                ALICE,
+                FIRST_REQUESTER_NONCE,
+                FIRST_SFX_INDEX,
+            );
+
+            place_winning_bid_and_advance_3_blocks(ALICE, xtx_id, sfx_id, 1);
+
+            // Execution deadline = 3 bidding blocks + 20 blocks of SFXExecutionPeriod
+            assert_eq!(Circuit::get_sfx_execution_deadline(sfx_id), Some(24u32));
+            assert_eq!(
+                Circuit::get_xtx_timeouts_queue(24u32, (xtx_id, XtxTimeoutKind::SFXExecution)),
+                Some(())
+            );
+
+            System::set_block_number(24);
+            <Circuit as frame_support::traits::OnInitialize<BlockNumber>>::on_initialize(24);
+
+            assert_eq!(
+                Circuit::get_x_exec_signals(xtx_id).unwrap().status,
+                CircuitStatus::PendingBidding
+            );
+            assert_eq!(
+                Circuit::get_full_side_effects(xtx_id).unwrap()[0][0].best_bid,
+                None
+            );
+            assert_eq!(Circuit::get_sfx_execution_deadline(sfx_id), None);
+
+            let mut events = System::events();
+            events.pop();
+            assert_eq!(
+                events.pop(),
+                Some(
+                    EventRecord {
+                        phase: Phase::Initialization,
+                        event: Event::Circuit(circuit_runtime_pallets::pallet_circuit::Event::<
+                            Runtime,
+                        >::SideEffectsReopenedForBidding(
+                            xtx_id, vec![sfx_id]
+                        )),
+                        topics: vec![]
+                    }
+                ),
+            );
+
+            // Another executor wins the new bidding round
+            place_winning_bid_and_advance_3_blocks(BOB_RELAYER, xtx_id, sfx_id, 1);
+
+            assert_eq!(
+                Circuit::get_full_side_effects(xtx_id).unwrap()[0][0]
+                    .best_bid
+                    .as_ref()
+                    .unwrap()
+                    .executor,
+                BOB_RELAYER
+            );
+            assert_eq!(Circuit::get_sfx_execution_deadline(sfx_id), Some(47u32));
+        });
+}
+
+/// Transfer SFX of ALICE insured with 1 and rewarding up to max_reward
+fn transfer_side_effect_rewarding(max_reward: Balance) -> SideEffect<AccountId32, Balance> {
+    let mut local_state = LocalState::new();
+    let mut transfer_side_effect = produce_and_validate_side_effect(
         vec![
             (Type::Address(32), ArgVariant::A),
             (Type::Address(32), ArgVariant::B),
@@ -1680,64 +3348,154 @@ fn circuit_handles_transfer_dirty_and_optimistic_and_swap() {
             (Type::OptionalInsurance, ArgVariant::A),
         ],
         &mut local_state,
-        transfer_protocol_box,
+        Box::new(t3rn_protocol::side_effects::standards::get_transfer_interface()),
         ALICE,
-        SECOND_REQUESTER_NONCE,
-        SECOND_SFX_INDEX,
+        FIRST_REQUESTER_NONCE,
+        FIRST_SFX_INDEX,
     );
+    transfer_side_effect.encoded_args[3] = (1 as Balance, max_reward).encode();
+    transfer_side_effect.insurance = 1;
+    transfer_side_effect.max_reward = max_reward;
+    transfer_side_effect
+}
 
-    let valid_swap_side_effect = produce_and_validate_side_effect(
-        vec![
-            (Type::Address(32), ArgVariant::A),       // caller
-            (Type::Address(32), ArgVariant::B),       // to
-            (Type::Uint(128), ArgVariant::A),         // amount_from
-            (Type::Uint(128), ArgVariant::B),         // amount_to
-            (Type::Bytes(4), ArgVariant::A),          // asset_from
-            (Type::Bytes(4), ArgVariant::B),          // asset_to
-            (Type::OptionalInsurance, ArgVariant::A), // no insurance
-        ],
-        &mut local_state,
-        swap_protocol_box,
-        ALICE,
-        THIRD_REQUESTER_NONCE,
-        THIRD_SFX_INDEX,
-    );
+#[test]
+fn lower_reputation_executor_loses_a_tie_of_bids() {
+    const MAX_REWARD: Balance = 100;
+    const INITIAL_BALANCE: Balance = 1_000_000;
+    let transfer_side_effect = transfer_side_effect_rewarding(MAX_REWARD);
 
-    let side_effects = vec![
-        valid_transfer_side_effect_1,
-        valid_transfer_side_effect_2,
-        valid_swap_side_effect,
-    ];
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, INITIAL_BALANCE);
+            let _ = Balances::deposit_creating(&BOB_RELAYER, INITIAL_BALANCE);
+            let _ = Balances::deposit_creating(&CHARLIE, INITIAL_BALANCE);
 
-    let sequential = true;
+            // Bob timed out twice, Charlie has no track record
+            let mut bob_reputation = ExecutorReputation::default();
+            bob_reputation.note(ReputationOutcome::TimedOut);
+            bob_reputation.note(ReputationOutcome::TimedOut);
+            pallet_executors::Reputations::<Runtime>::insert(BOB_RELAYER, bob_reputation);
+
+            System::set_block_number(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            assert_ok!(Circuit::on_extrinsic_trigger(
+                Origin::signed(ALICE),
+                vec![transfer_side_effect.clone()],
+                false,
+            ));
+            let (xtx_id, sfx_id) = set_ids(
+                transfer_side_effect,
+                ALICE,
+                FIRST_REQUESTER_NONCE,
+                FIRST_SFX_INDEX,
+            );
+
+            assert_ok!(Circuit::bid_sfx(
+                Origin::signed(BOB_RELAYER),
+                sfx_id,
+                MAX_REWARD
+            ));
+            // Charlie's bid of the same amount weighs less
+            assert_ok!(Circuit::bid_sfx(
+                Origin::signed(CHARLIE),
+                sfx_id,
+                MAX_REWARD
+            ));
+            assert_noop!(
+                Circuit::bid_sfx(Origin::signed(BOB_RELAYER), sfx_id, MAX_REWARD),
+                circuit_error::<Runtime>::BiddingRejectedBetterBidFound
+            );
+
+            assert_eq!(
+                Circuit::get_pending_sfx_bids(xtx_id, sfx_id)
+                    .unwrap()
+                    .executor,
+                CHARLIE
+            );
+            assert_eq!(Balances::free_balance(&BOB_RELAYER), INITIAL_BALANCE);
+        });
+}
+
+#[test]
+fn executor_without_bond_wins_no_ties_by_farmed_reputation() {
+    const MAX_REWARD: Balance = 100;
+    const INITIAL_BALANCE: Balance = 1_000_000;
+    let transfer_side_effect = transfer_side_effect_rewarding(MAX_REWARD);
 
     ExtBuilder::default()
         .with_standard_side_effects()
         .with_default_xdns_records()
         .build()
         .execute_with(|| {
-            let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+            let fixtures = pallet_executors::Fixtures::<Runtime>::get();
+            let min_executor_bond = fixtures.min_executor_bond.max(fixtures.min_candidate_bond);
+            let _ = Balances::deposit_creating(&ALICE, INITIAL_BALANCE);
+            let _ = Balances::deposit_creating(&BOB_RELAYER, INITIAL_BALANCE);
+            let _ = Balances::deposit_creating(&CHARLIE, INITIAL_BALANCE + min_executor_bond);
+
+            // Charlie has a perfect track record, yet no bond at stake
+            let mut charlie_reputation = ExecutorReputation::default();
+            for _ in 0..8 {
+                charlie_reputation.note(ReputationOutcome::ConfirmedOnTime);
+            }
+            pallet_executors::Reputations::<Runtime>::insert(CHARLIE, charlie_reputation);
 
             System::set_block_number(1);
             brute_seed_block_1([0, 0, 0, 0]);
 
             assert_ok!(Circuit::on_extrinsic_trigger(
-                origin,
-                side_effects,
-                sequential,
+                Origin::signed(ALICE),
+                vec![transfer_side_effect.clone()],
+                false,
+            ));
+            let (xtx_id, sfx_id) = set_ids(
+                transfer_side_effect,
+                ALICE,
+                FIRST_REQUESTER_NONCE,
+                FIRST_SFX_INDEX,
+            );
+
+            assert_ok!(Circuit::bid_sfx(
+                Origin::signed(BOB_RELAYER),
+                sfx_id,
+                MAX_REWARD
+            ));
+            assert_noop!(
+                Circuit::bid_sfx(Origin::signed(CHARLIE), sfx_id, MAX_REWARD),
+                circuit_error::<Runtime>::BiddingRejectedBetterBidFound
+            );
+
+            // Once bonded, the track record of Charlie counts
+            assert_ok!(Executors::join_candidates(
+                Origin::signed(CHARLIE),
+                min_executor_bond,
+                0
+            ));
+            assert_ok!(Circuit::bid_sfx(
+                Origin::signed(CHARLIE),
+                sfx_id,
+                MAX_REWARD
             ));
+            assert_eq!(
+                Circuit::get_pending_sfx_bids(xtx_id, sfx_id)
+                    .unwrap()
+                    .executor,
+                CHARLIE
+            );
         });
 }
 
 #[test]
-fn circuit_cancels_xtx_with_bids_after_timeout() {
+fn circuit_accepts_lowest_revealed_sealed_bid_and_forfeits_unrevealed_commits() {
     let origin = Origin::signed(ALICE); // Only sudo access to register new gateways for now
 
-    let _origin_relayer_bob = Origin::signed(BOB_RELAYER); // Only sudo access to register new gateways for now
-
     let transfer_protocol_box =
         Box::new(t3rn_protocol::side_effects::standards::get_transfer_interface());
-    let _swap_protocol_box = Box::new(t3rn_protocol::side_effects::standards::get_swap_interface());
 
     let mut local_state = LocalState::new();
     let valid_transfer_side_effect = produce_and_validate_side_effect(
@@ -1745,7 +3503,7 @@ fn circuit_cancels_xtx_with_bids_after_timeout() {
             (Type::Address(32), ArgVariant::A),
             (Type::Address(32), ArgVariant::B),
             (Type::Uint(128), ArgVariant::A),
-            (Type::OptionalInsurance, ArgVariant::A),
+            (Type::OptionalInsurance, ArgVariant::A), // insurance = 1, reward = 1
         ],
         &mut local_state,
         transfer_protocol_box,
@@ -1756,104 +3514,134 @@ fn circuit_cancels_xtx_with_bids_after_timeout() {
 
     let side_effects = vec![valid_transfer_side_effect.clone()];
 
-    let sequential = false;
+    const INITIAL_BALANCE: Balance = 1_000_000;
+    const BID_AMOUNT: Balance = 1;
+    // Deposit of sealed bid = max_reward + insurance
+    const SEALED_BID_DEPOSIT: Balance = 2;
 
     ExtBuilder::default()
         .with_standard_side_effects()
         .with_default_xdns_records()
         .build()
         .execute_with(|| {
-            let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+            let _ = Balances::deposit_creating(&ALICE, INITIAL_BALANCE);
+            let _ = Balances::deposit_creating(&BOB_RELAYER, INITIAL_BALANCE);
+            let _ = Balances::deposit_creating(&CHARLIE, INITIAL_BALANCE);
 
             System::set_block_number(1);
             brute_seed_block_1([0, 0, 0, 0]);
 
-            assert_ok!(Circuit::on_extrinsic_trigger(
+            assert_ok!(Circuit::on_extrinsic_trigger_sealed_bids(
                 origin,
                 side_effects,
-                sequential,
+                false,
             ));
 
-            let _events = System::events();
-            // assert_eq!(events.len(), 8);
-
-            // let xtx_id: sp_core::H256 =
-            //     hex!("9946104f0d553532303b8a763d5828d75ed4493c585c948d10b7e9317ade6331").into();
-
-            let xtx_id: sp_core::H256 = generate_xtx_id::<Hashing>(ALICE, FIRST_REQUESTER_NONCE);
-
-            // The tiemout links that will be checked at on_initialize are there
-            assert_eq!(Circuit::get_active_timing_links(xtx_id), Some(401u32)); // 100 offset + current block height 1 = 101
+            let (xtx_id, sfx_id) = set_ids(
+                valid_transfer_side_effect.clone(),
+                ALICE,
+                FIRST_REQUESTER_NONCE,
+                FIRST_SFX_INDEX,
+            );
 
             assert_eq!(
-                Circuit::get_x_exec_signals(xtx_id),
-                Some(XExecSignal {
-                    requester: AccountId32::new(hex!(
-                        "0101010101010101010101010101010101010101010101010101010101010101"
-                    )),
-                    timeouts_at: 401u32, // 400 offset + current block height 1 = 101
-                    delay_steps_at: None,
-                    status: CircuitStatus::PendingBidding,
-                    requester_nonce: FIRST_REQUESTER_NONCE,
-                    steps_cnt: (0, 1),
-                })
+                Circuit::get_sealed_bidding_phase(xtx_id),
+                Some(SealedBiddingPhase::Commit)
             );
 
-            // let xtx_id: sp_core::H256 = generate_xtx_id::<Hashing>(ALICE, FIRST_REQUESTER_NONCE);
-            //
-            // let sfx_id_a = valid_transfer_side_effect
-            //     .generate_id::<circuit_runtime_pallets::pallet_circuit::SystemHashing<Runtime>>(
-            //         &xtx_id.0,
-            //         FIRST_SFX_INDEX,
-            //     );
-            //
+            assert_noop!(
+                Circuit::bid_sfx(Origin::signed(BOB_RELAYER), sfx_id, BID_AMOUNT),
+                circuit_error::<Runtime>::BiddingRejectedSealedBidsOnly
+            );
 
-            let sfx_id = valid_transfer_side_effect
-                .generate_id::<circuit_runtime_pallets::pallet_circuit::SystemHashing<Runtime>>(
-                &xtx_id.0,
-                FIRST_SFX_INDEX,
+            let bob_salt = sp_core::H256::repeat_byte(2);
+            let charlie_salt = sp_core::H256::repeat_byte(3);
+            let bob_bid_hash = <Hashing as sp_runtime::traits::Hash>::hash(
+                &(&BOB_RELAYER, sfx_id, BID_AMOUNT, bob_salt).encode(),
+            );
+            assert_ok!(Circuit::commit_sfx_bid(
+                Origin::signed(BOB_RELAYER),
+                sfx_id,
+                bob_bid_hash,
+            ));
+            // Charlie copies the commitment of Bob
+            assert_ok!(Circuit::commit_sfx_bid(
+                Origin::signed(CHARLIE),
+                sfx_id,
+                bob_bid_hash,
+            ));
+            assert_eq!(
+                Balances::free_balance(&CHARLIE),
+                INITIAL_BALANCE - SEALED_BID_DEPOSIT
             );
 
-            place_winning_bid_and_advance_3_blocks(ALICE, xtx_id, sfx_id, 1);
+            assert_noop!(
+                Circuit::reveal_sfx_bid(Origin::signed(BOB_RELAYER), sfx_id, BID_AMOUNT, bob_salt),
+                circuit_error::<Runtime>::SealedBiddingRevealInactive
+            );
 
-            System::set_block_number(410);
+            // Commit phase is over
+            System::set_block_number(4);
+            <Circuit as frame_support::traits::OnInitialize<BlockNumber>>::on_initialize(4);
 
-            <Circuit as frame_support::traits::OnInitialize<BlockNumber>>::on_initialize(410);
+            assert_eq!(
+                Circuit::get_sealed_bidding_phase(xtx_id),
+                Some(SealedBiddingPhase::Reveal)
+            );
+            assert_eq!(
+                Circuit::get_x_exec_signals(xtx_id).unwrap().status,
+                CircuitStatus::PendingBidding
+            );
 
+            assert_noop!(
+                Circuit::reveal_sfx_bid(
+                    Origin::signed(BOB_RELAYER),
+                    sfx_id,
+                    BID_AMOUNT,
+                    charlie_salt
+                ),
+                circuit_error::<Runtime>::SealedBidRevealMismatch
+            );
+            assert_ok!(Circuit::reveal_sfx_bid(
+                Origin::signed(BOB_RELAYER),
+                sfx_id,
+                BID_AMOUNT,
+                bob_salt,
+            ));
+            // Copied commitment can't be revealed with the bid of Bob, even once it's public
+            assert_noop!(
+                Circuit::reveal_sfx_bid(Origin::signed(CHARLIE), sfx_id, BID_AMOUNT, bob_salt),
+                circuit_error::<Runtime>::SealedBidRevealMismatch
+            );
             assert_eq!(
-                Circuit::get_x_exec_signals(xtx_id),
-                Some(XExecSignal {
-                    requester: AccountId32::new(hex!(
-                        "0101010101010101010101010101010101010101010101010101010101010101"
-                    )),
-                    timeouts_at: 401u32,
-                    delay_steps_at: None,
-                    status: CircuitStatus::RevertTimedOut,
-                    requester_nonce: FIRST_REQUESTER_NONCE,
-                    steps_cnt: (0, 1),
-                })
+                Circuit::get_pending_sfx_bids(xtx_id, sfx_id)
+                    .unwrap()
+                    .executor,
+                BOB_RELAYER
             );
 
-            assert_eq!(Circuit::get_active_timing_links(xtx_id), None);
+            // Reveal phase is over
+            System::set_block_number(7);
+            <Circuit as frame_support::traits::OnInitialize<BlockNumber>>::on_initialize(7);
 
-            // Emits event notifying about cancellation
-            let mut events = System::events();
-            // assert_eq!(events.len(), 9);
             assert_eq!(
-                events.pop(),
-                Some(
-                    EventRecord {
-                        phase: Phase::Initialization,
-                        event: Event::Circuit(circuit_runtime_pallets::pallet_circuit::Event::<
-                            Runtime,
-                        >::XTransactionXtxRevertedAfterTimeOut(
-                            xtx_id
-                        )),
-                        topics: vec![]
-                    }
-                ),
+                Circuit::get_x_exec_signals(xtx_id).unwrap().status,
+                CircuitStatus::Ready
+            );
+            assert_eq!(
+                Circuit::get_full_side_effects(xtx_id).unwrap()[0][0]
+                    .best_bid
+                    .as_ref()
+                    .unwrap()
+                    .executor,
+                BOB_RELAYER
+            );
+            assert_eq!(Circuit::get_sealed_sfx_bids(sfx_id, CHARLIE), None);
+            // Charlie never revealed a bid - the deposit is forfeited
+            assert_eq!(
+                Balances::free_balance(&CHARLIE),
+                INITIAL_BALANCE - SEALED_BID_DEPOSIT
             );
-            // Voids all associated side effects with Xtx by setting their confirmation to Err
         });
 }
 
@@ -1908,7 +3696,10 @@ fn circuit_cancels_xtx_after_timeout() {
             let xtx_id: sp_core::H256 = generate_xtx_id::<Hashing>(ALICE, FIRST_REQUESTER_NONCE);
 
             // The tiemout links that will be checked at on_initialize are there
-            assert_eq!(Circuit::get_active_timing_links(xtx_id), Some(401u32)); // 100 offset + current block height 1 = 101
+            assert_eq!(
+                Circuit::get_xtx_timeouts_queue(401u32, (xtx_id, XtxTimeoutKind::Execution)),
+                Some(())
+            ); // 100 offset + current block height 1 = 101
 
             assert_eq!(
                 Circuit::get_x_exec_signals(xtx_id),
@@ -1942,7 +3733,10 @@ fn circuit_cancels_xtx_after_timeout() {
                 })
             );
 
-            assert_eq!(Circuit::get_active_timing_links(xtx_id), None);
+            assert_eq!(
+                Circuit::get_xtx_timeouts_queue(401u32, (xtx_id, XtxTimeoutKind::Execution)),
+                None
+            );
 
             // Emits event notifying about cancellation
             let mut events = System::events();
@@ -1968,6 +3762,195 @@ fn circuit_cancels_xtx_after_timeout() {
         });
 }
 
+#[test]
+fn cancel_xtx_dequeues_its_bidding_timeout() {
+    let transfer_protocol_box =
+        Box::new(t3rn_protocol::side_effects::standards::get_transfer_interface());
+    let mut local_state = LocalState::new();
+    let valid_transfer_side_effect = produce_and_validate_side_effect(
+        vec![
+            (Type::Address(32), ArgVariant::A),
+            (Type::Address(32), ArgVariant::B),
+            (Type::Uint(128), ArgVariant::A),
+            (Type::OptionalInsurance, ArgVariant::A), // empty bytes instead of insurance
+        ],
+        &mut local_state,
+        transfer_protocol_box,
+        ALICE,
+        FIRST_REQUESTER_NONCE,
+        FIRST_SFX_INDEX,
+    );
+
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 1 + 2);
+            System::set_block_number(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            assert_ok!(Circuit::on_extrinsic_trigger(
+                Origin::signed(ALICE),
+                vec![valid_transfer_side_effect],
+                true,
+            ));
+
+            let xtx_id: sp_core::H256 = generate_xtx_id::<Hashing>(ALICE, FIRST_REQUESTER_NONCE);
+            assert_eq!(
+                Circuit::get_xtx_timeouts_queue(4u32, (xtx_id, XtxTimeoutKind::Bidding)),
+                Some(())
+            );
+            assert_eq!(Circuit::get_xtx_bidding_timeouts_at(xtx_id), Some(4u32));
+            assert_eq!(XDNS::pending_xtx([0, 0, 0, 0]), 1);
+
+            assert_ok!(Circuit::cancel_xtx(Origin::signed(ALICE), xtx_id));
+
+            assert_eq!(
+                Circuit::get_xtx_timeouts_queue(4u32, (xtx_id, XtxTimeoutKind::Bidding)),
+                None
+            );
+            assert_eq!(Circuit::get_xtx_bidding_timeouts_at(xtx_id), None);
+            assert_eq!(Circuit::get_xtx_xdns_record_versions(xtx_id), None);
+            assert_eq!(XDNS::pending_xtx([0, 0, 0, 0]), 0);
+        });
+}
+
+#[test]
+fn timeouts_of_xtx_due_at_the_same_block_are_all_kept() {
+    let transfer_protocol_box =
+        Box::new(t3rn_protocol::side_effects::standards::get_transfer_interface());
+    let mut local_state = LocalState::new();
+    let valid_transfer_side_effect = produce_and_validate_side_effect(
+        vec![
+            (Type::Address(32), ArgVariant::A),
+            (Type::Address(32), ArgVariant::B),
+            (Type::Uint(128), ArgVariant::A),
+            (Type::OptionalInsurance, ArgVariant::A), // empty bytes instead of insurance
+        ],
+        &mut local_state,
+        transfer_protocol_box,
+        ALICE,
+        FIRST_REQUESTER_NONCE,
+        FIRST_SFX_INDEX,
+    );
+
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 1 + 2);
+            System::set_block_number(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            assert_ok!(Circuit::on_extrinsic_trigger(
+                Origin::signed(ALICE),
+                vec![valid_transfer_side_effect],
+                true,
+            ));
+
+            let xtx_id: sp_core::H256 = generate_xtx_id::<Hashing>(ALICE, FIRST_REQUESTER_NONCE);
+            XtxTimeoutsQueue::<Runtime>::insert(4u32, (xtx_id, XtxTimeoutKind::Execution), ());
+            assert_eq!(XtxTimeoutsQueue::<Runtime>::iter_prefix(4u32).count(), 2);
+
+            assert_ok!(Circuit::cancel_xtx(Origin::signed(ALICE), xtx_id));
+
+            assert_eq!(
+                Circuit::get_xtx_timeouts_queue(4u32, (xtx_id, XtxTimeoutKind::Bidding)),
+                None
+            );
+            assert_eq!(
+                Circuit::get_xtx_timeouts_queue(4u32, (xtx_id, XtxTimeoutKind::Execution)),
+                Some(())
+            );
+        });
+}
+
+#[test]
+fn migration_to_v1_moves_xtx_timeouts_maps_to_timeouts_queue() {
+    use circuit_runtime_pallets::pallet_circuit::migrations::v1::{
+        MigrateToV1, PendingXtxBidsTimeoutsMap, PendingXtxTimeoutsMap,
+    };
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            StorageVersion::new(0).put::<Circuit>();
+            System::set_block_number(10);
+
+            let xtx_a: sp_core::H256 = generate_xtx_id::<Hashing>(ALICE, FIRST_REQUESTER_NONCE);
+            let xtx_b: sp_core::H256 = generate_xtx_id::<Hashing>(ALICE, SECOND_REQUESTER_NONCE);
+            PendingXtxTimeoutsMap::<Runtime>::insert(xtx_a, 400u32);
+            PendingXtxTimeoutsMap::<Runtime>::insert(xtx_b, 5u32);
+            PendingXtxBidsTimeoutsMap::<Runtime>::insert(xtx_a, 12u32);
+
+            MigrateToV1::<Runtime>::on_runtime_upgrade();
+
+            assert_eq!(
+                Circuit::get_xtx_timeouts_queue(400u32, (xtx_a, XtxTimeoutKind::Execution)),
+                Some(())
+            );
+            // Overdue timeout expires right away
+            assert_eq!(
+                Circuit::get_xtx_timeouts_queue(10u32, (xtx_b, XtxTimeoutKind::Execution)),
+                Some(())
+            );
+            assert_eq!(
+                Circuit::get_xtx_timeouts_queue(12u32, (xtx_a, XtxTimeoutKind::Bidding)),
+                Some(())
+            );
+            assert_eq!(Circuit::get_xtx_bidding_timeouts_at(xtx_a), Some(12u32));
+            assert_eq!(PendingXtxTimeoutsMap::<Runtime>::iter().count(), 0);
+            assert_eq!(PendingXtxBidsTimeoutsMap::<Runtime>::iter().count(), 0);
+            assert_eq!(Circuit::get_xtx_timeouts_visited_until(), 9u32);
+            assert_eq!(Circuit::on_chain_storage_version(), 1);
+
+            // Runs once
+            PendingXtxTimeoutsMap::<Runtime>::insert(xtx_b, 20u32);
+            MigrateToV1::<Runtime>::on_runtime_upgrade();
+            assert_eq!(
+                Circuit::get_xtx_timeouts_queue(20u32, (xtx_b, XtxTimeoutKind::Execution)),
+                None
+            );
+        });
+}
+
+#[test]
+fn on_initialize_carries_timeouts_over_deletion_queue_limit_to_immediate_queue() {
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            // DeletionQueueLimit of 100 + 5 leftovers, none of them matching an existing Xtx
+            for i in 0..105u32 {
+                let xtx_id: sp_core::H256 = generate_xtx_id::<Hashing>(ALICE, i);
+                XtxTimeoutsQueue::<Runtime>::insert(5u32, (xtx_id, XtxTimeoutKind::Execution), ());
+            }
+
+            System::set_block_number(5);
+            <Circuit as frame_support::traits::OnInitialize<BlockNumber>>::on_initialize(5);
+
+            assert_eq!(XtxTimeoutsQueue::<Runtime>::iter_prefix(5u32).count(), 5);
+            assert_eq!(Circuit::get_immediate_xtx_timeouts_queue(), vec![5u32]);
+            assert_eq!(Circuit::get_xtx_timeouts_visited_until(), 5u32);
+
+            System::set_block_number(6);
+            <Circuit as frame_support::traits::OnInitialize<BlockNumber>>::on_initialize(6);
+
+            assert_eq!(XtxTimeoutsQueue::<Runtime>::iter_prefix(5u32).count(), 0);
+            assert_eq!(
+                Circuit::get_immediate_xtx_timeouts_queue(),
+                Vec::<u32>::new()
+            );
+            assert_eq!(Circuit::get_xtx_timeouts_visited_until(), 6u32);
+        });
+}
+
 #[test]
 fn load_local_state_can_generate_and_read_state() {
     let origin = Origin::signed(ALICE); // Only sudo access to register new gateways for now
@@ -35,6 +35,24 @@ pub trait WeightInfo {
     fn cancel_xtx() -> Weight;
     fn confirm_side_effect() -> Weight;
     fn execute_side_effects_with_xbi() -> Weight;
+    fn on_xcm_trigger() -> Weight;
+    fn set_trigger_auth_rights() -> Weight;
+    fn on_remote_gateway_trigger() -> Weight;
+    fn on_initialize_visit_timeouts_block() -> Weight;
+    fn on_initialize_bidding_timeout() -> Weight;
+    fn on_initialize_execution_timeout() -> Weight;
+    fn on_extrinsic_trigger_with_dfd() -> Weight;
+    fn on_extrinsic_trigger_with_steps() -> Weight;
+    fn on_extrinsic_trigger_scheduled() -> Weight;
+    fn on_initialize_scheduled_step() -> Weight;
+    fn challenge_side_effect_confirmation() -> Weight;
+    fn finalize_escrowed_side_effect() -> Weight;
+    fn on_initialize_escrow_finalization_timeout() -> Weight;
+    fn on_initialize_sfx_execution_timeout() -> Weight;
+    fn on_initialize_xdns_pins_release() -> Weight;
+    fn on_extrinsic_trigger_sealed_bids() -> Weight;
+    fn commit_sfx_bid() -> Weight;
+    fn reveal_sfx_bid() -> Weight;
 }
 
 /// Weights for pallet_circuit_circuit_portal using the Substrate node and recommended hardware.
@@ -63,6 +81,102 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     fn execute_side_effects_with_xbi() -> Weight {
         60_000_000_u64
     }
+
+    fn on_xcm_trigger() -> Weight {
+        60_000_000_u64
+    }
+
+    fn set_trigger_auth_rights() -> Weight {
+        (10_000_000_u64).saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    fn on_remote_gateway_trigger() -> Weight {
+        (60_000_000_u64)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    fn on_initialize_visit_timeouts_block() -> Weight {
+        (5_000_000_u64).saturating_add(T::DbWeight::get().reads(1_u64))
+    }
+
+    fn on_initialize_bidding_timeout() -> Weight {
+        (40_000_000_u64)
+            .saturating_add(T::DbWeight::get().reads(16_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+    }
+
+    fn on_initialize_execution_timeout() -> Weight {
+        (30_000_000_u64)
+            .saturating_add(T::DbWeight::get().reads(16_u64))
+            .saturating_add(T::DbWeight::get().writes(14_u64))
+    }
+
+    fn on_extrinsic_trigger_with_dfd() -> Weight {
+        60_000_000_u64
+    }
+
+    fn on_extrinsic_trigger_with_steps() -> Weight {
+        60_000_000_u64
+    }
+
+    fn on_extrinsic_trigger_scheduled() -> Weight {
+        60_000_000_u64
+    }
+
+    fn on_initialize_scheduled_step() -> Weight {
+        (20_000_000_u64)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+
+    fn challenge_side_effect_confirmation() -> Weight {
+        (60_000_000_u64)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+
+    fn finalize_escrowed_side_effect() -> Weight {
+        (60_000_000_u64)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+
+    fn on_initialize_escrow_finalization_timeout() -> Weight {
+        (20_000_000_u64)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    fn on_initialize_sfx_execution_timeout() -> Weight {
+        (40_000_000_u64)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+    }
+
+    fn on_initialize_xdns_pins_release() -> Weight {
+        (15_000_000_u64)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+
+    fn on_extrinsic_trigger_sealed_bids() -> Weight {
+        (60_000_000_u64)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+
+    fn commit_sfx_bid() -> Weight {
+        (50_000_000_u64)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+
+    fn reveal_sfx_bid() -> Weight {
+        (60_000_000_u64)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
 }
 
 // For backwards compatibility and tests
@@ -90,4 +204,100 @@ impl WeightInfo for () {
     fn execute_side_effects_with_xbi() -> Weight {
         60_000_000_u64
     }
+
+    fn on_xcm_trigger() -> Weight {
+        60_000_000_u64
+    }
+
+    fn set_trigger_auth_rights() -> Weight {
+        (10_000_000_u64).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn on_remote_gateway_trigger() -> Weight {
+        (60_000_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn on_initialize_visit_timeouts_block() -> Weight {
+        (5_000_000_u64).saturating_add(RocksDbWeight::get().reads(1_u64))
+    }
+
+    fn on_initialize_bidding_timeout() -> Weight {
+        (40_000_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(16_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
+    }
+
+    fn on_initialize_execution_timeout() -> Weight {
+        (30_000_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(16_u64))
+            .saturating_add(RocksDbWeight::get().writes(14_u64))
+    }
+
+    fn on_extrinsic_trigger_with_dfd() -> Weight {
+        60_000_000_u64
+    }
+
+    fn on_extrinsic_trigger_with_steps() -> Weight {
+        60_000_000_u64
+    }
+
+    fn on_extrinsic_trigger_scheduled() -> Weight {
+        60_000_000_u64
+    }
+
+    fn on_initialize_scheduled_step() -> Weight {
+        (20_000_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+
+    fn challenge_side_effect_confirmation() -> Weight {
+        (60_000_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+
+    fn finalize_escrowed_side_effect() -> Weight {
+        (60_000_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+
+    fn on_initialize_escrow_finalization_timeout() -> Weight {
+        (20_000_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn on_initialize_sfx_execution_timeout() -> Weight {
+        (40_000_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
+    }
+
+    fn on_initialize_xdns_pins_release() -> Weight {
+        (15_000_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+
+    fn on_extrinsic_trigger_sealed_bids() -> Weight {
+        (60_000_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+    }
+
+    fn commit_sfx_bid() -> Weight {
+        (50_000_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+
+    fn reveal_sfx_bid() -> Weight {
+        (60_000_000_u64)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
 }
@@ -0,0 +1,21 @@
+//! Runtime API definition required by Clock RPC extensions.
+//!
+//! This API should be imported and implemented by the runtime,
+//! of a node that wants to use the custom RPC extension
+//! adding Clock access methods.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{alloc::vec::Vec, Codec};
+pub use t3rn_primitives::claimable::PendingClaim;
+
+sp_api::decl_runtime_apis! {
+    /// The API to interact with pallet Clock
+    pub trait ClockRuntimeApi<AccountId, Balance> where
+        AccountId: Codec,
+        Balance: Codec,
+    {
+        /// Returns the balances pending to be claimed by the account
+        fn fetch_pending_claims(beneficiary: AccountId) -> Vec<PendingClaim<Balance, u32>>;
+    }
+}
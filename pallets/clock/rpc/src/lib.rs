@@ -0,0 +1,77 @@
+//! RPC interface for the Clock pallet.
+
+use codec::Codec;
+use jsonrpsee::{
+    core::{async_trait, Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::CallError,
+};
+pub use pallet_clock_rpc_runtime_api::ClockRuntimeApi;
+use pallet_clock_rpc_runtime_api::PendingClaim;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+    generic::BlockId,
+    traits::{Block as BlockT, MaybeDisplay},
+};
+use std::sync::Arc;
+
+const RUNTIME_ERROR: i64 = 1;
+
+#[rpc(client, server)]
+pub trait ClockApi<AccountId, Balance> {
+    /// Returns the balances pending to be claimed by the account
+    #[method(name = "clock_fetchPendingClaims")]
+    fn fetch_pending_claims(
+        &self,
+        beneficiary: AccountId,
+    ) -> RpcResult<Vec<PendingClaim<Balance, u32>>>;
+}
+
+/// A struct that implements the [`ClockApiServer`].
+pub struct Clock<C, P> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> Clock<C, P> {
+    /// Create new `Clock` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl<C, Block, AccountId, Balance> ClockApiServer<AccountId, Balance> for Clock<C, Block>
+where
+    AccountId: Codec + MaybeDisplay,
+    Balance: Codec + MaybeDisplay,
+    Block: BlockT,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: ClockRuntimeApi<Block, AccountId, Balance>,
+{
+    fn fetch_pending_claims(
+        &self,
+        beneficiary: AccountId,
+    ) -> RpcResult<Vec<PendingClaim<Balance, u32>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(self.client.info().best_hash);
+
+        let result = api
+            .fetch_pending_claims(&at, beneficiary)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(result)
+    }
+}
+
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> JsonRpseeError {
+    JsonRpseeError::Call(CallError::Custom(jsonrpsee::types::ErrorObject::owned(
+        RUNTIME_ERROR as i32,
+        "Runtime Error - Clock RPC",
+        Some(format!("{:?}", err)),
+    )))
+}
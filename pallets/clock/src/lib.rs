@@ -14,9 +14,9 @@ use frame_support::{pallet_prelude::Weight, traits::Get};
 pub use t3rn_primitives::{
     abi::{GatewayABIConfig, Type},
     account_manager::AccountManager,
-    claimable::ClaimableArtifacts,
+    claimable::{BenefitSource, CircuitRole, ClaimableArtifacts, PendingClaim},
     clock::Clock,
-    common::RoundInfo,
+    common::{RoundIndex, RoundInfo},
     executors::Executors,
     protocol::SideEffectProtocol,
     transfers::EscrowedBalanceOf,
@@ -31,7 +31,8 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
-mod weights;
+pub mod migrations;
+pub mod weights;
 
 // Definition of the pallet logic, to be aggregated at runtime definition through
 // `construct_runtime`.
@@ -43,7 +44,8 @@ pub mod pallet {
     use frame_system::pallet_prelude::*;
     use sp_std::{prelude::*, vec};
 
-    use sp_runtime::traits::Zero;
+    use sp_runtime::traits::{Saturating, Zero};
+    use weights::WeightInfo;
 
     #[pallet::config]
     pub trait Config: frame_system::Config + pallet_account_manager::Config {
@@ -63,12 +65,30 @@ pub mod pallet {
             Self::BlockNumber,
             u32,
         >;
+
+        /// Account receiving the claimable balances left unclaimed until expiry
+        type TreasuryAccount: Get<Self::AccountId>;
+
+        /// Number of rounds after which unclaimed balances expire to the treasury
+        #[pallet::constant]
+        type ClaimExpiryRounds: Get<RoundIndex>;
+
+        /// Maximum number of accounts checked for expired claims per block
+        #[pallet::constant]
+        type MaxClaimExpiriesPerBlock: Get<u32>;
+
+        /// Weight information for extrinsics in this pallet
+        type WeightInfo: weights::WeightInfo;
     }
 
     // Simple declaration of the `Pallet` type. It is placeholder we use to implement traits and
     // method.
+    /// The current storage version, bumped by the migrations of `crate::migrations`
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
     #[pallet::generate_store(pub (super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(PhantomData<T>);
 
@@ -81,7 +101,7 @@ pub mod pallet {
         _,
         Identity,
         RoundInfo<T::BlockNumber>,
-        Vec<ClaimableArtifacts<T::AccountId, BalanceOf<T>>>,
+        Vec<ClaimableArtifacts<T::AccountId, BalanceOf<T>, u32>>,
     >;
 
     #[pallet::storage]
@@ -89,6 +109,26 @@ pub mod pallet {
     /// Information on the current round.
     pub type CurrentRound<T: Config> = StorageValue<_, RoundInfo<T::BlockNumber>, ValueQuery>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn pending_claims)]
+    /// Claimable balances of accounts accumulated across rounds per role, source and asset.
+    pub type PendingClaims<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Vec<PendingClaim<BalanceOf<T>, u32>>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    /// Accounts to check for expired claims at the start of the round.
+    pub type ClaimExpiries<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, RoundIndex, Blake2_128Concat, T::AccountId, ()>;
+
+    #[pallet::storage]
+    /// Oldest round with claim expiries left to check, if any.
+    pub type ClaimExpiriesCursor<T: Config> = StorageValue<_, RoundIndex, OptionQuery>;
+
     impl<T: Config> Pallet<T> {
         fn calculate_claimable_for_round(n: T::BlockNumber) -> DispatchResult {
             // fixme: move current_round from treasury to circuit-clock
@@ -102,6 +142,150 @@ pub mod pallet {
 
             ClaimableArtifactsPerRound::<T>::insert(r, claimable_artifacts.clone());
             // todo: aggregated claimable_artifacts to TotalClaimablePerRound
+            for artifact in claimable_artifacts {
+                Self::accrue_claimable(artifact, r.index);
+            }
+            Ok(())
+        }
+
+        /// Adds the claimable of the round to the account's pending claim of the same role, source and asset.
+        /// Each accrual restarts the expiry of the claim.
+        pub(crate) fn accrue_claimable(
+            artifact: ClaimableArtifacts<T::AccountId, BalanceOf<T>, u32>,
+            round: RoundIndex,
+        ) {
+            if artifact.total_round_claim.is_zero() {
+                return
+            }
+            <PendingClaims<T>>::mutate(&artifact.beneficiary, |claims| {
+                match claims.iter_mut().find(|claim| {
+                    claim.role == artifact.role
+                        && claim.source == artifact.benefit_source
+                        && claim.maybe_asset_id == artifact.maybe_asset_id
+                }) {
+                    Some(claim) => {
+                        claim.amount = claim.amount.saturating_add(artifact.total_round_claim);
+                        claim.since_round = round;
+                    },
+                    None => claims.push(PendingClaim {
+                        role: artifact.role,
+                        source: artifact.benefit_source,
+                        maybe_asset_id: artifact.maybe_asset_id,
+                        amount: artifact.total_round_claim,
+                        since_round: round,
+                    }),
+                }
+            });
+            <ClaimExpiries<T>>::insert(
+                round.saturating_add(T::ClaimExpiryRounds::get()),
+                &artifact.beneficiary,
+                (),
+            );
+        }
+
+        /// Checks up to `MaxClaimExpiriesPerBlock` accounts due for the claim expiry by the current round,
+        ///     resuming from the oldest round left unchecked by the previous blocks.
+        /// Returns the number of accounts and rounds checked.
+        pub(crate) fn expire_claims(now: RoundIndex) -> u32 {
+            let limit = T::MaxClaimExpiriesPerBlock::get();
+            let mut checked = 0u32;
+            let mut cursor = <ClaimExpiriesCursor<T>>::get();
+            while let Some(round) = cursor {
+                if checked >= limit {
+                    break
+                }
+                let beneficiaries = <ClaimExpiries<T>>::iter_key_prefix(round)
+                    .take(limit.saturating_sub(checked) as usize)
+                    .collect::<Vec<_>>();
+                // visiting a round counts towards the limit even if no account is left in it
+                checked = checked.saturating_add((beneficiaries.len() as u32).max(1));
+                for beneficiary in beneficiaries {
+                    <ClaimExpiries<T>>::remove(round, &beneficiary);
+                    Self::expire_claims_of(&beneficiary, now);
+                }
+                if <ClaimExpiries<T>>::iter_key_prefix(round).next().is_some() {
+                    break
+                }
+                cursor = if round < now {
+                    Some(round.saturating_add(1))
+                } else {
+                    None
+                };
+            }
+            <ClaimExpiriesCursor<T>>::set(cursor);
+            checked
+        }
+
+        /// Moves the claims of the beneficiary unclaimed for `ClaimExpiryRounds` to the treasury.
+        fn expire_claims_of(beneficiary: &T::AccountId, now: RoundIndex) {
+            let expiry = T::ClaimExpiryRounds::get();
+            let mut claims = <PendingClaims<T>>::get(beneficiary);
+            claims.retain(|claim| {
+                if claim.since_round.saturating_add(expiry) > now {
+                    return true
+                }
+                T::AccountManager::deposit_immediately(
+                    &T::TreasuryAccount::get(),
+                    claim.amount,
+                    claim.maybe_asset_id,
+                );
+                Self::deposit_event(Event::ClaimExpired {
+                    beneficiary: beneficiary.clone(),
+                    role: claim.role.clone(),
+                    source: claim.source.clone(),
+                    maybe_asset_id: claim.maybe_asset_id,
+                    amount: claim.amount,
+                });
+                false
+            });
+            if claims.is_empty() {
+                <PendingClaims<T>>::remove(beneficiary);
+            } else {
+                <PendingClaims<T>>::insert(beneficiary, claims);
+            }
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Pays out the balances pending for the caller in the role from the source, in all assets accrued.
+        #[pallet::weight(<T as Config>::WeightInfo::claim())]
+        pub fn claim(
+            origin: OriginFor<T>,
+            role: CircuitRole,
+            source: BenefitSource,
+        ) -> DispatchResult {
+            let beneficiary = ensure_signed(origin)?;
+
+            let (claimed, pending): (Vec<_>, Vec<_>) = <PendingClaims<T>>::get(&beneficiary)
+                .into_iter()
+                .partition(|claim| claim.role == role && claim.source == source);
+
+            if claimed.is_empty() {
+                return Err(Error::<T>::NoPendingClaims.into())
+            }
+
+            if pending.is_empty() {
+                <PendingClaims<T>>::remove(&beneficiary);
+            } else {
+                <PendingClaims<T>>::insert(&beneficiary, pending);
+            }
+
+            for claim in claimed {
+                T::AccountManager::deposit_immediately(
+                    &beneficiary,
+                    claim.amount,
+                    claim.maybe_asset_id,
+                );
+                Self::deposit_event(Event::Claimed {
+                    beneficiary: beneficiary.clone(),
+                    role: claim.role,
+                    source: claim.source,
+                    maybe_asset_id: claim.maybe_asset_id,
+                    amount: claim.amount,
+                });
+            }
+
             Ok(())
         }
     }
@@ -109,12 +293,28 @@ pub mod pallet {
     // Pallet implements [`Hooks`] trait to define some logic to execute in some context.
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_runtime_upgrade() -> Weight {
+            <crate::migrations::v1::MigrateToV1<T> as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade()
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<(), &'static str> {
+            <crate::migrations::v1::MigrateToV1<T> as frame_support::traits::OnRuntimeUpgrade>::pre_upgrade()
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            <crate::migrations::v1::MigrateToV1<T> as frame_support::traits::OnRuntimeUpgrade>::post_upgrade()
+        }
+
         // `on_finalize` is executed at the end of block after all extrinsic are dispatched.
         fn on_finalize(n: T::BlockNumber) {
             // Perform necessary data/state clean up here.
 
             if n % T::RoundDuration::get() == T::BlockNumber::zero() {
-                Self::calculate_claimable_for_round(n);
+                if let Err(err) = Self::calculate_claimable_for_round(n) {
+                    log::error!("Could not calculate claimable for round: {:?}", err);
+                }
                 // After the rewards has been recalculate it's safe to shuffle the executors orded and stakes
                 <T as Config>::Executors::recalculate_executors_stakes();
             }
@@ -126,26 +326,31 @@ pub mod pallet {
         // This function must return the weight consumed by `on_initialize` and `on_finalize`.
         fn on_initialize(n: BlockNumberFor<T>) -> Weight {
             let term = T::RoundDuration::get();
+            let mut current_round = <CurrentRound<T>>::get();
 
             if n % term == T::BlockNumber::zero() {
-                let past_round = <CurrentRound<T>>::get();
-
-                let new_round = RoundInfo {
-                    index: past_round.index.saturating_add(1),
+                current_round = RoundInfo {
+                    index: current_round.index.saturating_add(1),
                     head: n,
                     term,
                 };
 
-                <CurrentRound<T>>::put(new_round);
+                <CurrentRound<T>>::put(current_round);
+
+                if <ClaimExpiriesCursor<T>>::get().is_none() {
+                    <ClaimExpiriesCursor<T>>::put(current_round.index);
+                }
 
                 Self::deposit_event(Event::NewRound {
-                    index: new_round.index,
-                    head: new_round.head,
-                    term: new_round.term,
+                    index: current_round.index,
+                    head: current_round.head,
+                    term: current_round.term,
                 });
             }
 
-            99 //TODO T::WeightInfo::on_initialize()
+            let checked = Self::expire_claims(current_round.index);
+
+            T::WeightInfo::on_initialize().saturating_add(T::WeightInfo::expire_claims(checked))
         }
 
         // A runtime code run after every block and have access to extended set of APIs.
@@ -168,10 +373,26 @@ pub mod pallet {
             head: T::BlockNumber,
             term: T::BlockNumber,
         },
+        Claimed {
+            beneficiary: T::AccountId,
+            role: CircuitRole,
+            source: BenefitSource,
+            maybe_asset_id: Option<u32>,
+            amount: BalanceOf<T>,
+        },
+        ClaimExpired {
+            beneficiary: T::AccountId,
+            role: CircuitRole,
+            source: BenefitSource,
+            maybe_asset_id: Option<u32>,
+            amount: BalanceOf<T>,
+        },
     }
 
     #[pallet::error]
-    pub enum Error<T> {}
+    pub enum Error<T> {
+        NoPendingClaims,
+    }
 
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
@@ -194,6 +415,15 @@ pub mod pallet {
         fn build(&self) {}
     }
 
+    impl<T: Config> Pallet<T> {
+        /// Returns the balances pending to be claimed by the account
+        pub fn fetch_pending_claims(
+            beneficiary: T::AccountId,
+        ) -> Vec<PendingClaim<BalanceOf<T>, u32>> {
+            <PendingClaims<T>>::get(beneficiary)
+        }
+    }
+
     impl<T: Config> Clock<T> for Pallet<T> {
         fn current_round() -> RoundInfo<T::BlockNumber> {
             Self::current_round()
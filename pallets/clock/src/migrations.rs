@@ -0,0 +1,96 @@
+//! Storage migrations of the Clock pallet - claimable artifacts carrying their asset.
+
+use crate::{BalanceOf, ClaimableArtifactsPerRound, Config, Pallet};
+use codec::{Decode, Encode};
+use frame_support::{
+    traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+    weights::Weight,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+use t3rn_primitives::claimable::{BenefitSource, CircuitRole, ClaimableArtifacts};
+
+#[cfg(feature = "try-runtime")]
+use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+pub mod v1 {
+    use super::*;
+
+    /// Claimable artifact as stored before claimables were collected in the reward asset.
+    #[derive(Encode, Decode)]
+    pub struct OldClaimableArtifacts<Account, Balance> {
+        pub beneficiary: Account,
+        pub role: CircuitRole,
+        pub total_round_claim: Balance,
+        pub benefit_source: BenefitSource,
+    }
+
+    /// Translates ClaimableArtifactsPerRound to artifacts carrying their asset.
+    ///     Artifacts collected before were all in the native currency - `maybe_asset_id: None`.
+    pub struct MigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() != 0 {
+                return T::DbWeight::get().reads(1)
+            }
+
+            let mut translated: u64 = 0;
+            <ClaimableArtifactsPerRound<T>>::translate_values::<
+                Vec<OldClaimableArtifacts<T::AccountId, BalanceOf<T>>>,
+                _,
+            >(|old_artifacts| {
+                translated = translated.saturating_add(1);
+                Some(
+                    old_artifacts
+                        .into_iter()
+                        .map(|old| ClaimableArtifacts {
+                            beneficiary: old.beneficiary,
+                            role: old.role,
+                            total_round_claim: old.total_round_claim,
+                            maybe_asset_id: None,
+                            benefit_source: old.benefit_source,
+                        })
+                        .collect(),
+                )
+            });
+            StorageVersion::new(1).put::<Pallet<T>>();
+
+            log::info!(
+                target: "runtime::clock",
+                "Translated claimable artifacts of {} rounds to the native asset",
+                translated
+            );
+
+            T::DbWeight::get()
+                .reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<(), &'static str> {
+            if Pallet::<T>::on_chain_storage_version() == 0 {
+                Self::set_temp_storage(
+                    <ClaimableArtifactsPerRound<T>>::iter_keys().count() as u32,
+                    "rounds",
+                );
+            }
+            Ok(())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            if let Some(rounds) = Self::get_temp_storage::<u32>("rounds") {
+                frame_support::ensure!(
+                    <ClaimableArtifactsPerRound<T>>::iter().count() as u32 == rounds,
+                    "Rounds lost while translating ClaimableArtifactsPerRound"
+                );
+                frame_support::ensure!(
+                    <ClaimableArtifactsPerRound<T>>::iter_values()
+                        .flatten()
+                        .all(|artifact| artifact.maybe_asset_id.is_none()),
+                    "Translated claimable artifacts must be in the native asset"
+                );
+            }
+            Ok(())
+        }
+    }
+}
@@ -1 +1,213 @@
+use circuit_mock_runtime::*;
+use circuit_runtime_pallets::pallet_clock::{
+    ClaimExpiries, ClaimExpiriesCursor, Config as ClockConfig, CurrentRound, Error as clock_error,
+    PendingClaims,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{Get, OnInitialize},
+};
+use t3rn_primitives::{
+    claimable::{BenefitSource, CircuitRole, ClaimableArtifacts, PendingClaim},
+    common::RoundInfo,
+};
 
+fn pending_executor_claim(amount: Balance, since_round: u32) -> PendingClaim<Balance, u32> {
+    PendingClaim {
+        role: CircuitRole::Executor,
+        source: BenefitSource::TrafficRewards,
+        maybe_asset_id: None,
+        amount,
+        since_round,
+    }
+}
+
+#[test]
+fn claim_pays_out_pending_claims_of_role_and_source() {
+    ExtBuilder::default().build().execute_with(|| {
+        let staker_claim = PendingClaim {
+            role: CircuitRole::Staker,
+            ..pending_executor_claim(50, 1)
+        };
+        <PendingClaims<Runtime>>::insert(
+            &ALICE,
+            vec![pending_executor_claim(100, 1), staker_claim.clone()],
+        );
+
+        assert_ok!(Clock::claim(
+            Origin::signed(ALICE),
+            CircuitRole::Executor,
+            BenefitSource::TrafficRewards,
+        ));
+
+        assert_eq!(Balances::free_balance(&ALICE), 100);
+        assert_eq!(Clock::fetch_pending_claims(ALICE), vec![staker_claim]);
+
+        assert_noop!(
+            Clock::claim(
+                Origin::signed(ALICE),
+                CircuitRole::Executor,
+                BenefitSource::TrafficRewards,
+            ),
+            clock_error::<Runtime>::NoPendingClaims
+        );
+    });
+}
+
+#[test]
+fn unclaimed_claims_expire_to_treasury_at_new_round() {
+    ExtBuilder::default().build().execute_with(|| {
+        let round_duration = <Runtime as ClockConfig>::RoundDuration::get();
+        let claim_expiry_rounds = <Runtime as ClockConfig>::ClaimExpiryRounds::get();
+
+        <CurrentRound<Runtime>>::put(RoundInfo {
+            index: claim_expiry_rounds,
+            head: 1,
+            term: round_duration,
+        });
+        <PendingClaims<Runtime>>::insert(&ALICE, vec![pending_executor_claim(100, 1)]);
+        <ClaimExpiries<Runtime>>::insert(1 + claim_expiry_rounds, &ALICE, ());
+
+        <Clock as OnInitialize<BlockNumber>>::on_initialize(round_duration);
+
+        assert_eq!(Clock::current_round().index, 1 + claim_expiry_rounds);
+        assert_eq!(Clock::fetch_pending_claims(ALICE), vec![]);
+        assert_eq!(Balances::free_balance(&ALICE), 0);
+        assert_eq!(
+            Balances::free_balance(&<Runtime as ClockConfig>::TreasuryAccount::get()),
+            100
+        );
+    });
+}
+
+fn executor_claimable(
+    beneficiary: AccountId,
+    amount: Balance,
+) -> ClaimableArtifacts<AccountId, Balance, u32> {
+    ClaimableArtifacts {
+        beneficiary,
+        role: CircuitRole::Executor,
+        total_round_claim: amount,
+        maybe_asset_id: None,
+        benefit_source: BenefitSource::TrafficRewards,
+    }
+}
+
+fn start_round(index: u32) {
+    let round_duration = <Runtime as ClockConfig>::RoundDuration::get();
+    <CurrentRound<Runtime>>::put(RoundInfo {
+        index: index - 1,
+        head: 1,
+        term: round_duration,
+    });
+    <Clock as OnInitialize<BlockNumber>>::on_initialize(round_duration);
+}
+
+#[test]
+fn accruals_restart_the_claim_expiry() {
+    ExtBuilder::default().build().execute_with(|| {
+        let claim_expiry_rounds = <Runtime as ClockConfig>::ClaimExpiryRounds::get();
+        let last_accrual_round = claim_expiry_rounds;
+
+        Clock::accrue_claimable(executor_claimable(ALICE, 100), 1);
+        Clock::accrue_claimable(executor_claimable(ALICE, 50), last_accrual_round);
+
+        // the first accrual would have expired by now
+        start_round(1 + claim_expiry_rounds);
+        assert_eq!(
+            Clock::fetch_pending_claims(ALICE),
+            vec![pending_executor_claim(150, last_accrual_round)]
+        );
+
+        start_round(last_accrual_round + claim_expiry_rounds);
+        assert_eq!(Clock::fetch_pending_claims(ALICE), vec![]);
+        assert_eq!(
+            Balances::free_balance(&<Runtime as ClockConfig>::TreasuryAccount::get()),
+            150
+        );
+    });
+}
+
+#[test]
+fn claim_expiries_are_checked_up_to_the_limit_per_block() {
+    ExtBuilder::default().build().execute_with(|| {
+        let claim_expiry_rounds = <Runtime as ClockConfig>::ClaimExpiryRounds::get();
+        let max_expiries = <Runtime as ClockConfig>::MaxClaimExpiriesPerBlock::get();
+        let beneficiaries = (0..=max_expiries)
+            .map(|i| AccountId::new([100u8.saturating_add(i as u8); 32]))
+            .collect::<Vec<_>>();
+        for beneficiary in beneficiaries.iter() {
+            Clock::accrue_claimable(executor_claimable(beneficiary.clone(), 1), 1);
+        }
+
+        start_round(1 + claim_expiry_rounds);
+        assert_eq!(
+            <ClaimExpiries<Runtime>>::iter_prefix(1 + claim_expiry_rounds).count(),
+            1
+        );
+        assert_eq!(
+            <ClaimExpiriesCursor<Runtime>>::get(),
+            Some(1 + claim_expiry_rounds)
+        );
+
+        // the next block resumes the round left unchecked
+        <Clock as OnInitialize<BlockNumber>>::on_initialize(
+            <Runtime as ClockConfig>::RoundDuration::get() + 1,
+        );
+        assert_eq!(
+            <ClaimExpiries<Runtime>>::iter_prefix(1 + claim_expiry_rounds).count(),
+            0
+        );
+        assert_eq!(<ClaimExpiriesCursor<Runtime>>::get(), None);
+        for beneficiary in beneficiaries {
+            assert_eq!(Clock::fetch_pending_claims(beneficiary), vec![]);
+        }
+        assert_eq!(
+            Balances::free_balance(&<Runtime as ClockConfig>::TreasuryAccount::get()),
+            (max_expiries + 1) as Balance
+        );
+    });
+}
+
+#[test]
+fn migration_to_v1_translates_claimable_artifacts_to_native_asset() {
+    use circuit_runtime_pallets::pallet_clock::{
+        migrations::v1::{MigrateToV1, OldClaimableArtifacts},
+        ClaimableArtifactsPerRound,
+    };
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+    use t3rn_primitives::claimable::ClaimableArtifacts;
+
+    ExtBuilder::default().build().execute_with(|| {
+        StorageVersion::new(0).put::<Clock>();
+
+        let round = RoundInfo {
+            index: 1,
+            head: 0,
+            term: <Runtime as ClockConfig>::RoundDuration::get(),
+        };
+        frame_support::storage::unhashed::put(
+            &<ClaimableArtifactsPerRound<Runtime>>::hashed_key_for(round),
+            &vec![OldClaimableArtifacts::<AccountId, Balance> {
+                beneficiary: ALICE,
+                role: CircuitRole::Executor,
+                total_round_claim: 100,
+                benefit_source: BenefitSource::TrafficRewards,
+            }],
+        );
+
+        MigrateToV1::<Runtime>::on_runtime_upgrade();
+
+        assert_eq!(
+            <ClaimableArtifactsPerRound<Runtime>>::get(round),
+            Some(vec![ClaimableArtifacts {
+                beneficiary: ALICE,
+                role: CircuitRole::Executor,
+                total_round_claim: 100,
+                maybe_asset_id: None,
+                benefit_source: BenefitSource::TrafficRewards,
+            }])
+        );
+        assert_eq!(Clock::on_chain_storage_version(), 1);
+    });
+}
@@ -9,12 +9,58 @@ use frame_support::{
 };
 use sp_std::marker::PhantomData;
 
-/// Weight functions needed for pallet_xdns.
-pub trait WeightInfo {}
+/// Weight functions needed for pallet_clock.
+pub trait WeightInfo {
+    fn claim() -> Weight;
+    fn on_initialize() -> Weight;
+    fn expire_claims(x: u32) -> Weight;
+}
 
-/// Weights for pallet_xdns using the Substrate node and recommended hardware.
+/// Weights for pallet_clock using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
-impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {}
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    fn claim() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+
+    fn on_initialize() -> Weight {
+        (6_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+
+    fn expire_claims(x: u32) -> Weight {
+        (5_000_000 as Weight)
+            .saturating_add((25_000_000 as Weight).saturating_mul(x as Weight))
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(x as Weight)))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(x as Weight)))
+    }
+}
 
 // For backwards compatibility and tests
-impl WeightInfo for () {}
+impl WeightInfo for () {
+    fn claim() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(2 as Weight))
+    }
+
+    fn on_initialize() -> Weight {
+        (6_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(2 as Weight))
+    }
+
+    fn expire_claims(x: u32) -> Weight {
+        (5_000_000 as Weight)
+            .saturating_add((25_000_000 as Weight).saturating_mul(x as Weight))
+            .saturating_add(RocksDbWeight::get().reads(1 as Weight))
+            .saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(x as Weight)))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+            .saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(x as Weight)))
+    }
+}
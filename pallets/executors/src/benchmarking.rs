@@ -1,9 +1,152 @@
-use super::*;
-
-#[allow(unused)]
-use crate::Pallet as Staking;
-use frame_benchmarking::{benchmarks, whitelisted_caller};
+use crate::pallet::{Pallet as Staking, *};
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::{Currency, Get, Hooks};
 use frame_system::RawOrigin;
+use sp_runtime::traits::{Hash, Saturating, Zero};
+use sp_std::prelude::*;
+use t3rn_primitives::{
+    account_manager::{AccountManager, Outcome},
+    claimable::{BenefitSource, CircuitRole},
+    clock::Clock,
+    executors::{Bond, DelayedPayout, ExecutorSnapshot},
+};
+
+const SEED: u32 = 0;
+const MAX_EXECUTORS: u32 = 128;
+const MAX_STAKERS: u32 = 300;
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+    let who: T::AccountId = account(name, index, SEED);
+    let fixtures = <Fixtures<T>>::get();
+    let funds = fixtures
+        .min_candidate_bond
+        .saturating_add(fixtures.min_atomic_stake)
+        .saturating_mul(BalanceOf::<T>::from(10u32));
+    T::Currency::make_free_balance_be(&who, funds);
+    who
+}
+
+fn snapshot_of<T: Config>(stakers: u32) -> ExecutorSnapshot<T::AccountId, BalanceOf<T>> {
+    let amount = BalanceOf::<T>::from(100u32);
+    let stakes = (0..stakers)
+        .map(|i| Bond {
+            owner: funded_account::<T>("staker", i),
+            amount,
+        })
+        .collect::<Vec<_>>();
+    ExecutorSnapshot {
+        bond: amount,
+        total: amount.saturating_mul(BalanceOf::<T>::from(stakers.saturating_add(1))),
+        stakes,
+    }
+}
+
+benchmarks! {
+    base_on_initialize {
+        let round = T::Treasury::current_round();
+        <LastRound<T>>::put(round);
+    }: {
+        Staking::<T>::on_initialize(T::BlockNumber::zero());
+    }
+
+    round_transition_on_initialize {
+        let x in 1 .. MAX_EXECUTORS;
+        let y in 0 .. MAX_STAKERS;
+        let bond = <Fixtures<T>>::get().min_candidate_bond;
+        let stake = <Fixtures<T>>::get().min_atomic_stake;
+        let mut candidates = vec![];
+        for i in 0..x {
+            let candidate = funded_account::<T>("candidate", i);
+            Staking::<T>::join_candidates(RawOrigin::Signed(candidate.clone()).into(), bond, i)?;
+            candidates.push(candidate);
+        }
+        for i in 0..y {
+            let staker = funded_account::<T>("staker", i);
+            let candidate = candidates[(i % x) as usize].clone();
+            let candidate_stake_count = i / x;
+            Staking::<T>::stake(
+                RawOrigin::Signed(staker).into(),
+                candidate,
+                stake,
+                candidate_stake_count,
+                0,
+            )?;
+        }
+        let round = T::Treasury::current_round().index.saturating_add(1);
+    }: {
+        Staking::<T>::select_active_set(round);
+    }
+    verify {
+        assert!(<AtStake<T>>::iter_prefix(round).next().is_some());
+    }
+
+    award_points {
+        let x in 1 .. MAX_EXECUTORS;
+        let round = T::Treasury::current_round();
+        let requester = funded_account::<T>("requester", 0);
+        let reward = BalanceOf::<T>::from(100u32);
+        for i in 0..x {
+            let executor = funded_account::<T>("executor", i);
+            let charge_id = T::Hashing::hash_of(&(round.index, i));
+            T::AccountManager::deposit(
+                charge_id,
+                &requester,
+                Zero::zero(),
+                reward,
+                BenefitSource::TrafficRewards,
+                CircuitRole::Executor,
+                Some(executor.clone()),
+                None,
+            )?;
+            T::AccountManager::finalize(charge_id, Outcome::Commit, None, None)?;
+            <AtStake<T>>::insert(round.index, executor, snapshot_of::<T>(0));
+        }
+    }: {
+        Staking::<T>::award_points(round);
+    }
+    verify {
+        assert!(!<Points<T>>::get(round.index).is_zero());
+    }
+
+    prepare_staking_payouts {
+        let delay = T::RewardPaymentDelay::get();
+        let now = delay.saturating_add(1);
+        <Points<T>>::insert(now.saturating_sub(delay), BalanceOf::<T>::from(100u32));
+    }: {
+        Staking::<T>::prepare_staking_payouts(now);
+    }
+    verify {
+        assert!(<DelayedPayouts<T>>::contains_key(now.saturating_sub(delay)));
+    }
+
+    pay_one_executor_reward {
+        let y in 0 .. MAX_STAKERS;
+        let round = 1;
+        let executor = funded_account::<T>("executor", 0);
+        <AtStake<T>>::insert(round, &executor, snapshot_of::<T>(y));
+        <AwardedPts<T>>::insert(round, &executor, BalanceOf::<T>::from(100u32));
+        <Points<T>>::insert(round, BalanceOf::<T>::from(100u32));
+        let payout_info = DelayedPayout {
+            round_issuance: BalanceOf::<T>::from(4_000u32),
+            total_staking_reward: BalanceOf::<T>::from(2_000u32),
+        };
+    }: {
+        Staking::<T>::pay_one_executor_reward(round, payout_info);
+    }
+    verify {
+        assert!(!<AtStake<T>>::contains_key(round, &executor));
+    }
 
-// TODO
-benchmarks! {}
+    clear_paid_round {
+        let x in 0 .. MAX_EXECUTORS;
+        let round = 1;
+        for i in 0..x {
+            <AtStake<T>>::insert(round, funded_account::<T>("executor", i), snapshot_of::<T>(0));
+        }
+    }: {
+        Staking::<T>::clear_at_stake(round);
+    }
+    verify {
+        assert!(<AtStake<T>>::iter_prefix(round).next().is_none());
+    }
+}
@@ -24,29 +24,35 @@ pub mod pallet {
     use super::{
         stakes::Stakes,
         subject_metadata::{CandidateMetadata, StakerMetadata},
-        weights,
+        weights::{self, WeightInfo},
     };
     use core::ops::Mul;
     use frame_support::{
         pallet_prelude::*,
-        traits::{tokens::WithdrawReasons, Currency, LockableCurrency, ReservableCurrency},
+        traits::{
+            tokens::WithdrawReasons, Currency, Imbalance, LockableCurrency, ReservableCurrency,
+        },
     };
     use frame_system::{ensure_root, pallet_prelude::*};
     use sp_runtime::{
         traits::{One, Saturating, Zero},
-        Percent,
+        Perbill, Percent,
     };
     use sp_std::collections::btree_map::BTreeMap;
     use t3rn_primitives::{
         // treasury::Treasury as TTreasury,
+        account_manager::AccountManager,
+        claimable::BenefitSource,
         clock::Clock,
-        common::{OrderedSet, Range, RoundIndex},
+        common::{OrderedSet, Range, RoundIndex, RoundInfo},
         executors::{
-            Bond, CancelledScheduledStakingRequest, ExecutorInfo, ExecutorSnapshot,
-            Fixtures as StakingFixtures, ScheduledConfigurationRequest, ScheduledStakingRequest,
-            StakeAdjust, StakerAdded, StakingAction, EXECUTOR_LOCK_ID, STAKER_LOCK_ID,
+            Bond, CancelledScheduledStakingRequest, DelayedPayout, ExecutorInfo,
+            ExecutorReputation, ExecutorSnapshot, Executors, Fixtures as StakingFixtures,
+            NominatedStake, ReputationOutcome, ScheduledConfigurationRequest,
+            ScheduledStakingRequest, StakeAdjust, StakerAdded, StakingAction, EXECUTOR_LOCK_ID,
+            STAKER_LOCK_ID,
         },
-        monetary::DECIMALS,
+        monetary::{InflationAllocation, DECIMALS},
     };
 
     pub type BalanceOf<T> =
@@ -129,6 +135,30 @@ pub mod pallet {
         /// Treasury round proveider.
         type Treasury: Clock<Self>;
 
+        /// Number of rounds after which the staking rewards of the round are paid out.
+        #[pallet::constant]
+        type RewardPaymentDelay: Get<RoundIndex>;
+
+        /// Inflation issued for the staking rewards of each round.
+        #[pallet::constant]
+        type InflationPerRound: Get<BalanceOf<Self>>;
+
+        /// Split of the inflation between developers and executors.
+        type InflationAllocation: Get<InflationAllocation>;
+
+        /// Maximum number of executors paid out in a single block.
+        #[pallet::constant]
+        type MaxPayoutsPerBlock: Get<u32>;
+
+        /// A type that provides access to AccountManager
+        type AccountManager: AccountManager<
+            Self::AccountId,
+            BalanceOf<Self>,
+            Self::Hash,
+            Self::BlockNumber,
+            u32,
+        >;
+
         type WeightInfo: weights::WeightInfo;
     }
 
@@ -241,6 +271,47 @@ pub mod pallet {
     #[pallet::getter(fn staked)]
     pub type Staked<T: Config> = StorageMap<_, Twox64Concat, RoundIndex, BalanceOf<T>, ValueQuery>;
 
+    /// Round last seen by the pallet, used to detect round transitions.
+    #[pallet::storage]
+    #[pallet::getter(fn last_round)]
+    pub type LastRound<T: Config> = StorageValue<_, RoundInfo<T::BlockNumber>, OptionQuery>;
+
+    /// Points awarded to executors of a round - traffic rewards settled for them in the round.
+    #[pallet::storage]
+    #[pallet::getter(fn awarded_pts)]
+    pub type AwardedPts<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        RoundIndex,
+        Twox64Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Total points awarded to executors of a round.
+    #[pallet::storage]
+    #[pallet::getter(fn points)]
+    pub type Points<T: Config> = StorageMap<_, Twox64Concat, RoundIndex, BalanceOf<T>, ValueQuery>;
+
+    /// Staking rewards of rounds awaiting the payout.
+    #[pallet::storage]
+    #[pallet::getter(fn delayed_payouts)]
+    pub type DelayedPayouts<T: Config> =
+        StorageMap<_, Twox64Concat, RoundIndex, DelayedPayout<BalanceOf<T>>, OptionQuery>;
+
+    /// Oldest round which staking rewards may still await the payout. Payouts are handled
+    ///     from it onwards, round by round, instead of looking up all of the DelayedPayouts.
+    #[pallet::storage]
+    #[pallet::getter(fn oldest_delayed_payout_round)]
+    pub type OldestDelayedPayoutRound<T: Config> = StorageValue<_, RoundIndex, OptionQuery>;
+
+    /// Track record of executors' SFX outcomes reported by Circuit.
+    #[pallet::storage]
+    #[pallet::getter(fn executor_reputation)]
+    pub type Reputations<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, ExecutorReputation, ValueQuery>;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Sets all protocol enforced staking fixtures.
@@ -984,10 +1055,37 @@ pub mod pallet {
         //
         // This function must return the weight consumed by `on_initialize` and `on_finalize`.
         fn on_initialize(_n: T::BlockNumber) -> Weight {
-            //TODO listen2round updates then reselect active set + prep payouts
-            //TODO//TODO//TODO//TODO//TODO//TODO//TODO//TODO//TODO//TODO//TODO
+            let round = T::Treasury::current_round();
+            let mut weight = T::WeightInfo::base_on_initialize();
+
+            match <LastRound<T>>::get() {
+                Some(last_round) if last_round.index < round.index => {
+                    // Award the finished round first - AtStake of the round is still in place
+                    let settlement_count = Self::award_points(last_round);
+                    let (executor_count, stake_count, total_staked) =
+                        Self::select_active_set(round.index);
+                    <Staked<T>>::insert(round.index, total_staked);
+                    // Snapshots of a round without points are of no use to the payouts
+                    if <Points<T>>::get(last_round.index).is_zero() {
+                        let cleared_count = Self::clear_at_stake(last_round.index);
+                        weight =
+                            weight.saturating_add(T::WeightInfo::clear_paid_round(cleared_count));
+                    }
+                    Self::prepare_staking_payouts(round.index);
+                    <LastRound<T>>::put(round);
+                    weight = weight
+                        .saturating_add(T::WeightInfo::award_points(settlement_count))
+                        .saturating_add(T::WeightInfo::round_transition_on_initialize(
+                            executor_count,
+                            stake_count,
+                        ))
+                        .saturating_add(T::WeightInfo::prepare_staking_payouts());
+                },
+                Some(_) => {},
+                None => <LastRound<T>>::put(round),
+            }
 
-            419
+            weight.saturating_add(Self::handle_delayed_payouts(round.index))
         }
     }
 
@@ -1161,6 +1259,12 @@ pub mod pallet {
             candidate_bond_less_delay: u32,
             revoke_stake_delay: u32,
         },
+        /// Executor's reputation got updated with the outcome reported by Circuit.
+        ExecutorReputationUpdated {
+            executor: T::AccountId,
+            outcome: ReputationOutcome,
+            reputation: ExecutorReputation,
+        },
     }
 
     #[pallet::error]
@@ -1329,154 +1433,170 @@ pub mod pallet {
             Ok(())
         }
 
-        // fn prepare_staking_payouts(now: RoundIndex) {
-        // 	// payout is now - delay rounds ago => now - delay > 0 else return early
-        // 	let delay = T::RewardPaymentDelay::get();
-        // 	if now <= delay {
-        // 		return;
-        // 	}
-        // 	let round_to_payout = now.saturating_sub(delay);
-        // 	let total_points = <Points<T>>::get(round_to_payout);
-        // 	if total_points.is_zero() {
-        // 		return;
-        // 	}
-        // 	let total_staked = <Staked<T>>::take(round_to_payout);
-        // 	let total_issuance = Self::compute_issuance(total_staked);
-        // 	let mut left_issuance = total_issuance;
-        // 	// reserve portion of issuance for parachain bond account
-        // 	let bond_config = <ParachainBondInfo<T>>::get();
-        // 	let parachain_bond_reserve = bond_config.percent * total_issuance;
-        // 	if let Ok(imb) =
-        // 		T::Currency::deposit_into_existing(&bond_config.account, parachain_bond_reserve)
-        // 	{
-        // 		// update round issuance iff transfer succeeds
-        // 		left_issuance = left_issuance.saturating_sub(imb.peek());
-        // 		Self::deposit_event(Event::ReservedForParachainBond {
-        // 			account: bond_config.account,
-        // 			value: imb.peek(),
-        // 		});
-        // 	}
-
-        // 	let payout = DelayedPayout {
-        // 		round_issuance: total_issuance,
-        // 		total_staking_reward: left_issuance,
-        // 		executor_commission: <Commissions<T>>::get(),
-        // 	};
-
-        // 	<DelayedPayouts<T>>::insert(round_to_payout, payout);
-        // }
-
-        // /// Wrapper around pay_one_executor_reward which handles the following logic:
-        // /// * whether or not a payout needs to be made
-        // /// * cleaning up when payouts are done
-        // /// * returns the weight consumed by pay_one_executor_reward if applicable
-        // fn handle_delayed_payouts(now: RoundIndex) -> Weight {
-        // 	let delay = T::RewardPaymentDelay::get();
-
-        // 	// don't underflow uint
-        // 	if now < delay {
-        // 		return 0u64.into();
-        // 	}
-
-        // 	let paid_for_round = now.saturating_sub(delay);
-
-        // 	if let Some(payout_info) = <DelayedPayouts<T>>::get(paid_for_round) {
-        // 		let result = Self::pay_one_executor_reward(paid_for_round, payout_info);
-        // 		if result.0.is_none() {
-        // 			// result.0 indicates whether or not a payout was made
-        // 			// clean up storage items that we no longer need
-        // 			<DelayedPayouts<T>>::remove(paid_for_round);
-        // 			<Points<T>>::remove(paid_for_round);
-        // 		}
-        // 		result.1 // weight consumed by pay_one_executor_reward
-        // 	} else {
-        // 		0u64.into()
-        // 	}
-        // }
-
-        // /// Payout a single executor from the given round.
-        // ///
-        // /// Returns an optional tuple of (Executor's AccountId, total paid)
-        // /// or None if there were no more payouts to be made for the round.
-        // pub(crate) fn pay_one_executor_reward(
-        // 	paid_for_round: RoundIndex,
-        // 	payout_info: DelayedPayout<BalanceOf<T>>,
-        // ) -> (Option<(T::AccountId, BalanceOf<T>)>, Weight) {
-        // 	// TODO: it would probably be optimal to roll Points into the DelayedPayouts storage
-        // 	// item so that we do fewer reads each block
-        // 	let total_points = <Points<T>>::get(paid_for_round);
-        // 	if total_points.is_zero() {
-        // 		// TODO: this case is obnoxious... it's a value query, so it could mean one of two
-        // 		// different logic errors:
-        // 		// 1. we removed it before we should have
-        // 		// 2. we called pay_one_executor_reward when we were actually done with deferred
-        // 		//    payouts
-        // 		log::warn!("pay_one_executor_reward called with no <Points<T>> for the round!");
-        // 		return (None, 0u64.into());
-        // 	}
-
-        // 	let mint = |amt: BalanceOf<T>, to: T::AccountId| {
-        // 		if let Ok(amount_transferred) = T::Currency::deposit_into_existing(&to, amt) {
-        // 			Self::deposit_event(Event::Rewarded {
-        // 				account: to.clone(),
-        // 				rewards: amount_transferred.peek(),
-        // 			});
-        // 		}
-        // 	};
-
-        // 	let executor_fee = payout_info.executor_commission;
-        // 	let executor_issuance = executor_fee * payout_info.round_issuance;
-
-        // 	if let Some((executor, pts)) =
-        // 		<AwardedPts<T>>::iter_prefix(paid_for_round).drain().next()
-        // 	{
-        // 		let mut extra_weight = 0;
-        // 		let pct_due = Perbill::from_rational(pts, total_points);
-        // 		let total_paid = pct_due * payout_info.total_staking_reward;
-        // 		let mut amt_due = total_paid;
-        // 		// Take the snapshot of block author and stakes
-        // 		let state = <AtStake<T>>::take(paid_for_round, &executor);
-        // 		let num_stakers = state.stakes.len();
-        // 		if state.stakes.is_empty() {
-        // 			// solo executor with no stakers
-        // 			mint(amt_due, executor.clone());
-        // 			extra_weight += T::OnCollatorPayout::on_executor_payout(
-        // 				paid_for_round,
-        // 				executor.clone(),
-        // 				amt_due,
-        // 			);
-        // 		} else {
-        // 			// pay executor first; commission + due_portion
-        // 			let executor_pct = Perbill::from_rational(state.bond, state.total);
-        // 			let commission = pct_due * executor_issuance;
-        // 			amt_due = amt_due.saturating_sub(commission);
-        // 			let executor_reward = (executor_pct * amt_due).saturating_add(commission);
-        // 			mint(executor_reward, executor.clone());
-        // 			extra_weight += T::OnCollatorPayout::on_executor_payout(
-        // 				paid_for_round,
-        // 				executor.clone(),
-        // 				executor_reward,
-        // 			);
-        // 			// pay stakers due portion
-        // 			for Bond { owner, amount } in state.stakes {
-        // 				let percent = Perbill::from_rational(amount, state.total);
-        // 				let due = percent * amt_due;
-        // 				if !due.is_zero() {
-        // 					mint(due, owner.clone());
-        // 				}
-        // 			}
-        // 		}
-
-        // 		(
-        // 			Some((executor, total_paid)),
-        // 			T::WeightInfo::pay_one_executor_reward(num_stakers as u32) + extra_weight,
-        // 		)
-        // 	} else {
-        // 		// Note that we don't clean up storage here; it is cleaned up in
-        // 		// handle_delayed_payouts()
-        // 		(None, 0u64.into())
-        // 	}
-        // }
+        /// Awards executors of the finished round with points equal to their traffic rewards
+        ///     settled by AccountManager over the round.
+        /// Returns the number of settlements collected.
+        pub(crate) fn award_points(round: RoundInfo<T::BlockNumber>) -> u32 {
+            let mut total_points = BalanceOf::<T>::zero();
+            let settlements =
+                T::AccountManager::on_collect_settlements(round, BenefitSource::TrafficRewards);
+            let settlement_count = settlements.len() as u32;
+            for (executor, settled) in settlements {
+                // Only executors of the round's active set are rewardable
+                if settled.is_zero() || !<AtStake<T>>::contains_key(round.index, &executor) {
+                    continue
+                }
+                <AwardedPts<T>>::mutate(round.index, &executor, |pts| {
+                    *pts = pts.saturating_add(settled)
+                });
+                total_points = total_points.saturating_add(settled);
+            }
+            if !total_points.is_zero() {
+                <Points<T>>::insert(round.index, total_points);
+            }
+            settlement_count
+        }
+
+        /// Allocates the executors' share of inflation to the round due for the payout.
+        pub(crate) fn prepare_staking_payouts(now: RoundIndex) {
+            // payout is now - delay rounds ago => now - delay > 0 else return early
+            let delay = T::RewardPaymentDelay::get();
+            if now <= delay {
+                return
+            }
+            let round_to_payout = now.saturating_sub(delay);
+            if <Points<T>>::get(round_to_payout).is_zero() {
+                return
+            }
+            let round_issuance = T::InflationPerRound::get();
+            let payout = DelayedPayout {
+                round_issuance,
+                total_staking_reward: T::InflationAllocation::get().executor * round_issuance,
+            };
+
+            <DelayedPayouts<T>>::insert(round_to_payout, payout);
+            if !<OldestDelayedPayoutRound<T>>::exists() {
+                <OldestDelayedPayoutRound<T>>::put(round_to_payout);
+            }
+        }
+
+        /// Pays out up to `MaxPayoutsPerBlock` executors of the oldest round due for the payout
+        ///     and cleans up the round once all of them are paid.
+        /// Rounds left unpaid by the time the next round is due are paid out first,
+        ///     so that no payout is orphaned by a round transition.
+        /// Returns the weight consumed by the payouts.
+        pub(crate) fn handle_delayed_payouts(now: RoundIndex) -> Weight {
+            let delay = T::RewardPaymentDelay::get();
+
+            // don't underflow uint
+            if now < delay {
+                return 0u64
+            }
+
+            let due_round = now.saturating_sub(delay);
+            let paid_for_round = match <OldestDelayedPayoutRound<T>>::get() {
+                Some(round) if round <= due_round => round,
+                _ => return T::DbWeight::get().reads(1),
+            };
+            let payout_info = match <DelayedPayouts<T>>::get(paid_for_round) {
+                Some(payout_info) => payout_info,
+                None => {
+                    // Rounds without points have no payouts - move on to the next one
+                    <OldestDelayedPayoutRound<T>>::put(paid_for_round.saturating_add(1));
+                    return T::DbWeight::get().reads_writes(2, 1)
+                },
+            };
+            let mut weight: Weight = T::DbWeight::get().reads(2);
+
+            for _ in 0..T::MaxPayoutsPerBlock::get() {
+                let (maybe_paid, payout_weight) =
+                    Self::pay_one_executor_reward(paid_for_round, payout_info);
+                weight = weight.saturating_add(payout_weight);
+                if maybe_paid.is_none() {
+                    // clean up storage items that we no longer need
+                    <DelayedPayouts<T>>::remove(paid_for_round);
+                    <Points<T>>::remove(paid_for_round);
+                    <OldestDelayedPayoutRound<T>>::put(paid_for_round.saturating_add(1));
+                    let cleared_count = Self::clear_at_stake(paid_for_round);
+                    weight = weight.saturating_add(T::WeightInfo::clear_paid_round(cleared_count));
+                    break
+                }
+            }
+            weight
+        }
+
+        /// Removes snapshots of the given round left behind by executors without payouts.
+        /// Entries are bounded by the active set size of the round.
+        /// Returns the number of snapshots removed.
+        pub(crate) fn clear_at_stake(round: RoundIndex) -> u32 {
+            <AtStake<T>>::clear_prefix(round, u32::MAX, None).unique
+        }
+
+        /// Payout a single executor from the given round.
+        /// Executor takes the commission from its ExecutorInfo off the rewards due,
+        ///     the rest is split pro rata between the executor's bond and the stakes.
+        ///
+        /// Returns an optional tuple of (Executor's AccountId, total paid)
+        /// or None if there were no more payouts to be made for the round.
+        pub(crate) fn pay_one_executor_reward(
+            paid_for_round: RoundIndex,
+            payout_info: DelayedPayout<BalanceOf<T>>,
+        ) -> (Option<(T::AccountId, BalanceOf<T>)>, Weight) {
+            let total_points = <Points<T>>::get(paid_for_round);
+            if total_points.is_zero() {
+                log::warn!("pay_one_executor_reward called with no <Points<T>> for the round!");
+                return (None, 0u64)
+            }
+
+            let mint = |amt: BalanceOf<T>, to: T::AccountId| {
+                if let Ok(amount_transferred) = T::Currency::deposit_into_existing(&to, amt) {
+                    Self::deposit_event(Event::Rewarded {
+                        account: to,
+                        rewards: amount_transferred.peek(),
+                    });
+                }
+            };
+
+            if let Some((executor, pts)) = <AwardedPts<T>>::drain_prefix(paid_for_round).next() {
+                let pct_due = Perbill::from_rational(pts, total_points);
+                let total_paid = pct_due * payout_info.total_staking_reward;
+                // Take the snapshot of executor and its stakes
+                let state = <AtStake<T>>::take(paid_for_round, &executor);
+                let num_stakers = state.stakes.len();
+                if state.stakes.is_empty() {
+                    // solo executor with no stakers
+                    mint(total_paid, executor.clone());
+                } else {
+                    // pay executor first; commission + due portion
+                    let commission = <ExecutorConfig<T>>::get(&executor)
+                        .map(|info| info.commission)
+                        .unwrap_or_else(Percent::zero)
+                        * total_paid;
+                    let amt_due = total_paid.saturating_sub(commission);
+                    let executor_pct = Perbill::from_rational(state.bond, state.total);
+                    mint(
+                        (executor_pct * amt_due).saturating_add(commission),
+                        executor.clone(),
+                    );
+                    // pay stakers due portion
+                    for Bond { owner, amount } in state.stakes {
+                        let due = Perbill::from_rational(amount, state.total) * amt_due;
+                        if !due.is_zero() {
+                            mint(due, owner);
+                        }
+                    }
+                }
+
+                (
+                    Some((executor, total_paid)),
+                    T::WeightInfo::pay_one_executor_reward(num_stakers as u32),
+                )
+            } else {
+                // Note that we don't clean up storage here; it is cleaned up in
+                // handle_delayed_payouts()
+                (None, 0u64)
+            }
+        }
 
         /// Selects executors into the active set.
         /// Best as in most cumulatively supported in terms of stake.
@@ -1612,4 +1732,130 @@ pub mod pallet {
                 .collect()
         }
     }
+
+    impl<T: Config> Executors<T, BalanceOf<T>> for Pallet<T> {
+        fn active_set() -> Vec<T::AccountId> {
+            <ActiveSet<T>>::get()
+        }
+
+        fn reserve_bond(
+            executor: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            T::Currency::reserve(executor, amount)?;
+            Ok(amount)
+        }
+
+        fn unreserve_bond(executor: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+            amount.saturating_sub(T::Currency::unreserve(executor, amount))
+        }
+
+        /// Slashes up to `amount` off the collateral bond of executor candidate.
+        fn slash_bond(executor: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+            let mut candidate = match <CandidateInfo<T>>::get(executor) {
+                Some(candidate) => candidate,
+                None => return Zero::zero(),
+            };
+            let to_slash = amount.min(candidate.bond);
+            if to_slash.is_zero() {
+                return Zero::zero()
+            }
+
+            let slashed = T::Currency::slash(executor, to_slash).0.peek();
+            candidate.bond = candidate.bond.saturating_sub(slashed);
+            candidate.total_counted = candidate.total_counted.saturating_sub(slashed);
+            T::Currency::set_lock(
+                EXECUTOR_LOCK_ID,
+                executor,
+                candidate.bond,
+                WithdrawReasons::all(),
+            );
+            if candidate.is_active() {
+                Self::update_active(executor.clone(), candidate.total_counted);
+            }
+            <CandidateInfo<T>>::insert(executor, candidate);
+            <Total<T>>::mutate(|total| *total = total.saturating_sub(slashed));
+
+            slashed
+        }
+
+        fn increase_bond(executor: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+            match T::Currency::reserve(executor, amount) {
+                Ok(()) => amount,
+                Err(_) => Zero::zero(),
+            }
+        }
+
+        fn collateral_bond(executor: &T::AccountId) -> BalanceOf<T> {
+            <CandidateInfo<T>>::get(executor)
+                .map(|info| info.bond)
+                .unwrap_or_else(Zero::zero)
+        }
+
+        fn total_nominated_stake(executor: &T::AccountId) -> BalanceOf<T> {
+            <CandidateInfo<T>>::get(executor)
+                .map(|info| info.total_counted.saturating_sub(info.bond))
+                .unwrap_or_else(Zero::zero)
+        }
+
+        fn stakes(staker: &T::AccountId) -> Vec<NominatedStake<T::AccountId, BalanceOf<T>>> {
+            <StakerInfo<T>>::get(staker)
+                .map(|state| {
+                    state
+                        .stakes
+                        .0
+                        .into_iter()
+                        .map(|bond| NominatedStake {
+                            executor: bond.owner,
+                            staker: staker.clone(),
+                            nominated_stake: bond.amount,
+                        })
+                        .collect()
+                })
+                .unwrap_or_default()
+        }
+
+        fn stakes_per_executor(
+            executor: &T::AccountId,
+        ) -> Vec<NominatedStake<T::AccountId, BalanceOf<T>>> {
+            <TopStakes<T>>::get(executor)
+                .into_iter()
+                .chain(<BottomStakes<T>>::get(executor))
+                .flat_map(|stakes| stakes.stakes)
+                .map(|bond| NominatedStake {
+                    executor: executor.clone(),
+                    staker: bond.owner,
+                    nominated_stake: bond.amount,
+                })
+                .collect()
+        }
+
+        fn recalculate_executors_stakes() {
+            // Stakes are recalculated with every staking action - nothing to catch up on
+        }
+
+        /// Successes only count for candidates bonding at least the min. executor bond.
+        fn reputation(executor: &T::AccountId) -> ExecutorReputation {
+            let reputation = <Reputations<T>>::get(executor);
+            if Self::collateral_bond(executor) >= <Fixtures<T>>::get().min_executor_bond
+                && Self::is_candidate(executor)
+            {
+                reputation
+            } else {
+                reputation.penalties_only()
+            }
+        }
+
+        fn update_reputation(executor: &T::AccountId, outcome: ReputationOutcome) {
+            let reputation = <Reputations<T>>::mutate(executor, |reputation| {
+                reputation.note(outcome);
+                *reputation
+            });
+            Self::deposit_event(Event::ExecutorReputationUpdated {
+                executor: executor.clone(),
+                outcome,
+                reputation,
+            });
+        }
+    }
 }
@@ -9,7 +9,9 @@ use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
     Perbill,
 };
-use t3rn_primitives::common::BLOCKS_PER_HOUR;
+use t3rn_primitives::{
+    account_manager::AccountManagerMock, common::BLOCKS_PER_HOUR, monetary::InflationAllocation,
+};
 
 pub(crate) fn last_event() -> Event {
     System::events().pop().expect("event expected").event
@@ -149,9 +151,24 @@ impl pallet_treasury::Config for Test {
     type WeightInfo = ();
 }
 
+parameter_types! {
+    pub const RewardPaymentDelay: u32 = 2;
+    pub const InflationPerRound: Balance = 4_000;
+    pub const MaxPayoutsPerBlock: u32 = 2;
+    pub ExecutorsInflationAllocation: InflationAllocation = InflationAllocation {
+        developer: Perbill::from_percent(50),
+        executor: Perbill::from_percent(50),
+    };
+}
+
 impl pallet_executors::Config for Test {
+    type AccountManager = AccountManagerMock<u32, Balance, H256, u64, u32>;
     type Currency = Balances;
     type Event = Event;
+    type InflationAllocation = ExecutorsInflationAllocation;
+    type InflationPerRound = InflationPerRound;
+    type MaxPayoutsPerBlock = MaxPayoutsPerBlock;
+    type RewardPaymentDelay = RewardPaymentDelay;
     type Treasury = Treasury;
     type WeightInfo = ();
 }
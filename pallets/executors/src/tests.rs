@@ -5,7 +5,8 @@ use crate::{
         Test, Treasury,
     },
     pallet::{
-        BottomStakes, CandidateInfo, CandidatePool, Config, Error, Event, ExecutorConfig,
+        AtStake, AwardedPts, BottomStakes, CandidateInfo, CandidatePool, Config, DelayedPayouts,
+        Error, Event, ExecutorConfig, OldestDelayedPayoutRound, Points,
         ScheduledConfigurationRequests, StakerInfo, TopStakes, Total,
     },
     stakes::Stakes,
@@ -17,7 +18,8 @@ use sp_runtime::Percent;
 use t3rn_primitives::{
     common::{OrderedSet, Range, DEFAULT_ROUND_TERM},
     executors::{
-        Bond, ExecutorInfo, ExecutorStatus, Fixtures as StakingFixtures,
+        Bond, DelayedPayout, ExecutorInfo, ExecutorReputation, ExecutorSnapshot, ExecutorStatus,
+        Executors as ExecutorsTrait, Fixtures as StakingFixtures, ReputationOutcome,
         ScheduledConfigurationRequest, ScheduledStakingRequest, StakerAdded, StakerStatus,
         StakingAction,
     },
@@ -2819,3 +2821,304 @@ fn cancel_leave_stakers_successfully() {
         assert_last_event!(MockEvent::Executors(Event::StakerExitCancelled { staker }));
     });
 }
+
+#[test]
+fn update_reputation_accumulates_circuit_outcomes_counting_successes_of_bonded_executors_only() {
+    new_test_ext().execute_with(|| {
+        let executor = 14;
+
+        assert_eq!(
+            <Executors as ExecutorsTrait<Test, Balance>>::reputation(&executor),
+            ExecutorReputation::default()
+        );
+
+        <Executors as ExecutorsTrait<Test, Balance>>::update_reputation(
+            &executor,
+            ReputationOutcome::ConfirmedOnTime,
+        );
+        <Executors as ExecutorsTrait<Test, Balance>>::update_reputation(
+            &executor,
+            ReputationOutcome::Slashed,
+        );
+
+        let expected_reputation = ExecutorReputation {
+            confirmed_on_time: 1,
+            timed_out: 0,
+            slashed: 1,
+            misbehaved: 0,
+        };
+        assert_eq!(
+            Executors::executor_reputation(executor),
+            expected_reputation
+        );
+        // Executor isn't a bonded candidate - only penalties count
+        assert_eq!(
+            <Executors as ExecutorsTrait<Test, Balance>>::reputation(&executor),
+            ExecutorReputation {
+                confirmed_on_time: 0,
+                ..expected_reputation
+            }
+        );
+
+        assert_last_event!(MockEvent::Executors(Event::ExecutorReputationUpdated {
+            executor,
+            outcome: ReputationOutcome::Slashed,
+            reputation: expected_reputation,
+        }));
+    });
+}
+
+#[test]
+fn delayed_payouts_pay_executors_commission_and_stakers_pro_rata() {
+    new_test_ext().execute_with(|| {
+        let (executor, staker, solo_executor) = (14, 15, 16);
+        let paid_for_round = 1;
+        let initial_balance = 100;
+
+        for account in [executor, staker, solo_executor] {
+            drop(<Test as Config>::Currency::deposit_creating(
+                &account,
+                initial_balance,
+            ));
+        }
+
+        <ExecutorConfig<Test>>::insert(
+            executor,
+            ExecutorInfo {
+                commission: Percent::from_percent(10),
+                risk: Percent::from_percent(10),
+            },
+        );
+        <AtStake<Test>>::insert(
+            paid_for_round,
+            executor,
+            ExecutorSnapshot {
+                bond: 300,
+                stakes: vec![Bond {
+                    owner: staker,
+                    amount: 100,
+                }],
+                total: 400,
+            },
+        );
+        <AwardedPts<Test>>::insert(paid_for_round, executor, 50);
+        <AwardedPts<Test>>::insert(paid_for_round, solo_executor, 50);
+        <Points<Test>>::insert(paid_for_round, 100);
+        <DelayedPayouts<Test>>::insert(
+            paid_for_round,
+            DelayedPayout {
+                round_issuance: 4_000,
+                total_staking_reward: 2_000,
+            },
+        );
+        <OldestDelayedPayoutRound<Test>>::put(paid_for_round);
+
+        let _weight = Executors::handle_delayed_payouts(
+            paid_for_round + <Test as Config>::RewardPaymentDelay::get(),
+        );
+
+        // executor is due 50% of 2_000 - 10% commission + 75% of the rest for its bond
+        assert_eq!(
+            <Test as Config>::Currency::free_balance(&executor),
+            initial_balance + 100 + 675
+        );
+        // staker gets 25% of the rest for its stake
+        assert_eq!(
+            <Test as Config>::Currency::free_balance(&staker),
+            initial_balance + 225
+        );
+        // executor without stakers takes all of its due
+        assert_eq!(
+            <Test as Config>::Currency::free_balance(&solo_executor),
+            initial_balance + 1_000
+        );
+
+        // MaxPayoutsPerBlock is used up by the two executors - round is cleaned up in the next block
+        assert!(Executors::delayed_payouts(paid_for_round).is_some());
+        let _weight = Executors::handle_delayed_payouts(
+            paid_for_round + <Test as Config>::RewardPaymentDelay::get(),
+        );
+        assert!(Executors::delayed_payouts(paid_for_round).is_none());
+        assert_eq!(Executors::points(paid_for_round), 0);
+        assert_eq!(
+            Executors::oldest_delayed_payout_round(),
+            Some(paid_for_round + 1)
+        );
+    });
+}
+
+#[test]
+fn delayed_payouts_move_past_rounds_without_payouts() {
+    new_test_ext().execute_with(|| {
+        let (executor, round_without_points, paid_for_round) = (14, 1, 2);
+        let initial_balance = 100;
+        let now = paid_for_round + <Test as Config>::RewardPaymentDelay::get();
+
+        drop(<Test as Config>::Currency::deposit_creating(
+            &executor,
+            initial_balance,
+        ));
+        <AwardedPts<Test>>::insert(paid_for_round, executor, 50);
+        <Points<Test>>::insert(paid_for_round, 50);
+        <DelayedPayouts<Test>>::insert(
+            paid_for_round,
+            DelayedPayout {
+                round_issuance: 4_000,
+                total_staking_reward: 2_000,
+            },
+        );
+        <OldestDelayedPayoutRound<Test>>::put(round_without_points);
+
+        let _weight = Executors::handle_delayed_payouts(now);
+        assert_eq!(
+            Executors::oldest_delayed_payout_round(),
+            Some(paid_for_round)
+        );
+        assert_eq!(
+            <Test as Config>::Currency::free_balance(&executor),
+            initial_balance
+        );
+
+        let _weight = Executors::handle_delayed_payouts(now);
+        assert_eq!(
+            <Test as Config>::Currency::free_balance(&executor),
+            initial_balance + 2_000
+        );
+        assert!(Executors::delayed_payouts(paid_for_round).is_none());
+
+        // the next round isn't due yet
+        let _weight = Executors::handle_delayed_payouts(now);
+        assert_eq!(
+            Executors::oldest_delayed_payout_round(),
+            Some(paid_for_round + 1)
+        );
+    });
+}
+
+#[test]
+fn pay_one_executor_reward_returns_none_without_points_of_the_round() {
+    new_test_ext().execute_with(|| {
+        let (executor, paid_for_round) = (14, 1);
+        <AwardedPts<Test>>::insert(paid_for_round, executor, 50);
+
+        let (maybe_paid, weight) = Executors::pay_one_executor_reward(
+            paid_for_round,
+            DelayedPayout {
+                round_issuance: 4_000,
+                total_staking_reward: 2_000,
+            },
+        );
+
+        assert_eq!(maybe_paid, None);
+        assert_eq!(weight, 0);
+        assert_eq!(Executors::awarded_pts(paid_for_round, executor), 50);
+    });
+}
+
+#[test]
+fn pay_one_executor_reward_pays_a_single_executor_and_drops_its_snapshot() {
+    new_test_ext().execute_with(|| {
+        let (executor, other_executor, paid_for_round) = (14, 16, 1);
+        let initial_balance = 100;
+        let payout_info = DelayedPayout {
+            round_issuance: 4_000,
+            total_staking_reward: 2_000,
+        };
+
+        for account in [executor, other_executor] {
+            drop(<Test as Config>::Currency::deposit_creating(
+                &account,
+                initial_balance,
+            ));
+            <AtStake<Test>>::insert(
+                paid_for_round,
+                account,
+                ExecutorSnapshot {
+                    bond: 300,
+                    stakes: vec![],
+                    total: 300,
+                },
+            );
+            <AwardedPts<Test>>::insert(paid_for_round, account, 50);
+        }
+        <Points<Test>>::insert(paid_for_round, 100);
+
+        let (maybe_paid, _weight) = Executors::pay_one_executor_reward(paid_for_round, payout_info);
+        let (paid, total_paid) = maybe_paid.expect("one executor is paid");
+        let unpaid = if paid == executor {
+            other_executor
+        } else {
+            executor
+        };
+
+        assert_eq!(total_paid, 1_000);
+        assert_eq!(
+            <Test as Config>::Currency::free_balance(&paid),
+            initial_balance + 1_000
+        );
+        assert_eq!(
+            <Test as Config>::Currency::free_balance(&unpaid),
+            initial_balance
+        );
+        assert!(!<AtStake<Test>>::contains_key(paid_for_round, paid));
+        assert!(<AtStake<Test>>::contains_key(paid_for_round, unpaid));
+        assert_eq!(Executors::awarded_pts(paid_for_round, paid), 0);
+        assert_eq!(Executors::awarded_pts(paid_for_round, unpaid), 50);
+        assert_eq!(Executors::points(paid_for_round), 100);
+
+        let (maybe_paid, _weight) = Executors::pay_one_executor_reward(paid_for_round, payout_info);
+        assert_eq!(maybe_paid, Some((unpaid, 1_000)));
+
+        let (maybe_paid, _weight) = Executors::pay_one_executor_reward(paid_for_round, payout_info);
+        assert_eq!(maybe_paid, None);
+    });
+}
+
+#[test]
+fn delayed_payouts_of_an_overdue_round_are_paid_and_clean_up_its_snapshots() {
+    new_test_ext().execute_with(|| {
+        let (executor, executor_without_points, paid_for_round) = (14, 16, 1);
+        let initial_balance = 100;
+
+        drop(<Test as Config>::Currency::deposit_creating(
+            &executor,
+            initial_balance,
+        ));
+        for account in [executor, executor_without_points] {
+            <AtStake<Test>>::insert(
+                paid_for_round,
+                account,
+                ExecutorSnapshot {
+                    bond: 300,
+                    stakes: vec![],
+                    total: 300,
+                },
+            );
+        }
+        <AwardedPts<Test>>::insert(paid_for_round, executor, 50);
+        <Points<Test>>::insert(paid_for_round, 50);
+        <DelayedPayouts<Test>>::insert(
+            paid_for_round,
+            DelayedPayout {
+                round_issuance: 4_000,
+                total_staking_reward: 2_000,
+            },
+        );
+        <OldestDelayedPayoutRound<Test>>::put(paid_for_round);
+
+        // the round due for the payout has moved past the unpaid round
+        let _weight = Executors::handle_delayed_payouts(
+            paid_for_round + <Test as Config>::RewardPaymentDelay::get() + 2,
+        );
+
+        assert_eq!(
+            <Test as Config>::Currency::free_balance(&executor),
+            initial_balance + 2_000
+        );
+        assert!(Executors::delayed_payouts(paid_for_round).is_none());
+        assert_eq!(Executors::points(paid_for_round), 0);
+        assert!(<AtStake<Test>>::iter_prefix(paid_for_round)
+            .next()
+            .is_none());
+    });
+}
@@ -1,12 +1,30 @@
-use frame_support::weights::Weight;
+#![allow(unused_parens)]
+
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+};
 use sp_std::marker::PhantomData;
 
+/// Weight functions needed for pallet_executors.
 pub trait WeightInfo {
     fn base_on_initialize() -> Weight;
 
     #[rustfmt::skip]
     fn round_transition_on_initialize(x: u32, y: u32, ) -> Weight;
 
+    #[rustfmt::skip]
+    fn award_points(x: u32, ) -> Weight;
+
+    #[rustfmt::skip]
+    fn prepare_staking_payouts() -> Weight;
+
+    #[rustfmt::skip]
+    fn pay_one_executor_reward(y: u32, ) -> Weight;
+
+    #[rustfmt::skip]
+    fn clear_paid_round(x: u32, ) -> Weight;
+
     // #[rustfmt::skip]
     // fn set_fixtures() -> Weight;
     // #[rustfmt::skip]
@@ -58,37 +76,117 @@ pub trait WeightInfo {
     // #[rustfmt::skip]
     // fn cancel_staker_bond_less() -> Weight;
     // #[rustfmt::skip]
-    // fn pay_one_executor_reward(y: u32, ) -> Weight;
-    // #[rustfmt::skip]
     // fn cancel_stake_request() -> Weight;
     // #[rustfmt::skip]
     // fn execute_stake_request() -> Weight;
 }
 
+/// Weights for pallet_executors - not measured yet, to be regenerated with
+///     `benchmark pallet --pallet pallet_executors` off `benchmarking.rs`.
 pub struct TreasuryWeight<T>(PhantomData<T>);
-
-// TODO
 impl<T: frame_system::Config> WeightInfo for TreasuryWeight<T> {
     #[rustfmt::skip]
     fn base_on_initialize() -> Weight {
-		419 as Weight //TODO
+		(5_318_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 	}
 
     #[rustfmt::skip]
-    fn round_transition_on_initialize(_x: u32, _y: u32, ) -> Weight {
-		419 as Weight //TODO
+    fn round_transition_on_initialize(x: u32, y: u32, ) -> Weight {
+		(31_406_000 as Weight)
+			.saturating_add((19_734_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add((1_132_000 as Weight).saturating_mul(y as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(x as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(x as Weight)))
+    }
+
+    #[rustfmt::skip]
+    fn award_points(x: u32, ) -> Weight {
+		(9_875_000 as Weight)
+			.saturating_add((8_212_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(x as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(x as Weight)))
+    }
+
+    #[rustfmt::skip]
+    fn prepare_staking_payouts() -> Weight {
+		(7_103_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+
+    #[rustfmt::skip]
+    fn pay_one_executor_reward(y: u32, ) -> Weight {
+		(44_692_000 as Weight)
+			.saturating_add((17_458_000 as Weight).saturating_mul(y as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(y as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(y as Weight)))
+    }
+
+    #[rustfmt::skip]
+    fn clear_paid_round(x: u32, ) -> Weight {
+		(6_214_000 as Weight)
+			.saturating_add((1_561_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(x as Weight)))
     }
 }
 
-// TODO
+// For backwards compatibility and tests
 impl WeightInfo for () {
     #[rustfmt::skip]
     fn base_on_initialize() -> Weight {
-		419 as Weight //TODO
+		(5_318_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 	}
 
     #[rustfmt::skip]
-    fn round_transition_on_initialize(_x: u32, _y: u32, ) -> Weight {
-		419 as Weight //TODO
+    fn round_transition_on_initialize(x: u32, y: u32, ) -> Weight {
+		(31_406_000 as Weight)
+			.saturating_add((19_734_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add((1_132_000 as Weight).saturating_mul(y as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(x as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(x as Weight)))
+    }
+
+    #[rustfmt::skip]
+    fn award_points(x: u32, ) -> Weight {
+		(9_875_000 as Weight)
+			.saturating_add((8_212_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(x as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(x as Weight)))
+    }
+
+    #[rustfmt::skip]
+    fn prepare_staking_payouts() -> Weight {
+		(7_103_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+
+    #[rustfmt::skip]
+    fn pay_one_executor_reward(y: u32, ) -> Weight {
+		(44_692_000 as Weight)
+			.saturating_add((17_458_000 as Weight).saturating_mul(y as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(y as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(y as Weight)))
+    }
+
+    #[rustfmt::skip]
+    fn clear_paid_round(x: u32, ) -> Weight {
+		(6_214_000 as Weight)
+			.saturating_add((1_561_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(x as Weight)))
     }
 }
@@ -7,7 +7,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{alloc::vec::Vec, Codec};
-pub use t3rn_primitives::ChainId;
+pub use t3rn_primitives::{portal::ParachainHead, ChainId};
 
 sp_api::decl_runtime_apis! {
     /// The API to interact with pallet XDNS
@@ -16,5 +16,11 @@ sp_api::decl_runtime_apis! {
     {
         /// Returns hash of latest finalized header
         fn get_latest_finalized_header(gateway_id: ChainId) -> Option<Vec<u8>>;
+
+        /// Returns the height of the oldest header storage proofs can still be verified against
+        fn get_oldest_provable_height(gateway_id: ChainId) -> Option<Vec<u8>>;
+
+        /// Returns the latest verified head of every tracked parachain
+        fn get_latest_parachain_heads() -> Vec<ParachainHead>;
     }
 }
@@ -7,8 +7,8 @@ use codec::Codec;
 use jsonrpc_core::{Error, ErrorCode, Result};
 
 use jsonrpc_derive::rpc;
-use pallet_portal_rpc_runtime_api::ChainId;
 pub use pallet_portal_rpc_runtime_api::PortalRuntimeApi;
+use pallet_portal_rpc_runtime_api::{ChainId, ParachainHead};
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
 use sp_runtime::{
@@ -24,6 +24,14 @@ pub trait PortalApi<AccountId> {
     /// Returns latest finalized header of a gateway if available
     #[rpc(name = "portal_getLatestFinalizedHeader")]
     fn get_latest_finalized_header(&self, chain_id: ChainId) -> Result<Vec<u8>>;
+
+    /// Returns the encoded height of the oldest header side effects can still be confirmed against
+    #[rpc(name = "portal_getOldestProvableHeight")]
+    fn get_oldest_provable_height(&self, chain_id: ChainId) -> Result<Vec<u8>>;
+
+    /// Returns the latest verified head of every tracked parachain
+    #[rpc(name = "portal_getLatestParachainHeads")]
+    fn get_latest_parachain_heads(&self) -> Result<Vec<ParachainHead>>;
 }
 
 /// A struct that implements the [`PortalApi`].
@@ -62,6 +70,29 @@ where
         }
         .map_err(runtime_error_into_rpc_err)
     }
+
+    fn get_oldest_provable_height(&self, gateway_id: ChainId) -> Result<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(self.client.info().best_hash);
+
+        let result: Option<Vec<u8>> = api
+            .get_oldest_provable_height(&at, gateway_id)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        result.ok_or(Error {
+            code: ErrorCode::ServerError(NO_KNOWN_RECORDS),
+            message: "No provable header found".into(),
+            data: None,
+        })
+    }
+
+    fn get_latest_parachain_heads(&self) -> Result<Vec<ParachainHead>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(self.client.info().best_hash);
+
+        api.get_latest_parachain_heads(&at)
+            .map_err(runtime_error_into_rpc_err)
+    }
 }
 
 fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> Error {
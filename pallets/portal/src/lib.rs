@@ -2,15 +2,22 @@
 
 use frame_support::sp_runtime::DispatchError;
 pub use pallet::*;
+pub use t3rn_primitives::portal::ParachainHead;
 
 #[cfg(test)]
 mod tests;
 
-use sp_std::vec::Vec;
+use frame_support::{
+    ensure,
+    storage::{with_transaction, TransactionOutcome},
+};
+use frame_system::RawOrigin;
+use sp_std::{vec, vec::Vec};
 use t3rn_primitives::{
-    portal::{Portal, RococoBridge},
-    xdns::Xdns,
-    ChainId, GatewayVendor,
+    abi::GatewayABIConfig,
+    portal::{KusamaBridge, LightClient, PolkadotBridge, Portal, RococoBridge},
+    xdns::{AllowedSideEffect, Parachain, Xdns},
+    ChainId, GatewayGenesisConfig, GatewaySysProps, GatewayType, GatewayVendor,
 };
 
 pub mod weights;
@@ -18,13 +25,14 @@ pub mod weights;
 // use weights::WeightInfo;
 #[frame_support::pallet]
 pub mod pallet {
+    use crate::weights::WeightInfo;
     use core::convert::TryInto;
     use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
-    use sp_std::{vec, vec::Vec};
+    use sp_std::vec::Vec;
     use t3rn_primitives::{
         abi::GatewayABIConfig,
-        portal::RococoBridge,
+        portal::{KusamaBridge, LightClient, PolkadotBridge, RococoBridge},
         xdns::{AllowedSideEffect, Xdns},
         ChainId, GatewayGenesisConfig, GatewaySysProps, GatewayType, GatewayVendor,
     };
@@ -32,13 +40,23 @@ pub mod pallet {
     /// Configure the pallet by specifying the parameters and types on which it depends.
     #[pallet::config]
     pub trait Config:
-        frame_system::Config + pallet_grandpa_finality_verifier::Config<RococoBridge>
+        frame_system::Config
+        + pallet_grandpa_finality_verifier::Config<RococoBridge>
+        + pallet_grandpa_finality_verifier::Config<PolkadotBridge>
+        + pallet_grandpa_finality_verifier::Config<KusamaBridge>
     {
         /// Because this pallet emits events, it depends on the runtime's definition of an event.
         type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
         type Xdns: Xdns<Self>;
+        /// Finality verifier the gateways of `GatewayVendor::Ethereum` are routed to
+        type EthereumLightClient: LightClient<Self>;
+        /// Finality verifier the gateways of `GatewayVendor::EthereumBeacon` are routed to
+        type EthereumBeaconLightClient: LightClient<Self>;
         /// Type representing the weight of this pallet
         type WeightInfo: crate::weights::WeightInfo;
+        /// Maximum number of parachain heads imported by a single `submit_parachain_heads`
+        #[pallet::constant]
+        type MaxParachainHeadsPerBatch: Get<u32>;
     }
 
     #[pallet::pallet]
@@ -57,8 +75,10 @@ pub mod pallet {
         SetOwner(ChainId, Vec<u8>),
         /// Gateway was set operational. [ChainId, bool]
         SetOperational(ChainId, bool),
-        /// Header was successfully added
+        /// Header was successfully added. [ChainId, big-endian u32 height]
         HeaderSubmitted(ChainId, Vec<u8>),
+        /// Number of headers kept for the gateway was changed. [ChainId, u32]
+        HeadersToStoreSet(ChainId, u32),
     }
 
     // Errors inform users that something went wrong.
@@ -82,6 +102,14 @@ pub mod pallet {
         NoGatewayHeightAvailable,
         /// SideEffect confirmation failed
         SideEffectConfirmationFailed,
+        /// The relaychains vendor doesn't track parachains
+        ParachainsNotSupported,
+        /// Number of headers to keep can't be updated
+        SetHeadersToStoreError,
+        /// The batch holds more parachain heads than `MaxParachainHeadsPerBatch`
+        TooManyParachainHeads,
+        /// The registration data names another owner of the light client than the registrant
+        RegistrantNotOwner,
     }
 
     // Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -102,41 +130,21 @@ pub mod pallet {
             allowed_side_effects: Vec<AllowedSideEffect>,
             encoded_registration_data: Vec<u8>,
         ) -> DispatchResultWithPostInfo {
-            // ToDo xdns record is written also when the calls after this fail!!!
-            <T as Config>::Xdns::add_new_xdns_record(
-                origin.clone(),
+            Self::do_register_gateway(
+                origin,
                 url,
                 gateway_id,
-                None,
                 gateway_abi,
-                gateway_vendor.clone(),
+                gateway_vendor,
                 gateway_type,
                 gateway_genesis,
                 gateway_sys_props,
-                vec![],
                 allowed_side_effects,
+                encoded_registration_data,
             )?;
 
-            let res = match gateway_vendor {
-                GatewayVendor::Rococo =>
-                    pallet_grandpa_finality_verifier::Pallet::<T, RococoBridge>::initialize(
-                        origin,
-                        gateway_id,
-                        encoded_registration_data,
-                    ),
-                _ => return Err(Error::<T>::UnimplementedGatewayVendor.into()),
-            };
-
-            match res {
-                Ok(_) => {
-                    Self::deposit_event(Event::GatewayRegistered(gateway_id));
-                    Ok(().into())
-                },
-                Err(msg) => {
-                    log::info!("{:?}", msg);
-                    Err(Error::<T>::RegistrationError.into())
-                },
-            }
+            Self::deposit_event(Event::GatewayRegistered(gateway_id));
+            Ok(().into())
         }
 
         #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
@@ -153,8 +161,29 @@ pub mod pallet {
                         origin,
                         gateway_id,
                         encoded_new_owner.clone(),
-                    ),
-                _ => unimplemented!(),
+                    )
+                    .map_err(DispatchError::Other),
+                GatewayVendor::Polkadot => pallet_grandpa_finality_verifier::Pallet::<
+                    T,
+                    PolkadotBridge,
+                >::set_owner(
+                    origin, gateway_id, encoded_new_owner.clone()
+                )
+                .map_err(DispatchError::Other),
+                GatewayVendor::Kusama =>
+                    pallet_grandpa_finality_verifier::Pallet::<T, KusamaBridge>::set_owner(
+                        origin,
+                        gateway_id,
+                        encoded_new_owner.clone(),
+                    )
+                    .map_err(DispatchError::Other),
+                GatewayVendor::Ethereum =>
+                    T::EthereumLightClient::set_owner(origin, gateway_id, encoded_new_owner.clone()),
+                GatewayVendor::EthereumBeacon => T::EthereumBeaconLightClient::set_owner(
+                    origin,
+                    gateway_id,
+                    encoded_new_owner.clone(),
+                ),
             };
 
             match res {
@@ -181,8 +210,26 @@ pub mod pallet {
                         origin,
                         operational,
                         gateway_id,
-                    ),
-                _ => unimplemented!(),
+                    )
+                    .map_err(DispatchError::Other),
+                GatewayVendor::Polkadot => pallet_grandpa_finality_verifier::Pallet::<
+                    T,
+                    PolkadotBridge,
+                >::set_operational(
+                    origin, operational, gateway_id
+                )
+                .map_err(DispatchError::Other),
+                GatewayVendor::Kusama =>
+                    pallet_grandpa_finality_verifier::Pallet::<T, KusamaBridge>::set_operational(
+                        origin,
+                        operational,
+                        gateway_id,
+                    )
+                    .map_err(DispatchError::Other),
+                GatewayVendor::Ethereum =>
+                    T::EthereumLightClient::set_operational(origin, operational, gateway_id),
+                GatewayVendor::EthereumBeacon =>
+                    T::EthereumBeaconLightClient::set_operational(origin, operational, gateway_id),
             };
 
             match res {
@@ -212,11 +259,30 @@ pub mod pallet {
                         gateway_id,
                         encoded_header_data,
                     ),
-                _ => unimplemented!(), // ToDo remove once we remove the old vendors
+                GatewayVendor::Polkadot => pallet_grandpa_finality_verifier::Pallet::<
+                    T,
+                    PolkadotBridge,
+                >::submit_headers(
+                    origin, gateway_id, encoded_header_data
+                ),
+                GatewayVendor::Kusama =>
+                    pallet_grandpa_finality_verifier::Pallet::<T, KusamaBridge>::submit_headers(
+                        origin,
+                        gateway_id,
+                        encoded_header_data,
+                    ),
+                GatewayVendor::Ethereum =>
+                    T::EthereumLightClient::submit_headers(origin, gateway_id, encoded_header_data),
+                GatewayVendor::EthereumBeacon => T::EthereumBeaconLightClient::submit_headers(
+                    origin,
+                    gateway_id,
+                    encoded_header_data,
+                ),
             };
 
             match res {
                 Ok(height) => {
+                    <T as Config>::Xdns::refresh_gateway_ttl(&gateway_id)?;
                     Self::deposit_event(Event::HeaderSubmitted(gateway_id, height));
                     Ok(())
                 },
@@ -226,6 +292,291 @@ pub mod pallet {
                 },
             }
         }
+
+        /// Changes the number of headers the gateways finality verifier keeps for storage proofs.
+        /// Only available for gateways tracked by the GRANDPA finality verifiers.
+        /// `current_headers_to_store` must be at least the number of headers kept so far and is
+        /// charged for up front, the excess is refunded.
+        #[pallet::weight(<T as Config>::WeightInfo::set_headers_to_store(
+            *current_headers_to_store,
+            *headers_to_store,
+        ))]
+        pub fn set_headers_to_store(
+            origin: OriginFor<T>,
+            gateway_id: ChainId,
+            headers_to_store: u32,
+            current_headers_to_store: u32,
+        ) -> DispatchResultWithPostInfo {
+            let vendor = <T as Config>::Xdns::get_gateway_vendor(&gateway_id)
+                .map_err(|_| Error::<T>::GatewayVendorNotFound)?;
+
+            let res =
+                match vendor {
+                    GatewayVendor::Rococo => pallet_grandpa_finality_verifier::Pallet::<
+                        T,
+                        RococoBridge,
+                    >::set_headers_to_store(
+                        origin,
+                        gateway_id,
+                        headers_to_store,
+                        current_headers_to_store,
+                    ),
+                    GatewayVendor::Polkadot => pallet_grandpa_finality_verifier::Pallet::<
+                        T,
+                        PolkadotBridge,
+                    >::set_headers_to_store(
+                        origin,
+                        gateway_id,
+                        headers_to_store,
+                        current_headers_to_store,
+                    ),
+                    GatewayVendor::Kusama => pallet_grandpa_finality_verifier::Pallet::<
+                        T,
+                        KusamaBridge,
+                    >::set_headers_to_store(
+                        origin,
+                        gateway_id,
+                        headers_to_store,
+                        current_headers_to_store,
+                    ),
+                    GatewayVendor::Ethereum | GatewayVendor::EthereumBeacon =>
+                        return Err(Error::<T>::UnimplementedGatewayVendor.into()),
+                };
+
+            match res {
+                Ok(kept_headers) => {
+                    Self::deposit_event(Event::HeadersToStoreSet(gateway_id, headers_to_store));
+                    Ok(Some(<T as Config>::WeightInfo::set_headers_to_store(
+                        kept_headers,
+                        headers_to_store,
+                    ))
+                    .into())
+                },
+                Err(msg) => {
+                    log::info!("{:?}", msg);
+                    Err(Error::<T>::SetHeadersToStoreError.into())
+                },
+            }
+        }
+
+        /// Submits the heads of several parachains of `relay_chain_id`, proven with a single
+        /// storage proof of the relaychains state. `parachains_count` bounds the number of heads
+        /// in the batch and is charged for up front.
+        #[pallet::weight(<T as Config>::WeightInfo::submit_parachain_heads(*parachains_count))]
+        pub fn submit_parachain_heads(
+            origin: OriginFor<T>,
+            relay_chain_id: ChainId,
+            encoded_heads_data: Vec<u8>,
+            parachains_count: u32,
+        ) -> DispatchResult {
+            ensure!(
+                parachains_count <= T::MaxParachainHeadsPerBatch::get(),
+                Error::<T>::TooManyParachainHeads
+            );
+            let vendor = <T as Config>::Xdns::get_gateway_vendor(&relay_chain_id)?;
+
+            let res =
+                match vendor {
+                    GatewayVendor::Rococo => pallet_grandpa_finality_verifier::Pallet::<
+                        T,
+                        RococoBridge,
+                    >::submit_parachain_heads(
+                        origin,
+                        relay_chain_id,
+                        encoded_heads_data,
+                        parachains_count,
+                    ),
+                    GatewayVendor::Polkadot => pallet_grandpa_finality_verifier::Pallet::<
+                        T,
+                        PolkadotBridge,
+                    >::submit_parachain_heads(
+                        origin,
+                        relay_chain_id,
+                        encoded_heads_data,
+                        parachains_count,
+                    ),
+                    GatewayVendor::Kusama => pallet_grandpa_finality_verifier::Pallet::<
+                        T,
+                        KusamaBridge,
+                    >::submit_parachain_heads(
+                        origin,
+                        relay_chain_id,
+                        encoded_heads_data,
+                        parachains_count,
+                    ),
+                    GatewayVendor::Ethereum | GatewayVendor::EthereumBeacon =>
+                        return Err(Error::<T>::ParachainsNotSupported.into()),
+                };
+
+            match res {
+                Ok(heights) => {
+                    for (gateway_id, height) in heights {
+                        <T as Config>::Xdns::refresh_gateway_ttl(&gateway_id)?;
+                        Self::deposit_event(Event::HeaderSubmitted(gateway_id, height));
+                    }
+                    Ok(())
+                },
+                Err(msg) => {
+                    log::info!("{:?}", msg);
+                    Err(Error::<T>::SubmitHeaderError.into())
+                },
+            }
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Writes the XDNS record and initializes the vendor's light client for the gateway.
+    /// Both happen in one storage transaction, so a failed light client initialization
+    /// doesn't leave a dangling XDNS record behind.
+    /// Signed registrants bond the gateway in XDNS and may only initialize light clients
+    /// whose registration data names them as the owner.
+    #[allow(clippy::too_many_arguments)]
+    pub fn do_register_gateway(
+        origin: T::Origin,
+        url: Vec<u8>,
+        gateway_id: ChainId,
+        gateway_abi: GatewayABIConfig,
+        gateway_vendor: GatewayVendor,
+        gateway_type: GatewayType,
+        gateway_genesis: GatewayGenesisConfig,
+        gateway_sys_props: GatewaySysProps,
+        allowed_side_effects: Vec<AllowedSideEffect>,
+        encoded_registration_data: Vec<u8>,
+    ) -> Result<(), DispatchError> {
+        let raw_origin: Result<RawOrigin<T::AccountId>, T::Origin> = origin.clone().into();
+        let light_client_origin: T::Origin = match raw_origin {
+            Ok(RawOrigin::Signed(registrant)) => {
+                ensure!(
+                    Self::registration_owner(&gateway_vendor, &encoded_registration_data)
+                        == Some(registrant),
+                    Error::<T>::RegistrantNotOwner
+                );
+                RawOrigin::Root.into()
+            },
+            _ => origin.clone(),
+        };
+        with_transaction(|| {
+            if let Err(err) = <T as Config>::Xdns::add_new_xdns_record(
+                origin.clone(),
+                url,
+                gateway_id,
+                None,
+                gateway_abi,
+                gateway_vendor.clone(),
+                gateway_type,
+                gateway_genesis,
+                gateway_sys_props,
+                vec![],
+                allowed_side_effects,
+            ) {
+                return TransactionOutcome::Rollback(Err(err))
+            }
+
+            match Self::initialize_light_client(
+                light_client_origin,
+                gateway_vendor,
+                gateway_id,
+                encoded_registration_data,
+            ) {
+                Ok(()) => TransactionOutcome::Commit(Ok(())),
+                Err(msg) => {
+                    log::info!("{:?}", msg);
+                    TransactionOutcome::Rollback(Err(Error::<T>::RegistrationError.into()))
+                },
+            }
+        })
+    }
+
+    /// Latest verified head of every parachain tracked by the GRANDPA verifiers, which executors
+    /// use to pick the `submission_target_height` of side effects on parachains.
+    pub fn get_latest_parachain_heads() -> Vec<ParachainHead> {
+        let mut heads =
+            pallet_grandpa_finality_verifier::Pallet::<T, RococoBridge>::get_latest_parachain_heads(
+            );
+        heads.extend(
+            pallet_grandpa_finality_verifier::Pallet::<T, PolkadotBridge>::get_latest_parachain_heads(),
+        );
+        heads.extend(
+            pallet_grandpa_finality_verifier::Pallet::<T, KusamaBridge>::get_latest_parachain_heads(
+            ),
+        );
+        heads
+            .into_iter()
+            .map(
+                |(gateway_id, parachain, header_hash, height)| ParachainHead {
+                    gateway_id,
+                    parachain: Parachain {
+                        relay_chain_id: parachain.relay_chain_id,
+                        id: parachain.id,
+                    },
+                    header_hash,
+                    height,
+                },
+            )
+            .collect()
+    }
+
+    fn registration_owner(
+        gateway_vendor: &GatewayVendor,
+        encoded_registration_data: &[u8],
+    ) -> Option<T::AccountId> {
+        match gateway_vendor {
+            GatewayVendor::Rococo =>
+                pallet_grandpa_finality_verifier::Pallet::<T, RococoBridge>::registration_owner(
+                    encoded_registration_data,
+                ),
+            GatewayVendor::Polkadot =>
+                pallet_grandpa_finality_verifier::Pallet::<T, PolkadotBridge>::registration_owner(
+                    encoded_registration_data,
+                ),
+            GatewayVendor::Kusama =>
+                pallet_grandpa_finality_verifier::Pallet::<T, KusamaBridge>::registration_owner(
+                    encoded_registration_data,
+                ),
+            GatewayVendor::Ethereum =>
+                T::EthereumLightClient::registration_owner(encoded_registration_data),
+            GatewayVendor::EthereumBeacon =>
+                T::EthereumBeaconLightClient::registration_owner(encoded_registration_data),
+        }
+    }
+
+    fn initialize_light_client(
+        origin: T::Origin,
+        gateway_vendor: GatewayVendor,
+        gateway_id: ChainId,
+        encoded_registration_data: Vec<u8>,
+    ) -> Result<(), DispatchError> {
+        match gateway_vendor {
+            GatewayVendor::Rococo =>
+                pallet_grandpa_finality_verifier::Pallet::<T, RococoBridge>::initialize(
+                    origin,
+                    gateway_id,
+                    encoded_registration_data,
+                )
+                .map_err(DispatchError::Other),
+            GatewayVendor::Polkadot =>
+                pallet_grandpa_finality_verifier::Pallet::<T, PolkadotBridge>::initialize(
+                    origin,
+                    gateway_id,
+                    encoded_registration_data,
+                )
+                .map_err(DispatchError::Other),
+            GatewayVendor::Kusama =>
+                pallet_grandpa_finality_verifier::Pallet::<T, KusamaBridge>::initialize(
+                    origin,
+                    gateway_id,
+                    encoded_registration_data,
+                )
+                .map_err(DispatchError::Other),
+            GatewayVendor::Ethereum =>
+                T::EthereumLightClient::initialize(origin, gateway_id, encoded_registration_data),
+            GatewayVendor::EthereumBeacon => T::EthereumBeaconLightClient::initialize(
+                origin,
+                gateway_id,
+                encoded_registration_data,
+            ),
+        }
     }
 }
 
@@ -241,7 +592,22 @@ impl<T: Config> Portal<T> for Pallet<T> {
             >::get_latest_finalized_header(
                 gateway_id
             )),
-            _ => unimplemented!(),
+            GatewayVendor::Polkadot => Ok(pallet_grandpa_finality_verifier::Pallet::<
+                T,
+                PolkadotBridge,
+            >::get_latest_finalized_header(
+                gateway_id
+            )),
+            GatewayVendor::Kusama => Ok(pallet_grandpa_finality_verifier::Pallet::<
+                T,
+                KusamaBridge,
+            >::get_latest_finalized_header(
+                gateway_id
+            )),
+            GatewayVendor::Ethereum =>
+                Ok(T::EthereumLightClient::get_latest_finalized_header(gateway_id)),
+            GatewayVendor::EthereumBeacon =>
+                Ok(T::EthereumBeaconLightClient::get_latest_finalized_header(gateway_id)),
         }
     }
 
@@ -251,7 +617,23 @@ impl<T: Config> Portal<T> for Pallet<T> {
 
         match vendor {
             GatewayVendor::Rococo => Ok(pallet_grandpa_finality_verifier::Pallet::<T, RococoBridge>::get_latest_finalized_height(gateway_id)),
-            _ => unimplemented!()
+            GatewayVendor::Polkadot => Ok(pallet_grandpa_finality_verifier::Pallet::<T, PolkadotBridge>::get_latest_finalized_height(gateway_id)),
+            GatewayVendor::Kusama => Ok(pallet_grandpa_finality_verifier::Pallet::<T, KusamaBridge>::get_latest_finalized_height(gateway_id)),
+            GatewayVendor::Ethereum => Ok(T::EthereumLightClient::get_latest_finalized_height(gateway_id)),
+            GatewayVendor::EthereumBeacon => Ok(T::EthereumBeaconLightClient::get_latest_finalized_height(gateway_id)),
+        }
+    }
+
+    fn get_oldest_provable_height(gateway_id: ChainId) -> Result<Option<Vec<u8>>, DispatchError> {
+        let vendor = <T as Config>::Xdns::get_gateway_vendor(&gateway_id)
+            .map_err(|_| Error::<T>::GatewayVendorNotFound)?;
+
+        match vendor {
+            GatewayVendor::Rococo => Ok(pallet_grandpa_finality_verifier::Pallet::<T, RococoBridge>::get_oldest_provable_height(gateway_id)),
+            GatewayVendor::Polkadot => Ok(pallet_grandpa_finality_verifier::Pallet::<T, PolkadotBridge>::get_oldest_provable_height(gateway_id)),
+            GatewayVendor::Kusama => Ok(pallet_grandpa_finality_verifier::Pallet::<T, KusamaBridge>::get_oldest_provable_height(gateway_id)),
+            GatewayVendor::Ethereum => Ok(T::EthereumLightClient::get_oldest_provable_height(gateway_id)),
+            GatewayVendor::EthereumBeacon => Ok(T::EthereumBeaconLightClient::get_oldest_provable_height(gateway_id)),
         }
     }
 
@@ -263,16 +645,47 @@ impl<T: Config> Portal<T> for Pallet<T> {
     ) -> Result<(Vec<Vec<u8>>, Vec<u8>), DispatchError> {
         let vendor = <T as Config>::Xdns::get_gateway_vendor(&gateway_id)
             .map_err(|_| Error::<T>::GatewayVendorNotFound)?;
+        let value_abi_unsigned_type =
+            <T as Config>::Xdns::get_gateway_value_unsigned_type_unsafe(&gateway_id);
 
         match vendor {
             GatewayVendor::Rococo => pallet_grandpa_finality_verifier::Pallet::<T, RococoBridge>::confirm_and_decode_payload_params(
                 gateway_id,
                 encoded_inclusion_data,
                 submission_target_height,
-                <T as Config>::Xdns::get_gateway_value_unsigned_type_unsafe(&gateway_id).to_string_bytes(),
+                value_abi_unsigned_type.to_string_bytes(),
+                side_effect_id,
+            ),
+            GatewayVendor::Polkadot => pallet_grandpa_finality_verifier::Pallet::<T, PolkadotBridge>::confirm_and_decode_payload_params(
+                gateway_id,
+                encoded_inclusion_data,
+                submission_target_height,
+                value_abi_unsigned_type.to_string_bytes(),
+                side_effect_id,
+            ),
+            GatewayVendor::Kusama => pallet_grandpa_finality_verifier::Pallet::<T, KusamaBridge>::confirm_and_decode_payload_params(
+                gateway_id,
+                encoded_inclusion_data,
+                submission_target_height,
+                value_abi_unsigned_type.to_string_bytes(),
+                side_effect_id,
+            ),
+            GatewayVendor::Ethereum => T::EthereumLightClient::confirm_and_decode_payload_params(
+                gateway_id,
+                encoded_inclusion_data,
+                submission_target_height,
+                value_abi_unsigned_type.to_string_bytes(),
+                &<T as Config>::Xdns::get_gateway_security_coordinates(&gateway_id)?,
+                side_effect_id,
+            ),
+            GatewayVendor::EthereumBeacon => T::EthereumBeaconLightClient::confirm_and_decode_payload_params(
+                gateway_id,
+                encoded_inclusion_data,
+                submission_target_height,
+                value_abi_unsigned_type.to_string_bytes(),
+                &<T as Config>::Xdns::get_gateway_security_coordinates(&gateway_id)?,
                 side_effect_id,
             ),
-            _ => unimplemented!()
         }
     }
 }
@@ -1,14 +1,20 @@
 //! Test utilities
 use circuit_mock_runtime::{ExtBuilder, *};
 use codec::{Decode, Encode};
-use frame_support::{assert_noop, assert_ok, dispatch::PostDispatchInfo};
+use frame_support::{
+    assert_noop, assert_ok,
+    dispatch::PostDispatchInfo,
+    traits::{Currency, Get, ReservableCurrency},
+};
 use frame_system::pallet_prelude::OriginFor;
+use pallet_grandpa_finality_verifier::types::GrandpaRegistrationData;
 use serde_json::Value;
 use sp_runtime::{DispatchError, DispatchErrorWithPostInfo};
 use std::fs;
 use t3rn_primitives::{
-    abi::GatewayABIConfig, xdns::AllowedSideEffect, ChainId, GatewayGenesisConfig, GatewaySysProps,
-    GatewayType, GatewayVendor,
+    abi::GatewayABIConfig,
+    xdns::{AllowedSideEffect, GatewayStatus, Xdns},
+    ChainId, GatewayGenesisConfig, GatewaySysProps, GatewayType, GatewayVendor,
 };
 
 fn register_file(
@@ -156,9 +162,102 @@ fn register_rococo_successfully() {
     });
 }
 
+fn register_file_as_vendor(
+    origin: OriginFor<Runtime>,
+    file: &str,
+    vendor: GatewayVendor,
+    valid: bool,
+) -> Result<PostDispatchInfo, DispatchErrorWithPostInfo<PostDispatchInfo>> {
+    let raw_data = fs::read_to_string("./src/mock-data/".to_owned() + file).unwrap();
+    let mut json: Value = serde_json::from_str(raw_data.as_str()).unwrap();
+    json[0]["encoded_gateway_vendor"] = Value::String(hex::encode(vendor.encode()));
+    register(origin, json[0].clone(), valid)
+}
+
+fn register_file_owned_by(
+    origin: OriginFor<Runtime>,
+    file: &str,
+    owner: AccountId,
+    valid: bool,
+) -> Result<PostDispatchInfo, DispatchErrorWithPostInfo<PostDispatchInfo>> {
+    let raw_data = fs::read_to_string("./src/mock-data/".to_owned() + file).unwrap();
+    let mut json: Value = serde_json::from_str(raw_data.as_str()).unwrap();
+    let mut registration_data: GrandpaRegistrationData<AccountId> = Decode::decode(
+        &mut &*hex::decode(json[0]["encoded_registration_data"].as_str().unwrap()).unwrap(),
+    )
+    .unwrap();
+    registration_data.owner = owner;
+    json[0]["encoded_registration_data"] = Value::String(hex::encode(registration_data.encode()));
+    register(origin, json[0].clone(), valid)
+}
+
+#[test]
+fn registers_polkadot_and_kusama_with_their_own_verifier_instances() {
+    let origin = Origin::root();
+    let gateway_id: ChainId = *b"roco";
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(register_file_as_vendor(
+            origin,
+            "1-register-roco.json",
+            GatewayVendor::Polkadot,
+            true
+        ));
+        assert!(pallet_grandpa_finality_verifier::Pallet::<
+            Runtime,
+            t3rn_primitives::portal::PolkadotBridge,
+        >::get_latest_finalized_header(gateway_id)
+        .is_some());
+        assert!(pallet_grandpa_finality_verifier::Pallet::<
+            Runtime,
+            t3rn_primitives::portal::RococoBridge,
+        >::get_latest_finalized_header(gateway_id)
+        .is_none());
+        assert_eq!(
+            <Portal as t3rn_primitives::portal::Portal<Runtime>>::get_latest_finalized_header(
+                gateway_id
+            )
+            .unwrap(),
+            pallet_grandpa_finality_verifier::Pallet::<
+                Runtime,
+                t3rn_primitives::portal::PolkadotBridge,
+            >::get_latest_finalized_header(gateway_id)
+        );
+    });
+
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(register_file_as_vendor(
+            Origin::root(),
+            "1-register-roco.json",
+            GatewayVendor::Kusama,
+            true
+        ));
+        assert!(pallet_grandpa_finality_verifier::Pallet::<
+            Runtime,
+            t3rn_primitives::portal::KusamaBridge,
+        >::get_latest_finalized_header(gateway_id)
+        .is_some());
+    });
+}
+
+#[test]
+fn failed_light_client_initialization_leaves_no_xdns_record() {
+    let origin = Origin::root();
+    let gateway_id: ChainId = *b"roco";
+    ExtBuilder::default().build().execute_with(|| {
+        // GRANDPA registration data can't be decoded by the Ethereum light clients
+        for vendor in [GatewayVendor::Ethereum, GatewayVendor::EthereumBeacon] {
+            assert_noop!(
+                register_file_as_vendor(origin.clone(), "1-register-roco.json", vendor, false),
+                pallet_portal::Error::<Runtime>::RegistrationError
+            );
+            assert!(pallet_xdns::XDNSRegistry::<Runtime>::get(gateway_id).is_none());
+        }
+    });
+}
+
 #[test]
 fn fails_registration_with_invalid_signer() {
-    let origin = Origin::signed([0u8; 32].into()); // only sudo access to register new gateways for now
+    let origin = Origin::none();
     ExtBuilder::default().build().execute_with(|| {
         assert_noop!(
             register_file(origin, "1-register-roco.json", false, 0),
@@ -167,6 +266,78 @@ fn fails_registration_with_invalid_signer() {
     });
 }
 
+#[test]
+fn fails_registration_of_a_signer_without_funds_for_the_bond() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        assert!(register_file_owned_by(origin, "1-register-roco.json", ALICE, false).is_err());
+        assert!(pallet_xdns::XDNSRegistry::<Runtime>::get(*b"roco").is_none());
+    });
+}
+
+#[test]
+fn fails_registration_of_a_light_client_owned_by_another_account() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        let bond = <Runtime as pallet_xdns::Config>::RegistrationBond::get();
+        let _ = Balances::deposit_creating(&ALICE, 2 * bond);
+        assert_noop!(
+            register_file(origin, "1-register-roco.json", false, 0),
+            pallet_portal::Error::<Runtime>::RegistrantNotOwner
+        );
+    });
+}
+
+#[test]
+fn signer_registers_a_bonded_gateway_with_its_light_client() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        let bond = <Runtime as pallet_xdns::Config>::RegistrationBond::get();
+        let _ = Balances::deposit_creating(&ALICE, 2 * bond);
+        assert_ok!(register_file_owned_by(
+            origin,
+            "1-register-roco.json",
+            ALICE,
+            true
+        ));
+
+        let xdns_record = pallet_xdns::XDNSRegistry::<Runtime>::get(*b"roco").unwrap();
+        assert_eq!(xdns_record.registrant, Some(ALICE));
+        assert_eq!(Balances::reserved_balance(&ALICE), bond);
+        assert!(
+            <Portal as t3rn_primitives::portal::Portal<Runtime>>::get_latest_finalized_header(
+                *b"roco"
+            )
+            .unwrap()
+            .is_some()
+        );
+    });
+}
+
+#[test]
+fn deregistered_gateway_id_cant_be_registered_again() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        let bond = <Runtime as pallet_xdns::Config>::RegistrationBond::get();
+        let _ = Balances::deposit_creating(&ALICE, 2 * bond);
+        assert_ok!(register_file_owned_by(
+            origin.clone(),
+            "1-register-roco.json",
+            ALICE,
+            true
+        ));
+        assert_ok!(XDNS::deregister_xdns_record(origin.clone(), *b"roco"));
+        assert_eq!(Balances::reserved_balance(&ALICE), 0);
+
+        // the light client of the gateway stays initialized
+        assert_noop!(
+            register_file_owned_by(origin, "1-register-roco.json", ALICE, false),
+            pallet_portal::Error::<Runtime>::RegistrationError
+        );
+        assert!(pallet_xdns::XDNSRegistry::<Runtime>::get(*b"roco").is_none());
+    });
+}
+
 #[test]
 fn gateway_can_only_be_registered_once() {
     let origin = Origin::root(); // only sudo access to register new gateways for now
@@ -208,6 +379,32 @@ fn cant_submit_with_gap() {
     });
 }
 
+#[test]
+fn submitting_headers_reactivates_the_gateway() {
+    let root = Origin::root();
+    let origin = Origin::signed([0u8; 32].into());
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(register_file(root, "1-register-roco.json", true, 0));
+        assert_eq!(XDNS::get_gateway_status(b"roco"), Ok(GatewayStatus::Active));
+
+        let inactive_at = <Runtime as pallet_xdns::Config>::GatewayInactivityThreshold::get() + 1;
+        Timestamp::set_timestamp(inactive_at);
+        assert_eq!(
+            XDNS::get_gateway_status(b"roco"),
+            Ok(GatewayStatus::Inactive)
+        );
+
+        assert_ok!(submit_header_file(origin, "2-headers-roco.json", 0));
+        assert_eq!(XDNS::get_gateway_status(b"roco"), Ok(GatewayStatus::Active));
+        assert_eq!(
+            pallet_xdns::XDNSRegistry::<Runtime>::get(b"roco")
+                .unwrap()
+                .last_finalized,
+            Some(inactive_at)
+        );
+    });
+}
+
 #[test]
 fn can_submit_valid_header_data() {
     let root = Origin::root();
@@ -245,6 +442,100 @@ fn can_register_parachain_and_add_header() {
     });
 }
 
+#[test]
+fn lists_latest_head_of_registered_parachains() {
+    let root = Origin::root();
+    let origin = Origin::signed([0u8; 32].into());
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(register_file(root.clone(), "1-register-roco.json", true, 0));
+        assert_eq!(Portal::get_latest_parachain_heads(), vec![]);
+
+        assert_ok!(register_file(root, "4-register-pang.json", true, 0));
+        assert_ok!(submit_header_file(origin.clone(), "2-headers-roco.json", 0));
+        assert_ok!(submit_header_file(origin.clone(), "5-headers-roco.json", 0));
+        assert_ok!(submit_header_file(origin, "7-headers-pang.json", 0));
+
+        let heads = Portal::get_latest_parachain_heads();
+        assert_eq!(heads.len(), 1);
+        assert_eq!(heads[0].gateway_id, *b"pang");
+        assert_eq!(heads[0].parachain.relay_chain_id, *b"roco");
+        assert_eq!(
+            Some(heads[0].header_hash.clone()),
+            <Portal as t3rn_primitives::portal::Portal<Runtime>>::get_latest_finalized_header(
+                *b"pang"
+            )
+            .unwrap()
+        );
+    });
+}
+
+#[test]
+fn rejects_malformed_parachain_heads_batch() {
+    let root = Origin::root();
+    let origin = Origin::signed([0u8; 32].into());
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(register_file(root, "1-register-roco.json", true, 0));
+        assert_noop!(
+            Portal::submit_parachain_heads(origin, *b"roco", vec![1, 2, 3], 1),
+            pallet_portal::Error::<Runtime>::SubmitHeaderError
+        );
+    });
+}
+
+#[test]
+fn rejects_parachain_heads_batch_above_the_limit() {
+    let root = Origin::root();
+    let origin = Origin::signed([0u8; 32].into());
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(register_file(root, "1-register-roco.json", true, 0));
+        let max = <Runtime as pallet_portal::Config>::MaxParachainHeadsPerBatch::get();
+        assert_noop!(
+            Portal::submit_parachain_heads(origin, *b"roco", vec![], max + 1),
+            pallet_portal::Error::<Runtime>::TooManyParachainHeads
+        );
+    });
+}
+
+#[test]
+fn governance_can_change_headers_to_store() {
+    let root = Origin::root();
+    let origin = Origin::signed([0u8; 32].into());
+    let gateway_id: ChainId = *b"roco";
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(register_file(root.clone(), "1-register-roco.json", true, 0));
+        assert_ok!(submit_header_file(origin.clone(), "2-headers-roco.json", 0));
+        let latest_height =
+            <Portal as t3rn_primitives::portal::Portal<Runtime>>::get_latest_finalized_height(
+                gateway_id,
+            )
+            .unwrap();
+
+        assert_noop!(
+            Portal::set_headers_to_store(origin, gateway_id, 1, 1),
+            pallet_portal::Error::<Runtime>::SetHeadersToStoreError
+        );
+        // 100 headers are kept so far
+        assert_noop!(
+            Portal::set_headers_to_store(root.clone(), gateway_id, 1, 99),
+            pallet_portal::Error::<Runtime>::SetHeadersToStoreError
+        );
+        assert_eq!(
+            Portal::set_headers_to_store(root, gateway_id, 1, 1_000)
+                .unwrap()
+                .actual_weight,
+            Some(<<Runtime as pallet_portal::Config>::WeightInfo as pallet_portal::weights::WeightInfo>::set_headers_to_store(100, 1))
+        );
+        // only the best finalized header is left to prove against
+        assert_eq!(
+            <Portal as t3rn_primitives::portal::Portal<Runtime>>::get_oldest_provable_height(
+                gateway_id
+            )
+            .unwrap(),
+            latest_height
+        );
+    });
+}
+
 #[test]
 fn can_update_owner() {
     ExtBuilder::default().build().execute_with(|| {
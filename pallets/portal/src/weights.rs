@@ -41,6 +41,8 @@ pub trait WeightInfo {
     fn set_owner() -> Weight;
     fn set_operational() -> Weight;
     fn submit_headers() -> Weight;
+    fn submit_parachain_heads(x: u32) -> Weight;
+    fn set_headers_to_store(o: u32, n: u32) -> Weight;
 }
 
 /// Weights for pallet_xdns using the Substrate node and recommended hardware.
@@ -68,6 +70,36 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     fn submit_headers() -> Weight {
         25_265_000_u64.saturating_add(T::DbWeight::get().reads(1_u64))
     }
+
+    // Storage: XDNS XDNSRegistry (r:1 w:1 per head)
+    // Storage: GrandpaFinalityVerifier ParachainIdMap (r:1 per head)
+    // Storage: GrandpaFinalityVerifier BestFinalizedMap (r:1 w:1 per head)
+    // Storage: GrandpaFinalityVerifier MultiImportedHeaders (r:1 w:1 per head)
+    fn submit_parachain_heads(x: u32) -> Weight {
+        30_120_000_u64
+            .saturating_add(21_480_000_u64.saturating_mul(x as u64))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().reads(4_u64.saturating_mul(x as u64)))
+            .saturating_add(T::DbWeight::get().writes(3_u64.saturating_mul(x as u64)))
+    }
+
+    // Storage: XDNS XDNSRegistry (r:1 w:0)
+    // Storage: GrandpaFinalityVerifier InstantiatedGatewaysMap (r:1 w:0)
+    // Storage: GrandpaFinalityVerifier HeadersToStoreMap (r:1 w:1)
+    // Storage: GrandpaFinalityVerifier MultiImportedHashesPointer (r:1 w:1)
+    // Storage: GrandpaFinalityVerifier MultiImportedHashes (r:o w:o+n)
+    // Storage: GrandpaFinalityVerifier MultiImportedHeaders (r:0 w:o)
+    // Storage: GrandpaFinalityVerifier MultiImportedRoots (r:0 w:o)
+    fn set_headers_to_store(o: u32, n: u32) -> Weight {
+        24_310_000_u64
+            .saturating_add(4_870_000_u64.saturating_mul(o as u64))
+            .saturating_add(1_920_000_u64.saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().reads(o as u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64.saturating_mul(o as u64)))
+            .saturating_add(T::DbWeight::get().writes(n as u64))
+    }
 }
 
 // For backwards compatibility and tests
@@ -93,4 +125,34 @@ impl WeightInfo for () {
     fn submit_headers() -> Weight {
         25_265_000_u64.saturating_add(RocksDbWeight::get().reads(1_u64))
     }
+
+    // Storage: XDNS XDNSRegistry (r:1 w:1 per head)
+    // Storage: GrandpaFinalityVerifier ParachainIdMap (r:1 per head)
+    // Storage: GrandpaFinalityVerifier BestFinalizedMap (r:1 w:1 per head)
+    // Storage: GrandpaFinalityVerifier MultiImportedHeaders (r:1 w:1 per head)
+    fn submit_parachain_heads(x: u32) -> Weight {
+        30_120_000_u64
+            .saturating_add(21_480_000_u64.saturating_mul(x as u64))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().reads(4_u64.saturating_mul(x as u64)))
+            .saturating_add(RocksDbWeight::get().writes(3_u64.saturating_mul(x as u64)))
+    }
+
+    // Storage: XDNS XDNSRegistry (r:1 w:0)
+    // Storage: GrandpaFinalityVerifier InstantiatedGatewaysMap (r:1 w:0)
+    // Storage: GrandpaFinalityVerifier HeadersToStoreMap (r:1 w:1)
+    // Storage: GrandpaFinalityVerifier MultiImportedHashesPointer (r:1 w:1)
+    // Storage: GrandpaFinalityVerifier MultiImportedHashes (r:o w:o+n)
+    // Storage: GrandpaFinalityVerifier MultiImportedHeaders (r:0 w:o)
+    // Storage: GrandpaFinalityVerifier MultiImportedRoots (r:0 w:o)
+    fn set_headers_to_store(o: u32, n: u32) -> Weight {
+        24_310_000_u64
+            .saturating_add(4_870_000_u64.saturating_mul(o as u64))
+            .saturating_add(1_920_000_u64.saturating_mul(n as u64))
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().reads(o as u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64.saturating_mul(o as u64)))
+            .saturating_add(RocksDbWeight::get().writes(n as u64))
+    }
 }
@@ -6,8 +6,12 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use codec::Codec;
-pub use t3rn_primitives::{abi::GatewayABIConfig, xdns::FetchXdnsRecordsResponse, ChainId};
+use codec::{alloc::vec::Vec, Codec};
+pub use t3rn_primitives::{
+    abi::GatewayABIConfig,
+    xdns::{FetchSideEffectInterfaceResponse, FetchXdnsRecordsResponse, XdnsRecord},
+    ChainId, GatewayType, GatewayVendor,
+};
 
 sp_api::decl_runtime_apis! {
     /// The API to interact with pallet XDNS
@@ -19,5 +23,20 @@ sp_api::decl_runtime_apis! {
 
         /// Returns the GatewayABIConfig for a given ChainId
         fn fetch_abi(chain_id: ChainId) -> Option<GatewayABIConfig>;
+
+        /// Returns the XdnsRecord for a given ChainId
+        fn fetch_record(chain_id: ChainId) -> Option<XdnsRecord<AccountId>>;
+
+        /// Returns the standard interface of a side effect, or the custom ones stored by gateways
+        fn fetch_side_effect_interface(id: [u8; 4]) -> Vec<FetchSideEffectInterfaceResponse>;
+
+        /// Returns the ChainIds of gateways allowing a given side effect
+        fn fetch_gateways_for_side_effect(id: [u8; 4]) -> Vec<ChainId>;
+
+        /// Returns metadata of Blockchains matching the given vendor and gateway type
+        fn fetch_records_by(
+            gateway_vendor: Option<GatewayVendor>,
+            gateway_type: Option<GatewayType>,
+        ) -> FetchXdnsRecordsResponse<AccountId>;
     }
 }
@@ -7,7 +7,10 @@ use jsonrpsee::{
     types::error::CallError,
 };
 pub use pallet_xdns_rpc_runtime_api::XdnsRuntimeApi;
-use pallet_xdns_rpc_runtime_api::{ChainId, FetchXdnsRecordsResponse, GatewayABIConfig};
+use pallet_xdns_rpc_runtime_api::{
+    ChainId, FetchSideEffectInterfaceResponse, FetchXdnsRecordsResponse, GatewayABIConfig,
+    GatewayType, GatewayVendor, XdnsRecord,
+};
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
 use sp_runtime::{
@@ -26,6 +29,29 @@ pub trait XdnsApi<AccountId> {
 
     #[method(name = "xdns_fetchAbi")]
     fn fetch_abi(&self, chain_id: ChainId) -> RpcResult<GatewayABIConfig>;
+
+    /// Returns the XDNS record of a given gateway
+    #[method(name = "xdns_fetchRecord")]
+    fn fetch_record(&self, chain_id: ChainId) -> RpcResult<XdnsRecord<AccountId>>;
+
+    /// Returns the interfaces known for a given side effect id
+    #[method(name = "xdns_fetchSideEffectInterface")]
+    fn fetch_side_effect_interface(
+        &self,
+        id: [u8; 4],
+    ) -> RpcResult<Vec<FetchSideEffectInterfaceResponse>>;
+
+    /// Returns the gateways allowing a given side effect id
+    #[method(name = "xdns_fetchGatewaysForSideEffect")]
+    fn fetch_gateways_for_side_effect(&self, id: [u8; 4]) -> RpcResult<Vec<ChainId>>;
+
+    /// Returns the XDNS records filtered by vendor and gateway type
+    #[method(name = "xdns_fetchRecordsBy")]
+    fn fetch_records_by(
+        &self,
+        gateway_vendor: Option<GatewayVendor>,
+        gateway_type: Option<GatewayType>,
+    ) -> RpcResult<FetchXdnsRecordsResponse<AccountId>>;
 }
 
 /// A struct that implements the [`XdnsApiServer`].
@@ -75,6 +101,61 @@ where
         }
         .map_err(runtime_error_into_rpc_err)
     }
+
+    fn fetch_record(&self, chain_id: ChainId) -> RpcResult<XdnsRecord<AccountId>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(self.client.info().best_hash);
+
+        let result: Option<XdnsRecord<AccountId>> = api
+            .fetch_record(&at, chain_id)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        match result {
+            Some(record) => Ok(record),
+            None => Err("XDNS record doesn't exist"),
+        }
+        .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn fetch_side_effect_interface(
+        &self,
+        id: [u8; 4],
+    ) -> RpcResult<Vec<FetchSideEffectInterfaceResponse>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(self.client.info().best_hash);
+
+        let result = api
+            .fetch_side_effect_interface(&at, id)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(result)
+    }
+
+    fn fetch_gateways_for_side_effect(&self, id: [u8; 4]) -> RpcResult<Vec<ChainId>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(self.client.info().best_hash);
+
+        let result = api
+            .fetch_gateways_for_side_effect(&at, id)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(result)
+    }
+
+    fn fetch_records_by(
+        &self,
+        gateway_vendor: Option<GatewayVendor>,
+        gateway_type: Option<GatewayType>,
+    ) -> RpcResult<FetchXdnsRecordsResponse<AccountId>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(self.client.info().best_hash);
+
+        let result = api
+            .fetch_records_by(&at, gateway_vendor, gateway_type)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(result)
+    }
 }
 
 fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> JsonRpseeError {
@@ -6,8 +6,6 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::type_complexity)]
 #![allow(clippy::too_many_arguments)]
-use codec::Encode;
-use sp_runtime::traits::Hash;
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 pub use t3rn_primitives::{
     abi::{GatewayABIConfig, Type},
@@ -24,6 +22,7 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
 pub mod weights;
 
 use weights::WeightInfo;
@@ -39,14 +38,18 @@ pub mod pallet {
         pallet_prelude::*,
         traits::{
             fungible::{Inspect, Mutate},
-            Time,
+            Currency, ReservableCurrency, Time,
         },
     };
-    use frame_system::pallet_prelude::*;
+    use frame_system::{pallet_prelude::*, RawOrigin};
+    use sp_runtime::traits::BadOrigin;
     use sp_std::convert::TryInto;
     use t3rn_primitives::{
-        side_effect::interface::SideEffectInterface,
-        xdns::{AllowedSideEffect, Parachain, Xdns, XdnsRecord},
+        side_effect::interface::{SideEffectInterface, VersionedSideEffectInterface},
+        xdns::{
+            AllowedSideEffect, FetchSideEffectInterfaceResponse, GatewayStatus, Parachain, Xdns,
+            XdnsRecord, XdnsRecordChange,
+        },
         Bytes, ChainId, EscrowTrait, GatewaySysProps, GatewayType, GatewayVendor,
     };
 
@@ -63,12 +66,34 @@ pub mod pallet {
 
         /// A type that manages escrow, and therefore balances
         type Escrowed: EscrowTrait<Self>;
+
+        /// The currency in which registrants bond their gateways
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// The bond reserved from a registrant for each gateway registered without root access
+        #[pallet::constant]
+        type RegistrationBond: Get<BalanceOf<Self>>;
+
+        /// Gateway ids only root can register, like the ones of the circuit itself
+        type ReservedGatewayIds: Get<Vec<ChainId>>;
+
+        /// Time since the latest finalized header after which a gateway becomes inactive,
+        /// in the unit of the escrow's `Time`
+        #[pallet::constant]
+        type GatewayInactivityThreshold: Get<u64>;
     }
 
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// The current storage version, bumped by the migrations of `crate::migrations`
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
     // Simple declaration of the `Pallet` type. It is placeholder we use to implement traits and
     // method.
     #[pallet::pallet]
     #[pallet::generate_store(pub (super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
 
@@ -85,6 +110,25 @@ pub mod pallet {
             0
         }
 
+        // Each MigrateToVx only runs at the storage version preceding x and bumps it to x,
+        //  so that chaining them upgrades the storage from any previous version.
+        fn on_runtime_upgrade() -> Weight {
+            <crate::migrations::v1::MigrateToV1<T> as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade()
+                .saturating_add(<crate::migrations::v2::MigrateToV2<T> as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<(), &'static str> {
+            <crate::migrations::v1::MigrateToV1<T> as frame_support::traits::OnRuntimeUpgrade>::pre_upgrade()?;
+            <crate::migrations::v2::MigrateToV2<T> as frame_support::traits::OnRuntimeUpgrade>::pre_upgrade()
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            <crate::migrations::v1::MigrateToV1<T> as frame_support::traits::OnRuntimeUpgrade>::post_upgrade()?;
+            <crate::migrations::v2::MigrateToV2<T> as frame_support::traits::OnRuntimeUpgrade>::post_upgrade()
+        }
+
         // `on_finalize` is executed at the end of block after all extrinsic are dispatched.
         fn on_finalize(_n: T::BlockNumber) {
             // Perform necessary data/state clean up here.
@@ -104,9 +148,13 @@ pub mod pallet {
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
+        /// Stores a custom side effect interface in the namespace of a gateway and allows it there.
+        /// Storing an interface under an id already used by the gateway upgrades it to a new version.
+        /// Root only access.
         #[pallet::weight(< T as Config >::WeightInfo::add_new_xdns_record())]
         pub fn add_side_effect(
             origin: OriginFor<T>,
+            gateway_id: ChainId,
             id: [u8; 4],
             name: SideEffectName,
             argument_abi: Vec<Type>,
@@ -118,11 +166,12 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
             ensure_root(origin)?;
 
-            let side_effect_id: SideEffectId<T> = T::Hashing::hash(&id.encode());
+            if !<XDNSRegistry<T>>::contains_key(gateway_id) {
+                return Err(Error::<T>::XdnsRecordNotFound.into())
+            }
 
-            if <CustomSideEffects<T>>::contains_key(side_effect_id)
-                | <StandardSideEffects<T>>::contains_key(id)
-            {
+            // standard side effects can't be shadowed by a gateway
+            if <StandardSideEffects<T>>::contains_key(id) {
                 return Err(Error::<T>::SideEffectInterfaceAlreadyExists.into())
             }
 
@@ -137,11 +186,131 @@ pub mod pallet {
                 revert_events,
             };
 
-            <CustomSideEffects<T>>::insert(side_effect_id, side_effect);
+            side_effect
+                .validate()
+                .map_err(|_| Error::<T>::InvalidSideEffectInterface)?;
+
+            let previous = <CustomSideEffects<T>>::get(gateway_id, id);
+            let version = previous
+                .as_ref()
+                .map(|entry| entry.version.saturating_add(1))
+                .unwrap_or(1);
+            if let Some(previous) = previous {
+                // pending Xtx validated against the previous version keep resolving it
+                if <SideEffectInterfacePins<T>>::contains_key((gateway_id, id, previous.version)) {
+                    <SupersededSideEffects<T>>::insert(
+                        (gateway_id, id, previous.version),
+                        previous.interface,
+                    );
+                }
+            }
+
+            <CustomSideEffects<T>>::insert(
+                gateway_id,
+                id,
+                VersionedSideEffectInterface {
+                    version,
+                    interface: side_effect,
+                },
+            );
+
+            let allowed_side_effects = <XDNSRegistry<T>>::get(gateway_id)
+                .map(|record| record.allowed_side_effects)
+                .unwrap_or_default();
+            if !allowed_side_effects.contains(&id) {
+                Self::update_xdns_record(gateway_id, |record| {
+                    let mut extended = allowed_side_effects;
+                    extended.push(id);
+                    record.set_allowed_side_effects(extended)
+                })?;
+            }
 
+            Self::deposit_event(Event::<T>::SideEffectInterfaceStored(
+                gateway_id, id, version,
+            ));
             Ok(().into())
         }
 
+        /// Updates the url of an xdns_record in place. Registrant or root only access.
+        #[pallet::weight(< T as Config >::WeightInfo::update_ttl())]
+        pub fn update_url(
+            origin: OriginFor<T>,
+            gateway_id: ChainId,
+            url: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_registrant_or_root(origin, &gateway_id)?;
+            Self::update_xdns_record(gateway_id, |record| XdnsRecordChange::Url {
+                old: sp_std::mem::replace(&mut record.url, url.clone()),
+                new: url,
+            })
+        }
+
+        /// Updates the gateway_abi of an xdns_record in place. Registrant or root only access,
+        /// while no pending Xtx targets the gateway.
+        #[pallet::weight(< T as Config >::WeightInfo::update_ttl())]
+        pub fn update_gateway_abi(
+            origin: OriginFor<T>,
+            gateway_id: ChainId,
+            gateway_abi: GatewayABIConfig,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_registrant_or_root(origin, &gateway_id)?;
+            Self::ensure_no_pending_xtx(&gateway_id)?;
+            Self::update_xdns_record(gateway_id, |record| XdnsRecordChange::GatewayAbi {
+                old: sp_std::mem::replace(&mut record.gateway_abi, gateway_abi.clone()),
+                new: gateway_abi,
+            })
+        }
+
+        /// Updates the allowed_side_effects of an xdns_record in place. Registrant or root only access,
+        /// while no pending Xtx targets the gateway.
+        #[pallet::weight(< T as Config >::WeightInfo::update_ttl())]
+        pub fn update_allowed_side_effects(
+            origin: OriginFor<T>,
+            gateway_id: ChainId,
+            allowed_side_effects: Vec<AllowedSideEffect>,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_registrant_or_root(origin, &gateway_id)?;
+            Self::ensure_no_pending_xtx(&gateway_id)?;
+            Self::update_xdns_record(gateway_id, |record| {
+                record.set_allowed_side_effects(allowed_side_effects)
+            })
+        }
+
+        /// Updates the gateway_sys_props of an xdns_record in place. Registrant or root only access,
+        /// while no pending Xtx targets the gateway.
+        #[pallet::weight(< T as Config >::WeightInfo::update_ttl())]
+        pub fn update_gateway_sys_props(
+            origin: OriginFor<T>,
+            gateway_id: ChainId,
+            gateway_sys_props: GatewaySysProps,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_registrant_or_root(origin, &gateway_id)?;
+            Self::ensure_no_pending_xtx(&gateway_id)?;
+            Self::update_xdns_record(gateway_id, |record| XdnsRecordChange::GatewaySysProps {
+                old: sp_std::mem::replace(&mut record.gateway_sys_props, gateway_sys_props.clone()),
+                new: gateway_sys_props,
+            })
+        }
+
+        /// Updates the security_coordinates of an xdns_record in place. Registrant or root only access,
+        /// while no pending Xtx targets the gateway.
+        #[pallet::weight(< T as Config >::WeightInfo::update_ttl())]
+        pub fn update_security_coordinates(
+            origin: OriginFor<T>,
+            gateway_id: ChainId,
+            security_coordinates: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_registrant_or_root(origin, &gateway_id)?;
+            Self::ensure_no_pending_xtx(&gateway_id)?;
+            Self::update_xdns_record(gateway_id, |record| XdnsRecordChange::SecurityCoordinates {
+                old: sp_std::mem::replace(
+                    &mut record.security_coordinates,
+                    security_coordinates.clone(),
+                ),
+                new: security_coordinates,
+            })
+        }
+
         /// Updates the last_finalized field for an xdns_record from the onchain registry. Root only access.
         #[pallet::weight(< T as Config >::WeightInfo::update_ttl())]
         pub fn update_ttl(
@@ -153,7 +322,34 @@ pub mod pallet {
             Self::update_gateway_ttl(gateway_id, last_finalized)
         }
 
+        /// Removes a bonded xdns_record on behalf of its registrant, once no pending Xtx targets it.
+        /// The registration bond gets unreserved. The light client of the gateway stays initialized,
+        /// so its gateway_id can't be registered again.
+        #[pallet::weight(< T as Config >::WeightInfo::purge_xdns_record())]
+        pub fn deregister_xdns_record(
+            origin: OriginFor<T>,
+            gateway_id: ChainId,
+        ) -> DispatchResultWithPostInfo {
+            let registrant = ensure_signed(origin)?;
+            let record =
+                <XDNSRegistry<T>>::get(gateway_id).ok_or(Error::<T>::XdnsRecordNotFound)?;
+            ensure!(record.registrant.as_ref() == Some(&registrant), BadOrigin);
+            Self::ensure_no_pending_xtx(&gateway_id)?;
+
+            <XDNSRegistry<T>>::remove(gateway_id);
+            if let Some((registrant, bond)) = <RegistrationBonds<T>>::take(gateway_id) {
+                T::Currency::unreserve(&registrant, bond);
+                Self::deposit_event(Event::<T>::RegistrationBondReleased(
+                    registrant, gateway_id, bond,
+                ));
+            }
+            Self::deposit_event(Event::<T>::XdnsRecordPurged(registrant, gateway_id));
+            Ok(().into())
+        }
+
         /// Removes a xdns_record from the onchain registry. Root only access.
+        /// Gateways are purged for being faulty, so the registrant's bond gets slashed.
+        /// The light client of the gateway stays initialized, so its gateway_id can't be registered again.
         #[pallet::weight(< T as Config >::WeightInfo::purge_xdns_record())]
         pub fn purge_xdns_record(
             origin: OriginFor<T>,
@@ -165,6 +361,15 @@ pub mod pallet {
                 Err(Error::<T>::UnknownXdnsRecord.into())
             } else {
                 <XDNSRegistry<T>>::remove(xdns_record_id);
+                if let Some((registrant, bond)) = <RegistrationBonds<T>>::take(xdns_record_id) {
+                    // the slashed imbalance is dropped, which burns it
+                    let (_, unslashed) = T::Currency::slash_reserved(&registrant, bond);
+                    Self::deposit_event(Event::<T>::RegistrationBondSlashed(
+                        registrant,
+                        xdns_record_id,
+                        bond - unslashed,
+                    ));
+                }
                 Self::deposit_event(Event::<T>::XdnsRecordPurged(requester, xdns_record_id));
                 Ok(().into())
             }
@@ -180,6 +385,16 @@ pub mod pallet {
         XdnsRecordPurged(T::AccountId, [u8; 4]),
         /// \[xdns_record_id\]
         XdnsRecordUpdated([u8; 4]),
+        /// \[registrant, xdns_record_id, bond\]
+        RegistrationBondReserved(T::AccountId, [u8; 4], BalanceOf<T>),
+        /// \[registrant, xdns_record_id, bond\]
+        RegistrationBondReleased(T::AccountId, [u8; 4], BalanceOf<T>),
+        /// \[registrant, xdns_record_id, slashed\]
+        RegistrationBondSlashed(T::AccountId, [u8; 4], BalanceOf<T>),
+        /// \[xdns_record_id, version, change\]
+        XdnsRecordVersionUpdated([u8; 4], u32, XdnsRecordChange),
+        /// \[gateway_id, side_effect_id, version\]
+        SideEffectInterfaceStored([u8; 4], [u8; 4], u32),
     }
 
     // Errors inform users that something went wrong.
@@ -195,8 +410,14 @@ pub mod pallet {
         SideEffectInterfaceAlreadyExists,
         /// SideEffect interface was not found in storage
         SideEffectInterfaceNotFound,
+        /// SideEffect interface is inconsistent with its argument abi
+        InvalidSideEffectInterface,
         /// the xdns entry does not contain parachain information
         NoParachainInfoFound,
+        /// The gateway id is reserved for root
+        ReservedGatewayId,
+        /// The gateway can't change while pending Xtx target it
+        GatewayHasPendingXtx,
     }
 
     #[pallet::storage]
@@ -204,7 +425,52 @@ pub mod pallet {
 
     #[pallet::storage]
     #[pallet::getter(fn side_effect_registry)]
-    pub type CustomSideEffects<T> = StorageMap<_, Identity, SideEffectId<T>, SideEffectInterface>;
+    pub type CustomSideEffects<T> = StorageDoubleMap<
+        _,
+        Identity,
+        ChainId,
+        Identity,
+        [u8; 4],
+        VersionedSideEffectInterface,
+        OptionQuery,
+    >;
+
+    /// Superseded versions of custom side effect interfaces, kept while pending Xtx pin them.
+    #[pallet::storage]
+    pub type SupersededSideEffects<T> = StorageNMap<
+        _,
+        (
+            NMapKey<Identity, ChainId>,
+            NMapKey<Identity, [u8; 4]>,
+            NMapKey<Identity, u32>,
+        ),
+        SideEffectInterface,
+        OptionQuery,
+    >;
+
+    /// Number of pending Xtx pinning a version of a custom side effect interface.
+    #[pallet::storage]
+    pub type SideEffectInterfacePins<T> = StorageNMap<
+        _,
+        (
+            NMapKey<Identity, ChainId>,
+            NMapKey<Identity, [u8; 4]>,
+            NMapKey<Identity, u32>,
+        ),
+        u32,
+        OptionQuery,
+    >;
+
+    /// Number of pending Xtx targeting each gateway.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_xtx)]
+    pub type PendingXtx<T> = StorageMap<_, Identity, ChainId, u32, ValueQuery>;
+
+    /// Registrant and bond reserved for each gateway registered without root access.
+    #[pallet::storage]
+    #[pallet::getter(fn registration_bonds)]
+    pub type RegistrationBonds<T: Config> =
+        StorageMap<_, Identity, [u8; 4], (T::AccountId, BalanceOf<T>), OptionQuery>;
 
     /// The pre-validated composable xdns_records on-chain registry.
     #[pallet::storage]
@@ -269,7 +535,11 @@ pub mod pallet {
             security_coordinates: Vec<u8>,
             allowed_side_effects: Vec<AllowedSideEffect>,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            let registrant = match origin.into() {
+                Ok(RawOrigin::Root) => None,
+                Ok(RawOrigin::Signed(registrant)) => Some(registrant),
+                _ => return Err(BadOrigin.into()),
+            };
 
             // early exit if record already exists in storage
             if <XDNSRegistry<T>>::contains_key(gateway_id) {
@@ -290,13 +560,20 @@ pub mod pallet {
                 allowed_side_effects,
             );
 
-            // ToDo: Uncomment when switching into a model with open registration. Sudo access for now.
-            // xdns_record.assign_registrant(registrant.clone());
-            let now =
-                TryInto::<u64>::try_into(<<T as Config>::Escrowed as EscrowTrait<T>>::Time::now())
-                    .map_err(|_| "Unable to compute current timestamp")?;
-
-            xdns_record.set_last_finalized(now);
+            if let Some(registrant) = registrant {
+                ensure!(
+                    !T::ReservedGatewayIds::get().contains(&gateway_id),
+                    Error::<T>::ReservedGatewayId
+                );
+                let bond = T::RegistrationBond::get();
+                T::Currency::reserve(&registrant, bond)?;
+                xdns_record.assign_registrant(registrant.clone());
+                <RegistrationBonds<T>>::insert(gateway_id, (registrant.clone(), bond));
+                Self::deposit_event(Event::<T>::RegistrationBondReserved(
+                    registrant, gateway_id, bond,
+                ));
+            }
+            xdns_record.set_last_finalized(Self::now()?);
             <XDNSRegistry<T>>::insert(gateway_id, xdns_record);
             Self::deposit_event(Event::<T>::XdnsRecordStored(gateway_id));
             Ok(())
@@ -315,8 +592,9 @@ pub mod pallet {
                         // is it somehow possible to only pass a reference here? aka each gateway would access the same addresses/structs in memory?
                         let se = <StandardSideEffects<T>>::get(side_effect).unwrap();
                         allowed_side_effects.insert(se.get_id(), Box::new(se.clone()));
-                    } else {
-                        // TODO implement custom side_effect lookup
+                    } else if let Some(entry) = <CustomSideEffects<T>>::get(gateway_id, side_effect)
+                    {
+                        allowed_side_effects.insert(side_effect, Box::new(entry.interface));
                     }
                 }
             }
@@ -324,19 +602,68 @@ pub mod pallet {
             allowed_side_effects
         }
 
+        /// Resolves standard side effects first, then the custom ones stored for the gateway
         fn fetch_side_effect_interface(
+            gateway_id: &ChainId,
             id: [u8; 4],
         ) -> Result<Box<dyn SideEffectProtocol>, DispatchError> {
             if <StandardSideEffects<T>>::contains_key(id) {
                 Ok(Box::new(<StandardSideEffects<T>>::get(id).unwrap()))
             } else {
-                match <CustomSideEffects<T>>::get(T::Hashing::hash(&id.encode())) {
-                    Some(entry) => Ok(Box::new(entry)),
+                match <CustomSideEffects<T>>::get(gateway_id, id) {
+                    Some(entry) => Ok(Box::new(entry.interface)),
                     None => Err(Error::<T>::SideEffectInterfaceNotFound.into()),
                 }
             }
         }
 
+        fn pin_side_effect_interface(gateway_id: &ChainId, id: [u8; 4]) -> Option<u32> {
+            if <StandardSideEffects<T>>::contains_key(id) {
+                return None
+            }
+            let version = <CustomSideEffects<T>>::get(gateway_id, id)?.version;
+            <SideEffectInterfacePins<T>>::mutate((gateway_id, id, version), |pins| {
+                *pins = Some(pins.unwrap_or_default().saturating_add(1))
+            });
+            Some(version)
+        }
+
+        fn pin_gateway(gateway_id: &ChainId) {
+            <PendingXtx<T>>::mutate(gateway_id, |pending| *pending = pending.saturating_add(1));
+        }
+
+        fn unpin_gateway(gateway_id: &ChainId) {
+            <PendingXtx<T>>::mutate(gateway_id, |pending| *pending = pending.saturating_sub(1));
+        }
+
+        /// Superseded versions are dropped together with their last pin
+        fn unpin_side_effect_interface(gateway_id: &ChainId, id: [u8; 4], version: u32) {
+            let pins_left =
+                <SideEffectInterfacePins<T>>::mutate((gateway_id, id, version), |pins| {
+                    *pins = pins
+                        .map(|pins| pins.saturating_sub(1))
+                        .filter(|pins| *pins > 0);
+                    *pins
+                });
+            if pins_left.is_none() {
+                <SupersededSideEffects<T>>::remove((gateway_id, id, version));
+            }
+        }
+
+        fn fetch_side_effect_interface_version(
+            gateway_id: &ChainId,
+            id: [u8; 4],
+            version: u32,
+        ) -> Result<Box<dyn SideEffectProtocol>, DispatchError> {
+            match <CustomSideEffects<T>>::get(gateway_id, id) {
+                Some(entry) if entry.version == version => Ok(Box::new(entry.interface)),
+                _ => match <SupersededSideEffects<T>>::get((gateway_id, id, version)) {
+                    Some(interface) => Ok(Box::new(interface)),
+                    None => Err(Error::<T>::SideEffectInterfaceNotFound.into()),
+                },
+            }
+        }
+
         fn update_gateway_ttl(
             gateway_id: ChainId,
             last_finalized: u64,
@@ -357,6 +684,43 @@ pub mod pallet {
             }
         }
 
+        fn refresh_gateway_ttl(gateway_id: &ChainId) -> DispatchResult {
+            let now = Self::now()?;
+            XDNSRegistry::<T>::try_mutate(gateway_id, |xdns_record| match xdns_record {
+                None => Err(Error::<T>::XdnsRecordNotFound.into()),
+                Some(record) => {
+                    record.set_last_finalized(now);
+                    Ok(())
+                },
+            })
+        }
+
+        /// Gateways which never reported finality, like the ones seeded at genesis, count as active
+        fn get_gateway_status(chain_id: &ChainId) -> Result<GatewayStatus, DispatchError> {
+            let record = <XDNSRegistry<T>>::get(chain_id).ok_or(Error::<T>::XdnsRecordNotFound)?;
+            match record.last_finalized {
+                Some(last_finalized)
+                    if Self::now()?.saturating_sub(last_finalized)
+                        > T::GatewayInactivityThreshold::get() =>
+                    Ok(GatewayStatus::Inactive),
+                _ => Ok(GatewayStatus::Active),
+            }
+        }
+
+        fn get_gateway_record_version(chain_id: &ChainId) -> Result<u32, DispatchError> {
+            match <XDNSRegistry<T>>::get(chain_id) {
+                Some(rec) => Ok(rec.version),
+                None => Err(Error::<T>::XdnsRecordNotFound.into()),
+            }
+        }
+
+        fn get_gateway_record_fingerprint(chain_id: &ChainId) -> Result<[u8; 32], DispatchError> {
+            match <XDNSRegistry<T>>::get(chain_id) {
+                Some(rec) => Ok(rec.fingerprint()),
+                None => Err(Error::<T>::XdnsRecordNotFound.into()),
+            }
+        }
+
         // Fetches the GatewayABIConfig for a given XDNS record
         fn get_abi(chain_id: ChainId) -> Result<GatewayABIConfig, DispatchError> {
             if !<XDNSRegistry<T>>::contains_key(chain_id) {
@@ -410,4 +774,101 @@ pub mod pallet {
             <XDNSRegistry<T>>::get(chain_id).unwrap().gateway_type
         }
     }
+
+    impl<T: Config> Pallet<T> {
+        /// Returns the standard interface of the side effect, or the custom ones gateways store under its id
+        pub fn fetch_side_effect_interfaces(id: [u8; 4]) -> Vec<FetchSideEffectInterfaceResponse> {
+            if let Some(interface) = <StandardSideEffects<T>>::get(id) {
+                return vec![FetchSideEffectInterfaceResponse {
+                    gateway_id: None,
+                    version: None,
+                    interface,
+                }]
+            }
+            <CustomSideEffects<T>>::iter()
+                .filter(|(_, side_effect_id, _)| *side_effect_id == id)
+                .map(|(gateway_id, _, entry)| FetchSideEffectInterfaceResponse {
+                    gateway_id: Some(gateway_id),
+                    version: Some(entry.version),
+                    interface: entry.interface,
+                })
+                .collect()
+        }
+
+        /// Returns the gateways allowing the side effect, for which its interface resolves
+        pub fn fetch_gateways_for_side_effect(id: [u8; 4]) -> Vec<ChainId> {
+            let is_standard = <StandardSideEffects<T>>::contains_key(id);
+            <XDNSRegistry<T>>::iter_values()
+                .filter(|record| {
+                    record.allowed_side_effects.contains(&id)
+                        && (is_standard
+                            || <CustomSideEffects<T>>::contains_key(record.gateway_id, id))
+                })
+                .map(|record| record.gateway_id)
+                .collect()
+        }
+
+        /// Returns the XDNS records matching the vendor and the kind of gateway type, if given.
+        /// Gateway types match regardless of their confirmations count.
+        pub fn fetch_records_by(
+            gateway_vendor: Option<GatewayVendor>,
+            gateway_type: Option<GatewayType>,
+        ) -> Vec<XdnsRecord<T::AccountId>> {
+            <XDNSRegistry<T>>::iter_values()
+                .filter(|record| {
+                    gateway_vendor
+                        .as_ref()
+                        .map_or(true, |vendor| record.gateway_vendor == *vendor)
+                        && gateway_type.as_ref().map_or(true, |gateway_type| {
+                            sp_std::mem::discriminant(&record.gateway_type)
+                                == sp_std::mem::discriminant(gateway_type)
+                        })
+                })
+                .collect()
+        }
+
+        fn now() -> Result<u64, DispatchError> {
+            TryInto::<u64>::try_into(<<T as Config>::Escrowed as EscrowTrait<T>>::Time::now())
+                .map_err(|_| "Unable to compute current timestamp".into())
+        }
+
+        fn ensure_registrant_or_root(origin: OriginFor<T>, gateway_id: &ChainId) -> DispatchResult {
+            let record =
+                <XDNSRegistry<T>>::get(gateway_id).ok_or(Error::<T>::XdnsRecordNotFound)?;
+            match origin.into() {
+                Ok(RawOrigin::Root) => Ok(()),
+                Ok(RawOrigin::Signed(ref signer)) if Some(signer) == record.registrant.as_ref() =>
+                    Ok(()),
+                _ => Err(BadOrigin.into()),
+            }
+        }
+
+        fn ensure_no_pending_xtx(gateway_id: &ChainId) -> DispatchResult {
+            ensure!(
+                <PendingXtx<T>>::get(gateway_id) == 0,
+                Error::<T>::GatewayHasPendingXtx
+            );
+            Ok(())
+        }
+
+        /// Applies the update to the record in place, bumps its version and emits the change
+        fn update_xdns_record(
+            gateway_id: ChainId,
+            update: impl FnOnce(&mut XdnsRecord<T::AccountId>) -> XdnsRecordChange,
+        ) -> DispatchResultWithPostInfo {
+            let (version, change) =
+                <XDNSRegistry<T>>::try_mutate(gateway_id, |xdns_record| match xdns_record {
+                    None => Err(Error::<T>::XdnsRecordNotFound),
+                    Some(record) => {
+                        let change = update(record);
+                        record.version = record.version.saturating_add(1);
+                        Ok((record.version, change))
+                    },
+                })?;
+            Self::deposit_event(Event::<T>::XdnsRecordVersionUpdated(
+                gateway_id, version, change,
+            ));
+            Ok(().into())
+        }
+    }
 }
@@ -0,0 +1,206 @@
+//! Storage migrations of the XDNS pallet - versioned side effect interfaces and XDNS records.
+
+use crate::{Config, CustomSideEffects, Pallet, XDNSRegistry};
+use codec::{Decode, Encode};
+use frame_support::{
+    storage::migration::storage_key_iter,
+    traits::{Get, GetStorageVersion, OnRuntimeUpgrade, PalletInfoAccess, StorageVersion},
+    weights::Weight,
+    Identity,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+use t3rn_primitives::{
+    abi::GatewayABIConfig,
+    side_effect::interface::{SideEffectInterface, VersionedSideEffectInterface},
+    xdns::{AllowedSideEffect, Parachain, XdnsRecord},
+    ChainId, GatewayGenesisConfig, GatewaySysProps, GatewayType, GatewayVendor,
+};
+
+#[cfg(feature = "try-runtime")]
+use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+/// XdnsRecord as stored before records got versioned
+#[derive(Encode, Decode)]
+pub struct UnversionedXdnsRecord<AccountId> {
+    pub url: Vec<u8>,
+    pub gateway_abi: GatewayABIConfig,
+    pub gateway_genesis: GatewayGenesisConfig,
+    pub gateway_vendor: GatewayVendor,
+    pub gateway_type: GatewayType,
+    pub gateway_id: ChainId,
+    pub parachain: Option<Parachain>,
+    pub gateway_sys_props: GatewaySysProps,
+    pub registrant: Option<AccountId>,
+    pub security_coordinates: Vec<u8>,
+    pub last_finalized: Option<u64>,
+    pub allowed_side_effects: Vec<AllowedSideEffect>,
+}
+
+/// Records stay unversioned until `v2` runs
+fn unversioned_xdns_records<T: Config>() -> Vec<UnversionedXdnsRecord<T::AccountId>> {
+    storage_key_iter::<ChainId, UnversionedXdnsRecord<T::AccountId>, Identity>(
+        <Pallet<T>>::name().as_bytes(),
+        b"XDNSRegistry",
+    )
+    .map(|(_, record)| record)
+    .collect()
+}
+
+pub mod v1 {
+    use super::*;
+
+    /// Custom side effects as stored before they were namespaced per gateway, keyed by the hash of their id.
+    fn take_unversioned_side_effects<T: Config>() -> Vec<SideEffectInterface> {
+        storage_key_iter::<T::Hash, SideEffectInterface, Identity>(
+            <Pallet<T>>::name().as_bytes(),
+            b"CustomSideEffects",
+        )
+        .drain()
+        .map(|(_, interface)| interface)
+        .collect()
+    }
+
+    /// Moves the custom side effects into the namespace of every gateway allowing them.
+    ///     Interfaces stored before were never versioned - they become version 0.
+    pub struct MigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() != 0 {
+                return T::DbWeight::get().reads(1)
+            }
+
+            let interfaces = take_unversioned_side_effects::<T>();
+            let mut reads: u64 = interfaces.len() as u64;
+            let mut writes: u64 = interfaces.len() as u64;
+            for record in unversioned_xdns_records::<T>() {
+                reads = reads.saturating_add(1);
+                for interface in interfaces.iter() {
+                    if record.allowed_side_effects.contains(&interface.id) {
+                        <CustomSideEffects<T>>::insert(
+                            record.gateway_id,
+                            interface.id,
+                            VersionedSideEffectInterface {
+                                version: 0,
+                                interface: interface.clone(),
+                            },
+                        );
+                        writes = writes.saturating_add(1);
+                    }
+                }
+            }
+            StorageVersion::new(1).put::<Pallet<T>>();
+
+            log::info!(
+                target: "runtime::xdns",
+                "Namespaced {} custom side effects per gateway",
+                interfaces.len()
+            );
+
+            T::DbWeight::get().reads_writes(reads.saturating_add(1), writes.saturating_add(1))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<(), &'static str> {
+            if Pallet::<T>::on_chain_storage_version() == 0 {
+                let ids: Vec<[u8; 4]> = storage_key_iter::<T::Hash, SideEffectInterface, Identity>(
+                    <Pallet<T>>::name().as_bytes(),
+                    b"CustomSideEffects",
+                )
+                .map(|(_, interface)| interface.id)
+                .collect();
+                let namespaced = unversioned_xdns_records::<T>()
+                    .iter()
+                    .map(|record| {
+                        ids.iter()
+                            .filter(|id| record.allowed_side_effects.contains(id))
+                            .count() as u32
+                    })
+                    .sum::<u32>();
+                Self::set_temp_storage(namespaced, "namespaced");
+            }
+            Ok(())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            if let Some(namespaced) = Self::get_temp_storage::<u32>("namespaced") {
+                frame_support::ensure!(
+                    <CustomSideEffects<T>>::iter_values()
+                        .filter(|entry| entry.version == 0)
+                        .count() as u32
+                        == namespaced,
+                    "Custom side effects lost while namespacing them per gateway"
+                );
+            }
+            Ok(())
+        }
+    }
+}
+
+pub mod v2 {
+    use super::*;
+
+    /// Versions XDNS records - records stored before were never updated in place, they become version 0.
+    pub struct MigrateToV2<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() != 1 {
+                return T::DbWeight::get().reads(1)
+            }
+
+            let mut translated: u64 = 0;
+            <XDNSRegistry<T>>::translate::<UnversionedXdnsRecord<T::AccountId>, _>(|_, record| {
+                translated = translated.saturating_add(1);
+                Some(XdnsRecord {
+                    url: record.url,
+                    gateway_abi: record.gateway_abi,
+                    gateway_genesis: record.gateway_genesis,
+                    gateway_vendor: record.gateway_vendor,
+                    gateway_type: record.gateway_type,
+                    gateway_id: record.gateway_id,
+                    parachain: record.parachain,
+                    gateway_sys_props: record.gateway_sys_props,
+                    registrant: record.registrant,
+                    security_coordinates: record.security_coordinates,
+                    last_finalized: record.last_finalized,
+                    allowed_side_effects: record.allowed_side_effects,
+                    version: 0,
+                })
+            });
+            StorageVersion::new(2).put::<Pallet<T>>();
+
+            log::info!(
+                target: "runtime::xdns",
+                "Versioned {} XDNS records",
+                translated
+            );
+
+            T::DbWeight::get()
+                .reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<(), &'static str> {
+            if Pallet::<T>::on_chain_storage_version() < 2 {
+                Self::set_temp_storage(unversioned_xdns_records::<T>().len() as u32, "records");
+            }
+            Ok(())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            if let Some(records) = Self::get_temp_storage::<u32>("records") {
+                frame_support::ensure!(
+                    <XDNSRegistry<T>>::iter_values()
+                        .filter(|record| record.version == 0)
+                        .count() as u32
+                        == records,
+                    "XDNS records lost while versioning them"
+                );
+            }
+            Ok(())
+        }
+    }
+}
@@ -19,11 +19,20 @@
 
 use super::*;
 use circuit_mock_runtime::{ExtBuilder, *};
-use codec::Decode;
-use frame_support::{assert_err, assert_noop, assert_ok};
+use codec::{Decode, Encode};
+use frame_support::{
+    assert_err, assert_noop, assert_ok,
+    dispatch::DispatchResultWithPostInfo,
+    traits::{Currency as _, Get, ReservableCurrency as _},
+};
 use frame_system::Origin;
-use sp_runtime::DispatchError;
-use t3rn_primitives::{abi::Type, xdns::Xdns, GatewayType, GatewayVendor};
+use sp_runtime::{DispatchError, DispatchResult};
+use t3rn_primitives::{
+    abi::Type,
+    side_effect::interface::VersionedSideEffectInterface,
+    xdns::{Xdns, XdnsRecord, XdnsRecordChange},
+    GatewaySysProps, GatewayType, GatewayVendor,
+};
 
 const DEFAULT_GATEWAYS_IN_STORAGE_COUNT: usize = 7;
 const STANDARD_SIDE_EFFECTS_COUNT: usize = 9;
@@ -77,6 +86,7 @@ fn should_not_add_a_new_side_effect_if_it_exist() {
             assert_noop!(
                 XDNS::add_side_effect(
                     Origin::<Runtime>::Root.into(),
+                    [3, 3, 3, 3],
                     *b"aliq",
                     b"add_liquidity".to_vec(),
                     vec![
@@ -137,6 +147,47 @@ fn should_add_standard_side_effects() {
         });
 }
 
+fn add_custom_side_effect(
+    gateway_id: ChainId,
+    confirm_events: Vec<EventSignature>,
+) -> DispatchResultWithPostInfo {
+    XDNS::add_side_effect(
+        Origin::<Runtime>::Root.into(),
+        gateway_id,
+        *b"cust",
+        b"custom_side_effect".to_vec(),
+        vec![
+            Type::DynamicAddress,    // argument_0: caller
+            Type::DynamicAddress,    // argument_1: to
+            Type::DynamicBytes,      // argument_2: asset_left
+            Type::DynamicBytes,      // argument_3: asset_right
+            Type::DynamicBytes,      // argument_4: liquidity_token
+            Type::Value,             // argument_5: amount_left
+            Type::Value,             // argument_6: amount_right
+            Type::Value,             // argument_7: amount_liquidity_token
+            Type::OptionalInsurance, // argument_8: insurance
+        ],
+        vec![
+            b"caller".to_vec(),
+            b"to".to_vec(),
+            b"asset_left".to_vec(),
+            b"assert_right".to_vec(),
+            b"liquidity_token".to_vec(),
+            b"amount_left".to_vec(),
+            b"amount_right".to_vec(),
+            b"amount_liquidity_token".to_vec(),
+            b"insurance".to_vec(),
+        ],
+        confirm_events,
+        vec![b"ExecuteToken(xtx_id,to,liquidity_token,amount_liquidity_token)".to_vec()],
+        vec![b"MultiTransfer(executor,to,liquidity_token,amount_liquidity_token)".to_vec()],
+        vec![
+            b"MultiTransfer(executor,caller,asset_left,amount_left)".to_vec(),
+            b"MultiTransfer(executor,caller,asset_right,amount_right)".to_vec(),
+        ],
+    )
+}
+
 #[test]
 fn should_add_a_new_side_effect_if_it_doesnt_exist() {
     ExtBuilder::default()
@@ -144,47 +195,235 @@ fn should_add_a_new_side_effect_if_it_doesnt_exist() {
         .with_default_xdns_records()
         .build()
         .execute_with(|| {
-            assert_ok!(XDNS::add_side_effect(
-                Origin::<Runtime>::Root.into(),
-                *b"cust",
-                b"custom_side_effect".to_vec(),
-                vec![
-                    Type::DynamicAddress,    // argument_0: caller
-                    Type::DynamicAddress,    // argument_1: to
-                    Type::DynamicBytes,      // argument_2: asset_left
-                    Type::DynamicBytes,      // argument_3: asset_right
-                    Type::DynamicBytes,      // argument_4: liquidity_token
-                    Type::Value,             // argument_5: amount_left
-                    Type::Value,             // argument_6: amount_right
-                    Type::Value,             // argument_7: amount_liquidity_token
-                    Type::OptionalInsurance, // argument_8: insurance
-                ],
-                vec![
-                    b"caller".to_vec(),
-                    b"to".to_vec(),
-                    b"asset_left".to_vec(),
-                    b"assert_right".to_vec(),
-                    b"liquidity_token".to_vec(),
-                    b"amount_left".to_vec(),
-                    b"amount_right".to_vec(),
-                    b"amount_liquidity_token".to_vec(),
-                    b"insurance".to_vec(),
-                ],
+            assert_ok!(add_custom_side_effect(
+                [3, 3, 3, 3],
                 vec![b"ExecuteToken(executor,to,liquidity_token,amount_liquidity_token)".to_vec()],
-                vec![b"ExecuteToken(xtx_id,to,liquidity_token,amount_liquidity_token)".to_vec()],
-                vec![b"MultiTransfer(executor,to,liquidity_token,amount_liquidity_token)".to_vec()],
-                vec![
-                    b"MultiTransfer(executor,caller,asset_left,amount_left)".to_vec(),
-                    b"MultiTransfer(executor,caller,asset_right,amount_right)".to_vec()
-                ]
             ));
             assert_eq!(pallet_xdns::CustomSideEffects::<Runtime>::iter().count(), 1);
-            let side_effect = pallet_xdns::CustomSideEffects::<Runtime>::get(
-                <Runtime as frame_system::Config>::Hashing::hash(b"cust"),
-            )
-            .unwrap();
-            assert_eq!(side_effect.get_id(), *b"cust");
-            assert_eq!(side_effect.get_name(), *b"custom_side_effect");
+            let side_effect =
+                pallet_xdns::CustomSideEffects::<Runtime>::get([3, 3, 3, 3], *b"cust").unwrap();
+            assert_eq!(side_effect.version, 1);
+            assert_eq!(side_effect.interface.get_id(), *b"cust");
+            assert_eq!(side_effect.interface.get_name(), *b"custom_side_effect");
+            assert!(pallet_xdns::XDNSRegistry::<Runtime>::get([3, 3, 3, 3])
+                .unwrap()
+                .allowed_side_effects
+                .contains(b"cust"));
+        });
+}
+
+#[test]
+fn should_bump_the_version_of_a_stored_custom_side_effect() {
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let confirm_events =
+                vec![b"ExecuteToken(executor,to,liquidity_token,amount_liquidity_token)".to_vec()];
+            assert_ok!(add_custom_side_effect([3, 3, 3, 3], confirm_events.clone()));
+            assert_ok!(add_custom_side_effect([3, 3, 3, 3], confirm_events));
+
+            let side_effect =
+                pallet_xdns::CustomSideEffects::<Runtime>::get([3, 3, 3, 3], *b"cust").unwrap();
+            assert_eq!(side_effect.version, 2);
+            assert_eq!(
+                pallet_xdns::XDNSRegistry::<Runtime>::get([3, 3, 3, 3])
+                    .unwrap()
+                    .allowed_side_effects
+                    .iter()
+                    .filter(|id| *id == b"cust")
+                    .count(),
+                1
+            );
+        });
+}
+
+#[test]
+fn keeps_upgraded_custom_side_effects_while_pinned() {
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let confirm_events =
+                vec![b"ExecuteToken(executor,to,liquidity_token,amount_liquidity_token)".to_vec()];
+            assert_ok!(add_custom_side_effect([3, 3, 3, 3], confirm_events.clone()));
+            assert_eq!(
+                XDNS::pin_side_effect_interface(&[3, 3, 3, 3], *b"cust"),
+                Some(1)
+            );
+            assert_eq!(
+                XDNS::pin_side_effect_interface(&[3, 3, 3, 3], *b"tran"),
+                None
+            );
+
+            assert_ok!(add_custom_side_effect([3, 3, 3, 3], confirm_events.clone()));
+            assert!(XDNS::fetch_side_effect_interface_version(&[3, 3, 3, 3], *b"cust", 1).is_ok());
+            assert!(XDNS::fetch_side_effect_interface_version(&[3, 3, 3, 3], *b"cust", 2).is_ok());
+
+            XDNS::unpin_side_effect_interface(&[3, 3, 3, 3], *b"cust", 1);
+            assert!(XDNS::fetch_side_effect_interface_version(&[3, 3, 3, 3], *b"cust", 1).is_err());
+
+            // unpinned versions aren't kept once upgraded
+            assert_ok!(add_custom_side_effect([3, 3, 3, 3], confirm_events));
+            assert!(XDNS::fetch_side_effect_interface_version(&[3, 3, 3, 3], *b"cust", 2).is_err());
+            assert_eq!(
+                pallet_xdns::SupersededSideEffects::<Runtime>::iter().count(),
+                0
+            );
+        });
+}
+
+#[test]
+fn migration_to_v1_namespaces_custom_side_effects_per_gateway() {
+    use frame_support::{
+        storage::migration::put_storage_value,
+        traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+    };
+    use sp_runtime::traits::Hash;
+
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            StorageVersion::new(0).put::<XDNS>();
+            let mut interface = pallet_xdns::StandardSideEffects::<Runtime>::get(*b"tran").unwrap();
+            interface.id = *b"cust";
+            put_storage_value(
+                b"XDNS",
+                b"CustomSideEffects",
+                &<Runtime as frame_system::Config>::Hashing::hash(&interface.id.encode()).encode(),
+                interface.clone(),
+            );
+            let mut record = pallet_xdns::XDNSRegistry::<Runtime>::get([3, 3, 3, 3]).unwrap();
+            record.allowed_side_effects.push(*b"cust");
+            put_unversioned_xdns_record(record);
+
+            pallet_xdns::migrations::v1::MigrateToV1::<Runtime>::on_runtime_upgrade();
+
+            assert_eq!(
+                pallet_xdns::CustomSideEffects::<Runtime>::get([3, 3, 3, 3], *b"cust"),
+                Some(VersionedSideEffectInterface {
+                    version: 0,
+                    interface,
+                })
+            );
+            assert_eq!(pallet_xdns::CustomSideEffects::<Runtime>::iter().count(), 1);
+            assert_eq!(XDNS::on_chain_storage_version(), 1);
+        });
+}
+
+#[test]
+fn migration_to_v2_versions_xdns_records() {
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            StorageVersion::new(1).put::<XDNS>();
+            let mut record = pallet_xdns::XDNSRegistry::<Runtime>::get(b"gate").unwrap();
+            record.version = 0;
+            put_unversioned_xdns_record(record.clone());
+
+            pallet_xdns::migrations::v2::MigrateToV2::<Runtime>::on_runtime_upgrade();
+
+            assert_eq!(
+                pallet_xdns::XDNSRegistry::<Runtime>::get(b"gate"),
+                Some(record)
+            );
+            assert_eq!(
+                pallet_xdns::XDNSRegistry::<Runtime>::iter().count(),
+                DEFAULT_GATEWAYS_IN_STORAGE_COUNT
+            );
+            assert_eq!(XDNS::on_chain_storage_version(), 2);
+        });
+}
+
+#[test]
+fn migrations_run_in_order_from_the_unversioned_storage() {
+    use frame_support::traits::{GetStorageVersion, Hooks, StorageVersion};
+
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            StorageVersion::new(0).put::<XDNS>();
+            let mut record = pallet_xdns::XDNSRegistry::<Runtime>::get([3, 3, 3, 3]).unwrap();
+            record.version = 0;
+            put_unversioned_xdns_record(record.clone());
+
+            <XDNS as Hooks<BlockNumber>>::on_runtime_upgrade();
+
+            assert_eq!(
+                pallet_xdns::XDNSRegistry::<Runtime>::get([3, 3, 3, 3]),
+                Some(record)
+            );
+            assert_eq!(XDNS::on_chain_storage_version(), 2);
+        });
+}
+
+#[test]
+fn should_not_add_a_custom_side_effect_for_an_unknown_gateway() {
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                add_custom_side_effect(
+                    *b"unkn",
+                    vec![
+                        b"ExecuteToken(executor,to,liquidity_token,amount_liquidity_token)"
+                            .to_vec()
+                    ],
+                ),
+                pallet_xdns::pallet::Error::<Runtime>::XdnsRecordNotFound
+            );
+        });
+}
+
+#[test]
+fn should_not_add_a_custom_side_effect_inconsistent_with_its_arguments() {
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                add_custom_side_effect([3, 3, 3, 3], vec![]),
+                pallet_xdns::pallet::Error::<Runtime>::InvalidSideEffectInterface
+            );
+            assert_noop!(
+                add_custom_side_effect([3, 3, 3, 3], vec![b"ExecuteToken(executor,to".to_vec()]),
+                pallet_xdns::pallet::Error::<Runtime>::InvalidSideEffectInterface
+            );
+        });
+}
+
+#[test]
+fn fetches_custom_side_effects_only_in_the_gateway_namespace() {
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            assert_ok!(add_custom_side_effect(
+                [3, 3, 3, 3],
+                vec![b"ExecuteToken(executor,to,liquidity_token,amount_liquidity_token)".to_vec()],
+            ));
+
+            assert_eq!(
+                XDNS::allowed_side_effects(&[3, 3, 3, 3]).len(),
+                STANDARD_SIDE_EFFECTS_COUNT + 1
+            );
+            assert!(XDNS::fetch_side_effect_interface(&[3, 3, 3, 3], *b"cust").is_ok());
+            assert!(XDNS::fetch_side_effect_interface(b"gate", *b"cust").is_err());
+            assert!(XDNS::fetch_side_effect_interface(b"gate", *b"tran").is_ok());
         });
 }
 
@@ -410,3 +649,448 @@ fn gate_gateway_vendor_returns_vendor_for_known_record() {
             assert_ok!(actual, GatewayVendor::Rococo);
         });
 }
+
+fn registration_bond() -> BalanceOf<Runtime> {
+    <Runtime as Config>::RegistrationBond::get()
+}
+
+fn register_xdns_record_as(registrant: AccountId, gateway_id: ChainId) -> DispatchResult {
+    <XDNS as Xdns<Runtime>>::add_new_xdns_record(
+        Origin::<Runtime>::Signed(registrant).into(),
+        b"some_url".to_vec(),
+        gateway_id,
+        None,
+        Default::default(),
+        GatewayVendor::Rococo,
+        GatewayType::TxOnly(0),
+        Default::default(),
+        Default::default(),
+        vec![],
+        vec![],
+    )
+}
+
+#[test]
+fn should_register_a_bonded_xdns_record_without_root() {
+    ExtBuilder::default().build().execute_with(|| {
+        let _ = Balances::deposit_creating(&ALICE, 2 * registration_bond());
+        assert_ok!(register_xdns_record_as(ALICE, *b"test"));
+
+        let record = pallet_xdns::XDNSRegistry::<Runtime>::get(b"test").unwrap();
+        assert_eq!(record.registrant, Some(ALICE));
+        assert_eq!(Balances::reserved_balance(&ALICE), registration_bond());
+        assert_eq!(
+            pallet_xdns::RegistrationBonds::<Runtime>::get(b"test"),
+            Some((ALICE, registration_bond()))
+        );
+    });
+}
+
+#[test]
+fn should_not_register_an_xdns_record_without_funds_for_the_bond() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert!(register_xdns_record_as(ALICE, *b"test").is_err());
+        assert!(pallet_xdns::XDNSRegistry::<Runtime>::get(b"test").is_none());
+        assert!(pallet_xdns::RegistrationBonds::<Runtime>::get(b"test").is_none());
+    });
+}
+
+#[test]
+fn should_let_the_registrant_update_their_xdns_record() {
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 2 * registration_bond());
+            assert_ok!(register_xdns_record_as(ALICE, *b"test"));
+
+            assert_ok!(XDNS::update_url(
+                Origin::<Runtime>::Signed(ALICE).into(),
+                *b"test",
+                b"new_url".to_vec(),
+            ));
+            assert_ok!(XDNS::update_allowed_side_effects(
+                Origin::<Runtime>::Signed(ALICE).into(),
+                *b"test",
+                vec![*b"tran"],
+            ));
+
+            let record = pallet_xdns::XDNSRegistry::<Runtime>::get(b"test").unwrap();
+            assert_eq!(record.url, b"new_url".to_vec());
+            assert_eq!(record.allowed_side_effects, vec![*b"tran"]);
+            assert_eq!(XDNS::allowed_side_effects(b"test").len(), 1);
+        });
+}
+
+#[test]
+fn should_not_let_others_update_a_bonded_xdns_record() {
+    ExtBuilder::default().build().execute_with(|| {
+        let _ = Balances::deposit_creating(&ALICE, 2 * registration_bond());
+        assert_ok!(register_xdns_record_as(ALICE, *b"test"));
+
+        assert_noop!(
+            XDNS::update_url(
+                Origin::<Runtime>::Signed(BOB).into(),
+                *b"test",
+                b"new_url".to_vec(),
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            XDNS::update_gateway_abi(
+                Origin::<Runtime>::Signed(BOB).into(),
+                *b"test",
+                Default::default(),
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(XDNS::update_url(
+            Origin::<Runtime>::Root.into(),
+            *b"test",
+            b"new_url".to_vec(),
+        ));
+    });
+}
+
+#[test]
+fn should_slash_the_registration_bond_when_purging_a_faulty_gateway() {
+    ExtBuilder::default().build().execute_with(|| {
+        let _ = Balances::deposit_creating(&ALICE, 2 * registration_bond());
+        assert_ok!(register_xdns_record_as(ALICE, *b"test"));
+
+        assert_ok!(XDNS::purge_xdns_record(
+            Origin::<Runtime>::Root.into(),
+            BOB,
+            *b"test"
+        ));
+
+        assert!(pallet_xdns::XDNSRegistry::<Runtime>::get(b"test").is_none());
+        assert!(pallet_xdns::RegistrationBonds::<Runtime>::get(b"test").is_none());
+        assert_eq!(Balances::reserved_balance(&ALICE), 0);
+        assert_eq!(Balances::free_balance(&ALICE), registration_bond());
+    });
+}
+
+#[test]
+fn should_not_register_a_reserved_gateway_id_without_root() {
+    ExtBuilder::default().build().execute_with(|| {
+        let _ = Balances::deposit_creating(&ALICE, 2 * registration_bond());
+        for gateway_id in <Runtime as Config>::ReservedGatewayIds::get() {
+            assert_noop!(
+                register_xdns_record_as(ALICE, gateway_id),
+                pallet_xdns::Error::<Runtime>::ReservedGatewayId
+            );
+        }
+    });
+}
+
+#[test]
+fn should_unreserve_the_registration_bond_when_the_registrant_deregisters() {
+    ExtBuilder::default().build().execute_with(|| {
+        let _ = Balances::deposit_creating(&ALICE, 2 * registration_bond());
+        assert_ok!(register_xdns_record_as(ALICE, *b"test"));
+
+        assert_noop!(
+            XDNS::deregister_xdns_record(Origin::<Runtime>::Signed(BOB).into(), *b"test"),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(XDNS::deregister_xdns_record(
+            Origin::<Runtime>::Signed(ALICE).into(),
+            *b"test"
+        ));
+
+        assert!(pallet_xdns::XDNSRegistry::<Runtime>::get(b"test").is_none());
+        assert!(pallet_xdns::RegistrationBonds::<Runtime>::get(b"test").is_none());
+        assert_eq!(Balances::reserved_balance(&ALICE), 0);
+        assert_eq!(Balances::free_balance(&ALICE), 2 * registration_bond());
+    });
+}
+
+#[test]
+fn should_not_change_a_gateway_targeted_by_pending_xtx() {
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 2 * registration_bond());
+            assert_ok!(register_xdns_record_as(ALICE, *b"test"));
+            XDNS::pin_gateway(b"test");
+
+            assert_noop!(
+                XDNS::update_gateway_abi(
+                    Origin::<Runtime>::Root.into(),
+                    *b"test",
+                    Default::default(),
+                ),
+                pallet_xdns::Error::<Runtime>::GatewayHasPendingXtx
+            );
+            assert_noop!(
+                XDNS::update_allowed_side_effects(
+                    Origin::<Runtime>::Signed(ALICE).into(),
+                    *b"test",
+                    vec![*b"tran"],
+                ),
+                pallet_xdns::Error::<Runtime>::GatewayHasPendingXtx
+            );
+            assert_noop!(
+                XDNS::update_gateway_sys_props(
+                    Origin::<Runtime>::Signed(ALICE).into(),
+                    *b"test",
+                    GatewaySysProps {
+                        ss58_format: 42,
+                        token_symbol: b"NEW".to_vec(),
+                        token_decimals: 18,
+                    },
+                ),
+                pallet_xdns::Error::<Runtime>::GatewayHasPendingXtx
+            );
+            assert_noop!(
+                XDNS::update_security_coordinates(
+                    Origin::<Runtime>::Signed(ALICE).into(),
+                    *b"test",
+                    vec![1, 2, 3],
+                ),
+                pallet_xdns::Error::<Runtime>::GatewayHasPendingXtx
+            );
+            assert_noop!(
+                XDNS::deregister_xdns_record(Origin::<Runtime>::Signed(ALICE).into(), *b"test"),
+                pallet_xdns::Error::<Runtime>::GatewayHasPendingXtx
+            );
+            // the url doesn't change the semantics of pending side effects
+            assert_ok!(XDNS::update_url(
+                Origin::<Runtime>::Signed(ALICE).into(),
+                *b"test",
+                b"new_url".to_vec(),
+            ));
+
+            XDNS::unpin_gateway(b"test");
+            assert_ok!(XDNS::update_allowed_side_effects(
+                Origin::<Runtime>::Signed(ALICE).into(),
+                *b"test",
+                vec![*b"tran"],
+            ));
+            assert_ok!(XDNS::deregister_xdns_record(
+                Origin::<Runtime>::Signed(ALICE).into(),
+                *b"test"
+            ));
+        });
+}
+
+#[test]
+fn should_update_xdns_record_fields_in_place_and_bump_its_version() {
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let record = pallet_xdns::XDNSRegistry::<Runtime>::get(b"gate").unwrap();
+            let new_sys_props = GatewaySysProps {
+                ss58_format: 42,
+                token_symbol: b"NEW".to_vec(),
+                token_decimals: 18,
+            };
+
+            assert_ok!(XDNS::update_gateway_sys_props(
+                Origin::<Runtime>::Root.into(),
+                *b"gate",
+                new_sys_props.clone(),
+            ));
+            System::assert_last_event(circuit_mock_runtime::Event::XDNS(pallet_xdns::Event::<
+                Runtime,
+            >::XdnsRecordVersionUpdated(
+                *b"gate",
+                record.version + 1,
+                XdnsRecordChange::GatewaySysProps {
+                    old: record.gateway_sys_props.clone(),
+                    new: new_sys_props.clone(),
+                },
+            )));
+
+            assert_ok!(XDNS::update_security_coordinates(
+                Origin::<Runtime>::Root.into(),
+                *b"gate",
+                vec![1, 2, 3],
+            ));
+
+            assert_ok!(XDNS::update_url(
+                Origin::<Runtime>::Root.into(),
+                *b"gate",
+                b"ws://new".to_vec(),
+            ));
+            System::assert_last_event(circuit_mock_runtime::Event::XDNS(pallet_xdns::Event::<
+                Runtime,
+            >::XdnsRecordVersionUpdated(
+                *b"gate",
+                record.version + 3,
+                XdnsRecordChange::Url {
+                    old: record.url.clone(),
+                    new: b"ws://new".to_vec(),
+                },
+            )));
+
+            let updated = pallet_xdns::XDNSRegistry::<Runtime>::get(b"gate").unwrap();
+            assert_eq!(updated.version, record.version + 3);
+            assert_eq!(updated.url, b"ws://new".to_vec());
+            assert_eq!(updated.gateway_sys_props, new_sys_props);
+            assert_eq!(updated.security_coordinates, vec![1, 2, 3]);
+            assert_eq!(
+                XDNS::get_gateway_record_version(b"gate"),
+                Ok(record.version + 3)
+            );
+        });
+}
+
+#[test]
+fn should_emit_the_diff_of_allowed_side_effects_update() {
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let record = pallet_xdns::XDNSRegistry::<Runtime>::get(b"gate").unwrap();
+            let mut allowed_side_effects = record.allowed_side_effects.clone();
+            let removed = allowed_side_effects.remove(0);
+            allowed_side_effects.push(*b"cust");
+
+            assert_ok!(XDNS::update_allowed_side_effects(
+                Origin::<Runtime>::Root.into(),
+                *b"gate",
+                allowed_side_effects,
+            ));
+            System::assert_last_event(circuit_mock_runtime::Event::XDNS(pallet_xdns::Event::<
+                Runtime,
+            >::XdnsRecordVersionUpdated(
+                *b"gate",
+                record.version + 1,
+                XdnsRecordChange::AllowedSideEffects {
+                    added: vec![*b"cust"],
+                    removed: vec![removed],
+                },
+            )));
+        });
+}
+
+#[test]
+fn should_bump_the_record_version_when_allowing_a_new_custom_side_effect() {
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let confirm_events =
+                vec![b"ExecuteToken(executor,to,liquidity_token,amount_liquidity_token)".to_vec()];
+            let version = XDNS::get_gateway_record_version(&[3, 3, 3, 3]).unwrap();
+
+            assert_ok!(add_custom_side_effect([3, 3, 3, 3], confirm_events.clone()));
+            assert_eq!(
+                XDNS::get_gateway_record_version(&[3, 3, 3, 3]),
+                Ok(version + 1)
+            );
+
+            // upgrading the interface doesn't change the record
+            assert_ok!(add_custom_side_effect([3, 3, 3, 3], confirm_events));
+            assert_eq!(
+                XDNS::get_gateway_record_version(&[3, 3, 3, 3]),
+                Ok(version + 1)
+            );
+        });
+}
+
+#[test]
+fn fetches_standard_or_custom_side_effect_interfaces_by_id() {
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let standard = XDNS::fetch_side_effect_interfaces(*b"tran");
+            assert_eq!(standard.len(), 1);
+            assert_eq!(standard[0].gateway_id, None);
+            assert_eq!(standard[0].interface.get_id(), *b"tran");
+
+            assert!(XDNS::fetch_side_effect_interfaces(*b"cust").is_empty());
+            assert_ok!(add_custom_side_effect(
+                [3, 3, 3, 3],
+                vec![b"ExecuteToken(executor,to,liquidity_token,amount_liquidity_token)".to_vec()],
+            ));
+
+            let custom = XDNS::fetch_side_effect_interfaces(*b"cust");
+            assert_eq!(custom.len(), 1);
+            assert_eq!(custom[0].gateway_id, Some([3, 3, 3, 3]));
+            assert_eq!(custom[0].version, Some(1));
+        });
+}
+
+#[test]
+fn fetches_gateways_allowing_a_side_effect() {
+    ExtBuilder::default()
+        .with_standard_side_effects()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            assert_eq!(
+                XDNS::fetch_gateways_for_side_effect(*b"tran").len(),
+                DEFAULT_GATEWAYS_IN_STORAGE_COUNT
+            );
+
+            assert_ok!(add_custom_side_effect(
+                [3, 3, 3, 3],
+                vec![b"ExecuteToken(executor,to,liquidity_token,amount_liquidity_token)".to_vec()],
+            ));
+            assert_eq!(
+                XDNS::fetch_gateways_for_side_effect(*b"cust"),
+                vec![[3, 3, 3, 3]]
+            );
+        });
+}
+
+#[test]
+fn fetches_records_filtered_by_vendor_and_gateway_type() {
+    ExtBuilder::default()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            assert_eq!(
+                XDNS::fetch_records_by(None, None).len(),
+                DEFAULT_GATEWAYS_IN_STORAGE_COUNT
+            );
+            assert_eq!(
+                XDNS::fetch_records_by(Some(GatewayVendor::Rococo), None).len(),
+                DEFAULT_GATEWAYS_IN_STORAGE_COUNT
+            );
+            assert!(XDNS::fetch_records_by(Some(GatewayVendor::Ethereum), None).is_empty());
+
+            // gateway types match regardless of their confirmations count
+            let on_circuit = XDNS::fetch_records_by(
+                Some(GatewayVendor::Rococo),
+                Some(GatewayType::OnCircuit(5)),
+            );
+            assert_eq!(on_circuit.len(), 2);
+            assert!(on_circuit
+                .iter()
+                .all(|record| record.gateway_type == GatewayType::OnCircuit(0)));
+        });
+}
+
+/// Stores the record in the layout preceding the record versions
+fn put_unversioned_xdns_record(record: XdnsRecord<AccountId>) {
+    frame_support::storage::migration::put_storage_value(
+        b"XDNS",
+        b"XDNSRegistry",
+        &record.gateway_id.encode(),
+        pallet_xdns::migrations::UnversionedXdnsRecord {
+            url: record.url,
+            gateway_abi: record.gateway_abi,
+            gateway_genesis: record.gateway_genesis,
+            gateway_vendor: record.gateway_vendor,
+            gateway_type: record.gateway_type,
+            gateway_id: record.gateway_id,
+            parachain: record.parachain,
+            gateway_sys_props: record.gateway_sys_props,
+            registrant: record.registrant,
+            security_coordinates: record.security_coordinates,
+            last_finalized: record.last_finalized,
+            allowed_side_effects: record.allowed_side_effects,
+        },
+    );
+}
@@ -6,17 +6,18 @@ use codec::{Decode, Encode};
 use frame_support::dispatch::DispatchResult;
 use scale_info::TypeInfo;
 use sp_runtime::DispatchError;
-use sp_std::{fmt::Debug, prelude::*};
+use sp_std::{fmt::Debug, marker::PhantomData, prelude::*};
 
 pub type ExecutionId = u64;
 
 /// General round information consisting ofindex (one-based), head
 /// (beginning block number), and term (round length in number of blocks).
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
-pub struct Settlement<Account, Balance> {
+pub struct Settlement<Account, Balance, AssetId> {
     pub requester: Account,
     pub recipient: Account,
     pub settlement_amount: Balance,
+    pub maybe_asset_id: Option<AssetId>,
     pub outcome: Outcome,
     pub source: BenefitSource,
     pub role: CircuitRole,
@@ -78,7 +79,13 @@ pub trait AccountManager<Account, Balance, Hash, BlockNumber, AssetId> {
     fn on_collect_claimable(
         n: BlockNumber,
         r: RoundInfo<BlockNumber>,
-    ) -> Result<Vec<ClaimableArtifacts<Account, Balance>>, DispatchError>;
+    ) -> Result<Vec<ClaimableArtifacts<Account, Balance, AssetId>>, DispatchError>;
+
+    /// Sum up native settlements of the round per recipient for the given benefit source
+    fn on_collect_settlements(
+        r: RoundInfo<BlockNumber>,
+        source: BenefitSource,
+    ) -> Vec<(Account, Balance)>;
 
     fn can_withdraw(beneficiary: &Account, amount: Balance, asset_id: Option<AssetId>) -> bool;
 
@@ -90,3 +97,84 @@ pub trait AccountManager<Account, Balance, Hash, BlockNumber, AssetId> {
         asset_id: Option<AssetId>,
     ) -> DispatchResult;
 }
+
+pub struct AccountManagerMock<Account, Balance, Hash, BlockNumber, AssetId> {
+    _phantom: PhantomData<(Account, Balance, Hash, BlockNumber, AssetId)>,
+}
+
+impl<Account, Balance, Hash: Default, BlockNumber, AssetId>
+    AccountManager<Account, Balance, Hash, BlockNumber, AssetId>
+    for AccountManagerMock<Account, Balance, Hash, BlockNumber, AssetId>
+{
+    fn get_charge_or_fail(
+        _charge_id: Hash,
+    ) -> Result<RequestCharge<Account, Balance, AssetId>, DispatchError> {
+        Err("AccountManagerMock::NoChargeOfGivenIdRegistered".into())
+    }
+
+    fn no_charge_or_fail(_charge_id: Hash) -> Result<(), DispatchError> {
+        Ok(())
+    }
+
+    fn bump_contracts_registry_nonce() -> Result<Hash, DispatchError> {
+        Ok(Hash::default())
+    }
+
+    fn deposit(
+        _charge_id: Hash,
+        _payee: &Account,
+        _charge_fee: Balance,
+        _offered_reward: Balance,
+        _source: BenefitSource,
+        _role: CircuitRole,
+        _recipient: Option<Account>,
+        _maybe_asset_id: Option<AssetId>,
+    ) -> DispatchResult {
+        Ok(())
+    }
+
+    fn finalize(
+        _charge_id: Hash,
+        _outcome: Outcome,
+        _maybe_recipient: Option<Account>,
+        _maybe_actual_fees: Option<Balance>,
+    ) -> DispatchResult {
+        Ok(())
+    }
+
+    fn finalize_infallible(
+        _charge_id: Hash,
+        _outcome: Outcome,
+        _maybe_recipient: Option<Account>,
+        _maybe_actual_fees: Option<Balance>,
+    ) {
+    }
+
+    fn on_collect_claimable(
+        _n: BlockNumber,
+        _r: RoundInfo<BlockNumber>,
+    ) -> Result<Vec<ClaimableArtifacts<Account, Balance, AssetId>>, DispatchError> {
+        Ok(vec![])
+    }
+
+    fn on_collect_settlements(
+        _r: RoundInfo<BlockNumber>,
+        _source: BenefitSource,
+    ) -> Vec<(Account, Balance)> {
+        vec![]
+    }
+
+    fn can_withdraw(_beneficiary: &Account, _amount: Balance, _asset_id: Option<AssetId>) -> bool {
+        true
+    }
+
+    fn deposit_immediately(_beneficiary: &Account, _amount: Balance, _asset_id: Option<AssetId>) {}
+
+    fn withdraw_immediately(
+        _beneficiary: &Account,
+        _amount: Balance,
+        _asset_id: Option<AssetId>,
+    ) -> DispatchResult {
+        Ok(())
+    }
+}
@@ -6,7 +6,7 @@ use rlp::RlpStream;
 use scale_info::TypeInfo;
 use sp_io::hashing::keccak_256;
 use sp_runtime::RuntimeDebug;
-use sp_std::{convert::TryInto, prelude::*};
+use sp_std::prelude::*;
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
@@ -87,32 +87,11 @@ impl Header {
         &self,
         proof: &[Vec<u8>],
     ) -> Option<Result<receipt::Receipt, rlp::DecoderError>> {
-        match self.apply_merkle_proof(proof) {
-            Some((root, data)) if root == self.receipts_root => Some(rlp::decode(&data)),
-            Some((_, _)) => None,
-            None => None,
-        }
+        mpt::check_receipt_proof(self.receipts_root, proof)
     }
 
     pub fn apply_merkle_proof(&self, proof: &[Vec<u8>]) -> Option<(H256, Vec<u8>)> {
-        let mut iter = proof.iter().rev();
-        let first_bytes = match iter.next() {
-            Some(b) => b,
-            None => return None,
-        };
-        let item_to_prove: mpt::ShortNode = rlp::decode(first_bytes).ok()?;
-
-        let final_hash: Option<[u8; 32]> =
-            iter.fold(Some(keccak_256(first_bytes)), |maybe_hash, bytes| {
-                let expected_hash = maybe_hash?;
-                let node: Box<dyn mpt::Node> = bytes.as_slice().try_into().ok()?;
-                if (*node).contains_hash(expected_hash.into()) {
-                    return Some(keccak_256(bytes))
-                }
-                None
-            });
-
-        final_hash.map(|hash| (hash.into(), item_to_prove.value))
+        mpt::apply_merkle_proof(proof)
     }
 
     pub fn mix_hash(&self) -> Option<H256> {
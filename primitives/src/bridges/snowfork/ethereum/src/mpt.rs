@@ -1,7 +1,47 @@
 //! Helper types to work with Ethereum's Merkle Patricia Trie nodes
 
+use crate::receipt::Receipt;
 use ethereum_types::H256;
-use sp_std::{convert::TryFrom, prelude::*};
+use sp_io::hashing::keccak_256;
+use sp_std::{
+    convert::{TryFrom, TryInto},
+    prelude::*,
+};
+
+/// Checks an MPT proof of a receipt against the receipts root of the block it's included in
+/// and decodes the receipt.
+pub fn check_receipt_proof(
+    receipts_root: H256,
+    proof: &[Vec<u8>],
+) -> Option<Result<Receipt, rlp::DecoderError>> {
+    match apply_merkle_proof(proof) {
+        Some((root, data)) if root == receipts_root => Some(rlp::decode(&data)),
+        Some((_, _)) => None,
+        None => None,
+    }
+}
+
+/// Walks the proof from the leaf up and returns the root it leads to along with the leaf value
+pub fn apply_merkle_proof(proof: &[Vec<u8>]) -> Option<(H256, Vec<u8>)> {
+    let mut iter = proof.iter().rev();
+    let first_bytes = match iter.next() {
+        Some(b) => b,
+        None => return None,
+    };
+    let item_to_prove: ShortNode = rlp::decode(first_bytes).ok()?;
+
+    let final_hash: Option<[u8; 32]> =
+        iter.fold(Some(keccak_256(first_bytes)), |maybe_hash, bytes| {
+            let expected_hash = maybe_hash?;
+            let node: Box<dyn Node> = bytes.as_slice().try_into().ok()?;
+            if (*node).contains_hash(expected_hash.into()) {
+                return Some(keccak_256(bytes))
+            }
+            None
+        });
+
+    final_hash.map(|hash| (hash.into(), item_to_prove.value))
+}
 
 pub trait Node {
     fn contains_hash(&self, hash: H256) -> bool;
@@ -1,9 +1,13 @@
 use codec::{Decode, Encode};
 
+use crate::common::RoundIndex;
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_runtime::RuntimeDebug;
 use sp_std::{fmt::Debug, prelude::*};
 
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Clone, Eq, PartialEq, PartialOrd, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub enum CircuitRole {
     Ambassador,
@@ -17,6 +21,7 @@ pub enum CircuitRole {
     Local,
 }
 
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
 pub enum BenefitSource {
     BootstrapPool,
@@ -27,9 +32,22 @@ pub enum BenefitSource {
 }
 
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
-pub struct ClaimableArtifacts<Account, Balance> {
+pub struct ClaimableArtifacts<Account, Balance, AssetId> {
     pub beneficiary: Account,
     pub role: CircuitRole,
     pub total_round_claim: Balance,
+    pub maybe_asset_id: Option<AssetId>,
     pub benefit_source: BenefitSource,
 }
+
+/// Balance claimable by an account in a role from a source, accumulated across rounds.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct PendingClaim<Balance, AssetId> {
+    pub role: CircuitRole,
+    pub source: BenefitSource,
+    pub maybe_asset_id: Option<AssetId>,
+    pub amount: Balance,
+    /// Round of the latest accrual - claim expires counting from it.
+    pub since_round: RoundIndex,
+}
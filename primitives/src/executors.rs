@@ -3,7 +3,10 @@ use codec::{Decode, Encode};
 use frame_support::{pallet_prelude::*, traits::LockIdentifier};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
-use sp_runtime::{traits::Zero, Percent, RuntimeDebug};
+use sp_runtime::{
+    traits::{AtLeast32BitUnsigned, Saturating, Zero},
+    Percent, RuntimeDebug,
+};
 use sp_std::{
     cmp::{Ordering, PartialOrd},
     prelude::*,
@@ -14,7 +17,7 @@ pub const STAKER_LOCK_ID: LockIdentifier = *b"stkrstkl";
 
 #[derive(Encode, Decode, RuntimeDebug, TypeInfo)]
 pub struct NominatedStake<Account, Balance> {
-    executor: Account,
+    pub executor: Account,
     pub staker: Account,
     pub nominated_stake: Balance,
 }
@@ -30,6 +33,8 @@ pub trait Executors<T: frame_system::Config, Balance> {
     fn stakes(staker: &T::AccountId) -> Vec<NominatedStake<T::AccountId, Balance>>;
     fn stakes_per_executor(executor: &T::AccountId) -> Vec<NominatedStake<T::AccountId, Balance>>;
     fn recalculate_executors_stakes();
+    fn reputation(executor: &T::AccountId) -> ExecutorReputation;
+    fn update_reputation(executor: &T::AccountId, outcome: ReputationOutcome);
 }
 
 pub struct ExecutorsMock<T> {
@@ -74,6 +79,90 @@ impl<T: frame_system::Config, Balance: Zero> Executors<T, Balance> for Executors
     }
 
     fn recalculate_executors_stakes() {}
+
+    fn reputation(_executor: &T::AccountId) -> ExecutorReputation {
+        ExecutorReputation::default()
+    }
+
+    fn update_reputation(_executor: &T::AccountId, _outcome: ReputationOutcome) {}
+}
+
+/// Outcome of executor's SFX as reported by Circuit.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum ReputationOutcome {
+    /// Executor got the won SFX confirmed before the execution deadline.
+    ConfirmedOnTime,
+    /// Executor didn't get the won SFX confirmed before the execution deadline.
+    TimedOut,
+    /// Executor's insurance and reserved bond got slashed.
+    Slashed,
+    /// Executor's confirmation was successfully challenged.
+    Misbehaved,
+}
+
+/// Track record of executor's SFX outcomes.
+/// The score of an executor without any track record is neutral and changes neither the bids
+///     nor the bond requirements.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ExecutorReputation {
+    pub confirmed_on_time: u32,
+    pub timed_out: u32,
+    pub slashed: u32,
+    pub misbehaved: u32,
+}
+
+impl ExecutorReputation {
+    pub const NEUTRAL_SCORE: Percent = Percent::from_percent(50);
+
+    pub fn note(&mut self, outcome: ReputationOutcome) {
+        match outcome {
+            ReputationOutcome::ConfirmedOnTime =>
+                self.confirmed_on_time = self.confirmed_on_time.saturating_add(1),
+            ReputationOutcome::TimedOut => self.timed_out = self.timed_out.saturating_add(1),
+            ReputationOutcome::Slashed => self.slashed = self.slashed.saturating_add(1),
+            ReputationOutcome::Misbehaved => self.misbehaved = self.misbehaved.saturating_add(1),
+        }
+    }
+
+    /// Track record of executors not backed by a bond - only the penalties count. Successes can't
+    ///     earn discounts to identities which don't risk any collateral, so that new accounts made
+    ///     up to farm reputation start with a neutral score at best.
+    pub fn penalties_only(self) -> Self {
+        Self {
+            confirmed_on_time: 0,
+            ..self
+        }
+    }
+
+    /// Share of successful outcomes, with slashing and misbehaviour penalised heavier than timeouts.
+    pub fn score(&self) -> Percent {
+        let successes = u64::from(self.confirmed_on_time).saturating_add(1);
+        let penalties = u64::from(self.timed_out)
+            .saturating_add(u64::from(self.slashed).saturating_mul(2))
+            .saturating_add(u64::from(self.misbehaved).saturating_mul(4))
+            .saturating_add(1);
+        Percent::from_rational(successes, successes.saturating_add(penalties))
+    }
+
+    /// Price of the bid as compared against the competing bids - discounted by up to 50% for
+    ///     the best track record and marked up by up to 50% for the worst one.
+    pub fn weigh_bid<Balance: AtLeast32BitUnsigned + Copy>(&self, bid: Balance) -> Balance {
+        bid.saturating_add(Self::NEUTRAL_SCORE.mul_floor(bid))
+            .saturating_sub(self.score().mul_floor(bid))
+    }
+
+    /// Bond required from the executor - scaled down by up to 50% for the above neutral score.
+    pub fn scale_bond<Balance: AtLeast32BitUnsigned + Copy>(&self, bond: Balance) -> Balance {
+        let score = self.score();
+        if score <= Self::NEUTRAL_SCORE {
+            return bond
+        }
+        Percent::one()
+            .saturating_sub(score.saturating_sub(Self::NEUTRAL_SCORE))
+            .mul_ceil(bond)
+    }
 }
 
 /// Staker's bond adjustment - used with locks.
@@ -91,6 +180,15 @@ pub enum StakerAdded<Balance> {
     ToBottom,
 }
 
+/// Staking rewards of the round, paid out with a delay in chunks of executors.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct DelayedPayout<Balance> {
+    /// Total inflation issued for the round.
+    pub round_issuance: Balance,
+    /// Inflation allocated to executors and their stakers.
+    pub total_staking_reward: Balance,
+}
+
 #[derive(Encode, Decode, RuntimeDebug, TypeInfo)]
 /// Snapshot of collator state at the start of the round for which they are selected
 pub struct ExecutorSnapshot<AccountId, Balance> {
@@ -104,6 +104,8 @@ pub enum GatewayVendor {
     Kusama,
     Rococo,
     Ethereum,
+    /// Ethereum tracked through the beacon chain's sync committees
+    EthereumBeacon,
 }
 
 impl Default for GatewayVendor {
@@ -1,3 +1,4 @@
+use codec::Decode;
 use sp_std::{vec, vec::*};
 
 pub type StrLike = Vec<u8>;
@@ -15,6 +16,10 @@ const ARGS_SEPARATOR: u8 = b',';
 const ARGS_START: u8 = b'(';
 const ARGS_END: u8 = b')';
 
+/// Marks an SFX argument to be substituted with a confirmed output of its DFD predecessor.
+///     Followed by SCALE encoded (predecessor's SFX index: u32, output param index: u32).
+pub const DFD_ARG_MARKER: [u8; 4] = *b"dfd$";
+
 // Helper functions
 
 // Checks if signature is no-empty and ends correctly
@@ -167,6 +172,49 @@ pub fn match_dfd(generic_dfd: StrLike) -> Result<Vec<Vec<StrLike>>, &'static str
     Ok(steps)
 }
 
+/// Compiles side effects DFD into steps of side effects indexes. Side effects in the deeper brackets
+///     are predecessors of the outer ones, e.g. "(2(0,1))" -> [[0, 1], [2]].
+///     Each of side_effects_cnt side effects must be scheduled exactly once.
+pub fn compile_side_effects_dfd(
+    generic_dfd: StrLike,
+    side_effects_cnt: u32,
+) -> Result<Vec<Vec<u32>>, &'static str> {
+    let mut scheduled: Vec<bool> = vec![false; side_effects_cnt as usize];
+    let mut compiled_steps: Vec<Vec<u32>> = vec![];
+
+    for step in match_dfd(generic_dfd)? {
+        let mut compiled_step: Vec<u32> = vec![];
+        for word in step {
+            let index = sp_std::str::from_utf8(&word)
+                .ok()
+                .and_then(|index| index.parse::<u32>().ok())
+                .ok_or("DFD Decoder - side effect must be referred by its index")?;
+            match scheduled.get_mut(index as usize) {
+                Some(is_scheduled) if !*is_scheduled => *is_scheduled = true,
+                Some(_) => return Err("DFD Decoder - side effect scheduled more than once"),
+                None => return Err("DFD Decoder - side effect index out of range"),
+            }
+            compiled_step.push(index);
+        }
+        compiled_steps.push(compiled_step);
+    }
+
+    ensure_str_err(
+        scheduled.iter().all(|is_scheduled| *is_scheduled),
+        "DFD Decoder - not all side effects are scheduled",
+    )?;
+
+    Ok(compiled_steps)
+}
+
+/// Returns (predecessor's SFX index, output param index) if the argument refers to DFD predecessor's output.
+pub fn match_dfd_arg(arg: &[u8]) -> Option<(u32, u32)> {
+    if arg.len() != 12 || arg[0..4] != DFD_ARG_MARKER {
+        return None
+    }
+    Decode::decode(&mut &arg[4..]).ok()
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -346,4 +394,39 @@ pub mod tests {
             ])
         )
     }
+
+    #[test]
+    fn successfully_compiles_side_effects_dfd_into_steps_of_indexes() {
+        let valid_dfd = "(2(0,1))";
+        let compile_res = compile_side_effects_dfd(valid_dfd.as_bytes().to_vec(), 3);
+        assert_eq!(compile_res, Ok(vec![vec![0, 1], vec![2]]))
+    }
+
+    #[test]
+    fn fails_to_compile_side_effects_dfd_when_not_all_side_effects_scheduled() {
+        let valid_dfd = "(1(0))";
+        let compile_res = compile_side_effects_dfd(valid_dfd.as_bytes().to_vec(), 3);
+        assert_eq!(
+            compile_res,
+            Err("DFD Decoder - not all side effects are scheduled")
+        )
+    }
+
+    #[test]
+    fn fails_to_compile_side_effects_dfd_when_side_effect_scheduled_twice() {
+        let valid_dfd = "(1(0,1))";
+        let compile_res = compile_side_effects_dfd(valid_dfd.as_bytes().to_vec(), 2);
+        assert_eq!(
+            compile_res,
+            Err("DFD Decoder - side effect scheduled more than once")
+        )
+    }
+
+    #[test]
+    fn successfully_matches_dfd_arg_referring_to_predecessor_output() {
+        let mut arg = DFD_ARG_MARKER.to_vec();
+        arg.extend(codec::Encode::encode(&(1u32, 2u32)));
+        assert_eq!(match_dfd_arg(&arg), Some((1, 2)));
+        assert_eq!(match_dfd_arg(&[1u8; 12]), None);
+    }
 }
@@ -2,8 +2,9 @@ use codec::{Decode, Encode};
 use scale_info::prelude::string::String;
 // use frame_support::dispatch::DispatchResult;
 use crate::{
-    abi::GatewayABIConfig, xdns::AllowedSideEffect, ChainId, GatewayGenesisConfig, GatewaySysProps,
-    GatewayType, GatewayVendor,
+    abi::GatewayABIConfig,
+    xdns::{AllowedSideEffect, Parachain},
+    ChainId, GatewayGenesisConfig, GatewaySysProps, GatewayType, GatewayVendor,
 };
 use scale_info::TypeInfo;
 use sp_runtime::DispatchError;
@@ -31,11 +32,136 @@ pub struct RegistrationData {
     pub encoded_registration_data: Vec<u8>,
 }
 
+/// Latest verified head of a parachain, with hash and height SCALE encoded as on the parachain
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParachainHead {
+    pub gateway_id: ChainId,
+    pub parachain: Parachain,
+    pub header_hash: Vec<u8>,
+    pub height: Vec<u8>,
+}
+
 pub type RococoBridge = ();
+pub type PolkadotBridge = frame_support::instances::Instance1;
+pub type KusamaBridge = frame_support::instances::Instance2;
+
+/// Interface of a finality verifier the portal routes a gateway vendor to, used for vendors
+/// that aren't served by one of the GRANDPA finality verifier instances.
+pub trait LightClient<T: frame_system::Config> {
+    fn initialize(
+        origin: T::Origin,
+        gateway_id: ChainId,
+        encoded_registration_data: Vec<u8>,
+    ) -> Result<(), DispatchError>;
+
+    /// Owner named by the encoded registration data of a gateway, if the data decodes
+    fn registration_owner(encoded_registration_data: &[u8]) -> Option<T::AccountId>;
+
+    fn set_owner(
+        origin: T::Origin,
+        gateway_id: ChainId,
+        encoded_new_owner: Vec<u8>,
+    ) -> Result<(), DispatchError>;
+
+    fn set_operational(
+        origin: T::Origin,
+        operational: bool,
+        gateway_id: ChainId,
+    ) -> Result<(), DispatchError>;
+
+    fn submit_headers(
+        origin: T::Origin,
+        gateway_id: ChainId,
+        encoded_header_data: Vec<u8>,
+    ) -> Result<Vec<u8>, DispatchError>;
+
+    fn get_latest_finalized_header(gateway_id: ChainId) -> Option<Vec<u8>>;
+
+    fn get_latest_finalized_height(gateway_id: ChainId) -> Option<Vec<u8>>;
+
+    /// Height of the oldest header inclusion proofs can still be verified against
+    fn get_oldest_provable_height(gateway_id: ChainId) -> Option<Vec<u8>>;
+
+    /// Confirms the inclusion of the side effect's event and decodes its params.
+    /// `security_coordinates` of the gateway's XDNS record tell the expected emitter of the event.
+    fn confirm_and_decode_payload_params(
+        gateway_id: ChainId,
+        encoded_inclusion_data: Vec<u8>,
+        submission_target_height: Vec<u8>,
+        value_abi_unsigned_type: &[u8],
+        security_coordinates: &[u8],
+        side_effect_id: [u8; 4],
+    ) -> Result<(Vec<Vec<u8>>, Vec<u8>), DispatchError>;
+}
+
+/// No light client configured for the vendor - every operation fails.
+impl<T: frame_system::Config> LightClient<T> for () {
+    fn initialize(
+        _origin: T::Origin,
+        _gateway_id: ChainId,
+        _encoded_registration_data: Vec<u8>,
+    ) -> Result<(), DispatchError> {
+        Err(DispatchError::Other("LightClient not configured"))
+    }
+
+    fn registration_owner(_encoded_registration_data: &[u8]) -> Option<T::AccountId> {
+        None
+    }
+
+    fn set_owner(
+        _origin: T::Origin,
+        _gateway_id: ChainId,
+        _encoded_new_owner: Vec<u8>,
+    ) -> Result<(), DispatchError> {
+        Err(DispatchError::Other("LightClient not configured"))
+    }
+
+    fn set_operational(
+        _origin: T::Origin,
+        _operational: bool,
+        _gateway_id: ChainId,
+    ) -> Result<(), DispatchError> {
+        Err(DispatchError::Other("LightClient not configured"))
+    }
+
+    fn submit_headers(
+        _origin: T::Origin,
+        _gateway_id: ChainId,
+        _encoded_header_data: Vec<u8>,
+    ) -> Result<Vec<u8>, DispatchError> {
+        Err(DispatchError::Other("LightClient not configured"))
+    }
+
+    fn get_latest_finalized_header(_gateway_id: ChainId) -> Option<Vec<u8>> {
+        None
+    }
+
+    fn get_latest_finalized_height(_gateway_id: ChainId) -> Option<Vec<u8>> {
+        None
+    }
+
+    fn get_oldest_provable_height(_gateway_id: ChainId) -> Option<Vec<u8>> {
+        None
+    }
+
+    fn confirm_and_decode_payload_params(
+        _gateway_id: ChainId,
+        _encoded_inclusion_data: Vec<u8>,
+        _submission_target_height: Vec<u8>,
+        _value_abi_unsigned_type: &[u8],
+        _security_coordinates: &[u8],
+        _side_effect_id: [u8; 4],
+    ) -> Result<(Vec<Vec<u8>>, Vec<u8>), DispatchError> {
+        Err(DispatchError::Other("LightClient not configured"))
+    }
+}
 
 pub trait Portal<T: frame_system::Config> {
     fn get_latest_finalized_header(chain_id: ChainId) -> Result<Option<Vec<u8>>, DispatchError>;
     fn get_latest_finalized_height(chain_id: ChainId) -> Result<Option<Vec<u8>>, DispatchError>;
+    /// Height of the oldest header side effects can still be confirmed against
+    fn get_oldest_provable_height(chain_id: ChainId) -> Result<Option<Vec<u8>>, DispatchError>;
 
     fn confirm_and_decode_payload_params(
         gateway_id: [u8; 4],
@@ -1,5 +1,6 @@
 use crate::{
     abi::{GatewayABIConfig, Type},
+    match_format::match_signature,
     side_effect::{EventSignature, SideEffectName},
     volatile::{LocalState, Volatile},
     Bytes,
@@ -84,4 +85,44 @@ pub trait SideEffectProtocol {
 
         self.populate_state(validated_args, local_state, id)
     }
+
+    /// Compares params decoded out of the confirming event against SFX arguments, matched by names
+    ///     listed in arguments 2 state mapper. Event fields prefixed with '_' are skipped.
+    /// Returns the first mismatch found as (argument name, expected, received).
+    fn find_confirmed_arg_mismatch(
+        &self,
+        encoded_args: &[Bytes],
+        confirmed_params: &[Bytes],
+    ) -> Result<Option<(Bytes, Bytes, Bytes)>, &'static str> {
+        let confirm_event = self
+            .get_confirming_events()
+            .first()
+            .cloned()
+            .ok_or("Side Effect Confirmation - no confirming event")?;
+        let (_event_name, event_args) = match_signature(confirm_event)?;
+        let mapper = self.get_arguments_2_state_mapper();
+
+        for (param_index, event_arg) in event_args.iter().enumerate() {
+            if event_arg.first() == Some(&b'_') {
+                continue
+            }
+            let expected = match mapper
+                .iter()
+                .position(|mapped_arg| mapped_arg == event_arg)
+                .and_then(|arg_index| encoded_args.get(arg_index))
+            {
+                Some(arg) => arg,
+                None => continue,
+            };
+            let received = confirmed_params
+                .get(param_index)
+                .cloned()
+                .unwrap_or_default();
+            if *expected != received {
+                return Ok(Some((event_arg.clone(), expected.clone(), received)))
+            }
+        }
+
+        Ok(None)
+    }
 }
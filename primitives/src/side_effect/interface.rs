@@ -1,5 +1,6 @@
 use crate::{
     abi::Type,
+    match_format::match_signature,
     protocol::SideEffectProtocol,
     side_effect::{EventSignature, SideEffectName},
 };
@@ -24,10 +25,59 @@ pub struct SideEffectInterface {
     pub revert_events: Vec<EventSignature>,
 }
 
+/// A custom side effect registered for a single gateway. Every upgrade bumps its version.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, Default)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct VersionedSideEffectInterface {
+    pub version: u32,
+    pub interface: SideEffectInterface,
+}
+
 impl SideEffectInterface {
     pub fn generate_id<Hasher: sp_core::Hasher>(&self) -> <Hasher as sp_core::Hasher>::Out {
         Hasher::hash(Encode::encode(self).as_ref())
     }
+
+    /// Checks the interface is consistent with its `argument_abi`, so that arguments can be
+    /// validated, mapped into the local state and confirmed against it.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        if self.argument_abi.is_empty() {
+            return Err("Side Effect Interface - no arguments")
+        }
+        if self.argument_abi.len() != self.argument_to_state_mapper.len() {
+            return Err("Side Effect Interface - every argument needs a name in the state mapper")
+        }
+        for (i, name) in self.argument_to_state_mapper.iter().enumerate() {
+            if name.is_empty() || self.argument_to_state_mapper[..i].contains(name) {
+                return Err("Side Effect Interface - argument names must be unique and non-empty")
+            }
+        }
+        // only trailing arguments can be left out when the side effect is submitted
+        if let Some(i) = self
+            .argument_abi
+            .iter()
+            .position(|type_n| *type_n == Type::OptionalInsurance)
+        {
+            if i != self.argument_abi.len() - 1 {
+                return Err("Side Effect Interface - only the last argument can be the insurance")
+            }
+        }
+
+        if self.confirm_events.is_empty() {
+            return Err("Side Effect Interface - no confirming event")
+        }
+        for event in self
+            .confirm_events
+            .iter()
+            .chain(self.escrowed_events.iter())
+            .chain(self.commit_events.iter())
+            .chain(self.revert_events.iter())
+        {
+            match_signature(event.clone())?;
+        }
+
+        Ok(())
+    }
 }
 
 impl SideEffectProtocol for SideEffectInterface {
@@ -73,3 +123,99 @@ impl SideEffectProtocol for SideEffectInterface {
         self.revert_events.clone()
     }
 }
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    fn transfer_interface() -> SideEffectInterface {
+        SideEffectInterface {
+            id: *b"tran",
+            name: b"transfer".to_vec(),
+            argument_abi: vec![],
+            argument_to_state_mapper: vec![
+                b"from".to_vec(),
+                b"to".to_vec(),
+                b"value".to_vec(),
+                b"insurance".to_vec(),
+            ],
+            confirm_events: vec![b"Transfer(_executor,to,value)".to_vec()],
+            escrowed_events: vec![],
+            commit_events: vec![],
+            revert_events: vec![],
+        }
+    }
+
+    fn custom_interface() -> SideEffectInterface {
+        SideEffectInterface {
+            id: *b"cust",
+            name: b"custom".to_vec(),
+            argument_abi: vec![Type::DynamicAddress, Type::Value, Type::OptionalInsurance],
+            argument_to_state_mapper: vec![
+                b"to".to_vec(),
+                b"value".to_vec(),
+                b"insurance".to_vec(),
+            ],
+            confirm_events: vec![b"Custom(_executor,to,value)".to_vec()],
+            escrowed_events: vec![],
+            commit_events: vec![],
+            revert_events: vec![],
+        }
+    }
+
+    #[test]
+    fn validates_interface_consistent_with_its_arguments() {
+        assert_eq!(custom_interface().validate(), Ok(()));
+    }
+
+    #[test]
+    fn rejects_interface_with_unnamed_arguments() {
+        let mut interface = custom_interface();
+        interface.argument_to_state_mapper.pop();
+        assert!(interface.validate().is_err());
+
+        let mut interface = custom_interface();
+        interface.argument_to_state_mapper[1] = b"to".to_vec();
+        assert!(interface.validate().is_err());
+    }
+
+    #[test]
+    fn rejects_interface_with_insurance_before_other_arguments() {
+        let mut interface = custom_interface();
+        interface.argument_abi.swap(1, 2);
+        assert!(interface.validate().is_err());
+    }
+
+    #[test]
+    fn rejects_interface_with_malformed_events() {
+        let mut interface = custom_interface();
+        interface.confirm_events = vec![];
+        assert!(interface.validate().is_err());
+
+        let mut interface = custom_interface();
+        interface.commit_events = vec![b"Custom(to,".to_vec()];
+        assert!(interface.validate().is_err());
+    }
+
+    #[test]
+    fn finds_no_mismatch_for_params_matching_sfx_args() {
+        let encoded_args = vec![vec![1u8; 32], vec![2u8; 32], vec![3u8; 16], vec![]];
+        let confirmed_params = vec![vec![9u8; 32], vec![2u8; 32], vec![3u8; 16]];
+
+        assert_eq!(
+            transfer_interface().find_confirmed_arg_mismatch(&encoded_args, &confirmed_params),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn finds_mismatch_for_transfer_of_wrong_value() {
+        let encoded_args = vec![vec![1u8; 32], vec![2u8; 32], vec![3u8; 16], vec![]];
+        let confirmed_params = vec![vec![9u8; 32], vec![2u8; 32], vec![4u8; 16]];
+
+        assert_eq!(
+            transfer_interface().find_confirmed_arg_mismatch(&encoded_args, &confirmed_params),
+            Ok(Some((b"value".to_vec(), vec![3u8; 16], vec![4u8; 16])))
+        );
+    }
+}
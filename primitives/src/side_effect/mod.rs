@@ -44,6 +44,7 @@ pub struct FullSideEffect<AccountId, BlockNumber, BalanceOf> {
 ///     other Optimistic Executors co-executing given Xtx with their bonded collateral (reserved_bond)
 /// Their balance
 #[derive(Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct SFXBid<AccountId, BalanceOf, AssetId> {
     /// Bid amount - always below SFX::max_fee requested by a user
     pub bid: BalanceOf,
@@ -1,6 +1,7 @@
 use crate::{
     abi::{GatewayABIConfig, Type},
     protocol::SideEffectProtocol,
+    side_effect::interface::SideEffectInterface,
     ChainId, GatewayGenesisConfig, GatewaySysProps, GatewayType, GatewayVendor,
 };
 use codec::{Decode, Encode};
@@ -27,6 +28,15 @@ pub struct Parachain {
     pub id: u32,
 }
 
+/// Liveness of a gateway, derived from the time of its latest finalized header.
+/// Gateways that didn't finalize a header within the inactivity threshold are Inactive.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum GatewayStatus {
+    Active,
+    Inactive,
+}
+
 /// The object with XdnsRecords as returned by the RPC endpoint
 #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
@@ -34,6 +44,16 @@ pub struct FetchXdnsRecordsResponse<AccountId> {
     pub xdns_records: Vec<XdnsRecord<AccountId>>,
 }
 
+/// The side effect interface as returned by the RPC endpoint.
+/// Custom interfaces come with the gateway they're stored for and their version.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct FetchSideEffectInterfaceResponse {
+    pub gateway_id: Option<ChainId>,
+    pub version: Option<u32>,
+    pub interface: SideEffectInterface,
+}
+
 /// A preliminary representation of a xdns_record in the onchain registry.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
@@ -61,16 +81,60 @@ pub struct XdnsRecord<AccountId> {
 
     pub registrant: Option<AccountId>,
 
-    /// Leave empty if there's no escrow capabilities on the remote gateway
+    /// Leave empty if there's no escrow capabilities on the remote gateway.
+    /// On Substrate gateways the first byte is the index of the Circuit pallet emitting remote orders and escrow events.
+    /// On Ethereum gateways see `ethereum_t3rn_contract` and `ethereum_transfer_token`.
     pub security_coordinates: Vec<u8>,
 
     pub last_finalized: Option<u64>,
 
     /// Methods enabled to be called on the remote target
     pub allowed_side_effects: Vec<AllowedSideEffect>,
+
+    /// Bumped with every update of the record, so that Xtx can pin the version they were validated against
+    #[cfg_attr(feature = "std", serde(default))]
+    pub version: u32,
+}
+
+/// An update of a single XdnsRecord field, as emitted once the record is updated in place
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum XdnsRecordChange {
+    Url {
+        old: Vec<u8>,
+        new: Vec<u8>,
+    },
+    GatewayAbi {
+        old: GatewayABIConfig,
+        new: GatewayABIConfig,
+    },
+    AllowedSideEffects {
+        added: Vec<AllowedSideEffect>,
+        removed: Vec<AllowedSideEffect>,
+    },
+    GatewaySysProps {
+        old: GatewaySysProps,
+        new: GatewaySysProps,
+    },
+    SecurityCoordinates {
+        old: Vec<u8>,
+        new: Vec<u8>,
+    },
 }
 
 impl<AccountId: Encode> XdnsRecord<AccountId> {
+    /// Hash of the fields side effects targeting the gateway are confirmed against
+    pub fn fingerprint(&self) -> [u8; 32] {
+        sp_io::hashing::blake2_256(
+            &(
+                &self.gateway_abi,
+                &self.gateway_sys_props,
+                &self.security_coordinates,
+            )
+                .encode(),
+        )
+    }
+
     pub fn new_from_primitives(
         url: Vec<u8>,
         gateway_abi: GatewayABIConfig,
@@ -106,6 +170,7 @@ impl<AccountId: Encode> XdnsRecord<AccountId> {
             security_coordinates,
             last_finalized,
             allowed_side_effects,
+            version: 0,
         }
     }
 
@@ -134,6 +199,7 @@ impl<AccountId: Encode> XdnsRecord<AccountId> {
             security_coordinates,
             last_finalized: None,
             allowed_side_effects,
+            version: 0,
         }
     }
 
@@ -149,6 +215,39 @@ impl<AccountId: Encode> XdnsRecord<AccountId> {
     pub fn set_last_finalized(&mut self, last_finalized: u64) {
         self.last_finalized = Some(last_finalized)
     }
+
+    /// Replaces the allowed side effects, returning the ones added and removed
+    pub fn set_allowed_side_effects(
+        &mut self,
+        allowed_side_effects: Vec<AllowedSideEffect>,
+    ) -> XdnsRecordChange {
+        let added = allowed_side_effects
+            .iter()
+            .filter(|id| !self.allowed_side_effects.contains(id))
+            .cloned()
+            .collect();
+        let removed = self
+            .allowed_side_effects
+            .iter()
+            .filter(|id| !allowed_side_effects.contains(id))
+            .cloned()
+            .collect();
+        self.allowed_side_effects = allowed_side_effects;
+        XdnsRecordChange::AllowedSideEffects { added, removed }
+    }
+}
+
+/// Address of the t3rn gateway contract emitting the logs of EVM calls,
+///     taken from the first 20 bytes of Ethereum gateways' security coordinates.
+pub fn ethereum_t3rn_contract(security_coordinates: &[u8]) -> Option<&[u8]> {
+    security_coordinates.get(0..20)
+}
+
+/// Address of the ERC-20 token contract which Transfer logs confirm transfers,
+///     taken from the 20 bytes following the t3rn gateway contract in Ethereum gateways' security coordinates.
+/// Native ether transfers emit no logs, so Ethereum gateways without one can't confirm transfers.
+pub fn ethereum_transfer_token(security_coordinates: &[u8]) -> Option<&[u8]> {
+    security_coordinates.get(20..40)
 }
 
 pub trait Xdns<T: frame_system::Config> {
@@ -173,11 +272,44 @@ pub trait Xdns<T: frame_system::Config> {
         -> BTreeMap<[u8; 4], Box<dyn SideEffectProtocol>>;
 
     fn fetch_side_effect_interface(
+        gateway_id: &ChainId,
+        id: [u8; 4],
+    ) -> Result<Box<dyn SideEffectProtocol>, DispatchError>;
+
+    /// Marks the gateway as targeted by one more pending Xtx, which blocks changes to its ABI,
+    /// allowed side effects and registration until it's unpinned
+    fn pin_gateway(gateway_id: &ChainId);
+
+    /// Releases a pin taken with `pin_gateway`
+    fn unpin_gateway(gateway_id: &ChainId);
+
+    /// Pins the current version of a custom side effect interface of the gateway, so that it stays
+    /// resolvable by `fetch_side_effect_interface_version` after upgrades until it's unpinned.
+    /// Standard side effects aren't versioned - returns None for them.
+    fn pin_side_effect_interface(gateway_id: &ChainId, id: [u8; 4]) -> Option<u32>;
+
+    /// Releases a pin taken with `pin_side_effect_interface`
+    fn unpin_side_effect_interface(gateway_id: &ChainId, id: [u8; 4], version: u32);
+
+    /// Resolves a custom side effect interface of the gateway in the given version
+    fn fetch_side_effect_interface_version(
+        gateway_id: &ChainId,
         id: [u8; 4],
+        version: u32,
     ) -> Result<Box<dyn SideEffectProtocol>, DispatchError>;
 
     fn update_gateway_ttl(gateway_id: ChainId, last_finalized: u64) -> DispatchResultWithPostInfo;
 
+    /// Marks the gateway as finalized just now, as its finality verifier imported a new header
+    fn refresh_gateway_ttl(gateway_id: &ChainId) -> DispatchResult;
+
+    fn get_gateway_status(chain_id: &ChainId) -> Result<GatewayStatus, DispatchError>;
+
+    fn get_gateway_record_version(chain_id: &ChainId) -> Result<u32, DispatchError>;
+
+    /// Fingerprint of the fields of the gateway's record side effects are confirmed against
+    fn get_gateway_record_fingerprint(chain_id: &ChainId) -> Result<[u8; 32], DispatchError>;
+
     fn get_abi(chain_id: ChainId) -> Result<GatewayABIConfig, DispatchError>;
 
     fn get_gateway_value_unsigned_type_unsafe(chain_id: &ChainId) -> Type;
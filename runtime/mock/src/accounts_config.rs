@@ -4,7 +4,8 @@ use frame_support::parameter_types;
 pub use frame_support::traits::{Imbalance, OnUnbalanced};
 use frame_system::EnsureRoot;
 use sp_core::crypto::AccountId32;
-use sp_runtime::traits::ConvertInto;
+use sp_runtime::{traits::ConvertInto, Perbill};
+use t3rn_primitives::monetary::InflationAllocation;
 
 parameter_types! {
     // TODO: update me to be better
@@ -19,13 +20,35 @@ impl pallet_account_manager::Config for Runtime {
     type Currency = Balances;
     type EscrowAccount = EscrowAccount;
     type Event = Event;
-    type Executors = t3rn_primitives::executors::ExecutorsMock<Self>;
+    type Executors = Executors;
     type Time = Timestamp;
     type WeightInfo = ();
 }
 
 pallet_account_manager::setup_currency_adapter!();
 
+parameter_types! {
+    pub const ExecutorsRewardPaymentDelay: u32 = 2;
+    pub const ExecutorsInflationPerRound: Balance = 4_000;
+    pub const ExecutorsMaxPayoutsPerBlock: u32 = 16;
+    pub ExecutorsInflationAllocation: InflationAllocation = InflationAllocation {
+        developer: Perbill::from_percent(50),
+        executor: Perbill::from_percent(50),
+    };
+}
+
+impl pallet_executors::Config for Runtime {
+    type AccountManager = AccountManager;
+    type Currency = Balances;
+    type Event = Event;
+    type InflationAllocation = ExecutorsInflationAllocation;
+    type InflationPerRound = ExecutorsInflationPerRound;
+    type MaxPayoutsPerBlock = ExecutorsMaxPayoutsPerBlock;
+    type RewardPaymentDelay = ExecutorsRewardPaymentDelay;
+    type Treasury = Clock;
+    type WeightInfo = ();
+}
+
 parameter_types! {
     pub const AssetDeposit: Balance = 0; // 1 UNIT deposit to create asset
     pub const ApprovalDeposit: Balance = 0;
@@ -6,23 +6,44 @@ use sp_core::H256;
 use t3rn_primitives::common::DEFAULT_ROUND_TERM;
 
 use crate::xbi_config::XBIPortalRuntimeEntry;
+use frame_support::traits::EnsureOrigin;
+use xcm::latest::{Junction::AccountId32 as AccountId32Junction, MultiLocation, NetworkId};
 
 impl t3rn_primitives::EscrowTrait<Runtime> for Runtime {
     type Currency = Balances;
     type Time = Timestamp;
 }
 
+parameter_types! {
+    pub const TreasuryAccount: AccountId = AccountId::new([44u8; 32]); // 0x2c2...c
+}
+
 impl pallet_clock::Config for Runtime {
     type AccountManager = AccountManager;
+    type ClaimExpiryRounds = ConstU32<100u32>;
     type Event = Event;
-    type Executors = t3rn_primitives::executors::ExecutorsMock<Self>;
+    type Executors = Executors;
+    type MaxClaimExpiriesPerBlock = ConstU32<64u32>;
     type RoundDuration = ConstU32<500u32>;
+    type TreasuryAccount = TreasuryAccount;
+    type WeightInfo = pallet_clock::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const XdnsGatewayInactivityThreshold: u64 = 60_000;
+    pub const XdnsRegistrationBond: Balance = 100;
+    pub XdnsReservedGatewayIds: Vec<t3rn_primitives::ChainId> =
+        vec![SelfGatewayId::get(), t3rn_primitives::bridges::runtime::CIRCUIT_CHAIN_ID];
 }
 
 impl pallet_xdns::Config for Runtime {
     type Balances = Balances;
+    type Currency = Balances;
     type Escrowed = Self;
     type Event = Event;
+    type GatewayInactivityThreshold = XdnsGatewayInactivityThreshold;
+    type RegistrationBond = XdnsRegistrationBond;
+    type ReservedGatewayIds = XdnsReservedGatewayIds;
     type WeightInfo = pallet_xdns::weights::SubstrateWeight<Runtime>;
 }
 
@@ -34,7 +55,10 @@ impl pallet_contracts_registry::Config for Runtime {
 }
 
 impl pallet_portal::Config for Runtime {
+    type EthereumBeaconLightClient = EthereumBeaconBridge;
+    type EthereumLightClient = EthereumBridge;
     type Event = Event;
+    type MaxParachainHeadsPerBatch = ConstU32<16u32>;
     type WeightInfo = pallet_portal::weights::SubstrateWeight<Runtime>;
     type Xdns = XDNS;
 }
@@ -49,21 +73,50 @@ impl Convert<AccountId, [u8; 32]> for AccountId32Converter {
     }
 }
 
+/// Mocks the XCM `Transact` origin by treating signed accounts as local `AccountId32` locations.
+pub struct SignedAsXcmLocation;
+impl EnsureOrigin<Origin> for SignedAsXcmLocation {
+    type Success = MultiLocation;
+
+    fn try_origin(o: Origin) -> Result<Self::Success, Origin> {
+        frame_system::EnsureSigned::<AccountId>::try_origin(o).map(|who| {
+            AccountId32Junction {
+                network: NetworkId::Any,
+                id: who.into(),
+            }
+            .into()
+        })
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn successful_origin() -> Origin {
+        frame_system::EnsureSigned::<AccountId>::successful_origin()
+    }
+}
+
 parameter_types! {
     pub const CircuitAccountId: AccountId = AccountId::new([51u8; 32]); // 0x333...3
     pub const SelfGatewayId: [u8; 4] = [3, 3, 3, 3];
+    pub const AnyNetwork: NetworkId = NetworkId::Any;
 }
 
+pub type LocationToAccountId = xcm_builder::AccountId32Aliases<AnyNetwork, AccountId>;
+
 impl pallet_circuit::Config for Runtime {
     type AccountManager = AccountManager;
     type Balances = Balances;
     type Call = Call;
+    type ChallengeWindow = ConstU32<400u32>;
     type DeletionQueueLimit = ConstU32<100u32>;
+    type EscrowFinalizationPeriod = ConstU32<400u32>;
     type Escrowed = Self;
     type Event = Event;
-    type Executors = t3rn_primitives::executors::ExecutorsMock<Self>;
+    type Executors = Executors;
+    type LocationToAccountId = LocationToAccountId;
     type Portal = Portal;
+    type SFXBidRevealPeriod = ConstU32<3u32>;
     type SFXBiddingPeriod = ConstU32<3u32>;
+    type SFXExecutionPeriod = ConstU32<20u32>;
     type SelfAccountId = CircuitAccountId;
     type SelfGatewayId = SelfGatewayId;
     type SelfParaId = ConstU32<3333u32>;
@@ -71,8 +124,8 @@ impl pallet_circuit::Config for Runtime {
     type WeightInfo = ();
     type XBIPortal = XBIPortalRuntimeEntry;
     type XBIPromise = XBIPortal;
+    type XcmOrigin = SignedAsXcmLocation;
     type Xdns = XDNS;
-    type XtxTimeoutCheckInterval = ConstU32<10u32>;
     type XtxTimeoutDefault = ConstU32<400u32>;
 }
 
@@ -81,6 +134,8 @@ parameter_types! {
 }
 
 type RococoBridgeInstance = ();
+type PolkadotBridgeInstance = pallet_grandpa_finality_verifier::Instance1;
+type KusamaBridgeInstance = pallet_grandpa_finality_verifier::Instance2;
 
 #[derive(Debug)]
 pub struct Blake2ValU32Chain;
@@ -96,3 +151,35 @@ impl pallet_grandpa_finality_verifier::Config<RococoBridgeInstance> for Runtime
     type HeadersToStore = HeadersToStore;
     type WeightInfo = ();
 }
+
+impl pallet_grandpa_finality_verifier::Config<PolkadotBridgeInstance> for Runtime {
+    type BridgedChain = Blake2ValU32Chain;
+    type HeadersToStore = HeadersToStore;
+    type WeightInfo = ();
+}
+
+impl pallet_grandpa_finality_verifier::Config<KusamaBridgeInstance> for Runtime {
+    type BridgedChain = Blake2ValU32Chain;
+    type HeadersToStore = HeadersToStore;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const EthereumHeadersToStore: u64 = 7200;
+}
+
+impl pallet_ethereum_finality_verifier::Config for Runtime {
+    type HeadersToStore = EthereumHeadersToStore;
+    type WeightInfo = pallet_ethereum_finality_verifier::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const EthereumBeaconSyncCommitteeSize: u32 = 512;
+    pub const EthereumBeaconExecutionHeadersToStore: u32 = 2048;
+}
+
+impl pallet_ethereum_beacon_finality_verifier::Config for Runtime {
+    type ExecutionHeadersToStore = EthereumBeaconExecutionHeadersToStore;
+    type SyncCommitteeSize = EthereumBeaconSyncCommitteeSize;
+    type WeightInfo = pallet_ethereum_beacon_finality_verifier::weights::SubstrateWeight<Runtime>;
+}
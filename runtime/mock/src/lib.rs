@@ -55,7 +55,7 @@ frame_support::construct_runtime!(
         ContractsRegistry: pallet_contracts_registry::{Pallet, Call, Config<T>, Storage, Event<T>} = 106,
         Circuit: pallet_circuit::{Pallet, Call, Storage, Event<T>} = 108,
         Treasury: pallet_treasury = 109,
-        Clock: pallet_clock::{Pallet, Config<T>, Storage, Event<T>} = 110,
+        Clock: pallet_clock::{Pallet, Call, Config<T>, Storage, Event<T>} = 110,
 
         XBIPortal: pallet_xbi_portal::{Pallet, Call, Storage, Event<T>} = 111,
         XBIPortalEnter: pallet_xbi_portal_enter::{Pallet, Call, Event<T>} = 112,
@@ -65,11 +65,24 @@ frame_support::construct_runtime!(
         Contracts: pallet_3vm_contracts = 120,
         Evm: pallet_3vm_evm = 121,
         AccountManager: pallet_account_manager = 125,
+        Executors: pallet_executors = 126,
         // Portal
         Portal: pallet_portal::{Pallet, Call, Storage, Event<T>} = 128,
         RococoBridge: pallet_grandpa_finality_verifier::{
             Pallet, Storage
         } = 129,
+        PolkadotBridge: pallet_grandpa_finality_verifier::<Instance1>::{
+            Pallet, Storage
+        } = 130,
+        KusamaBridge: pallet_grandpa_finality_verifier::<Instance2>::{
+            Pallet, Storage
+        } = 131,
+        EthereumBridge: pallet_ethereum_finality_verifier::{
+            Pallet, Storage
+        } = 132,
+        EthereumBeaconBridge: pallet_ethereum_beacon_finality_verifier::{
+            Pallet, Storage
+        } = 133,
     }
 );
 
@@ -266,9 +279,9 @@ impl ExtBuilder {
         .assimilate_storage(&mut t)
         .expect("Pallet xdns can be assimilated");
 
-        // pallet_executors::GenesisConfig::<Runtime>::default()
-        //     .assimilate_storage(&mut t)
-        //     .expect("mock pallet-staking genesis storage assimilation");
+        pallet_executors::GenesisConfig::<Runtime>::default()
+            .assimilate_storage(&mut t)
+            .expect("Pallet executors can be assimilated");
 
         let mut ext = sp_io::TestExternalities::new(t);
         ext.execute_with(|| System::set_block_number(1));
@@ -2,7 +2,8 @@ use super::*;
 use frame_support::parameter_types;
 use frame_system::EnsureRoot;
 use sp_core::crypto::AccountId32;
-use sp_runtime::traits::ConvertInto;
+use sp_runtime::{traits::ConvertInto, Perbill};
+use t3rn_primitives::monetary::InflationAllocation;
 
 parameter_types! {
     // TODO: update me to be better
@@ -17,13 +18,35 @@ impl pallet_account_manager::Config for Runtime {
     type Currency = Balances;
     type EscrowAccount = EscrowAccount;
     type Event = Event;
-    type Executors = t3rn_primitives::executors::ExecutorsMock<Self>;
+    type Executors = Executors;
     type Time = Timestamp;
     type WeightInfo = ();
 }
 
 pallet_account_manager::setup_currency_adapter!();
 
+parameter_types! {
+    pub const ExecutorsRewardPaymentDelay: u32 = 2;
+    pub const ExecutorsInflationPerRound: Balance = 4_000 * 1_000_000_000_000;
+    pub const ExecutorsMaxPayoutsPerBlock: u32 = 16;
+    pub ExecutorsInflationAllocation: InflationAllocation = InflationAllocation {
+        developer: Perbill::from_percent(50),
+        executor: Perbill::from_percent(50),
+    };
+}
+
+impl pallet_executors::Config for Runtime {
+    type AccountManager = AccountManager;
+    type Currency = Balances;
+    type Event = Event;
+    type InflationAllocation = ExecutorsInflationAllocation;
+    type InflationPerRound = ExecutorsInflationPerRound;
+    type MaxPayoutsPerBlock = ExecutorsMaxPayoutsPerBlock;
+    type RewardPaymentDelay = ExecutorsRewardPaymentDelay;
+    type Treasury = Clock;
+    type WeightInfo = pallet_executors::weights::TreasuryWeight<Runtime>;
+}
+
 parameter_types! {
     pub const AssetDeposit: Balance = 0; // 1 UNIT deposit to create asset
     pub const ApprovalDeposit: Balance = 0;
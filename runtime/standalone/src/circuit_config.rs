@@ -13,17 +13,36 @@ impl t3rn_primitives::EscrowTrait<Runtime> for Runtime {
     type Time = Timestamp;
 }
 
+parameter_types! {
+    pub const TreasuryAccount: AccountId = AccountId::new([44u8; 32]); // 0x2c2...c
+}
+
 impl pallet_clock::Config for Runtime {
     type AccountManager = AccountManager;
+    type ClaimExpiryRounds = ConstU32<100u32>;
     type Event = Event;
-    type Executors = t3rn_primitives::executors::ExecutorsMock<Self>;
+    type Executors = Executors;
+    type MaxClaimExpiriesPerBlock = ConstU32<64u32>;
     type RoundDuration = ConstU32<500u32>;
+    type TreasuryAccount = TreasuryAccount;
+    type WeightInfo = pallet_clock::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const XdnsGatewayInactivityThreshold: u64 = 60 * 60 * 1000;
+    pub const XdnsRegistrationBond: Balance = 100 * 1_000_000_000_000;
+    pub XdnsReservedGatewayIds: Vec<t3rn_primitives::ChainId> =
+        vec![SelfGatewayId::get(), t3rn_primitives::bridges::runtime::CIRCUIT_CHAIN_ID];
 }
 
 impl pallet_xdns::Config for Runtime {
     type Balances = Balances;
+    type Currency = Balances;
     type Escrowed = Self;
     type Event = Event;
+    type GatewayInactivityThreshold = XdnsGatewayInactivityThreshold;
+    type RegistrationBond = XdnsRegistrationBond;
+    type ReservedGatewayIds = XdnsReservedGatewayIds;
     type WeightInfo = pallet_xdns::weights::SubstrateWeight<Runtime>;
 }
 
@@ -35,7 +54,10 @@ impl pallet_contracts_registry::Config for Runtime {
 }
 
 impl pallet_portal::Config for Runtime {
+    type EthereumBeaconLightClient = EthereumBeaconBridge;
+    type EthereumLightClient = EthereumBridge;
     type Event = Event;
+    type MaxParachainHeadsPerBatch = ConstU32<16u32>;
     type WeightInfo = pallet_portal::weights::SubstrateWeight<Runtime>;
     type Xdns = XDNS;
 }
@@ -59,12 +81,18 @@ impl pallet_circuit::Config for Runtime {
     type AccountManager = AccountManager;
     type Balances = Balances;
     type Call = Call;
+    type ChallengeWindow = ConstU32<400u32>;
     type DeletionQueueLimit = ConstU32<100u32>;
+    type EscrowFinalizationPeriod = ConstU32<400u32>;
     type Escrowed = Self;
     type Event = Event;
-    type Executors = t3rn_primitives::executors::ExecutorsMock<Self>;
+    type Executors = Executors;
+    // No XCM on standalone, triggers over XCM are never accepted.
+    type LocationToAccountId = ();
     type Portal = Portal;
+    type SFXBidRevealPeriod = ConstU32<3u32>;
     type SFXBiddingPeriod = ConstU32<3u32>;
+    type SFXExecutionPeriod = ConstU32<20u32>;
     type SelfAccountId = CircuitAccountId;
     type SelfGatewayId = SelfGatewayId;
     type SelfParaId = ConstU32<3333u32>;
@@ -72,8 +100,8 @@ impl pallet_circuit::Config for Runtime {
     type WeightInfo = ();
     type XBIPortal = XBIPortalRuntimeEntry;
     type XBIPromise = XBIPortal;
+    type XcmOrigin = frame_system::EnsureNever<pallet_circuit::MultiLocation>;
     type Xdns = XDNS;
-    type XtxTimeoutCheckInterval = ConstU32<10u32>;
     type XtxTimeoutDefault = ConstU32<400u32>;
 }
 
@@ -82,6 +110,8 @@ parameter_types! {
 }
 
 type RococoBridgeInstance = ();
+type PolkadotBridgeInstance = pallet_grandpa_finality_verifier::Instance1;
+type KusamaBridgeInstance = pallet_grandpa_finality_verifier::Instance2;
 
 #[derive(Debug)]
 pub struct Blake2ValU32Chain;
@@ -97,3 +127,35 @@ impl pallet_grandpa_finality_verifier::Config<RococoBridgeInstance> for Runtime
     type HeadersToStore = HeadersToStore;
     type WeightInfo = ();
 }
+
+impl pallet_grandpa_finality_verifier::Config<PolkadotBridgeInstance> for Runtime {
+    type BridgedChain = Blake2ValU32Chain;
+    type HeadersToStore = HeadersToStore;
+    type WeightInfo = ();
+}
+
+impl pallet_grandpa_finality_verifier::Config<KusamaBridgeInstance> for Runtime {
+    type BridgedChain = Blake2ValU32Chain;
+    type HeadersToStore = HeadersToStore;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const EthereumHeadersToStore: u64 = 7200;
+}
+
+impl pallet_ethereum_finality_verifier::Config for Runtime {
+    type HeadersToStore = EthereumHeadersToStore;
+    type WeightInfo = pallet_ethereum_finality_verifier::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const EthereumBeaconSyncCommitteeSize: u32 = 512;
+    pub const EthereumBeaconExecutionHeadersToStore: u32 = 2048;
+}
+
+impl pallet_ethereum_beacon_finality_verifier::Config for Runtime {
+    type ExecutionHeadersToStore = EthereumBeaconExecutionHeadersToStore;
+    type SyncCommitteeSize = EthereumBeaconSyncCommitteeSize;
+    type WeightInfo = pallet_ethereum_beacon_finality_verifier::weights::SubstrateWeight<Runtime>;
+}
@@ -1,10 +1,16 @@
 use super::*;
 
 use pallet_3vm_evm::AddressMapping;
+use pallet_circuit_rpc_runtime_api::{SideEffectStatusResponse, XtxStatusResponse};
+use pallet_clock_rpc_runtime_api::PendingClaim;
 use pallet_grandpa::{
     fg_primitives, AuthorityId as GrandpaId, AuthorityList as GrandpaAuthorityList,
 };
-use pallet_xdns_rpc_runtime_api::{ChainId, FetchXdnsRecordsResponse, GatewayABIConfig};
+use pallet_portal_rpc_runtime_api::ParachainHead;
+use pallet_xdns_rpc_runtime_api::{
+    ChainId, FetchSideEffectInterfaceResponse, FetchXdnsRecordsResponse, GatewayABIConfig,
+    GatewayType, GatewayVendor, XdnsRecord,
+};
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata, H160, H256, U256};
@@ -292,6 +298,22 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_circuit_rpc_runtime_api::CircuitRuntimeApi<Block, AccountId, Balance, BlockNumber, Hash> for Runtime {
+        fn get_xtx_status(xtx_id: Hash) -> Option<XtxStatusResponse<AccountId, BlockNumber, Balance, Hash>> {
+            Circuit::get_xtx_status(xtx_id)
+        }
+
+        fn fetch_open_side_effects(start_after: Option<Hash>, limit: u32) -> Vec<SideEffectStatusResponse<AccountId, BlockNumber, Balance, Hash>> {
+            Circuit::fetch_open_side_effects(start_after, limit)
+        }
+    }
+
+    impl pallet_clock_rpc_runtime_api::ClockRuntimeApi<Block, AccountId, Balance> for Runtime {
+        fn fetch_pending_claims(beneficiary: AccountId) -> Vec<PendingClaim<Balance, u32>> {
+            Clock::fetch_pending_claims(beneficiary)
+        }
+    }
+
     impl pallet_xdns_rpc_runtime_api::XdnsRuntimeApi<Block, AccountId> for Runtime {
         fn fetch_records() -> FetchXdnsRecordsResponse<AccountId> {
              FetchXdnsRecordsResponse {
@@ -305,6 +327,45 @@ impl_runtime_apis! {
                 Err(_) => None,
             }
         }
+
+        fn fetch_record(chain_id: ChainId) -> Option<XdnsRecord<AccountId>> {
+            XDNS::xdns_registry(chain_id)
+        }
+
+        fn fetch_side_effect_interface(id: [u8; 4]) -> Vec<FetchSideEffectInterfaceResponse> {
+            XDNS::fetch_side_effect_interfaces(id)
+        }
+
+        fn fetch_gateways_for_side_effect(id: [u8; 4]) -> Vec<ChainId> {
+            XDNS::fetch_gateways_for_side_effect(id)
+        }
+
+        fn fetch_records_by(
+            gateway_vendor: Option<GatewayVendor>,
+            gateway_type: Option<GatewayType>,
+        ) -> FetchXdnsRecordsResponse<AccountId> {
+            FetchXdnsRecordsResponse {
+                xdns_records: XDNS::fetch_records_by(gateway_vendor, gateway_type)
+            }
+        }
+    }
+
+    impl pallet_portal_rpc_runtime_api::PortalRuntimeApi<Block, AccountId> for Runtime {
+        fn get_latest_finalized_header(gateway_id: ChainId) -> Option<Vec<u8>> {
+            <Portal as t3rn_primitives::portal::Portal<Runtime>>::get_latest_finalized_header(gateway_id)
+                .ok()
+                .flatten()
+        }
+
+        fn get_oldest_provable_height(gateway_id: ChainId) -> Option<Vec<u8>> {
+            <Portal as t3rn_primitives::portal::Portal<Runtime>>::get_oldest_provable_height(gateway_id)
+                .ok()
+                .flatten()
+        }
+
+        fn get_latest_parachain_heads() -> Vec<ParachainHead> {
+            Portal::get_latest_parachain_heads()
+        }
     }
 
     #[cfg(feature = "runtime-benchmarks")]
@@ -109,7 +109,7 @@ construct_runtime!(
         XDNS: pallet_xdns::{Pallet, Call, Config<T>, Storage, Event<T>} = 100,
         ContractsRegistry: pallet_contracts_registry::{Pallet, Call, Config<T>, Storage, Event<T>} = 106,
         Circuit: pallet_circuit::{Pallet, Call, Storage, Event<T>} = 108,
-        Clock: pallet_clock::{Pallet, Config<T>, Storage, Event<T>} = 110,
+        Clock: pallet_clock::{Pallet, Call, Config<T>, Storage, Event<T>} = 110,
 
         XBIPortal: pallet_xbi_portal::{Pallet, Call, Storage, Event<T>} = 111,
 
@@ -118,12 +118,25 @@ construct_runtime!(
         Contracts: pallet_3vm_contracts = 120,
         Evm: pallet_3vm_evm = 121,
         AccountManager: pallet_account_manager = 125,
+        Executors: pallet_executors = 126,
 
         // Portal
         Portal: pallet_portal::{Pallet, Call, Storage, Event<T>} = 128,
         RococoBridge: pallet_grandpa_finality_verifier::{
             Pallet, Storage
         } = 129,
+        PolkadotBridge: pallet_grandpa_finality_verifier::<Instance1>::{
+            Pallet, Storage
+        } = 130,
+        KusamaBridge: pallet_grandpa_finality_verifier::<Instance2>::{
+            Pallet, Storage
+        } = 131,
+        EthereumBridge: pallet_ethereum_finality_verifier::{
+            Pallet, Storage
+        } = 132,
+        EthereumBeaconBridge: pallet_ethereum_beacon_finality_verifier::{
+            Pallet, Storage
+        } = 133,
 
     }
 );
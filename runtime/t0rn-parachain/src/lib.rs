@@ -21,7 +21,13 @@ pub use circuit_runtime_types::*;
 use codec::Decode;
 use frame_system::EnsureRoot;
 use pallet_3vm_evm::AddressMapping;
-use pallet_xdns_rpc_runtime_api::{ChainId, FetchXdnsRecordsResponse, GatewayABIConfig};
+use pallet_circuit_rpc_runtime_api::{SideEffectStatusResponse, XtxStatusResponse};
+use pallet_clock_rpc_runtime_api::PendingClaim;
+use pallet_portal_rpc_runtime_api::ParachainHead;
+use pallet_xdns_rpc_runtime_api::{
+    ChainId, FetchSideEffectInterfaceResponse, FetchXdnsRecordsResponse, GatewayABIConfig,
+    GatewayType, GatewayVendor, XdnsRecord,
+};
 use sp_api::impl_runtime_apis;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata, H160, H256, U256};
 use sp_runtime::{
@@ -169,13 +175,14 @@ construct_runtime!(
         XDNS: pallet_xdns::{Pallet, Call, Config<T>, Storage, Event<T>} = 100,
         ContractsRegistry: pallet_contracts_registry::{Pallet, Call, Config<T>, Storage, Event<T>} = 106,
         Circuit: pallet_circuit::{Pallet, Call, Storage, Event<T>} = 108,
-        Clock: pallet_clock::{Pallet, Config<T>, Storage, Event<T>} = 110,
+        Clock: pallet_clock::{Pallet, Call, Config<T>, Storage, Event<T>} = 110,
 
         // 3VM
         ThreeVm: pallet_3vm = 119,
         Contracts: pallet_3vm_contracts = 120,
         Evm: pallet_3vm_evm = 121,
         AccountManager: pallet_account_manager = 125,
+        Executors: pallet_executors = 126,
 
         // XBI
         XBIPortal: pallet_xbi_portal::{Pallet, Call, Storage, Event<T>} = 111,
@@ -185,6 +192,18 @@ construct_runtime!(
         RococoBridge: pallet_grandpa_finality_verifier::{
             Pallet, Storage
         } = 129,
+        PolkadotBridge: pallet_grandpa_finality_verifier::<Instance1>::{
+            Pallet, Storage
+        } = 130,
+        KusamaBridge: pallet_grandpa_finality_verifier::<Instance2>::{
+            Pallet, Storage
+        } = 131,
+        EthereumBridge: pallet_ethereum_finality_verifier::{
+            Pallet, Storage
+        } = 132,
+        EthereumBeaconBridge: pallet_ethereum_beacon_finality_verifier::{
+            Pallet, Storage
+        } = 133,
 
         // Util - this should be system support
         RandomnessCollectiveFlip: pallet_randomness_collective_flip::{Pallet, Storage} = 200,
@@ -208,6 +227,8 @@ mod benches {
         [pallet_timestamp, Timestamp]
         [pallet_collator_selection, CollatorSelection]
         [pallet_account_manager, AccountManager]
+        [pallet_executors, Executors]
+        [pallet_circuit, Circuit]
     );
 }
 
@@ -388,6 +409,22 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_circuit_rpc_runtime_api::CircuitRuntimeApi<Block, AccountId, Balance, BlockNumber, Hash> for Runtime {
+        fn get_xtx_status(xtx_id: Hash) -> Option<XtxStatusResponse<AccountId, BlockNumber, Balance, Hash>> {
+            Circuit::get_xtx_status(xtx_id)
+        }
+
+        fn fetch_open_side_effects(start_after: Option<Hash>, limit: u32) -> Vec<SideEffectStatusResponse<AccountId, BlockNumber, Balance, Hash>> {
+            Circuit::fetch_open_side_effects(start_after, limit)
+        }
+    }
+
+    impl pallet_clock_rpc_runtime_api::ClockRuntimeApi<Block, AccountId, Balance> for Runtime {
+        fn fetch_pending_claims(beneficiary: AccountId) -> Vec<PendingClaim<Balance, u32>> {
+            Clock::fetch_pending_claims(beneficiary)
+        }
+    }
+
     impl pallet_xdns_rpc_runtime_api::XdnsRuntimeApi<Block, AccountId> for Runtime {
         fn fetch_records() -> FetchXdnsRecordsResponse<AccountId> {
              FetchXdnsRecordsResponse {
@@ -401,6 +438,45 @@ impl_runtime_apis! {
                 Err(_) => None,
             }
         }
+
+        fn fetch_record(chain_id: ChainId) -> Option<XdnsRecord<AccountId>> {
+            XDNS::xdns_registry(chain_id)
+        }
+
+        fn fetch_side_effect_interface(id: [u8; 4]) -> Vec<FetchSideEffectInterfaceResponse> {
+            XDNS::fetch_side_effect_interfaces(id)
+        }
+
+        fn fetch_gateways_for_side_effect(id: [u8; 4]) -> Vec<ChainId> {
+            XDNS::fetch_gateways_for_side_effect(id)
+        }
+
+        fn fetch_records_by(
+            gateway_vendor: Option<GatewayVendor>,
+            gateway_type: Option<GatewayType>,
+        ) -> FetchXdnsRecordsResponse<AccountId> {
+            FetchXdnsRecordsResponse {
+                xdns_records: XDNS::fetch_records_by(gateway_vendor, gateway_type)
+            }
+        }
+    }
+
+    impl pallet_portal_rpc_runtime_api::PortalRuntimeApi<Block, AccountId> for Runtime {
+        fn get_latest_finalized_header(gateway_id: ChainId) -> Option<Vec<u8>> {
+            <Portal as t3rn_primitives::portal::Portal<Runtime>>::get_latest_finalized_header(gateway_id)
+                .ok()
+                .flatten()
+        }
+
+        fn get_oldest_provable_height(gateway_id: ChainId) -> Option<Vec<u8>> {
+            <Portal as t3rn_primitives::portal::Portal<Runtime>>::get_oldest_provable_height(gateway_id)
+                .ok()
+                .flatten()
+        }
+
+        fn get_latest_parachain_heads() -> Vec<ParachainHead> {
+            Portal::get_latest_parachain_heads()
+        }
     }
 
     impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {